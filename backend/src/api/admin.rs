@@ -1,48 +1,252 @@
-use axum::{extract::State, Json};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
 
-use crate::{app::AppState, auth, error::AppResult, model};
-use crate::repo::events::{self as repo_events, NewEvent};
+use crate::{
+    api::payload::ValidJson,
+    app::AppState,
+    auth::{self, AdminContext, SessionMeta},
+    error::{AppError, AppResult},
+    model,
+    ops::event_catalog::{Event, EventCode},
+    service,
+    util::client_ip::ClientIp,
+};
 
 pub async fn login(
     State(state): State<AppState>,
-    Json(payload): Json<model::AdminLoginPayload>,
+    client: ClientIp,
+    headers: HeaderMap,
+    ValidJson(payload): ValidJson<model::AdminLoginPayload>,
 ) -> AppResult<Json<model::AdminLoginResponse>> {
-    if !state
+    let Some(account) = state
         .admin
-        .verify_credentials(&payload.username, &payload.password)
-    {
+        .authenticate(&payload.username, &payload.password)
+        .await?
+    else {
+        tracing::warn!(client_ip = %client, "admin login rejected");
         return Err(auth::invalid_credentials_error());
+    };
+    let response = start_session(&state, &client, &headers, account, "password").await?;
+    Ok(Json(response))
+}
+
+/// 单点登录入口：把本次登录的 state 与 PKCE verifier 写入 Cookie 后跳转到身份提供方的授权页
+pub async fn oidc_login(State(state): State<AppState>) -> AppResult<Response> {
+    let oidc = state
+        .oidc
+        .as_ref()
+        .ok_or_else(|| AppError::BadRequest("未配置单点登录（admin.oidc）".to_string()))?;
+    let request = oidc.authorization_url().await?;
+    Ok(found(request.url, request.cookie))
+}
+
+/// 身份提供方回调：登录成功后带着会话跳回前端后台页，结果放在 URL 片段中（`#token=...`，失败时为 `#oidc_error=...`）
+pub async fn oidc_callback(
+    State(state): State<AppState>,
+    client: ClientIp,
+    headers: HeaderMap,
+    Query(query): Query<model::OidcCallbackQuery>,
+) -> AppResult<Response> {
+    let oidc = state
+        .oidc
+        .clone()
+        .ok_or_else(|| AppError::BadRequest("未配置单点登录（admin.oidc）".to_string()))?;
+    let result = complete_oidc(&state, &oidc, &client, &headers, query).await;
+    if let Err(err) = &result {
+        tracing::warn!(client_ip = %client, error = %err, "admin oidc login rejected");
     }
+    Ok(found(
+        format!("{}#{}", oidc.post_login_redirect(), oidc_fragment(result)),
+        oidc.clear_cookie(),
+    ))
+}
 
-    let token = state.admin.issue_session().await;
+// 回调结果写入 URL 片段：成功时为会话信息，失败时为可展示给用户的原因
+fn oidc_fragment(result: AppResult<model::AdminLoginResponse>) -> String {
+    let mut fragment = form_urlencoded::Serializer::new(String::new());
+    match result {
+        Ok(response) => {
+            fragment
+                .append_pair("token", &response.token)
+                .append_pair("expires_in", &response.expires_in.to_string())
+                .append_pair("username", &response.username)
+                .append_pair("role", &response.role);
+        }
+        Err(err) => {
+            let message = match err {
+                AppError::BadRequest(msg)
+                | AppError::Unauthorized(msg)
+                | AppError::Forbidden(msg)
+                | AppError::Unavailable(msg) => msg,
+                _ => "单点登录失败，请稍后重试".to_string(),
+            };
+            fragment.append_pair("oidc_error", &message);
+        }
+    }
+    fragment.finish()
+}
+
+async fn complete_oidc(
+    state: &AppState,
+    oidc: &service::oidc::Oidc,
+    client: &ClientIp,
+    headers: &HeaderMap,
+    query: model::OidcCallbackQuery,
+) -> AppResult<model::AdminLoginResponse> {
+    if let Some(error) = query.error {
+        return Err(AppError::Unauthorized(format!(
+            "身份提供方拒绝了登录：{}",
+            query.error_description.unwrap_or(error)
+        )));
+    }
+    let (Some(code), Some(oidc_state)) = (query.code, query.state) else {
+        return Err(AppError::BadRequest("回调缺少 code 或 state".to_string()));
+    };
+    let account = oidc
+        .complete(
+            &state.pool,
+            state.admin.builtin_username(),
+            &code,
+            &oidc_state,
+            cookie_value(headers, service::oidc::LOGIN_COOKIE),
+        )
+        .await?;
+    start_session(state, client, headers, account, "oidc").await
+}
+
+// 密码登录与单点登录共用：签发会话并记录登录事件
+async fn start_session(
+    state: &AppState,
+    client: &ClientIp,
+    headers: &HeaderMap,
+    account: auth::Account,
+    method: &str,
+) -> AppResult<model::AdminLoginResponse> {
+    let user_agent = headers
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.chars().take(256).collect::<String>());
+    let issued = state
+        .admin
+        .issue_session(
+            &account.username,
+            SessionMeta {
+                client_ip: client.0.map(|ip| ip.to_string()),
+                user_agent,
+            },
+        )
+        .await?;
+
+    if issued.evicted > 0 {
+        Event::new(EventCode::AdminSessionEvicted)
+            .attr("evicted", issued.evicted)
+            .attr("client_ip", client.to_string())
+            .attr("username", account.username.as_str())
+            .detail(format!(
+                "账号 {} 超出同时登录会话上限，已注销最早的 {} 个会话（新登录来源 IP：{client}）",
+                account.username, issued.evicted
+            ))
+            .emit(&state.pool)
+            .await;
+    }
 
-    // Record a simple admin login event (no source_domain)
-    let _ = repo_events::upsert_event(
-        &state.pool,
-        &NewEvent { level: "info".to_string(), code: "ADMIN_LOGIN".to_string(), addition_info: None },
-        0,
-    ).await;
+    Event::new(EventCode::AdminLogin)
+        .attr("client_ip", client.to_string())
+        .attr("username", account.username.as_str())
+        .attr("role", account.role.as_str())
+        .attr("method", method)
+        .detail(format!("账号 {}，来源 IP：{client}", account.username))
+        .emit(&state.pool)
+        .await;
 
-    Ok(Json(model::AdminLoginResponse {
-        token,
+    Ok(model::AdminLoginResponse {
+        token: issued.token,
         expires_in: state.admin.ttl_secs(),
-    }))
+        username: account.username,
+        role: account.role.as_str().to_string(),
+    })
+}
+
+fn cookie_value<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
+
+fn found(location: String, cookie: String) -> Response {
+    (
+        StatusCode::FOUND,
+        [
+            (header::LOCATION, location),
+            (header::SET_COOKIE, cookie),
+            (header::CACHE_CONTROL, "no-store".to_string()),
+        ],
+    )
+        .into_response()
 }
 
 pub async fn logout(
     State(state): State<AppState>,
-    Json(payload): Json<model::AdminLogoutPayload>,
+    client: ClientIp,
+    ValidJson(payload): ValidJson<model::AdminLogoutPayload>,
 ) -> AppResult<Json<serde_json::Value>> {
-    state.admin.revoke_session(&payload.token).await;
-    // Record a manual logout event
-    let _ = repo_events::upsert_event(
-        &state.pool,
-        &NewEvent {
-            level: "info".to_string(),
-            code: "ADMIN_LOGOUT".to_string(),
-            addition_info: Some("主动登出".to_string()),
-        },
-        0,
-    ).await;
+    state.admin.revoke_session(&payload.token).await?;
+    Event::new(EventCode::AdminLogout)
+        .attr("reason", "manual")
+        .attr("client_ip", client.to_string())
+        .detail(format!("主动登出，来源 IP：{client}"))
+        .emit(&state.pool)
+        .await;
     Ok(Json(serde_json::json!({ "ok": true })))
 }
+
+pub async fn list_sessions(
+    State(state): State<AppState>,
+    admin: AdminContext,
+) -> AppResult<Json<Vec<model::AdminSessionOut>>> {
+    let sessions = service::sessions::list(&state.pool, admin.session_id).await?;
+    Ok(Json(sessions))
+}
+
+pub async fn revoke_session(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<model::AdminSessionRevokeOut>> {
+    let result = service::sessions::revoke(&state.pool, id).await?;
+    Event::new(EventCode::AdminSessionRevoked)
+        .attr("revoked", result.revoked)
+        .attr("session_id", id)
+        .detail(format!("会话 {id} 已被强制下线"))
+        .emit(&state.pool)
+        .await;
+    Ok(Json(result))
+}
+
+pub async fn revoke_other_sessions(
+    State(state): State<AppState>,
+    admin: AdminContext,
+) -> AppResult<Json<model::AdminSessionRevokeOut>> {
+    let result =
+        service::sessions::revoke_others(&state.pool, &admin.username, admin.session_id).await?;
+    if result.revoked > 0 {
+        Event::new(EventCode::AdminSessionRevoked)
+            .attr("revoked", result.revoked)
+            .attr("username", admin.username.as_str())
+            .detail(format!(
+                "账号 {} 已强制下线其他 {} 个会话",
+                admin.username, result.revoked
+            ))
+            .emit(&state.pool)
+            .await;
+    }
+    Ok(Json(result))
+}
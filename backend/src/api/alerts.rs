@@ -1,8 +1,13 @@
 use axum::{extract::State, response::IntoResponse, Json};
 use axum::response::sse::Sse;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::{app::AppState, ops::events as ops_events, repo::events as repo_events};
+use crate::{
+    app::AppState,
+    error::{AppError, AppResult},
+    ops::events as ops_events,
+    repo::events as repo_events,
+};
 
 #[derive(Deserialize)]
 pub struct ListQuery {
@@ -14,10 +19,34 @@ pub struct ListQuery {
     #[serde(default)]
     to: Option<chrono::DateTime<chrono::Utc>>,
     since_id: Option<i64>,
+    before_id: Option<i64>,
+    q: Option<String>,
+    /// 目前只支持 `code`：额外返回按事件码分组的计数
+    group_by: Option<String>,
     limit: Option<i64>,
 }
 
-pub async fn list_alerts(State(state): State<AppState>, axum::extract::Query(q): axum::extract::Query<ListQuery>) -> impl IntoResponse {
+#[derive(Serialize)]
+pub struct ListResp {
+    /// 按 id 倒序（即时间倒序）
+    items: Vec<repo_events::EventRecord>,
+    /// 本页取满时给出，作为 `before_id` 传回即取下一页（更早的事件）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_before_id: Option<i64>,
+    /// `group_by=code` 时返回，统计口径为全部筛选条件命中的事件（不受游标与 limit 影响）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    groups: Option<Vec<repo_events::EventCodeCount>>,
+}
+
+pub async fn list_alerts(State(state): State<AppState>, axum::extract::Query(q): axum::extract::Query<ListQuery>) -> AppResult<Json<ListResp>> {
+    let group_by_code = match q.group_by.as_deref().map(str::trim) {
+        None | Some("") => false,
+        Some("code") => true,
+        Some(other) => {
+            return Err(AppError::BadRequest(format!("unsupported group_by: {other}, expected code")));
+        }
+    };
+    let limit = q.limit.unwrap_or(50).clamp(1, 200);
     let params = repo_events::ListParams {
         level: q.level,
         code: q.code,
@@ -25,12 +54,96 @@ pub async fn list_alerts(State(state): State<AppState>, axum::extract::Query(q):
         from: q.from,
         to: q.to,
         since_id: q.since_id,
-        limit: q.limit,
+        before_id: q.before_id,
+        q: q.q.map(|text| text.trim().to_string()).filter(|text| !text.is_empty()),
+        limit: Some(limit),
+    };
+
+    let items = repo_events::list_events(&state.pool, &params).await?;
+    let next_before_id = (items.len() as i64 == limit)
+        .then(|| items.last().map(|ev| ev.id))
+        .flatten();
+    let groups = if group_by_code {
+        Some(repo_events::count_by_code(&state.pool, &params).await?)
+    } else {
+        None
     };
-    match repo_events::list_events(&state.pool, &params).await {
-        Ok(items) => Json(items).into_response(),
-        Err(err) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    Ok(Json(ListResp {
+        items,
+        next_before_id,
+        groups,
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct ExportQuery {
+    level: Option<String>,
+    code: Option<String>,
+    source: Option<String>,
+    #[serde(default)]
+    from: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    to: Option<chrono::DateTime<chrono::Utc>>,
+    since_id: Option<i64>,
+    q: Option<String>,
+    format: Option<String>,
+}
+
+// 导出时每批读取的事件数量
+const EXPORT_BATCH_SIZE: i64 = 500;
+
+/// 以 NDJSON 流式导出事件（每行一个 JSON），筛选条件与列表接口一致，按 id 升序分批读取。
+pub async fn export_alerts(State(state): State<AppState>, axum::extract::Query(q): axum::extract::Query<ExportQuery>) -> axum::response::Response {
+    let format = q.format.as_deref().unwrap_or("ndjson");
+    if !format.eq_ignore_ascii_case("ndjson") {
+        return (axum::http::StatusCode::BAD_REQUEST, format!("unsupported export format: {format}")).into_response();
     }
+
+    let params = repo_events::ListParams {
+        level: q.level,
+        code: q.code,
+        source: q.source,
+        from: q.from,
+        to: q.to,
+        since_id: q.since_id,
+        before_id: None,
+        q: q.q.map(|text| text.trim().to_string()).filter(|text| !text.is_empty()),
+        limit: None,
+    };
+    let pool = state.pool.clone();
+
+    let stream = futures::stream::try_unfold(
+        (pool, params, None::<i64>, false),
+        |(pool, params, after_id, done)| async move {
+            if done {
+                return Ok::<_, sqlx::Error>(None);
+            }
+            let batch = repo_events::export_batch(&pool, &params, after_id, EXPORT_BATCH_SIZE)
+                .await
+                .inspect_err(|err| tracing::warn!(error = ?err, "event export aborted"))?;
+            if batch.is_empty() {
+                return Ok(None);
+            }
+            let last_id = batch.last().map(|ev| ev.id);
+            let done = (batch.len() as i64) < EXPORT_BATCH_SIZE;
+            let mut chunk = Vec::new();
+            for ev in &batch {
+                if serde_json::to_writer(&mut chunk, ev).is_ok() {
+                    chunk.push(b'\n');
+                }
+            }
+            Ok(Some((axum::body::Bytes::from(chunk), (pool, params, last_id, done))))
+        },
+    );
+
+    (
+        [
+            (axum::http::header::CONTENT_TYPE, "application/x-ndjson"),
+            (axum::http::header::CONTENT_DISPOSITION, "attachment; filename=\"events.ndjson\""),
+        ],
+        axum::body::Body::from_stream(stream),
+    )
+        .into_response()
 }
 
 pub async fn stream_alerts(State(state): State<AppState>) -> Sse<impl futures::Stream<Item = Result<axum::response::sse::Event, std::convert::Infallible>>> {
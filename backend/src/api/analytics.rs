@@ -0,0 +1,19 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+
+use crate::{
+    app::AppState,
+    error::AppResult,
+    model::{DedupAnalyticsOut, DedupAnalyticsQuery},
+    service,
+};
+
+pub async fn dedup(
+    State(state): State<AppState>,
+    Query(query): Query<DedupAnalyticsQuery>,
+) -> AppResult<Json<DedupAnalyticsOut>> {
+    let result = service::analytics::dedup(&state.pool, query).await?;
+    Ok(Json(result))
+}
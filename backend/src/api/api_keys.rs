@@ -0,0 +1,36 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+
+use crate::{
+    api::payload::ValidJson,
+    app::AppState,
+    auth::AdminContext,
+    error::AppResult,
+    model::{ApiKeyCreatePayload, ApiKeyCreatedOut, ApiKeyOut},
+    service,
+};
+
+pub async fn list_api_keys(State(state): State<AppState>) -> AppResult<Json<Vec<ApiKeyOut>>> {
+    let keys = service::api_keys::list(&state.pool).await?;
+    Ok(Json(keys))
+}
+
+pub async fn create_api_key(
+    State(state): State<AppState>,
+    admin: AdminContext,
+    ValidJson(payload): ValidJson<ApiKeyCreatePayload>,
+) -> AppResult<Json<ApiKeyCreatedOut>> {
+    let created = service::api_keys::create(&state.pool, &admin.username, payload).await?;
+    Ok(Json(created))
+}
+
+pub async fn revoke_api_key(
+    State(state): State<AppState>,
+    admin: AdminContext,
+    Path(id): Path<i64>,
+) -> AppResult<Json<ApiKeyOut>> {
+    let revoked = service::api_keys::revoke(&state.pool, &admin.username, id).await?;
+    Ok(Json(revoked))
+}
@@ -1,43 +1,446 @@
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
+    extract::{Path, Query, RawQuery, State},
+    http::{header, HeaderMap, StatusCode, Uri},
+    response::{IntoResponse, Response},
     Json,
 };
 use serde::Deserialize;
 
 use crate::{
-    app::AppState,
-    error::AppResult,
-    model::{ArticleListQuery, ArticleOut, PageResp},
+    api::payload::{OptionalJson, ValidJson},
+    app::{AppState, FEATURED_CACHE_TTL},
+    auth::AdminContext,
+    error::{AppError, AppResult},
+    model::{
+        ArchiveQuery, ArchiveResp, ArticleAttrsOut, ArticleClusterOut, ClusterListQuery, ClusterOut, PageResp, PublicArticleSourceOut, ArticleChangesOut, ArticleContentOut, ArticleReaderOut, ArticleChangesQuery, ArticleApprovePayload, ArticleApproveResult, ArticleBookmarkPayload, ArticleBulkPayload, ArticleBulkResult, ArticleFeedQuery, ArticleHidePayload, ArticleListQuery, ArticleOut, ArticleReadPayload, ArticleSourceOut, ArticleStateOut, ArticleTranslateQuery, ArticleTranslationOut, ArticleUpdatePayload, ModerationLogOut, PendingArticleOut, ReviewQueueQuery, SearchQuery,
+        SearchResp, SimilarArticlesOut, SimilarArticlesQuery,
+    },
+    repo::articles::ArticleSort,
     service,
+    util::{cache::CachedJson, client_ip::ClientIp, reader_id::ReaderId},
 };
 
+// 不带筛选条件的首页（默认排序或 sort=rank）是最常见的公开请求：命中内存缓存时不访问数据库
 pub async fn list_articles(
     State(state): State<AppState>,
+    reader: ReaderId,
     Query(query): Query<ArticleListQuery>,
-) -> AppResult<Json<PageResp<ArticleOut>>> {
-    let page = service::articles::list(&state.pool, query).await?;
-    Ok(Json(page))
+) -> AppResult<Response> {
+    let cache_key = first_page_cache_key(&query);
+    if let Some(cached) = cache_key.and_then(|key| state.latest_cache.get(&key)) {
+        return Ok(json_body(cached));
+    }
+
+    let page = service::articles::list(&state.pool, query, reader.0.as_deref()).await?;
+    let Some(key) = cache_key else {
+        return Ok(Json(page).into_response());
+    };
+    let body = serde_json::to_vec(&page).map_err(|err| AppError::Internal(err.into()))?;
+    let cached = CachedJson::new(body);
+    state.latest_cache.insert(key, cached.clone());
+    Ok(json_body(cached))
+}
+
+/// Atom 输出；feed 自身地址按站点地址、请求路径（公开或 `/shared` 签名链接）与原始查询参数拼出，不依赖请求的 Host 头
+pub async fn articles_feed(
+    State(state): State<AppState>,
+    uri: Uri,
+    RawQuery(raw_query): RawQuery,
+    Query(query): Query<ArticleFeedQuery>,
+) -> AppResult<Response> {
+    let site_url = state.config.site_base_url();
+    let self_url = feed_self_url(&site_url, &uri, raw_query);
+    let xml = service::articles::atom(&state.pool, query, &site_url, &self_url).await?;
+    Ok((
+        [(header::CONTENT_TYPE, "application/atom+xml; charset=utf-8")],
+        xml,
+    )
+        .into_response())
+}
+
+/// RSS 2.0 输出，筛选参数与 Atom 输出相同
+pub async fn articles_rss(
+    State(state): State<AppState>,
+    uri: Uri,
+    RawQuery(raw_query): RawQuery,
+    Query(query): Query<ArticleFeedQuery>,
+) -> AppResult<Response> {
+    let site_url = state.config.site_base_url();
+    let self_url = feed_self_url(&site_url, &uri, raw_query);
+    let xml = service::articles::rss(&state.pool, query, &site_url, &self_url).await?;
+    Ok((
+        [(header::CONTENT_TYPE, "application/rss+xml; charset=utf-8")],
+        xml,
+    )
+        .into_response())
+}
+
+fn feed_self_url(site_url: &str, uri: &Uri, raw_query: Option<String>) -> String {
+    let mut self_url = format!("{site_url}/api{}", uri.path());
+    if let Some(raw) = raw_query.filter(|raw| !raw.is_empty()) {
+        self_url.push('?');
+        self_url.push_str(&raw);
+    }
+    self_url
+}
+
+fn first_page_cache_key(query: &ArticleListQuery) -> Option<(ArticleSort, u32)> {
+    let sort = match query.sort.as_deref() {
+        None => ArticleSort::default(),
+        Some("rank") => ArticleSort::Rank,
+        Some(_) => return None,
+    };
+    let unfiltered = query.page <= 1
+        && query.from.is_none()
+        && query.to.is_none()
+        && query.keyword.is_none()
+        && query.original_language.is_none()
+        && query.feed_tag.is_none()
+        && query.source_domain.is_none()
+        && query.q.is_none()
+        && query.count.is_none()
+        && query.after_published_at.is_none()
+        && query.after_id.is_none()
+        && query.order.is_none()
+        && query.read.is_none()
+        && query.bookmarked.is_none();
+    unfiltered.then(|| (sort, query.page_size.clamp(1, 50)))
+}
+
+fn json_body(cached: CachedJson) -> Response {
+    ([(header::CONTENT_TYPE, "application/json")], cached.body).into_response()
+}
+
+// 文章可见性变化（隐藏、归并、恢复）后清空公开接口缓存
+pub fn invalidate_article_caches(state: &AppState) {
+    state.featured_cache.clear();
+    state.latest_cache.clear();
+}
+
+pub async fn list_changes(
+    State(state): State<AppState>,
+    Query(query): Query<ArticleChangesQuery>,
+) -> AppResult<Json<ArticleChangesOut>> {
+    let changes = service::articles::changes(&state.pool, query).await?;
+    Ok(Json(changes))
+}
+
+pub async fn search_articles(
+    State(state): State<AppState>,
+    Query(query): Query<SearchQuery>,
+) -> AppResult<Json<SearchResp>> {
+    let resp = service::articles::search(&state.pool, query).await?;
+    Ok(Json(resp))
+}
+
+pub async fn archive(
+    State(state): State<AppState>,
+    Query(query): Query<ArchiveQuery>,
+) -> AppResult<Json<ArchiveResp>> {
+    let resp = service::articles::archive(&state.pool, query).await?;
+    Ok(Json(resp))
 }
 
 #[derive(Debug, Deserialize)]
 pub struct FeaturedQuery {
     pub limit: Option<i64>,
+    /// 统计窗口（小时），默认 24，上限 168
+    pub window_hours: Option<i32>,
+    /// 每个来源最多入选的篇数，默认不限制
+    pub max_per_source: Option<i64>,
 }
 
+// 热门文章每个访客都会请求且查询需扫描窗口内的数据：响应在内存中缓存一小段时间，
+// 并带上 Cache-Control / ETag，客户端可用 If-None-Match 做条件请求
 pub async fn list_featured(
     State(state): State<AppState>,
     Query(query): Query<FeaturedQuery>,
-) -> AppResult<Json<Vec<ArticleOut>>> {
-    let limit = query.limit.unwrap_or(10).clamp(1, 100);
-    let articles = service::articles::list_featured(&state.pool, limit).await?;
-    Ok(Json(articles))
+    headers: HeaderMap,
+) -> AppResult<Response> {
+    let args = service::articles::featured_args(query.limit, query.window_hours, query.max_per_source)?;
+    let cached = match state.featured_cache.get(&args) {
+        Some(cached) => cached,
+        None => {
+            let articles = service::articles::list_featured(&state.pool, args).await?;
+            let body = serde_json::to_vec(&articles).map_err(|err| AppError::Internal(err.into()))?;
+            let cached = CachedJson::new(body);
+            state.featured_cache.insert(args, cached.clone());
+            cached
+        }
+    };
+
+    let cache_control = format!("public, max-age={}", FEATURED_CACHE_TTL.as_secs());
+    let not_modified = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| cached.matches(value));
+    if not_modified {
+        return Ok((
+            StatusCode::NOT_MODIFIED,
+            [(header::ETAG, cached.etag), (header::CACHE_CONTROL, cache_control)],
+        )
+            .into_response());
+    }
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (header::ETAG, cached.etag),
+            (header::CACHE_CONTROL, cache_control),
+        ],
+        cached.body,
+    )
+        .into_response())
 }
 
 pub async fn record_click(
     State(state): State<AppState>,
     Path(id): Path<i64>,
+    client: ClientIp,
 ) -> AppResult<StatusCode> {
-    service::articles::record_click(&state.pool, id).await?;
+    count_click(&state, &client, id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// 标记已读；body 可省略（即 `{"read": true}`）
+pub async fn mark_read(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    reader: ReaderId,
+    OptionalJson(payload): OptionalJson<ArticleReadPayload>,
+) -> AppResult<Json<ArticleStateOut>> {
+    let read = payload.is_none_or(|payload| payload.read);
+    let out = service::articles::set_read(&state.pool, reader.require()?, id, read).await?;
+    Ok(Json(out))
+}
+
+/// 加入稍后阅读；body 可省略（即 `{"bookmarked": true}`）
+pub async fn mark_bookmarked(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    reader: ReaderId,
+    OptionalJson(payload): OptionalJson<ArticleBookmarkPayload>,
+) -> AppResult<Json<ArticleStateOut>> {
+    let bookmarked = payload.is_none_or(|payload| payload.bookmarked);
+    let out = service::articles::set_bookmarked(&state.pool, reader.require()?, id, bookmarked).await?;
+    Ok(Json(out))
+}
+
+pub async fn translate_article(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Query(query): Query<ArticleTranslateQuery>,
+) -> AppResult<Json<ArticleTranslationOut>> {
+    let out = service::translations::translate(
+        &state.pool,
+        &state.translator,
+        id,
+        query.lang.as_deref(),
+    )
+    .await?;
+    Ok(Json(out))
+}
+
+// 服务端计数后跳转原文：不依赖前端单独发送点击请求，Referrer-Policy 由设置统一控制
+pub async fn redirect_to_article(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    client: ClientIp,
+) -> AppResult<Response> {
+    let target = service::articles::click_redirect(&state.pool, id).await?;
+    count_click(&state, &client, target.canonical_id).await?;
+    Ok((
+        StatusCode::FOUND,
+        [
+            (header::LOCATION, target.url),
+            (header::REFERRER_POLICY, target.referrer_policy),
+            (header::CACHE_CONTROL, "no-store".to_string()),
+        ],
+    )
+        .into_response())
+}
+
+// 同一客户端对同一文章的重复点击在去重窗口内只计一次；无法识别客户端时不做去重，照常计数
+async fn count_click(state: &AppState, client: &ClientIp, id: i64) -> AppResult<()> {
+    if let Some(ip) = client.0 {
+        if state.click_dedup.get(&(ip, id)).is_some() {
+            return Ok(());
+        }
+        state.click_dedup.insert((ip, id), ());
+    }
+    service::articles::record_click(&state.pool, id).await
+}
+
+pub async fn list_article_sources(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Vec<ArticleSourceOut>>> {
+    let sources = service::articles::list_sources(&state.pool, id).await?;
+    Ok(Json(sources))
+}
+
+pub async fn list_public_sources(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Vec<PublicArticleSourceOut>>> {
+    let sources = service::articles::list_public_sources(&state.pool, id).await?;
+    Ok(Json(sources))
+}
+
+pub async fn list_similar_articles(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Query(query): Query<SimilarArticlesQuery>,
+) -> AppResult<Json<SimilarArticlesOut>> {
+    let similar = service::articles::similar(&state.pool, id, query).await?;
+    Ok(Json(similar))
+}
+
+pub async fn get_article_cluster(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<ArticleClusterOut>> {
+    let cluster = service::articles::cluster(&state.pool, id).await?;
+    Ok(Json(cluster))
+}
+
+pub async fn list_clusters(
+    State(state): State<AppState>,
+    Query(query): Query<ClusterListQuery>,
+) -> AppResult<Json<PageResp<ClusterOut>>> {
+    let clusters = service::articles::clusters(&state.pool, query).await?;
+    Ok(Json(clusters))
+}
+
+pub async fn merge_article(
+    State(state): State<AppState>,
+    Path((id, target)): Path<(i64, i64)>,
+) -> AppResult<StatusCode> {
+    service::articles::merge_into(&state.pool, id, target).await?;
+    invalidate_article_caches(&state);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn unmerge_article(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<StatusCode> {
+    service::articles::unmerge(&state.pool, id).await?;
+    invalidate_article_caches(&state);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn hide_article(
+    State(state): State<AppState>,
+    admin: AdminContext,
+    Path(id): Path<i64>,
+    ValidJson(payload): ValidJson<ArticleHidePayload>,
+) -> AppResult<StatusCode> {
+    service::articles::hide(&state.pool, &admin, id, payload).await?;
+    invalidate_article_caches(&state);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn unhide_article(
+    State(state): State<AppState>,
+    admin: AdminContext,
+    Path(id): Path<i64>,
+) -> AppResult<StatusCode> {
+    service::articles::unhide(&state.pool, &admin, id).await?;
+    invalidate_article_caches(&state);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn list_pending_articles(
+    State(state): State<AppState>,
+    Query(query): Query<ReviewQueueQuery>,
+) -> AppResult<Json<PageResp<PendingArticleOut>>> {
+    Ok(Json(service::articles::list_pending(&state.pool, query).await?))
+}
+
+pub async fn approve_article(
+    State(state): State<AppState>,
+    admin: AdminContext,
+    Path(id): Path<i64>,
+) -> AppResult<StatusCode> {
+    service::articles::approve(&state.pool, &admin, id).await?;
+    invalidate_article_caches(&state);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn approve_articles(
+    State(state): State<AppState>,
+    admin: AdminContext,
+    ValidJson(payload): ValidJson<ArticleApprovePayload>,
+) -> AppResult<Json<ArticleApproveResult>> {
+    let result = service::articles::approve_bulk(&state.pool, &admin, payload).await?;
+    if !result.approved.is_empty() {
+        invalidate_article_caches(&state);
+    }
+    Ok(Json(result))
+}
+
+pub async fn bulk_articles(
+    State(state): State<AppState>,
+    admin: AdminContext,
+    ValidJson(payload): ValidJson<ArticleBulkPayload>,
+) -> AppResult<Json<ArticleBulkResult>> {
+    let result = service::articles::bulk(&state.pool, &admin, payload).await?;
+    if result.affected > 0 {
+        invalidate_article_caches(&state);
+    }
+    Ok(Json(result))
+}
+
+pub async fn update_article(
+    State(state): State<AppState>,
+    admin: AdminContext,
+    Path(id): Path<i64>,
+    ValidJson(payload): ValidJson<ArticleUpdatePayload>,
+) -> AppResult<Json<ArticleOut>> {
+    let article = service::articles::update(&state.pool, &admin, id, payload).await?;
+    invalidate_article_caches(&state);
+    Ok(Json(article))
+}
+
+pub async fn delete_article(
+    State(state): State<AppState>,
+    admin: AdminContext,
+    Path(id): Path<i64>,
+) -> AppResult<StatusCode> {
+    service::articles::delete(&state.pool, &admin, id).await?;
+    invalidate_article_caches(&state);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn list_article_moderation(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Vec<ModerationLogOut>>> {
+    let logs = service::articles::list_moderation(&state.pool, id).await?;
+    Ok(Json(logs))
+}
+
+pub async fn get_article_attrs(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<ArticleAttrsOut>> {
+    let attrs = service::enrichment::attrs(&state.pool, id).await?;
+    Ok(Json(attrs))
+}
+
+pub async fn get_article_content(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<ArticleContentOut>> {
+    let content = service::extraction::content(&state.pool, id).await?;
+    Ok(Json(content))
+}
+
+pub async fn get_reader_content(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<ArticleReaderOut>> {
+    let content = service::extraction::reader(&state.pool, id).await?;
+    Ok(Json(content))
+}
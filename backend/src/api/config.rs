@@ -1,7 +1,14 @@
 use axum::{extract::State, Json};
 
-use crate::{app::AppState, config::FrontendPublicConfig};
+use crate::{app::AppState, error::AppResult, model::FrontendConfigOut, service};
 
-pub async fn frontend_config(State(state): State<AppState>) -> Json<FrontendPublicConfig> {
-    Json(state.config.clone())
+pub async fn frontend_config(State(state): State<AppState>) -> AppResult<Json<FrontendConfigOut>> {
+    let layout = service::layout::load(&state.pool).await?;
+    let features = service::layout::load_features(&state.pool).await?;
+    Ok(Json(FrontendConfigOut {
+        public: state.config.clone(),
+        layout,
+        features,
+        admin_oidc: state.oidc.is_some(),
+    }))
 }
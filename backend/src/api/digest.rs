@@ -0,0 +1,51 @@
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Deserialize;
+
+use crate::{
+    api::payload::OptionalJson,
+    app::AppState,
+    error::AppResult,
+    model::{DigestGeneratePayload, DigestOut},
+    service,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct DigestListQuery {
+    pub limit: Option<i64>,
+}
+
+pub async fn rss(State(state): State<AppState>) -> AppResult<Response> {
+    let xml = service::digest::rss(&state.pool, &state.config.site_base_url()).await?;
+    Ok((
+        [(header::CONTENT_TYPE, "application/rss+xml; charset=utf-8")],
+        xml,
+    )
+        .into_response())
+}
+
+pub async fn list_digests(
+    State(state): State<AppState>,
+    Query(query): Query<DigestListQuery>,
+) -> AppResult<Json<Vec<DigestOut>>> {
+    let digests = service::digest::list(&state.pool, query.limit.unwrap_or(10)).await?;
+    Ok(Json(digests))
+}
+
+pub async fn generate_digest(
+    State(state): State<AppState>,
+    OptionalJson(payload): OptionalJson<DigestGeneratePayload>,
+) -> AppResult<Json<DigestOut>> {
+    let digest = service::digest::generate(
+        &state.pool,
+        state.digest_mailer.as_deref(),
+        &state.config.site_base_url(),
+        payload.unwrap_or_default(),
+    )
+    .await?;
+    Ok(Json(digest))
+}
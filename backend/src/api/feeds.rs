@@ -1,12 +1,17 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Json,
 };
 
 use crate::{
+    api::payload::{OptionalJson, ValidJson},
     app::AppState,
     error::AppResult,
-    model::{FeedOut, FeedTestPayload, FeedTestResult, FeedUpsertPayload},
+    model::{
+        BookmarkImportOut, BookmarkImportPayload, FeedClonePayload, FeedDiscoverOut, FeedDiscoverPayload, FeedMuteQuery, FilteredArticleOut,
+        FilteredRestoreOut, FilteredRestorePayload, FeedOut, FeedTestPayload, FeedTestResult, FeedUpsertPayload,
+        FetcherQueueOut, FilterSuggestionsOut, SourceImportOut, SourceImportPayload,
+    },
     service,
 };
 
@@ -17,7 +22,7 @@ pub async fn list_feeds(State(state): State<AppState>) -> AppResult<Json<Vec<Fee
 
 pub async fn upsert_feed(
     State(state): State<AppState>,
-    Json(payload): Json<FeedUpsertPayload>,
+    ValidJson(payload): ValidJson<FeedUpsertPayload>,
 ) -> AppResult<Json<FeedOut>> {
     let feed = service::feeds::upsert(
         &state.pool,
@@ -25,6 +30,26 @@ pub async fn upsert_feed(
         &state.fetcher_config,
         &state.translator,
         &state.events,
+        &state.latest_cache,
+        payload,
+    )
+    .await?;
+    Ok(Json(feed))
+}
+
+pub async fn clone_feed(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    ValidJson(payload): ValidJson<FeedClonePayload>,
+) -> AppResult<Json<FeedOut>> {
+    let feed = service::feeds::clone_feed(
+        &state.pool,
+        &state.http_client,
+        &state.fetcher_config,
+        &state.translator,
+        &state.events,
+        &state.latest_cache,
+        id,
         payload,
     )
     .await?;
@@ -41,8 +66,117 @@ pub async fn delete_feed(
 
 pub async fn test_feed(
     State(state): State<AppState>,
-    Json(payload): Json<FeedTestPayload>,
+    ValidJson(payload): ValidJson<FeedTestPayload>,
 ) -> AppResult<Json<FeedTestResult>> {
-    let result = service::feeds::test(&state.http_client, payload).await?;
+    let result = service::feeds::test(&state.pool, &state.http_client, payload).await?;
+    Ok(Json(result))
+}
+
+pub async fn discover_feeds(
+    State(state): State<AppState>,
+    ValidJson(payload): ValidJson<FeedDiscoverPayload>,
+) -> AppResult<Json<FeedDiscoverOut>> {
+    let result = service::feeds::discover(&state.pool, &state.http_client, payload).await?;
+    Ok(Json(result))
+}
+
+pub async fn list_filtered_articles(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Vec<FilteredArticleOut>>> {
+    let items = service::feeds::list_filtered(&state.pool, id).await?;
+    Ok(Json(items))
+}
+
+pub async fn restore_filtered_articles(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    OptionalJson(payload): OptionalJson<FilteredRestorePayload>,
+) -> AppResult<Json<FilteredRestoreOut>> {
+    let payload = payload.unwrap_or_default();
+    let result = service::feeds::restore_filtered(&state.pool, id, payload).await?;
+    if result.restored > 0 {
+        super::articles::invalidate_article_caches(&state);
+    }
+    Ok(Json(result))
+}
+
+pub async fn clear_feed_etag(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<FeedOut>> {
+    let feed = service::feeds::clear_etag(&state.pool, id).await?;
+    Ok(Json(feed))
+}
+
+pub async fn mute_feed(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Query(query): Query<FeedMuteQuery>,
+) -> AppResult<Json<FeedOut>> {
+    let feed = service::feeds::mute(&state.pool, id, &query.until).await?;
+    Ok(Json(feed))
+}
+
+pub async fn unmute_feed(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<FeedOut>> {
+    let feed = service::feeds::unmute(&state.pool, id).await?;
+    Ok(Json(feed))
+}
+
+pub async fn enable_feed(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<FeedOut>> {
+    let feed = service::feeds::reenable(&state.pool, id).await?;
+    Ok(Json(feed))
+}
+
+pub async fn import_bookmarks(
+    State(state): State<AppState>,
+    ValidJson(payload): ValidJson<BookmarkImportPayload>,
+) -> AppResult<Json<BookmarkImportOut>> {
+    let result = service::feeds::import_bookmarks(
+        &state.pool,
+        &state.http_client,
+        &state.fetcher_config,
+        &state.translator,
+        &state.events,
+        &state.latest_cache,
+        payload,
+    )
+    .await?;
+    Ok(Json(result))
+}
+
+pub async fn import_sources(
+    State(state): State<AppState>,
+    ValidJson(payload): ValidJson<SourceImportPayload>,
+) -> AppResult<Json<SourceImportOut>> {
+    let result = service::feeds::import_sources(
+        &state.pool,
+        &state.http_client,
+        &state.fetcher_config,
+        &state.translator,
+        &state.events,
+        &state.latest_cache,
+        payload,
+    )
+    .await?;
+    Ok(Json(result))
+}
+
+pub async fn fetcher_queue(State(state): State<AppState>) -> AppResult<Json<FetcherQueueOut>> {
+    let queue = service::feeds::fetcher_queue(&state.pool, &state.fetcher_config).await?;
+    Ok(Json(queue))
+}
+
+pub async fn suggest_filters(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<FilterSuggestionsOut>> {
+    let result = service::feeds::suggest_filters(&state.pool, &state.translator, id).await?;
     Ok(Json(result))
 }
@@ -1,6 +1,8 @@
-use axum::Json;
+use axum::{extract::State, http::StatusCode, Json};
 use serde::Serialize;
 
+use crate::app::AppState;
+
 #[derive(Serialize)]
 pub struct HealthResponse {
     ok: bool,
@@ -9,3 +11,55 @@ pub struct HealthResponse {
 pub async fn health_check() -> Json<HealthResponse> {
     Json(HealthResponse { ok: true })
 }
+
+#[derive(Serialize)]
+pub struct QdrantHealthResponse {
+    ok: bool,
+    /// 未配置 `qdrant` 时为 false，此时 ok 恒为 true
+    enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collection: Option<String>,
+    /// 集合在首篇文章建立索引时创建，之前为 false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collection_exists: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    points_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// 向量库连通性检查：不可达或返回错误时响应 503
+pub async fn qdrant_health(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<QdrantHealthResponse>) {
+    let mut response = QdrantHealthResponse {
+        ok: true,
+        enabled: false,
+        collection: None,
+        collection_exists: None,
+        status: None,
+        points_count: None,
+        error: None,
+    };
+    let Some(qdrant) = state.qdrant.as_ref() else {
+        return (StatusCode::OK, Json(response));
+    };
+    response.enabled = true;
+    match qdrant.health().await {
+        Ok(health) => {
+            response.collection = Some(health.collection);
+            response.collection_exists = Some(health.exists);
+            response.status = health.status;
+            response.points_count = health.points_count;
+            (StatusCode::OK, Json(response))
+        }
+        Err(err) => {
+            tracing::warn!(error = ?err, "qdrant health check failed");
+            response.ok = false;
+            response.error = Some(format!("{err:#}"));
+            (StatusCode::SERVICE_UNAVAILABLE, Json(response))
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::Deserialize;
+
+use crate::{
+    app::AppState,
+    error::AppResult,
+    model::{BackupOut, LanguageBackfillOut, RankingRecomputeOut, RetentionArchiveOut},
+    service,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct BackupListQuery {
+    pub limit: Option<i64>,
+}
+
+pub async fn backfill_languages(
+    State(state): State<AppState>,
+) -> AppResult<Json<LanguageBackfillOut>> {
+    let result = service::maintenance::backfill_languages(&state.pool).await?;
+    Ok(Json(result))
+}
+
+pub async fn archive_retention(
+    State(state): State<AppState>,
+) -> AppResult<Json<RetentionArchiveOut>> {
+    let result = service::retention::archive_expired(&state.pool).await?;
+    if result.archived_articles > 0 {
+        super::articles::invalidate_article_caches(&state);
+    }
+    Ok(Json(result))
+}
+
+pub async fn recompute_ranking(
+    State(state): State<AppState>,
+) -> AppResult<Json<RankingRecomputeOut>> {
+    let result = service::ranking::recompute(&state.pool).await?;
+    state.latest_cache.clear();
+    Ok(Json(result))
+}
+
+pub async fn list_backups(
+    State(state): State<AppState>,
+    Query(query): Query<BackupListQuery>,
+) -> AppResult<Json<Vec<BackupOut>>> {
+    let backups = service::backup::list(&state.pool, query.limit.unwrap_or(20)).await?;
+    Ok(Json(backups))
+}
+
+pub async fn trigger_backup(State(state): State<AppState>) -> AppResult<Json<BackupOut>> {
+    let backup = service::backup::trigger(&state.pool, state.backup.as_ref()).await?;
+    Ok(Json(backup))
+}
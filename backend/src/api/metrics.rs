@@ -0,0 +1,29 @@
+use axum::{
+    extract::State,
+    http::{header, HeaderMap},
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    app::AppState,
+    error::{AppError, AppResult},
+    ops,
+};
+
+pub async fn metrics(State(state): State<AppState>, headers: HeaderMap) -> AppResult<Response> {
+    if let Some(token) = state.metrics_token.as_deref() {
+        let provided = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        if provided != Some(token) {
+            return Err(AppError::Unauthorized("metrics token 无效".into()));
+        }
+    }
+    let body = ops::metrics::render(&state.pool).await;
+    Ok((
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")],
+        body,
+    )
+        .into_response())
+}
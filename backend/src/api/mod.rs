@@ -1,7 +1,18 @@
 pub mod admin;
+pub mod analytics;
+pub mod api_keys;
 pub mod articles;
 pub mod config;
+pub mod digest;
 pub mod feeds;
 pub mod health;
+pub mod maintenance;
+pub mod metrics;
+pub mod payload;
 pub mod settings;
+pub mod share;
+pub mod sources;
+pub mod stats;
+pub mod tools;
+pub mod users;
 pub mod alerts;
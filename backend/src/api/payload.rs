@@ -0,0 +1,86 @@
+//! 管理端请求体提取器：在 axum `Json` 的基础上把拒绝原因统一转换为 `AppError`，
+//! 反序列化失败时返回带字段路径的 422，超出请求体上限时返回 413。
+
+use std::error::Error as _;
+
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    http::StatusCode,
+    Json,
+};
+use serde::de::DeserializeOwned;
+
+use crate::error::AppError;
+
+/// 替代 `Json<T>` 的请求体提取器。
+pub struct ValidJson<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequest<S> for ValidJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        Json::<T>::from_request(req, state)
+            .await
+            .map(|Json(value)| ValidJson(value))
+            .map_err(rejection_to_error)
+    }
+}
+
+/// 请求体可省略的 JSON：空 body 视为 `None`，非空时按 `ValidJson` 规则校验，
+/// 避免 `Option<Json<T>>` 把格式错误的请求静默当作未提供。
+pub struct OptionalJson<T>(pub Option<T>);
+
+#[async_trait]
+impl<S, T> FromRequest<S> for OptionalJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let body = Bytes::from_request(req, state).await.map_err(|err| {
+            if err.status() == StatusCode::PAYLOAD_TOO_LARGE {
+                too_large()
+            } else {
+                AppError::BadRequest(format!("读取请求体失败：{}", err.body_text()))
+            }
+        })?;
+        if body.iter().all(u8::is_ascii_whitespace) {
+            return Ok(OptionalJson(None));
+        }
+        Json::<T>::from_bytes(&body)
+            .map(|Json(value)| OptionalJson(Some(value)))
+            .map_err(rejection_to_error)
+    }
+}
+
+fn rejection_to_error(rejection: JsonRejection) -> AppError {
+    // 内层错误由 serde_path_to_error 给出，形如 `items[0].url: missing field ...`
+    let detail = rejection
+        .source()
+        .map(|inner| inner.to_string())
+        .unwrap_or_else(|| rejection.body_text());
+    match rejection {
+        JsonRejection::JsonDataError(_) => AppError::Unprocessable(format!("请求字段无效：{detail}")),
+        JsonRejection::JsonSyntaxError(_) => AppError::BadRequest(format!("JSON 格式错误：{detail}")),
+        JsonRejection::MissingJsonContentType(_) => {
+            AppError::BadRequest("请求头需包含 Content-Type: application/json".to_string())
+        }
+        JsonRejection::BytesRejection(ref inner) if inner.status() == StatusCode::PAYLOAD_TOO_LARGE => {
+            too_large()
+        }
+        other => AppError::BadRequest(format!("读取请求体失败：{}", other.body_text())),
+    }
+}
+
+fn too_large() -> AppError {
+    AppError::PayloadTooLarge("请求体超过大小上限".to_string())
+}
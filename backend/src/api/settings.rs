@@ -1,11 +1,16 @@
-use axum::{extract::State, Json};
+use axum::{
+    extract::{Path, State},
+    Json,
+};
 
 use crate::{
+    api::payload::ValidJson,
     app::AppState,
     error::AppResult,
     model::{
         TranslationSettingsOut, TranslationSettingsUpdate, AiDedupSettingsOut, AiDedupSettingsUpdate,
-        ModelSettingsOut, ModelSettingsUpdate,
+        ModelSettingsOut, ModelSettingsUpdate, SettingUpdate, SettingValueOut, SettingsExport,
+        SettingsImport, SettingsImportResult,
     },
     service,
 };
@@ -19,7 +24,7 @@ pub async fn get_translation_settings(
 
 pub async fn update_translation_settings(
     State(state): State<AppState>,
-    Json(payload): Json<TranslationSettingsUpdate>,
+    ValidJson(payload): ValidJson<TranslationSettingsUpdate>,
 ) -> AppResult<Json<TranslationSettingsOut>> {
     let settings =
         service::settings::update_translation_settings(&state.pool, &state.translator, &state.events, payload)
@@ -36,7 +41,7 @@ pub async fn get_model_settings(
 
 pub async fn update_model_settings(
     State(state): State<AppState>,
-    Json(payload): Json<ModelSettingsUpdate>,
+    ValidJson(payload): ValidJson<ModelSettingsUpdate>,
 ) -> AppResult<Json<ModelSettingsOut>> {
     let settings = service::settings::update_model_settings(&state.pool, &state.translator, payload).await?;
     Ok(Json(settings))
@@ -47,7 +52,7 @@ pub struct ModelTestPayload { pub provider: String }
 
 pub async fn test_model_connectivity(
     State(state): State<AppState>,
-    Json(payload): Json<ModelTestPayload>,
+    ValidJson(payload): ValidJson<ModelTestPayload>,
 ) -> AppResult<Json<serde_json::Value>> {
     service::settings::test_model_connectivity(&state.translator, &payload.provider).await?;
     Ok(Json(serde_json::json!({"ok": true})))
@@ -62,8 +67,38 @@ pub async fn get_ai_dedup_settings(
 
 pub async fn update_ai_dedup_settings(
     State(state): State<AppState>,
-    Json(payload): Json<AiDedupSettingsUpdate>,
+    ValidJson(payload): ValidJson<AiDedupSettingsUpdate>,
 ) -> AppResult<Json<AiDedupSettingsOut>> {
     let settings = service::settings::update_ai_dedup_settings(&state.pool, &state.translator, payload).await?;
     Ok(Json(settings))
 }
+
+pub async fn export_settings(State(state): State<AppState>) -> AppResult<Json<SettingsExport>> {
+    let export = service::settings::export_settings(&state.pool).await?;
+    Ok(Json(export))
+}
+
+pub async fn import_settings(
+    State(state): State<AppState>,
+    ValidJson(payload): ValidJson<SettingsImport>,
+) -> AppResult<Json<SettingsImportResult>> {
+    let result = service::settings::import_settings(&state.pool, &state.translator, payload).await?;
+    Ok(Json(result))
+}
+
+pub async fn get_setting(
+    State(state): State<AppState>,
+    Path(key): Path<String>,
+) -> AppResult<Json<SettingValueOut>> {
+    let setting = service::settings_registry::get(&state.pool, &key).await?;
+    Ok(Json(setting))
+}
+
+pub async fn put_setting(
+    State(state): State<AppState>,
+    Path(key): Path<String>,
+    ValidJson(payload): ValidJson<SettingUpdate>,
+) -> AppResult<Json<SettingValueOut>> {
+    let setting = service::settings_registry::put(&state.pool, &state.translator, &key, payload).await?;
+    Ok(Json(setting))
+}
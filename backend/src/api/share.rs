@@ -0,0 +1,17 @@
+use axum::{extract::State, Json};
+
+use crate::{
+    api::payload::ValidJson,
+    app::AppState,
+    error::AppResult,
+    model::{SignedUrlOut, SignedUrlPayload},
+    service,
+};
+
+pub async fn sign_url(
+    State(state): State<AppState>,
+    ValidJson(payload): ValidJson<SignedUrlPayload>,
+) -> AppResult<Json<SignedUrlOut>> {
+    let signed = service::share::sign(&state.pool, &state.config.site_base_url(), payload).await?;
+    Ok(Json(signed))
+}
@@ -0,0 +1,85 @@
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::{
+    api::payload::ValidJson,
+    app::{AppState, SOURCES_CACHE_TTL},
+    error::{AppError, AppResult},
+    model::{SourcePreferenceOut, SourcePreferenceUpdate, SourceWeightOut, SourceWeightUpdate},
+    service,
+    util::cache::CachedJson,
+};
+
+// 公开来源页：计数无需实时，整体缓存，仅按有效期刷新
+pub async fn list_public(State(state): State<AppState>) -> AppResult<Response> {
+    let cached = match state.sources_cache.get(&()) {
+        Some(cached) => cached,
+        None => {
+            let sources = service::sources::list_public(&state.pool).await?;
+            let body = serde_json::to_vec(&sources).map_err(|err| AppError::Internal(err.into()))?;
+            let cached = CachedJson::new(body);
+            state.sources_cache.insert((), cached.clone());
+            cached
+        }
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (
+                header::CACHE_CONTROL,
+                format!("public, max-age={}", SOURCES_CACHE_TTL.as_secs()),
+            ),
+        ],
+        cached.body,
+    )
+        .into_response())
+}
+
+pub async fn list_weights(State(state): State<AppState>) -> AppResult<Json<Vec<SourceWeightOut>>> {
+    let weights = service::sources::list_weights(&state.pool).await?;
+    Ok(Json(weights))
+}
+
+pub async fn upsert_weight(
+    State(state): State<AppState>,
+    ValidJson(payload): ValidJson<SourceWeightUpdate>,
+) -> AppResult<Json<SourceWeightOut>> {
+    let weight = service::sources::upsert_weight(&state.pool, payload).await?;
+    Ok(Json(weight))
+}
+
+pub async fn delete_weight(
+    State(state): State<AppState>,
+    Path(source_domain): Path<String>,
+) -> AppResult<Json<serde_json::Value>> {
+    service::sources::delete_weight(&state.pool, &source_domain).await?;
+    Ok(Json(serde_json::json!({ "ok": true })))
+}
+
+pub async fn list_preferences(
+    State(state): State<AppState>,
+) -> AppResult<Json<Vec<SourcePreferenceOut>>> {
+    let preferences = service::sources::list_preferences(&state.pool).await?;
+    Ok(Json(preferences))
+}
+
+pub async fn upsert_preference(
+    State(state): State<AppState>,
+    ValidJson(payload): ValidJson<SourcePreferenceUpdate>,
+) -> AppResult<Json<SourcePreferenceOut>> {
+    let preference = service::sources::upsert_preference(&state.pool, payload).await?;
+    Ok(Json(preference))
+}
+
+pub async fn delete_preference(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<serde_json::Value>> {
+    service::sources::delete_preference(&state.pool, id).await?;
+    Ok(Json(serde_json::json!({ "ok": true })))
+}
@@ -0,0 +1,38 @@
+use axum::{
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    app::{AppState, STATS_CACHE_TTL},
+    error::{AppError, AppResult},
+    service,
+    util::cache::CachedJson,
+};
+
+// 页脚统计每个页面都会请求：整体缓存一分钟，无需感知文章入库
+pub async fn summary(State(state): State<AppState>) -> AppResult<Response> {
+    let cached = match state.stats_cache.get(&()) {
+        Some(cached) => cached,
+        None => {
+            let stats = service::stats::summary(&state.pool).await?;
+            let body = serde_json::to_vec(&stats).map_err(|err| AppError::Internal(err.into()))?;
+            let cached = CachedJson::new(body);
+            state.stats_cache.insert((), cached.clone());
+            cached
+        }
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (
+                header::CACHE_CONTROL,
+                format!("public, max-age={}", STATS_CACHE_TTL.as_secs()),
+            ),
+        ],
+        cached.body,
+    )
+        .into_response())
+}
@@ -0,0 +1,17 @@
+use axum::{extract::State, Json};
+
+use crate::{
+    api::payload::ValidJson,
+    app::AppState,
+    error::AppResult,
+    model::{NormalizePreviewOut, NormalizePreviewPayload},
+    service,
+};
+
+pub async fn normalize_preview(
+    State(state): State<AppState>,
+    ValidJson(payload): ValidJson<NormalizePreviewPayload>,
+) -> AppResult<Json<NormalizePreviewOut>> {
+    let result = service::tools::normalize_preview(&state.translator, payload)?;
+    Ok(Json(result))
+}
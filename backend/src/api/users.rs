@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+
+use crate::{
+    api::payload::ValidJson,
+    app::AppState,
+    auth::AdminContext,
+    error::AppResult,
+    model::{AdminMeOut, UserCreatePayload, UserOut, UserUpdatePayload},
+    service,
+};
+
+pub async fn me(
+    State(state): State<AppState>,
+    admin: AdminContext,
+) -> Json<AdminMeOut> {
+    Json(AdminMeOut {
+        builtin: admin.username == state.admin.builtin_username(),
+        user_id: admin.user_id,
+        scopes: admin.scopes.iter().map(|scope| scope.to_string()).collect(),
+        username: admin.username,
+        role: admin.role.as_str().to_string(),
+    })
+}
+
+pub async fn list_users(State(state): State<AppState>) -> AppResult<Json<Vec<UserOut>>> {
+    let users = service::users::list(&state.pool).await?;
+    Ok(Json(users))
+}
+
+pub async fn create_user(
+    State(state): State<AppState>,
+    ValidJson(payload): ValidJson<UserCreatePayload>,
+) -> AppResult<Json<UserOut>> {
+    let user = service::users::create(&state.pool, state.admin.builtin_username(), payload).await?;
+    Ok(Json(user))
+}
+
+pub async fn update_user(
+    State(state): State<AppState>,
+    admin: AdminContext,
+    Path(id): Path<i64>,
+    ValidJson(payload): ValidJson<UserUpdatePayload>,
+) -> AppResult<Json<UserOut>> {
+    let user = service::users::update(&state.pool, &admin.username, id, payload).await?;
+    Ok(Json(user))
+}
+
+pub async fn delete_user(
+    State(state): State<AppState>,
+    admin: AdminContext,
+    Path(id): Path<i64>,
+) -> AppResult<Json<serde_json::Value>> {
+    service::users::delete(&state.pool, &admin.username, id).await?;
+    Ok(Json(serde_json::json!({ "ok": true })))
+}
@@ -1,22 +1,42 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
 
 use axum::{
-    middleware,
-    routing::{delete, get, post},
+    extract::{ConnectInfo, DefaultBodyLimit, Request, State},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{delete, get, patch, post},
     Router,
 };
-use sqlx::{postgres::PgPoolOptions, PgPool};
+use sqlx::{
+    postgres::{PgConnectOptions, PgPoolOptions},
+    ConnectOptions, PgPool,
+};
 use tower::ServiceBuilder;
 use tower_http::cors::{Any, CorsLayer};
 
 use crate::{
     api, auth,
+    error::AppError,
     config::{AppConfig, FetcherConfig, FrontendPublicConfig, HttpClientConfig},
-    fetcher, repo,
-    util::translator::{TranslationEngine, TranslatorCredentialsUpdate, TranslatorProvider},
-    ops::events::EventsHub,
+    fetcher, repo, service,
+    util::{
+        cache::{CachedJson, LatestArticlesCache, TtlCache},
+        client_ip::{self, TrustedProxies},
+        mailer::Mailer,
+        qdrant::QdrantManager,
+        translator::{TranslationEngine, TranslatorCredentialsUpdate, TranslatorProvider},
+    },
+    ops::{
+        db_monitor,
+        event_catalog::{Event, EventCode},
+        events::EventsHub,
+    },
 };
-use crate::repo::events as repo_events;
+use crate::repo::articles::FeaturedArgs;
 
 #[derive(Clone)]
 pub struct AppState {
@@ -27,24 +47,88 @@ pub struct AppState {
     pub fetcher_config: FetcherConfig,
     pub translator: Arc<TranslationEngine>,
     pub events: EventsHub,
+    /// 热门文章响应缓存（按查询参数），见 `api::articles::list_featured`
+    pub featured_cache: Arc<TtlCache<FeaturedArgs, CachedJson>>,
+    /// 默认文章列表首页的缓存，新文章入库时由抓取器清空
+    pub latest_cache: LatestArticlesCache,
+    /// 公开统计摘要缓存，仅按有效期刷新
+    pub stats_cache: Arc<TtlCache<(), CachedJson>>,
+    /// 公开来源列表缓存，仅按有效期刷新
+    pub sources_cache: Arc<TtlCache<(), CachedJson>>,
+    /// 点击去重：同一客户端对同一文章在有效期内只计一次
+    pub click_dedup: Arc<TtlCache<(IpAddr, i64), ()>>,
+    /// 未配置 `digest.smtp` 时为 None，每周精选只发布 RSS
+    pub digest_mailer: Option<Arc<Mailer>>,
+    /// `/metrics` 的访问令牌，None 表示不校验
+    pub metrics_token: Option<Arc<str>>,
+    /// 未配置 `qdrant` 时为 None，历史去重只做标题 Jaccard 比较
+    pub qdrant: Option<Arc<QdrantManager>>,
+    /// 未配置 `backup` 时为 None，手动触发备份返回 400
+    pub backup: Option<Arc<service::backup::Backup>>,
+    /// 未配置 `admin.oidc` 时为 None，后台只能用密码登录
+    pub oidc: Option<Arc<service::oidc::Oidc>>,
 }
 
-pub async fn build_router(config: &AppConfig) -> anyhow::Result<Router> {
+// 热门文章缓存有效期，同时作为响应的 Cache-Control max-age
+pub const FEATURED_CACHE_TTL: Duration = Duration::from_secs(30);
+// 首页缓存主要依赖入库时失效，有效期仅用于兜底刷新点击数等字段
+const LATEST_CACHE_TTL: Duration = Duration::from_secs(300);
+// 统计摘要有效期，同时作为响应的 Cache-Control max-age
+pub const STATS_CACHE_TTL: Duration = Duration::from_secs(60);
+// 公开来源列表有效期，同时作为响应的 Cache-Control max-age
+pub const SOURCES_CACHE_TTL: Duration = Duration::from_secs(300);
+// 点击去重窗口
+const CLICK_DEDUP_TTL: Duration = Duration::from_secs(600);
+// 点击去重最多记录的 (客户端, 文章) 数，超出后任意淘汰一条，只会让少量重复点击多计一次
+const CLICK_DEDUP_CAPACITY: usize = 100_000;
+// 书签文件可能较大，导入接口单独放宽请求体上限
+const BOOKMARK_BODY_LIMIT: usize = 10 * 1024 * 1024;
+
+/// 连接数据库并确保表结构就绪；服务启动与 `--seed-from` 导入共用
+pub async fn connect_db(config: &AppConfig) -> anyhow::Result<PgPool> {
+    // 慢语句由 sqlx 以 WARN 级别输出，再由 db_monitor::SlowQueryLayer 捕获上报
+    let mut connect_options = config
+        .db
+        .url
+        .parse::<PgConnectOptions>()?
+        .log_slow_statements(
+            log::LevelFilter::Warn,
+            Duration::from_millis(config.db.slow_query_ms.max(1)),
+        );
+    if config.db.statement_timeout_ms > 0 {
+        // 防止单条失控查询长期占住连接
+        connect_options = connect_options.options([(
+            "statement_timeout",
+            format!("{}ms", config.db.statement_timeout_ms),
+        )]);
+    }
     let pool = PgPoolOptions::new()
         .max_connections(config.db.max_connections)
         .acquire_timeout(Duration::from_secs(5))
-        .connect(&config.db.url)
+        .connect_with(connect_options)
         .await?;
 
     repo::migrations::ensure_schema(&pool).await?;
+    Ok(pool)
+}
+
+pub async fn build_router(config: &AppConfig) -> anyhow::Result<Router> {
+    let pool = connect_db(config).await?;
     repo::maintenance::cleanup_orphan_content(&pool).await?;
+    db_monitor::spawn(pool.clone(), &config.db);
 
-    // Emit a simple system startup event (no source_domain)
-    let _ = repo_events::upsert_event(
-        &pool,
-        &repo_events::NewEvent { level: "info".to_string(), code: "SYSTEM_STARTED".to_string(), addition_info: None },
-        0,
-    ).await;
+    // 启动时在后台补全历史文章的语言列与标题 SimHash，不阻塞服务启动
+    let backfill_pool = pool.clone();
+    tokio::spawn(async move {
+        if let Err(err) = service::maintenance::backfill_languages(&backfill_pool).await {
+            tracing::warn!(error = ?err, "article language backfill failed");
+        }
+        if let Err(err) = service::maintenance::backfill_simhashes(&backfill_pool).await {
+            tracing::warn!(error = ?err, "article simhash backfill failed");
+        }
+    });
+
+    Event::new(EventCode::SystemStarted).emit(&pool).await;
 
     // Normalize translation-related settings at startup:
     // - Force default provider to 'ollama'
@@ -75,20 +159,13 @@ pub async fn build_router(config: &AppConfig) -> anyhow::Result<Router> {
         repo::settings::get_setting(&pool, "translation.ollama_base_url").await?;
     let stored_ollama_model =
         repo::settings::get_setting(&pool, "translation.ollama_model").await?;
-    let stored_translation_enabled =
-        repo::settings::get_setting(&pool, "translation.enabled").await?;
+    let translation_rules = service::settings::load_translation_rules(&pool).await?;
 
     translator.update_credentials(TranslatorCredentialsUpdate {
         deepseek_api_key: stored_deepseek_key,
         ollama_base_url: stored_ollama_base_url,
         ollama_model: stored_ollama_model,
-        translation_enabled: stored_translation_enabled.as_ref().and_then(|v| {
-            match v.trim().to_ascii_lowercase().as_str() {
-                "true" | "1" | "yes" | "on" => Some(true),
-                "false" | "0" | "no" | "off" => Some(false),
-                _ => None,
-            }
-        }),
+        translation_rules: Some(translation_rules),
         ..Default::default()
     })?;
 
@@ -121,20 +198,71 @@ pub async fn build_router(config: &AppConfig) -> anyhow::Result<Router> {
     // init events hub early so background tasks can broadcast
     let events_hub = EventsHub::new(256);
 
+    let latest_cache: LatestArticlesCache = Arc::new(TtlCache::new(LATEST_CACHE_TTL));
+
+    // 配置有误时启动失败；Qdrant 暂时不可达不影响启动，抓取时退回 Jaccard 比较
+    let qdrant = config
+        .qdrant
+        .as_ref()
+        .map(|qdrant| QdrantManager::new(qdrant, &config.http_client))
+        .transpose()?
+        .map(Arc::new);
+    service::embedding::init(qdrant.clone(), config.qdrant.as_ref());
+    service::embedding::spawn_backfill(pool.clone(), Arc::clone(&translator));
+
     fetcher::spawn(
         pool.clone(),
         config.fetcher.clone(),
         config.http_client.clone(),
         Arc::clone(&translator),
         events_hub.clone(),
+        Arc::clone(&latest_cache),
     )?;
 
     let public_config = config.frontend_public_config();
+    let digest_mailer = config
+        .digest
+        .smtp
+        .as_ref()
+        .map(Mailer::new)
+        .transpose()?
+        .map(Arc::new);
+    service::digest::spawn(
+        pool.clone(),
+        digest_mailer.clone(),
+        public_config.site_base_url(),
+    );
+    service::retention::spawn(pool.clone());
+    service::ranking::spawn(pool.clone(), Arc::clone(&latest_cache));
+    service::enrichment::spawn(pool.clone(), config.http_client.clone())?;
+    service::extraction::spawn(
+        pool.clone(),
+        config.http_client.clone(),
+        config.fetcher.respect_robots_txt,
+    )?;
+    let backup = config
+        .backup
+        .as_ref()
+        .map(|backup| service::backup::Backup::new(backup, &config.db.url))
+        .transpose()?
+        .map(Arc::new);
+    if let Some(backup) = backup.as_ref() {
+        service::backup::spawn(pool.clone(), Arc::clone(backup));
+    }
+    let oidc = config
+        .admin
+        .oidc
+        .as_ref()
+        .map(|oidc| service::oidc::Oidc::new(oidc, &config.http_client, &public_config))
+        .transpose()?
+        .map(Arc::new);
     let admin_manager = auth::AdminManager::new(
         config.admin.username.clone(),
         config.admin.password.clone(),
+        config.admin.password_hash.clone(),
         Duration::from_secs(std::cmp::max(60_u64, config.admin.session_ttl_secs)),
-    );
+        pool.clone(),
+    )?;
 
     let state = AppState {
         pool,
@@ -144,7 +272,27 @@ pub async fn build_router(config: &AppConfig) -> anyhow::Result<Router> {
         fetcher_config: config.fetcher.clone(),
         translator,
         events: events_hub,
+        featured_cache: Arc::new(TtlCache::new(FEATURED_CACHE_TTL)),
+        latest_cache,
+        stats_cache: Arc::new(TtlCache::new(STATS_CACHE_TTL)),
+        sources_cache: Arc::new(TtlCache::new(SOURCES_CACHE_TTL)),
+        click_dedup: Arc::new(TtlCache::with_capacity(
+            CLICK_DEDUP_TTL,
+            CLICK_DEDUP_CAPACITY,
+        )),
+        digest_mailer,
+        metrics_token: config
+            .server
+            .metrics_token
+            .as_deref()
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .map(Arc::from),
+        qdrant,
+        backup,
+        oidc,
     };
+    let trusted_proxies = Arc::new(TrustedProxies::parse(&config.server.trusted_proxies)?);
 
     let cors = CorsLayer::new()
         .allow_origin(Any)
@@ -158,9 +306,115 @@ pub async fn build_router(config: &AppConfig) -> anyhow::Result<Router> {
             get(api::feeds::list_feeds).post(api::feeds::upsert_feed),
         )
         .route("/feeds/test", post(api::feeds::test_feed))
+        .route("/feeds/discover", post(api::feeds::discover_feeds))
+        .route(
+            "/feeds/import/bookmarks",
+            post(api::feeds::import_bookmarks).layer(DefaultBodyLimit::max(
+                BOOKMARK_BODY_LIMIT.max(config.server.max_body_bytes),
+            )),
+        )
+        .route("/feeds/import/sources", post(api::feeds::import_sources))
         .route("/feeds/:id", delete(api::feeds::delete_feed))
+        .route("/feeds/:id/clone", post(api::feeds::clone_feed))
+        .route("/feeds/:id/clear_etag", post(api::feeds::clear_feed_etag))
+        .route("/feeds/:id/mute", post(api::feeds::mute_feed))
+        .route("/feeds/:id/unmute", post(api::feeds::unmute_feed))
+        .route("/feeds/:id/enable", post(api::feeds::enable_feed))
+        .route("/feeds/:id/filtered", get(api::feeds::list_filtered_articles))
+        .route(
+            "/feeds/:id/filtered/restore",
+            post(api::feeds::restore_filtered_articles),
+        )
+        .route("/feeds/:id/filter_suggestions", post(api::feeds::suggest_filters))
+        .route("/fetcher/queue", get(api::feeds::fetcher_queue))
+        .route(
+            "/articles/:id/sources",
+            get(api::articles::list_article_sources),
+        )
+        .route("/articles/bulk", post(api::articles::bulk_articles))
+        .route("/articles/pending", get(api::articles::list_pending_articles))
+        .route("/articles/approve", post(api::articles::approve_articles))
+        .route(
+            "/articles/:id",
+            patch(api::articles::update_article).delete(api::articles::delete_article),
+        )
+        .route("/articles/:id/attrs", get(api::articles::get_article_attrs))
+        .route("/articles/:id/content", get(api::articles::get_article_content))
+        .route(
+            "/articles/:id/similar",
+            get(api::articles::list_similar_articles),
+        )
+        .route("/articles/:id/cluster", get(api::articles::get_article_cluster))
+        .route("/clusters", get(api::articles::list_clusters))
+        .route(
+            "/articles/:id/merge_into/:target",
+            post(api::articles::merge_article),
+        )
+        .route("/articles/:id/unmerge", post(api::articles::unmerge_article))
+        .route("/articles/:id/hide", post(api::articles::hide_article))
+        .route("/articles/:id/unhide", post(api::articles::unhide_article))
+        .route("/articles/:id/approve", post(api::articles::approve_article))
+        .route(
+            "/articles/:id/moderation",
+            get(api::articles::list_article_moderation),
+        )
+        .route(
+            "/sources/weights",
+            get(api::sources::list_weights).post(api::sources::upsert_weight),
+        )
+        .route(
+            "/sources/weights/:source_domain",
+            delete(api::sources::delete_weight),
+        )
+        .route(
+            "/sources/preferences",
+            get(api::sources::list_preferences).post(api::sources::upsert_preference),
+        )
+        .route(
+            "/sources/preferences/:id",
+            delete(api::sources::delete_preference),
+        )
+        .route("/tools/normalize", post(api::tools::normalize_preview))
+        .route(
+            "/maintenance/language_backfill",
+            post(api::maintenance::backfill_languages),
+        )
+        .route("/maintenance/retention", post(api::maintenance::archive_retention))
+        .route("/maintenance/ranking", post(api::maintenance::recompute_ranking))
+        .route(
+            "/maintenance/backups",
+            get(api::maintenance::list_backups).post(api::maintenance::trigger_backup),
+        )
+        .route("/me", get(api::users::me))
+        .route(
+            "/users",
+            get(api::users::list_users).post(api::users::create_user),
+        )
+        .route(
+            "/users/:id",
+            patch(api::users::update_user).delete(api::users::delete_user),
+        )
+        .route(
+            "/api_keys",
+            get(api::api_keys::list_api_keys).post(api::api_keys::create_api_key),
+        )
+        .route("/api_keys/:id", delete(api::api_keys::revoke_api_key))
+        .route("/sessions", get(api::admin::list_sessions))
+        .route("/sessions/revoke_others", post(api::admin::revoke_other_sessions))
+        .route("/sessions/:id", delete(api::admin::revoke_session))
+        .route("/analytics/dedup", get(api::analytics::dedup))
+        .route("/digests", get(api::digest::list_digests))
+        .route("/digests/generate", post(api::digest::generate_digest))
         .route("/alerts", get(api::alerts::list_alerts))
         .route("/alerts/stream", get(api::alerts::stream_alerts))
+        .route("/alerts/export", get(api::alerts::export_alerts))
+        .route("/share/sign", post(api::share::sign_url))
+        .route("/settings/export", get(api::settings::export_settings))
+        .route("/settings/import", post(api::settings::import_settings))
+        .route(
+            "/settings/:key",
+            get(api::settings::get_setting).put(api::settings::put_setting),
+        )
         .route(
             "/settings/translation",
             get(api::settings::get_translation_settings)
@@ -186,17 +440,88 @@ pub async fn build_router(config: &AppConfig) -> anyhow::Result<Router> {
         ))
         .with_state(state.clone());
 
-    let router = Router::new()
-        .route("/healthz", get(api::health::health_check))
+    // 公共接口加请求级时限；后台接口包含模型调用等长耗时操作，由各自的超时控制
+    let public_api = Router::new()
         .route("/articles", get(api::articles::list_articles))
         .route("/articles/featured", get(api::articles::list_featured))
+        .route("/articles/changes", get(api::articles::list_changes))
+        .route("/articles/feed.xml", get(api::articles::articles_feed))
+        .route("/articles/feed.rss", get(api::articles::articles_rss))
+        .route("/search", get(api::articles::search_articles))
+        .route("/archive", get(api::articles::archive))
+        .route("/stats/summary", get(api::stats::summary))
+        .route("/sources", get(api::sources::list_public))
+        .route("/digest.rss", get(api::digest::rss))
         .route("/articles/:id/click", post(api::articles::record_click))
+        .route("/articles/:id/read", post(api::articles::mark_read))
+        .route("/articles/:id/bookmark", post(api::articles::mark_bookmarked))
+        .route("/r/:id", get(api::articles::redirect_to_article))
+        .route("/articles/:id/content", get(api::articles::get_reader_content))
+        .route("/articles/:id/sources", get(api::articles::list_public_sources))
         .route("/config/frontend", get(api::config::frontend_config))
+        .route_layer(middleware::from_fn_with_state(
+            Duration::from_millis(config.db.request_timeout_ms),
+            request_timeout,
+        ));
+
+    // 签名分享链接：路径需与 `service::share::SIGNABLE_PATHS` 一一对应
+    let shared_api = Router::new()
+        .route("/shared/articles/feed.xml", get(api::articles::articles_feed))
+        .route("/shared/articles/feed.rss", get(api::articles::articles_rss))
+        .route("/shared/digest.rss", get(api::digest::rss))
+        .route("/shared/alerts/export", get(api::alerts::export_alerts))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::require_signed_url,
+        ));
+
+    let router = Router::new()
+        .route("/healthz", get(api::health::health_check))
+        .route("/healthz/qdrant", get(api::health::qdrant_health))
+        .route("/metrics", get(api::metrics::metrics))
+        .merge(public_api)
+        // 按需翻译要等待模型返回，不受公共接口的请求时限约束，由翻译客户端自身的超时控制
+        .route("/articles/:id/translate", post(api::articles::translate_article))
+        .merge(shared_api)
         .route("/admin/login", post(api::admin::login))
         .route("/admin/logout", post(api::admin::logout))
+        .route("/admin/oidc/login", get(api::admin::oidc_login))
+        .route("/admin/oidc/callback", get(api::admin::oidc_callback))
         .nest("/admin/api", admin_api)
+        .layer(DefaultBodyLimit::max(config.server.max_body_bytes))
+        .layer(middleware::from_fn_with_state(trusted_proxies, resolve_client_ip))
         .layer(middleware)
         .with_state(state);
 
     Ok(router)
 }
+
+// 超时后丢弃处理中的 future，占用的连接随之释放
+async fn request_timeout(State(limit): State<Duration>, req: Request, next: Next) -> Response {
+    if limit.is_zero() {
+        return next.run(req).await;
+    }
+    let path = req.uri().path().to_string();
+    match tokio::time::timeout(limit, next.run(req)).await {
+        Ok(response) => response,
+        Err(_) => {
+            tracing::warn!(path = %path, limit_ms = limit.as_millis() as u64, "request timed out");
+            AppError::Unavailable("请求处理超时，请稍后重试".to_string()).into_response()
+        }
+    }
+}
+
+// 解析真实客户端地址并写入请求扩展，供点击去重、审计等使用
+async fn resolve_client_ip(
+    State(proxies): State<Arc<TrustedProxies>>,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    let client = client_ip::from_parts(
+        &proxies,
+        req.extensions().get::<ConnectInfo<SocketAddr>>(),
+        req.headers(),
+    );
+    req.extensions_mut().insert(client);
+    next.run(req).await
+}
@@ -1,103 +1,369 @@
-use std::{
-    collections::HashMap,
-    sync::Arc,
-    time::{Duration, Instant},
-};
+use std::{sync::Arc, time::Duration};
 
 use axum::{
-    http::{header, HeaderMap, StatusCode},
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderMap, Method, StatusCode},
     middleware::Next,
     response::Response,
 };
-use tokio::sync::RwLock;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::{app::AppState, error::AppError};
+use crate::{
+    app::AppState,
+    error::{AppError, AppResult},
+    ops::event_catalog::{Event, EventCode},
+    repo::{
+        self,
+        admin_sessions::{self, NewAdminSession},
+    },
+    service::{self, settings_registry},
+    util::password::{is_phc_hash, verify_password},
+};
+
+/// 同时在线会话上限（0 为不限制）
+pub const MAX_SESSIONS_KEY: &str = "admin.max_sessions";
+/// 达到上限时的处理方式：`evict_oldest` 踢掉最早的会话，`reject` 拒绝新登录
+pub const SESSION_LIMIT_POLICY_KEY: &str = "admin.session_limit_policy";
+pub const SESSION_LIMIT_POLICIES: &[&str] = &["evict_oldest", "reject"];
+
+/// 脚本与集成以该请求头携带 API 密钥，代替交互式登录得到的 Bearer token
+pub const API_KEY_HEADER: &str = "x-api-key";
+// 以 API 密钥调用时的身份名前缀；用户名不允许包含冒号，不会与账号重名
+const API_KEY_IDENTITY_PREFIX: &str = "api-key:";
+
+/// 后台角色，按权限从低到高排列：`viewer` 只读，`editor` 可维护订阅源与文章，
+/// `admin` 另可管理账号、设置、会话与维护任务
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    Viewer,
+    Editor,
+    Admin,
+}
 
+pub const ROLES: &[&str] = &["admin", "editor", "viewer"];
+
+impl Role {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "admin" => Some(Role::Admin),
+            "editor" => Some(Role::Editor),
+            "viewer" => Some(Role::Viewer),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::Editor => "editor",
+            Role::Viewer => "viewer",
+        }
+    }
+
+    /// 角色授予的权限范围：`read` 读取，`write` 维护订阅源与文章，`admin` 管理类接口
+    pub fn scopes(self) -> &'static [&'static str] {
+        match self {
+            Role::Admin => &["read", "write", "admin"],
+            Role::Editor => &["read", "write"],
+            Role::Viewer => &["read"],
+        }
+    }
+}
+
+// 只有 admin 可访问的后台接口（路径前缀，相对 `/admin/api`）
+const ADMIN_ONLY_PREFIXES: &[&str] = &[
+    "/users",
+    "/api_keys",
+    "/settings",
+    "/sessions",
+    "/maintenance",
+    "/share",
+];
+
+/// 访问某个后台接口所需的最低角色：账号、设置等管理类接口仅限 admin，
+/// 其余接口 viewer 只能读取（GET），写操作需要 editor
+pub fn required_role(method: &Method, path: &str) -> Role {
+    let path = path.strip_prefix("/admin/api").unwrap_or(path);
+    let admin_only = ADMIN_ONLY_PREFIXES.iter().any(|prefix| {
+        path.strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    });
+    if admin_only {
+        Role::Admin
+    } else if method == Method::GET || method == Method::HEAD {
+        Role::Viewer
+    } else {
+        Role::Editor
+    }
+}
+
+/// 登录校验通过的账号
+#[derive(Debug, Clone)]
+pub struct Account {
+    pub username: String,
+    pub role: Role,
+}
+
+/// 会话保存在 `news.admin_sessions`，数据库中只存 token 的 SHA-256 摘要。
+/// 配置文件中的管理员始终为 admin 角色，其余账号保存在 `news.users`
 #[derive(Clone)]
 pub struct AdminManager {
     username: Arc<str>,
-    password: Arc<str>,
+    password: BuiltinPassword,
     session_ttl: Duration,
-    sessions: Arc<RwLock<HashMap<String, Instant>>>,
+    pool: PgPool,
+}
+
+// 配置文件中管理员的密码：优先使用 `admin.password_hash`，未配置时退回明文 `admin.password`
+#[derive(Clone)]
+enum BuiltinPassword {
+    Plain(Arc<str>),
+    Hash(Arc<str>),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SessionStatus {
-    Valid,
+    Valid(AdminContext),
     Expired,
     Invalid,
 }
 
+/// 登录时记录的客户端信息
+#[derive(Debug, Default)]
+pub struct SessionMeta {
+    pub client_ip: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+/// 新会话及因超出上限被踢下线的会话数
+pub struct IssuedSession {
+    pub token: String,
+    pub evicted: u64,
+}
+
 impl AdminManager {
-    pub fn new(username: String, password: String, session_ttl: Duration) -> Self {
+    /// `password_hash` 无法解析时启动失败，避免配置写错后管理员无法登录却不易察觉
+    pub fn new(
+        username: String,
+        password: String,
+        password_hash: Option<String>,
+        session_ttl: Duration,
+        pool: PgPool,
+    ) -> anyhow::Result<Self> {
         let ttl = if session_ttl.is_zero() {
             Duration::from_secs(300)
         } else {
             session_ttl
         };
+        let password = match password_hash
+            .as_deref()
+            .map(str::trim)
+            .filter(|hash| !hash.is_empty())
+        {
+            Some(hash) => {
+                if !is_phc_hash(hash) {
+                    anyhow::bail!(
+                        "admin.password_hash is not a valid PHC string (generate one with --hash-password)"
+                    );
+                }
+                BuiltinPassword::Hash(Arc::from(hash))
+            }
+            None => {
+                tracing::warn!("admin.password is stored in plaintext; consider admin.password_hash");
+                BuiltinPassword::Plain(Arc::from(password))
+            }
+        };
 
-        Self {
+        Ok(Self {
             username: Arc::from(username.trim().to_string()),
-            password: Arc::from(password),
+            password,
             session_ttl: ttl,
-            sessions: Arc::new(RwLock::new(HashMap::new())),
+            pool,
+        })
+    }
+
+    /// 配置文件中的管理员用户名，`news.users` 中不允许使用
+    pub fn builtin_username(&self) -> &str {
+        &self.username
+    }
+
+    /// 校验用户名与密码，成功时返回账号及其角色；停用的账号无法登录
+    pub async fn authenticate(&self, username: &str, password: &str) -> AppResult<Option<Account>> {
+        let username = username.trim();
+        if username == self.username.as_ref() {
+            return Ok(self.verify_builtin_password(password).await.then(|| Account {
+                username: username.to_string(),
+                role: Role::Admin,
+            }));
+        }
+        let Some(user) = repo::users::find_by_username(&self.pool, username).await? else {
+            return Ok(None);
+        };
+        // 单点登录账号只能经身份提供方登录
+        if user.disabled_at.is_some()
+            || user.auth_source != repo::users::AUTH_SOURCE_PASSWORD
+            || !verify_password(password, &user.password_hash).await
+        {
+            return Ok(None);
+        }
+        let Some(role) = Role::parse(&user.role) else {
+            return Ok(None);
+        };
+        repo::users::record_login(&self.pool, user.id).await?;
+        Ok(Some(Account {
+            username: user.username,
+            role,
+        }))
+    }
+
+    async fn verify_builtin_password(&self, password: &str) -> bool {
+        match &self.password {
+            BuiltinPassword::Hash(hash) => verify_password(password, hash).await,
+            BuiltinPassword::Plain(expected) => password == expected.as_ref(),
         }
     }
 
-    pub fn verify_credentials(&self, username: &str, password: &str) -> bool {
-        username == self.username.as_ref() && password == self.password.as_ref()
+    // 角色在每次请求时读取，调整角色立即生效，无需重新登录；配置文件中的管理员没有账号 ID
+    async fn resolve_account(&self, username: &str) -> Result<Option<(Option<i64>, Role)>, sqlx::Error> {
+        if username == self.username.as_ref() {
+            return Ok(Some((None, Role::Admin)));
+        }
+        let account = repo::users::find_active(&self.pool, username).await?;
+        Ok(account.and_then(|(id, role)| Role::parse(&role).map(|role| (Some(id), role))))
     }
 
     pub fn ttl_secs(&self) -> u64 {
         self.session_ttl.as_secs()
     }
 
-    pub async fn issue_session(&self) -> String {
-        self.prune_expired().await;
+    pub async fn issue_session(&self, username: &str, meta: SessionMeta) -> AppResult<IssuedSession> {
+        admin_sessions::delete_expired(&self.pool).await?;
+
+        let limit = settings_registry::get_i64(&self.pool, MAX_SESSIONS_KEY)
+            .await?
+            .unwrap_or(0);
+        let mut evicted = 0;
+        if limit > 0 {
+            let active = admin_sessions::count_active(&self.pool, username).await?;
+            if active >= limit {
+                let policy = settings_registry::get_value(&self.pool, SESSION_LIMIT_POLICY_KEY).await?;
+                if policy.as_deref() == Some("reject") {
+                    return Err(AppError::Unauthorized(format!(
+                        "已达到同时登录会话上限（{limit}），请先在已登录的设备上退出"
+                    )));
+                }
+                evicted = admin_sessions::evict_oldest(&self.pool, username, limit - 1).await?;
+            }
+        }
+
         let token = Uuid::new_v4().to_string();
-        let expires_at = Instant::now() + self.session_ttl;
-        self.sessions
-            .write()
-            .await
-            .insert(token.clone(), expires_at);
-        token
+        admin_sessions::insert(
+            &self.pool,
+            &NewAdminSession {
+                token_hash: &token_hash(&token),
+                username,
+                client_ip: meta.client_ip.as_deref(),
+                user_agent: meta.user_agent.as_deref(),
+                ttl_secs: self.session_ttl.as_secs() as i64,
+            },
+        )
+        .await?;
+        Ok(IssuedSession { token, evicted })
     }
 
-    pub async fn validate_session(&self, token: &str) -> SessionStatus {
-        let mut guard = self.sessions.write().await;
-        let now = Instant::now();
-        if let Some(expiry) = guard.get_mut(token) {
-            if *expiry > now {
-                *expiry = now + self.session_ttl;
-                return SessionStatus::Valid;
+    pub async fn validate_session(&self, token: &str) -> Result<SessionStatus, sqlx::Error> {
+        let hash = token_hash(token);
+        let ttl = self.session_ttl.as_secs() as i64;
+        if let Some((session_id, username)) = admin_sessions::touch(&self.pool, &hash, ttl).await? {
+            if let Some((user_id, role)) = self.resolve_account(&username).await? {
+                return Ok(SessionStatus::Valid(AdminContext {
+                    session_id: Some(session_id),
+                    user_id,
+                    api_key_id: None,
+                    username,
+                    role,
+                    scopes: role.scopes(),
+                }));
             }
-            // expired -> remove and signal Expired
-            guard.remove(token);
-            return SessionStatus::Expired;
+            // 账号已删除、停用，或配置中的管理员用户名已变更
+            admin_sessions::delete_by_hash(&self.pool, &hash).await?;
+            return Ok(SessionStatus::Invalid);
+        }
+        // 存在但已过期 -> 删除并视为过期
+        if admin_sessions::delete_by_hash(&self.pool, &hash).await? {
+            return Ok(SessionStatus::Expired);
         }
-        SessionStatus::Invalid
+        Ok(SessionStatus::Invalid)
     }
 
-    pub async fn revoke_session(&self, token: &str) {
-        self.sessions.write().await.remove(token);
+    pub async fn revoke_session(&self, token: &str) -> Result<bool, sqlx::Error> {
+        admin_sessions::delete_by_hash(&self.pool, &token_hash(token)).await
     }
 
-    async fn prune_expired(&self) {
-        let now = Instant::now();
-        self.sessions
-            .write()
-            .await
-            .retain(|_, expiry| *expiry > now);
+    /// 校验 `X-Api-Key`：未吊销、未过期的密钥按其角色放行，身份名为 `api-key:<名称>`
+    pub async fn validate_api_key(&self, key: &str) -> Result<Option<AdminContext>, sqlx::Error> {
+        let Some((id, name, role)) = repo::api_keys::touch(&self.pool, &token_hash(key)).await? else {
+            return Ok(None);
+        };
+        let Some(role) = Role::parse(&role) else {
+            return Ok(None);
+        };
+        tracing::debug!(api_key_id = id, name = %name, "admin request authenticated by api key");
+        Ok(Some(AdminContext {
+            session_id: None,
+            user_id: None,
+            api_key_id: Some(id),
+            username: format!("{API_KEY_IDENTITY_PREFIX}{name}"),
+            role,
+            scopes: role.scopes(),
+        }))
     }
 }
 
+/// SHA-256 十六进制摘要；泄露的数据库记录无法还原出可用的 token 或 API 密钥
+pub(crate) fn token_hash(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
 pub async fn require_admin(
     axum::extract::State(state): axum::extract::State<AppState>,
     mut req: axum::http::Request<axum::body::Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    let token = extract_bearer(req.headers()).or_else(|| {
+    // 携带 API 密钥时只按密钥校验，密钥无效不再回退到会话 token
+    let context = match extract_api_key(req.headers()) {
+        Some(key) => state
+            .admin
+            .validate_api_key(&key)
+            .await
+            .map_err(|err| {
+                tracing::error!(error = ?err, "failed to validate api key");
+                StatusCode::SERVICE_UNAVAILABLE
+            })?
+            .ok_or(StatusCode::UNAUTHORIZED)?,
+        None => session_context(&state, session_token(&req)).await?,
+    };
+
+    let required = required_role(req.method(), req.uri().path());
+    if context.role < required {
+        tracing::warn!(
+            username = %context.username,
+            role = context.role.as_str(),
+            method = %req.method(),
+            path = req.uri().path(),
+            "admin request forbidden for role"
+        );
+        return Err(StatusCode::FORBIDDEN);
+    }
+    req.extensions_mut().insert(context);
+    Ok(next.run(req).await)
+}
+
+fn session_token(req: &axum::http::Request<axum::body::Body>) -> Option<String> {
+    extract_bearer(req.headers()).or_else(|| {
         // Fallback: allow query param `token` (for SSE/EventSource which can't set headers)
         req.uri().query().and_then(|q| {
             let params = form_urlencoded::parse(q.as_bytes());
@@ -109,26 +375,26 @@ pub async fn require_admin(
             }
             None
         })
-    }).ok_or(StatusCode::UNAUTHORIZED)?;
+    })
+}
 
-    match state.admin.validate_session(&token).await {
-        SessionStatus::Valid => {
-            req.extensions_mut().insert(AdminIdentity {});
-            Ok(next.run(req).await)
-        }
+async fn session_context(state: &AppState, token: Option<String>) -> Result<AdminContext, StatusCode> {
+    let token = token.ok_or(StatusCode::UNAUTHORIZED)?;
+    let status = state.admin.validate_session(&token).await.map_err(|err| {
+        tracing::error!(error = ?err, "failed to validate admin session");
+        StatusCode::SERVICE_UNAVAILABLE
+    })?;
+    match status {
+        SessionStatus::Valid(context) => Ok(context),
         SessionStatus::Expired => {
             // 写入一条“管理员登出（会话过期）”事件，避免敏感信息泄露，不记录 token
             let pool = state.pool.clone();
             tokio::spawn(async move {
-                let _ = crate::repo::events::upsert_event(
-                    &pool,
-                    &crate::repo::events::NewEvent {
-                        level: "info".to_string(),
-                        code: "ADMIN_LOGOUT".to_string(),
-                        addition_info: Some("会话已过期，自动登出".to_string()),
-                    },
-                    0,
-                ).await;
+                Event::new(EventCode::AdminLogout)
+                    .attr("reason", "expired")
+                    .detail("会话已过期，自动登出")
+                    .emit(&pool)
+                    .await;
             });
             Err(StatusCode::UNAUTHORIZED)
         }
@@ -136,6 +402,21 @@ pub async fn require_admin(
     }
 }
 
+/// `/shared` 下的接口不需要登录，但必须携带有效的签名与过期时间，见 `service::share`
+pub async fn require_signed_url(
+    axum::extract::State(state): axum::extract::State<AppState>,
+    req: axum::http::Request<axum::body::Body>,
+    next: Next,
+) -> AppResult<Response> {
+    service::share::verify(&state.pool, req.uri().path(), req.uri().query(), chrono::Utc::now()).await?;
+    Ok(next.run(req).await)
+}
+
+fn extract_api_key(headers: &HeaderMap) -> Option<String> {
+    let value = headers.get(API_KEY_HEADER)?.to_str().ok()?.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
 fn extract_bearer(headers: &HeaderMap) -> Option<String> {
     let value = headers.get(header::AUTHORIZATION)?;
     let raw = value.to_str().ok()?;
@@ -149,8 +430,49 @@ fn extract_bearer(headers: &HeaderMap) -> Option<String> {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub struct AdminIdentity;
+/// `require_admin` 校验通过后写入请求扩展的认证上下文，处理函数直接作为提取器使用，
+/// 服务层据此记录操作人、执行按角色的规则，无需再解析请求头
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdminContext {
+    /// 当前请求所用会话，便于会话管理接口识别“本会话”；以 API 密钥调用时为 None
+    pub session_id: Option<i64>,
+    /// `news.users` 中的账号 ID；配置文件中的管理员与 API 密钥为 None
+    pub user_id: Option<i64>,
+    /// 以 API 密钥调用时为密钥 ID
+    pub api_key_id: Option<i64>,
+    /// 账号用户名，API 密钥为 `api-key:<名称>`；同时作为审计记录中的操作人
+    pub username: String,
+    pub role: Role,
+    /// 本次凭据的权限范围，见 `Role::scopes`
+    pub scopes: &'static [&'static str],
+}
+
+impl AdminContext {
+    /// 路由级权限之外的细分规则，角色不足时返回 403
+    pub fn require_role(&self, role: Role) -> AppResult<()> {
+        if self.role < role {
+            return Err(AppError::Forbidden(format!(
+                "该操作需要 {} 角色",
+                role.as_str()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for AdminContext {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        // 只在 `require_admin` 之后的路由上可用；缺失说明路由未挂认证中间件
+        parts
+            .extensions
+            .get::<AdminContext>()
+            .cloned()
+            .ok_or_else(|| AppError::Unauthorized("未登录".to_string()))
+    }
+}
 
 
 pub fn invalid_credentials_error() -> AppError {
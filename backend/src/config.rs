@@ -9,12 +9,21 @@ use std::{
 #[serde(default)]
 pub struct ServerConfig {
     pub bind: String,
+    /// 受信反向代理地址或网段；仅这些对端发来的 X-Forwarded-For 会被采信
+    pub trusted_proxies: Vec<String>,
+    /// 请求体大小上限（字节），书签导入接口另有单独上限
+    pub max_body_bytes: usize,
+    /// 设置后 `/metrics` 需携带 `Authorization: Bearer <token>`，缺省不校验
+    pub metrics_token: Option<String>,
 }
 
 impl Default for ServerConfig {
     fn default() -> Self {
         Self {
             bind: "127.0.0.1:8080".to_string(),
+            trusted_proxies: vec!["127.0.0.1".to_string(), "::1".to_string()],
+            max_body_bytes: 2 * 1024 * 1024,
+            metrics_token: None,
         }
     }
 }
@@ -24,6 +33,16 @@ impl Default for ServerConfig {
 pub struct DbConfig {
     pub url: String,
     pub max_connections: u32,
+    /// 单条 SQL 执行超过该毫秒数视为慢查询
+    pub slow_query_ms: u64,
+    /// 探测获取连接的等待超过该毫秒数视为连接池吃紧
+    pub acquire_warn_ms: u64,
+    /// 连接池探测间隔（秒）
+    pub pool_probe_secs: u64,
+    /// 会话级 statement_timeout（毫秒），0 表示不限制
+    pub statement_timeout_ms: u64,
+    /// 公共接口单次请求的处理时限（毫秒），超时返回 503，0 表示不限制
+    pub request_timeout_ms: u64,
 }
 
 impl Default for DbConfig {
@@ -31,6 +50,11 @@ impl Default for DbConfig {
         Self {
             url: String::new(),
             max_connections: 5,
+            slow_query_ms: 2000,
+            acquire_warn_ms: 1000,
+            pool_probe_secs: 30,
+            statement_timeout_ms: 15000,
+            request_timeout_ms: 10000,
         }
     }
 }
@@ -41,9 +65,19 @@ pub struct FetcherConfig {
     pub interval_secs: u64,
     pub batch_size: u32,
     pub concurrency: u32,
+    /// 慢车道（需要翻译或 LLM 去重的 feed）并发数，与 `concurrency` 分开计算
+    pub slow_lane_concurrency: u32,
     pub request_timeout_secs: u64,
     pub quick_retry_attempts: u32,
     pub quick_retry_delay_secs: u64,
+    /// 同一来源域名每分钟最多发起的抓取请求数，0 表示不限制
+    pub domain_requests_per_minute: u32,
+    /// 同一来源域名可连续发出的请求数（令牌桶容量），超出后按上面的速率排队
+    pub domain_burst: u32,
+    /// 抓取前检查目标站点的 robots.txt，被禁止的地址不抓取
+    pub respect_robots_txt: bool,
+    /// 多实例部署时的实例标识，各实例须不同且重启后保持不变；缺省为主机名
+    pub instance_id: Option<String>,
 }
 
 impl Default for FetcherConfig {
@@ -52,9 +86,14 @@ impl Default for FetcherConfig {
             interval_secs: 300,
             batch_size: 8,
             concurrency: 4,
+            slow_lane_concurrency: 1,
             request_timeout_secs: 15,
             quick_retry_attempts: 1,
             quick_retry_delay_secs: 10,
+            domain_requests_per_minute: 0,
+            domain_burst: 2,
+            respect_robots_txt: false,
+            instance_id: None,
         }
     }
 }
@@ -137,45 +176,174 @@ impl Default for DeepseekConfig {
 // Baidu translator support removed
 
 
+// Ollama 连接参数仅从 settings 读取（见 util::translator）
+
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
-pub struct OllamaConfig {
-    pub base_url: String,
-    pub model: String,
+pub struct AdminConfig {
+    pub username: String,
+    /// 明文密码，仅在未配置 `password_hash` 时使用
+    pub password: String,
+    /// argon2id 哈希（PHC 字符串），可用 `--hash-password` 生成；配置后忽略 `password`
+    pub password_hash: Option<String>,
+    pub session_ttl_secs: u64,
+    /// 配置后后台登录页提供单点登录入口，与密码登录并存
+    pub oidc: Option<OidcConfig>,
+}
+
+impl Default for AdminConfig {
+    fn default() -> Self {
+        Self {
+            username: "admin".to_string(),
+            password: "123456".to_string(),
+            password_hash: None,
+            session_ttl_secs: 300,
+            oidc: None,
+        }
+    }
+}
+
+/// OpenID Connect 单点登录（授权码流程）。端点从 `{issuer}/.well-known/openid-configuration` 发现，
+/// 用户名与角色取自 userinfo 端点返回的声明
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct OidcConfig {
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    /// 在身份提供方登记的回调地址，缺省为 `{api_base_url}/admin/oidc/callback`
+    pub redirect_uri: Option<String>,
+    pub scopes: Vec<String>,
+    /// 作为后台用户名的声明
+    pub username_claim: String,
+    /// 用于映射角色的声明，可以是字符串或字符串数组（如 `groups`）
+    pub role_claim: String,
+    pub role_mapping: OidcRoleMapping,
+    /// 声明未匹配任何角色时授予的角色，缺省拒绝登录
+    pub default_role: Option<String>,
+    /// 登录完成后跳转的前端地址，缺省为站点的 `/admin`
+    pub post_login_redirect: Option<String>,
     pub timeout_secs: u64,
 }
 
-impl Default for OllamaConfig {
+impl Default for OidcConfig {
     fn default() -> Self {
         Self {
-            base_url: "http://127.0.0.1:11434".to_string(),
-            model: "qwen2.5:3b".to_string(),
-            timeout_secs: 30,
+            issuer: String::new(),
+            client_id: String::new(),
+            client_secret: String::new(),
+            redirect_uri: None,
+            scopes: vec![
+                "openid".to_string(),
+                "profile".to_string(),
+                "email".to_string(),
+            ],
+            username_claim: "preferred_username".to_string(),
+            role_claim: "groups".to_string(),
+            role_mapping: OidcRoleMapping::default(),
+            default_role: None,
+            post_login_redirect: None,
+            timeout_secs: 10,
         }
     }
 }
 
+/// 角色声明的取值到后台角色的映射，同时匹配多个时取最高的角色
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct OidcRoleMapping {
+    pub admin: Vec<String>,
+    pub editor: Vec<String>,
+    pub viewer: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct DigestConfig {
+    /// 配置后每周精选生成时同时发送邮件；缺省只提供 RSS
+    pub smtp: Option<SmtpConfig>,
+}
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
-pub struct AdminConfig {
-    pub username: String,
-    pub password: String,
-    pub session_ttl_secs: u64,
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    /// `starttls`（缺省）、`tls`（隐式 TLS，通常为 465 端口）或 `none`
+    pub security: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from: String,
+    pub to: Vec<String>,
 }
 
-impl Default for AdminConfig {
+impl Default for SmtpConfig {
     fn default() -> Self {
         Self {
-            username: "admin".to_string(),
-            password: "123456".to_string(),
-            session_ttl_secs: 300,
+            host: String::new(),
+            port: 587,
+            security: "starttls".to_string(),
+            username: None,
+            password: None,
+            from: String::new(),
+            to: Vec::new(),
         }
     }
 }
 
+/// 数据库逻辑备份。配置 `command` 时交给外部命令（如 pg_dump、上传 S3 的脚本），
+/// 否则在事务快照内逐表 `COPY ... TO STDOUT` 导出 CSV 到 `dir` 下的子目录
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
+pub struct BackupConfig {
+    pub dir: String,
+    /// 经 `sh -c` 执行，环境变量 `BACKUP_FILE`（建议的输出文件）、`BACKUP_DIR`、`DATABASE_URL` 可用
+    pub command: Option<String>,
+    /// 定时备份的 cron 表达式（UTC，5 或 6 段），缺省只能手动触发
+    pub schedule: Option<String>,
+    /// `dir` 下保留的最近备份份数，0 表示不清理
+    pub keep: u32,
+    pub timeout_secs: u64,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            dir: "backups".to_string(),
+            command: None,
+            schedule: None,
+            keep: 7,
+            timeout_secs: 3600,
+        }
+    }
+}
+
+// Embedding 由 Ollama 生成，Ollama 地址沿用翻译设置中的 `translation.ollama_base_url`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct QdrantConfig {
+    pub url: String,
+    pub api_key: Option<String>,
+    pub collection: String,
+    pub embedding_model: String,
+    pub timeout_secs: u64,
+}
+
+impl Default for QdrantConfig {
+    fn default() -> Self {
+        Self {
+            url: "http://127.0.0.1:6333".to_string(),
+            api_key: None,
+            collection: "news_articles".to_string(),
+            embedding_model: "nomic-embed-text".to_string(),
+            timeout_secs: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct AppConfig {
     pub server: ServerConfig,
     pub db: DbConfig,
@@ -184,20 +352,11 @@ pub struct AppConfig {
     pub http_client: HttpClientConfig,
     pub deployment: DeploymentConfig,
     pub admin: AdminConfig,
-}
-
-impl Default for AppConfig {
-    fn default() -> Self {
-        Self {
-            server: ServerConfig::default(),
-            db: DbConfig::default(),
-            fetcher: FetcherConfig::default(),
-            logging: LoggingConfig::default(),
-            http_client: HttpClientConfig::default(),
-            deployment: DeploymentConfig::default(),
-            admin: AdminConfig::default(),
-        }
-    }
+    pub digest: DigestConfig,
+    /// 配置后启用基于向量检索的历史去重；缺省只做标题 Jaccard 比较
+    pub qdrant: Option<QdrantConfig>,
+    /// 配置后可在后台触发或定时执行数据库备份
+    pub backup: Option<BackupConfig>,
 }
 
 impl AppConfig {
@@ -210,7 +369,7 @@ impl AppConfig {
         }
     }
 
-    /// 从指定的文件路径显式加载配置。
+    // 从指定的文件路径显式加载配置。
     // 删除未用到的 API（可从 Git 历史恢复）
 
     fn load_from_file(path: &Path) -> anyhow::Result<Self> {
@@ -293,13 +452,7 @@ fn locate_default_config() -> Option<PathBuf> {
         PathBuf::from("../config/config.yaml"),
     ];
 
-    for path in candidates {
-        if path.exists() {
-            return Some(path);
-        }
-    }
-
-    None
+    candidates.into_iter().find(|path| path.exists())
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -338,6 +491,16 @@ pub struct FrontendPublicConfig {
     pub api_base_url: String,
 }
 
+impl FrontendPublicConfig {
+    /// 站点根地址（API 基地址去掉 `/api`），用于 RSS、邮件中的链接
+    pub fn site_base_url(&self) -> String {
+        self.api_base_url
+            .strip_suffix("/api")
+            .unwrap_or(&self.api_base_url)
+            .to_string()
+    }
+}
+
 // 将主机或地址补全为带协议的形式，未指定协议时默认使用 http。
 fn format_host_base(host: &str) -> String {
     let trimmed = host.trim();
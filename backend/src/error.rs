@@ -12,10 +12,23 @@ pub enum AppError {
     BadRequest(String),
     #[error("unauthorized: {0}")]
     Unauthorized(String),
+    #[error("forbidden: {0}")]
+    Forbidden(String),
+    #[error("payload too large: {0}")]
+    PayloadTooLarge(String),
+    #[error("unprocessable entity: {0}")]
+    Unprocessable(String),
+    #[error("service unavailable: {0}")]
+    Unavailable(String),
     #[error("internal server error")]
     Internal(#[from] anyhow::Error),
 }
 
+/// 503 响应附带的 Retry-After（秒）
+pub const RETRY_AFTER_SECS: u64 = 5;
+// Postgres 的 query_canceled，statement_timeout 触发时返回该错误码
+const PG_QUERY_CANCELED: &str = "57014";
+
 #[derive(Serialize)]
 struct ErrorBody {
     error: ErrorDetail,
@@ -34,6 +47,17 @@ impl IntoResponse for AppError {
             AppError::Unauthorized(msg) => {
                 (StatusCode::UNAUTHORIZED, "Unauthorized".to_string(), msg)
             }
+            AppError::Forbidden(msg) => (StatusCode::FORBIDDEN, "Forbidden".to_string(), msg),
+            AppError::PayloadTooLarge(msg) => {
+                (StatusCode::PAYLOAD_TOO_LARGE, "PayloadTooLarge".to_string(), msg)
+            }
+            AppError::Unprocessable(msg) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "Unprocessable".to_string(), msg)
+            }
+            AppError::Unavailable(msg) => {
+                tracing::warn!(reason = %msg, "service unavailable");
+                (StatusCode::SERVICE_UNAVAILABLE, "Unavailable".to_string(), msg)
+            }
             AppError::Internal(err) => {
                 tracing::error!(error = ?err, "internal server error");
                 (
@@ -55,13 +79,25 @@ impl IntoResponse for AppError {
                 header::HeaderValue::from_static("Bearer"),
             );
         }
+        if matches!(status, StatusCode::SERVICE_UNAVAILABLE) {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, header::HeaderValue::from(RETRY_AFTER_SECS));
+        }
         response
     }
 }
 
 impl From<sqlx::Error> for AppError {
     fn from(err: sqlx::Error) -> Self {
-        AppError::Internal(err.into())
+        match &err {
+            // 连接池耗尽或语句超时属于暂时性压力，提示客户端稍后重试
+            sqlx::Error::PoolTimedOut => AppError::Unavailable("数据库繁忙，请稍后重试".to_string()),
+            sqlx::Error::Database(db_err) if db_err.code().as_deref() == Some(PG_QUERY_CANCELED) => {
+                AppError::Unavailable("数据库查询超时，请稍后重试".to_string())
+            }
+            _ => AppError::Internal(err.into()),
+        }
     }
 }
 
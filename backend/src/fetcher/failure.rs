@@ -0,0 +1,86 @@
+//! 抓取失败分类：写入 `news.feeds.last_error_kind`，管理端据此给出可操作的提示，
+//! 不必只凭状态码（网络层失败时为 0）判断原因。
+
+use reqwest::StatusCode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchErrorKind {
+    /// 域名解析失败
+    Dns,
+    /// TLS 握手或证书校验失败
+    Tls,
+    /// 连接或读取超时
+    Timeout,
+    /// 连接被拒绝、重置等其他网络错误
+    Connection,
+    Http4xx,
+    Http5xx,
+    /// 响应不是可解析的 RSS / Atom / JSON Feed
+    Parse,
+    /// 内容编码（gzip 等）解码失败，或非 UTF-8 内容转码后仍无法解析
+    Encoding,
+    /// robots.txt 禁止抓取
+    Robots,
+    Other,
+}
+
+impl FetchErrorKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FetchErrorKind::Dns => "dns",
+            FetchErrorKind::Tls => "tls",
+            FetchErrorKind::Timeout => "timeout",
+            FetchErrorKind::Connection => "connection",
+            FetchErrorKind::Http4xx => "http_4xx",
+            FetchErrorKind::Http5xx => "http_5xx",
+            FetchErrorKind::Parse => "parse",
+            FetchErrorKind::Encoding => "encoding",
+            FetchErrorKind::Robots => "robots",
+            FetchErrorKind::Other => "other",
+        }
+    }
+
+    pub fn from_status(status: StatusCode) -> Self {
+        if status.is_client_error() {
+            FetchErrorKind::Http4xx
+        } else if status.is_server_error() {
+            FetchErrorKind::Http5xx
+        } else {
+            FetchErrorKind::Other
+        }
+    }
+
+    /// 请求或读取响应体时的错误。reqwest 不区分 DNS 与 TLS 失败，按错误链中的底层信息判断
+    pub fn from_request_error(err: &reqwest::Error) -> Self {
+        if err.is_timeout() {
+            return FetchErrorKind::Timeout;
+        }
+        if let Some(status) = err.status() {
+            return Self::from_status(status);
+        }
+        if err.is_decode() {
+            return FetchErrorKind::Encoding;
+        }
+        let mut chain = String::new();
+        let mut source: Option<&(dyn std::error::Error + 'static)> = Some(err);
+        while let Some(current) = source {
+            if let Some(io) = current.downcast_ref::<std::io::Error>() {
+                if io.kind() == std::io::ErrorKind::TimedOut {
+                    return FetchErrorKind::Timeout;
+                }
+            }
+            chain.push_str(&current.to_string().to_ascii_lowercase());
+            chain.push('\n');
+            source = current.source();
+        }
+        if chain.contains("dns error") || chain.contains("failed to lookup address") {
+            FetchErrorKind::Dns
+        } else if chain.contains("certificate") || chain.contains("tls") || chain.contains("handshake") {
+            FetchErrorKind::Tls
+        } else if err.is_connect() || err.is_request() || err.is_body() {
+            FetchErrorKind::Connection
+        } else {
+            FetchErrorKind::Other
+        }
+    }
+}
@@ -1,11 +1,19 @@
-use std::{collections::BTreeSet, sync::Arc, time::Duration};
+pub mod failure;
+pub mod seed;
+pub mod shard;
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
 
 // 抓取器（Fetcher）模块：
 // 负责周期性地抓取订阅源（RSS/Atom）内容，进行：
 // 1. 网络请求（支持代理与超时）
 // 2. 条目解析与字段规范化（URL 归一化、发布时间提取）
 // 3. 标题去重（同一批次内 + 与最近历史文章）
-// 4. 可选的标题与摘要翻译（多翻译提供者级联，失败重试一次）
+// 4. 可选的标题与摘要翻译（多翻译提供者级联，失败则标记待翻译由补偿任务重试）
 // 5. 基于 Jaccard 相似度 + LLM（Deepseek/Ollama）判断跨文章重复
 // 6. 入库（文章主表 + 来源追踪表）与失败状态标记
 // 7. 支持快速重试与并发抓取控制
@@ -14,7 +22,8 @@ use std::{collections::BTreeSet, sync::Arc, time::Duration};
 
 use anyhow::{anyhow, Context};
 use chrono::{DateTime, Utc};
-use feed_rs::{model::Entry, parser};
+use feed_rs::model::{Entry, FeedType, Text};
+use futures::{stream, StreamExt};
 use reqwest::{Client, StatusCode};
 use reqwest::header::CONTENT_TYPE;
 use tokio::{
@@ -25,28 +34,48 @@ use tracing::{info, warn};
 
 use crate::{
     config::{FetcherConfig, HttpClientConfig},
-    ops::events::EventsHub,
+    ops::{
+        event_catalog::{Event, EventCode},
+        events::{ActivityVerbosity, EventsHub, FetcherActivity, FetcherActivityKind},
+    },
     repo::{
+        analytics::{self, DEDUP_METHOD_INTRA_BATCH, DEDUP_METHOD_URL},
         article_sources::{self, ArticleSourceRecord},
         articles::{self, ArticleRow, NewArticle},
         feeds::{self, DueFeedRow},
-        settings,
+        maintenance,
+        sources::{self, ANY_SOURCE, DEFAULT_SOURCE_WEIGHT},
+    },
+    service::{
+        embedding::{self, EmbeddingIndex},
+        enrichment::pipeline::{self, PipelineArticle},
+        extraction, retention, settings_registry,
     },
     util::{
         deepseek::ArticleSnippet,
-        html::strip_html_basic,
-        title::{jaccard_similarity, prepare_title_signature},
-        translator::TranslationEngine,
+        cache::LatestArticlesCache,
+        feed_dates::{parse_feed, DateOverrides},
+        feed_headers,
+        html::{strip_html_basic, truncate_summary},
+        rate_limit::DomainRateLimiter,
+        robots,
+        lang::{detect_language, normalize_language_tag},
+        qdrant::ScoredPoint,
+        schedule,
+        title::{content_fingerprint, jaccard_similarity, prepare_title_signature, title_simhash},
+        translator::{DedupSwitch, TranslationEngine, TranslationFailed, TranslatorProvider},
         url_norm::normalize_article_url,
     },
 };
-use crate::repo::events as repo_events;
+
+use failure::FetchErrorKind;
+use shard::ShardMember;
 
 // 编码探测与转码
 use encoding_rs::Encoding;
 use chardetng::EncodingDetector;
 
-fn transcode_to_utf8(bytes: &[u8], content_type: Option<&str>) -> Vec<u8> {
+pub(crate) fn transcode_to_utf8(bytes: &[u8], content_type: Option<&str>) -> Vec<u8> {
     // 快速路径：若本身是有效 UTF-8，直接返回原始字节，避免误判造成的乱码
     if std::str::from_utf8(bytes).is_ok() {
         return bytes.to_vec();
@@ -106,11 +135,12 @@ struct CandidateArticle {
     summary: ArticleSummary,
 }
 
-const TRANSLATION_LANG: &str = "zh-CN";
+/// 翻译完成的文章写入的 `language`
+pub const TRANSLATION_LANG: &str = "zh-CN";
 
 // 轻量级 HTML 实体解码：
 // 支持常见命名实体与十进制/十六进制数字实体，避免引入额外依赖。
-fn html_unescape_minimal(input: &str) -> String {
+pub(crate) fn html_unescape_minimal(input: &str) -> String {
     // 快速路径：没有'&'则直接返回原字符串拷贝
     if !input.as_bytes().contains(&b'&') {
         return input.to_string();
@@ -171,68 +201,48 @@ fn html_unescape_minimal(input: &str) -> String {
     out
 }
 
-fn should_translate_title(title: &str) -> bool {
-    // 翻译判定逻辑：
-    // 1. 空标题不翻译
-    // 2. 已包含 CJK（中文、日文、韩文统一表意字符）则认为不需要翻译
-    // 3. 统计 ASCII 字母 vs 非 ASCII 字母比例，避免纯符号或数字
-    // 4. ASCII 比例 >= 0.6 认为是英文主导，触发翻译
-    if title.trim().is_empty() {
-        return false;
-    }
-
-    if contains_cjk(title) {
-        return false;
-    }
-
-    let mut ascii_letters = 0;
-    let mut non_ascii_letters = 0;
-
-    for ch in title.chars() {
-        if ch.is_ascii_alphabetic() {
-            ascii_letters += 1;
-        } else if ch.is_alphabetic() {
-            non_ascii_letters += 1;
-        }
-    }
-
-    let total_letters = ascii_letters + non_ascii_letters;
-    if total_letters == 0 {
-        return false;
-    }
-
-    if ascii_letters == 0 {
-        return false;
-    }
-
-    let ratio = ascii_letters as f32 / total_letters as f32;
-    ratio >= 0.6
-}
-
-fn contains_cjk(value: &str) -> bool {
-    value.chars().any(|ch| {
-        matches!(
-            ch,
-            '\u{4E00}'..='\u{9FFF}'
-                | '\u{3400}'..='\u{4DBF}'
-                | '\u{20000}'..='\u{2A6DF}'
-                | '\u{2A700}'..='\u{2B73F}'
-                | '\u{2B740}'..='\u{2B81F}'
-                | '\u{2B820}'..='\u{2CEAF}'
-                | '\u{F900}'..='\u{FAFF}'
-                | '\u{2F800}'..='\u{2FA1F}'
-        )
-    })
-}
-
 // Jaccard 严格重复阈值：>= 0.9 判定为几乎完全重复
-const STRICT_DUP_THRESHOLD: f32 = 0.9;
+pub(crate) const STRICT_DUP_THRESHOLD: f32 = 0.9;
+/// 标题 SimHash 全量历史去重的开关与最大汉明距离（0~3，受分段索引限制），见 `articles::find_by_simhashes`
+pub const SIMHASH_ENABLED_KEY: &str = "dedup.simhash_enabled";
+pub const SIMHASH_DISTANCE_KEY: &str = "dedup.simhash_distance";
+pub const DEFAULT_SIMHASH_DISTANCE: i64 = 3;
+/// 入库摘要的最大字符数，0 表示不限制；超出时在句子边界截断并追加省略号
+pub const DESCRIPTION_MAX_CHARS_KEY: &str = "ingest.description_max_chars";
+/// 推送给管理端的抓取进度详细程度，见 `ops::events::ActivityVerbosity`
+pub const ACTIVITY_VERBOSITY_KEY: &str = "fetcher.activity_verbosity";
 // 触发 LLM 深度相似度判定的较宽松阈值：>= 0.6 进入 Deepseek 检查
-const DEEPSEEK_THRESHOLD: f32 = 0.6;
-// 最近历史文章数量上限：控制比较规模与性能
-const RECENT_ARTICLE_LIMIT: i64 = 100;
+pub(crate) const DEEPSEEK_THRESHOLD: f32 = 0.6;
+
+/// 按阈值给出标题相似度的判定：`strict_duplicate`、`llm_check` 或 `distinct`
+pub(crate) fn dedup_verdict(jaccard: f32, identical_normalized: bool) -> &'static str {
+    if jaccard >= STRICT_DUP_THRESHOLD || identical_normalized {
+        "strict_duplicate"
+    } else if jaccard >= DEEPSEEK_THRESHOLD {
+        "llm_check"
+    } else {
+        "distinct"
+    }
+}
+// 去重时间窗口（小时）的缺省值，可通过 `dedup.window_hours` 设置调整
+pub(crate) const DEFAULT_DEDUP_WINDOW_HOURS: i64 = 72;
+// 时间窗口内历史候选数量的安全上限：防止窗口过大时比较规模失控
+const DEDUP_CANDIDATE_CAP: i64 = 2000;
+// 超出来源每日上限时写入 hidden_reason 的原因
+const DAILY_CAP_HIDDEN_REASON: &str = "daily_cap";
+/// 需审核的源（`feeds.require_review`）新文章入库时写入 hidden_reason 的原因，批准后清除
+pub const PENDING_REVIEW_HIDDEN_REASON: &str = "pending_review";
+// JSON Feed 条目无标题时，从正文截取的标题长度
+const JSON_FEED_TITLE_CHARS: usize = 80;
 // 对单篇新文章进行 LLM 相似度检查的最大次数（防止成本与延迟爆炸）
 const MAX_DEEPSEEK_CHECKS: usize = 3;
+// 补偿翻译：每轮处理的待翻译文章数量与单篇最大重试次数
+const TRANSLATION_BACKLOG_BATCH: i64 = 20;
+const TRANSLATION_MAX_ATTEMPTS: i32 = 5;
+// 单个 feed 内同时进行的条目翻译数量上限，避免压垮翻译服务商
+const TRANSLATION_CONCURRENCY: usize = 3;
+// 写入 `news.feeds.last_error` 的错误信息长度上限
+const FETCH_ERROR_MAX_CHARS: usize = 500;
 
 pub fn spawn(
     pool: sqlx::PgPool,
@@ -240,15 +250,112 @@ pub fn spawn(
     http_client_config: HttpClientConfig,
     translator: Arc<TranslationEngine>,
     events: EventsHub,
+    latest_cache: LatestArticlesCache,
 ) -> anyhow::Result<()> {
     // 后台启动永久运行的抓取循环任务
-    let fetcher = Fetcher::new(pool.clone(), fetcher_config, http_client_config, translator, events.clone())?;
+    let backlog_interval = Duration::from_secs(normalize_fetcher_config(fetcher_config.clone()).interval_secs);
+    let fetcher = Fetcher::new(
+        pool.clone(),
+        fetcher_config,
+        http_client_config,
+        Arc::clone(&translator),
+        events.clone(),
+        Arc::clone(&latest_cache),
+    )?;
     tokio::spawn(async move {
         if let Err(err) = fetcher.run().await {
             tracing::error!(error = ?err, "fetcher stopped");
             // event suppressed per request to cancel all bindings
         }
     });
+    // 补偿翻译任务：重试抓取时翻译失败、以原文入库的文章
+    tokio::spawn(run_translation_backlog(pool, translator, latest_cache, backlog_interval));
+    Ok(())
+}
+
+async fn run_translation_backlog(
+    pool: sqlx::PgPool,
+    translation: Arc<TranslationEngine>,
+    latest_cache: LatestArticlesCache,
+    period: Duration,
+) {
+    let mut ticker = interval(period);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        if !translation.translation_enabled() {
+            continue;
+        }
+        match settings_registry::quiet_hours_active(&pool, Utc::now()).await {
+            Ok(true) => continue,
+            Ok(false) => {}
+            Err(err) => warn!(error = ?err, "failed to read quiet hours setting"),
+        }
+        if let Err(err) = process_translation_backlog(&pool, &translation, &latest_cache).await {
+            warn!(error = ?err, "translation backlog iteration failed");
+        }
+    }
+}
+
+async fn process_translation_backlog(
+    pool: &sqlx::PgPool,
+    translation: &TranslationEngine,
+    latest_cache: &LatestArticlesCache,
+) -> anyhow::Result<()> {
+    let pending = articles::list_translation_pending(pool, TRANSLATION_BACKLOG_BATCH).await?;
+    if pending.is_empty() {
+        return Ok(());
+    }
+    info!(count = pending.len(), "retrying pending translations");
+
+    for row in pending {
+        if !translation.should_translate(row.original_language.as_deref()) {
+            articles::clear_translation_pending(pool, row.id).await?;
+            info!(article_id = row.id, "pending translation dropped (no matching translation rule)");
+            continue;
+        }
+        match translation.translate(&row.title, row.description.as_deref()).await {
+            Ok(Some(translated)) => {
+                articles::apply_translation(
+                    pool,
+                    row.id,
+                    &translated.title,
+                    translated.description.as_deref(),
+                    TRANSLATION_LANG,
+                )
+                .await?;
+                latest_cache.clear();
+                info!(article_id = row.id, "pending translation completed");
+            }
+            Ok(None) => {
+                // 当前无可用 provider：保持待翻译状态，等待下一轮
+                info!(article_id = row.id, "pending translation skipped (provider unavailable)");
+                break;
+            }
+            Err(err) => {
+                let still_pending =
+                    articles::record_translation_failure(pool, row.id, TRANSLATION_MAX_ATTEMPTS).await?;
+                warn!(
+                    error = %err,
+                    article_id = row.id,
+                    attempts = row.translation_attempts + 1,
+                    still_pending,
+                    "pending translation failed"
+                );
+                report_translation_failure(pool, &err).await;
+                if !still_pending {
+                    // 重试次数耗尽后才上报事件
+                    Event::new(EventCode::TranslationFailed)
+                        .attr("article_id", row.id)
+                        .attr("source_domain", row.source_domain.as_str())
+                        .detail(format!("{}｜{}", row.source_domain, row.title))
+                        .emit(pool)
+                        .await;
+                }
+            }
+        }
+    }
+
     Ok(())
 }
 
@@ -258,8 +365,15 @@ pub async fn fetch_feed_once(
     http_client_config: HttpClientConfig,
     translator: Arc<TranslationEngine>,
     events: EventsHub,
+    latest_cache: LatestArticlesCache,
     feed_id: i64,
 ) -> anyhow::Result<()> {
+    if settings_registry::quiet_hours_active(&pool, Utc::now()).await? {
+        // 静默时段内不做即时抓取，feed 保持到期状态，时段结束后由调度器接手
+        info!(feed_id, "quiet hours active, deferring immediate fetch");
+        return Ok(());
+    }
+
     let config = normalize_fetcher_config(fetcher_config);
 
     let client_builder = http_client_config
@@ -282,6 +396,12 @@ pub async fn fetch_feed_once(
         config.quick_retry_attempts,
         retry_delay,
         events,
+        latest_cache,
+        // 管理端触发的单次抓取不经过域名限速，但同样遵守 robots.txt
+        &Politeness {
+            rate_limiter: None,
+            respect_robots_txt: config.respect_robots_txt,
+        },
     )
     .await
 }
@@ -297,12 +417,19 @@ fn normalize_fetcher_config(mut config: FetcherConfig) -> FetcherConfig {
     if config.concurrency == 0 {
         config.concurrency = 1;
     }
+    if config.slow_lane_concurrency == 0 {
+        config.slow_lane_concurrency = 1;
+    }
     if config.request_timeout_secs == 0 {
         config.request_timeout_secs = 10;
     }
     if config.quick_retry_attempts > 0 && config.quick_retry_delay_secs == 0 {
         config.quick_retry_delay_secs = 10;
     }
+    if config.domain_burst == 0 {
+        config.domain_burst = 1;
+    }
+    config.instance_id = Some(shard::resolve_instance_id(config.instance_id.as_deref()));
     config
 }
 
@@ -310,8 +437,11 @@ struct Fetcher {
     pool: sqlx::PgPool,
     client: Client,
     config: FetcherConfig,
+    politeness: Politeness,
+    shard: ShardMember,
     translation: Arc<TranslationEngine>,
     events: EventsHub,
+    latest_cache: LatestArticlesCache,
 }
 
 impl Fetcher {
@@ -321,6 +451,7 @@ impl Fetcher {
         http_client_config: HttpClientConfig,
         translator: Arc<TranslationEngine>,
         events: EventsHub,
+        latest_cache: LatestArticlesCache,
     ) -> anyhow::Result<Self> {
         let config = normalize_fetcher_config(config);
 
@@ -330,13 +461,27 @@ impl Fetcher {
             .timeout(Duration::from_secs(config.request_timeout_secs));
 
         let client = client_builder.build()?;
+        let politeness = Politeness {
+            // 跨轮次保留，避免下一轮开始时同域名请求再次集中爆发
+            rate_limiter: DomainRateLimiter::new(config.domain_requests_per_minute, config.domain_burst)
+                .map(Arc::new),
+            respect_robots_txt: config.respect_robots_txt,
+        };
+        let shard = ShardMember::new(
+            shard::resolve_instance_id(config.instance_id.as_deref()),
+            config.interval_secs,
+        );
+        info!(instance_id = shard.instance_id(), "fetcher instance registered for sharding");
 
         Ok(Self {
             pool,
             client,
             config,
+            politeness,
+            shard,
             translation: translator,
             events,
+            latest_cache,
         })
     }
 
@@ -345,8 +490,11 @@ impl Fetcher {
             pool,
             client,
             config,
+            politeness,
+            shard,
             translation,
             events,
+            latest_cache,
         } = self;
 
         let client = Arc::new(client);
@@ -354,63 +502,164 @@ impl Fetcher {
         let mut ticker = interval(Duration::from_secs(config.interval_secs));
         ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
     ticker.tick().await; // 立即执行一次（不等待第一个间隔）
+        // 启动即登记，其余实例下一轮就会把部分 feed 让出来
+        shard.heartbeat(&pool).await;
 
         loop {
             ticker.tick().await;
-            if let Err(err) = Self::run_once(
+            if let Err(err) = maintenance::purge_expired_filtered(&pool).await {
+                warn!(error = ?err, "failed to purge expired filtered articles");
+            }
+            release_expired_mutes(&pool).await;
+            // 静默时段也续期，避免时段结束时各实例的分片视图不一致
+            shard.heartbeat(&pool).await;
+            match settings_registry::quiet_hours_active(&pool, Utc::now()).await {
+                Ok(true) => {
+                    info!("quiet hours active, skipping fetch round");
+                    continue;
+                }
+                Ok(false) => {}
+                Err(err) => warn!(error = ?err, "failed to read quiet hours setting"),
+            }
+            match Self::run_once(
                 pool.clone(),
                 client.clone(),
                 Arc::clone(&translation),
                 &config,
+                shard.instance_id(),
+                politeness.clone(),
                 events.clone(),
+                Arc::clone(&latest_cache),
             )
             .await
             {
+                Ok(feed_count) => shard.record_round(&pool, feed_count).await,
                 // 单轮抓取失败记录日志，但不退出主循环（保持自恢复）
-                warn!(error = ?err, "fetcher iteration failed");
+                Err(err) => warn!(error = ?err, "fetcher iteration failed"),
             }
         }
 
     }
 
+    /// 过滤掉 cron 尚未到点的 feed，并按上次抓取时间排序后截断到本轮批量上限。
+    fn select_due_feeds(feeds: Vec<DueFeedRow>, batch_size: usize) -> Vec<DueFeedRow> {
+        let now = Utc::now();
+        let mut due: Vec<DueFeedRow> = feeds
+            .into_iter()
+            .filter(|feed| {
+                let Some(expr) = feed.schedule_cron.as_deref() else {
+                    return true;
+                };
+                match schedule::parse_cron(expr) {
+                    Ok(parsed) => schedule::is_due(&parsed, feed.last_fetch_at, now),
+                    Err(err) => {
+                        warn!(feed_id = feed.id, cron = expr, error = %err, "invalid feed schedule, skipping");
+                        false
+                    }
+                }
+            })
+            .collect();
+        due.sort_by_key(|feed| feed.last_fetch_at);
+        due.truncate(batch_size);
+        due
+    }
+
+    /// 返回本轮处理的 feed 数
+    #[allow(clippy::too_many_arguments)]
     async fn run_once(
         pool: sqlx::PgPool,
         client: Arc<Client>,
         translation: Arc<TranslationEngine>,
         config: &FetcherConfig,
+        instance_id: &str,
+        politeness: Politeness,
         events: EventsHub,
-    ) -> anyhow::Result<()> {
-        let feeds = feeds::list_due_feeds(&pool, config.batch_size as i64).await?;
+        latest_cache: LatestArticlesCache,
+    ) -> anyhow::Result<usize> {
+        let feeds =
+            feeds::list_due_feeds(&pool, config.batch_size as i64, Some(instance_id)).await?;
+        let feeds = Self::select_due_feeds(feeds, config.batch_size as usize);
         if feeds.is_empty() {
             info!("no feeds eligible this round");
-            return Ok(());
+            return Ok(0);
         }
+        let feed_count = feeds.len();
+
+        // 需要翻译 / LLM 去重的 feed 走慢车道，避免其占满并发导致普通 feed 排队
+        let ai_dedup_enabled = settings_registry::get_bool(&pool, "ai_dedup.enabled").await?;
+        let feed_ids: Vec<i64> = feeds.iter().map(|feed| feed.id).collect();
+        let heavy: HashSet<i64> = feeds::llm_heavy_feed_ids(
+            &pool,
+            &feed_ids,
+            translation.translation_enabled(),
+            ai_dedup_enabled,
+        )
+        .await?
+        .into_iter()
+        .collect();
+        let (slow, fast): (Vec<DueFeedRow>, Vec<DueFeedRow>) =
+            feeds.into_iter().partition(|feed| heavy.contains(&feed.id));
+
+        info!(fast = fast.len(), slow = slow.len(), "starting fetch round");
+
+        let lane = LaneContext {
+            pool,
+            client,
+            translation,
+            events,
+            latest_cache,
+            politeness,
+            retry_attempts: config.quick_retry_attempts,
+            retry_delay: Duration::from_secs(config.quick_retry_delay_secs),
+        };
+        tokio::join!(
+            lane.clone().run("fast", fast, config.concurrency as usize),
+            lane.run("slow", slow, config.slow_lane_concurrency as usize),
+        );
+
+        Ok(feed_count)
+    }
+}
+
+/// 抓取礼貌策略：按域名限速与 robots.txt 检查
+#[derive(Clone)]
+struct Politeness {
+    rate_limiter: Option<Arc<DomainRateLimiter>>,
+    respect_robots_txt: bool,
+}
 
-        info!(count = feeds.len(), "starting fetch round");
+/// 一轮抓取中各车道共享的依赖
+#[derive(Clone)]
+struct LaneContext {
+    pool: sqlx::PgPool,
+    client: Arc<Client>,
+    translation: Arc<TranslationEngine>,
+    events: EventsHub,
+    latest_cache: LatestArticlesCache,
+    politeness: Politeness,
+    retry_attempts: u32,
+    retry_delay: Duration,
+}
 
-        let concurrency = config.concurrency as usize;
+impl LaneContext {
+    async fn run(self, lane: &'static str, feeds: Vec<DueFeedRow>, concurrency: usize) {
         let mut set = JoinSet::new();
-        let retry_attempts = config.quick_retry_attempts;
-        let retry_delay = Duration::from_secs(config.quick_retry_delay_secs);
 
         for feed in feeds {
-            // 每个 feed 使用 tokio JoinSet 并发处理，受 concurrency 限制
-            let pool_cloned = pool.clone();
-            let client_cloned = client.clone();
-            let translation_cloned = Arc::clone(&translation);
-            let delay = retry_delay;
-
-            let events_cloned = events.clone();
+            // 每个 feed 使用 tokio JoinSet 并发处理，受所在车道的 concurrency 限制
+            let ctx = self.clone();
             set.spawn(async move {
-                info!(feed_id = feed.id, url = %feed.url, "fetching feed");
+                info!(feed_id = feed.id, url = %feed.url, lane, "fetching feed");
                 if let Err(err) = process_feed(
-                    pool_cloned,
-                    client_cloned,
-                    translation_cloned,
+                    ctx.pool,
+                    ctx.client,
+                    ctx.translation,
                     feed.clone(),
-                    retry_attempts,
-                    delay,
-                    events_cloned.clone(),
+                    ctx.retry_attempts,
+                    ctx.retry_delay,
+                    ctx.events,
+                    ctx.latest_cache,
+                    &ctx.politeness,
                 )
                 .await
                 {
@@ -418,6 +667,7 @@ impl Fetcher {
                         error = ?err,
                         feed_id = feed.id,
                         url = %feed.url,
+                        lane,
                         "failed to process feed"
                     );
                     // event suppressed per new minimal set
@@ -432,11 +682,10 @@ impl Fetcher {
         }
 
         while set.join_next().await.is_some() {}
-
-        Ok(())
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn process_feed(
     pool: sqlx::PgPool,
     client: Arc<Client>,
@@ -445,6 +694,8 @@ async fn process_feed(
     retry_attempts: u32,
     retry_delay: Duration,
     events: EventsHub,
+    latest_cache: LatestArticlesCache,
+    politeness: &Politeness,
 ) -> anyhow::Result<()> {
     let mut lock_conn = pool.acquire().await?;
     // 非阻塞尝试获取分布式/数据库级锁；若未获取到，说明该 feed 正在处理，直接跳过本轮
@@ -455,7 +706,9 @@ async fn process_feed(
 
     let feed_id = feed.id;
     let max_attempts = retry_attempts.saturating_add(1) as usize;
-    let mut result = Ok(());
+    let mut result = Ok(IngestStats::default());
+    let verbosity = activity_verbosity(&pool, &events).await;
+    publish_activity(&events, verbosity, &feed, FetcherActivityKind::Started);
 
     for attempt in 0..max_attempts {
         let is_last = attempt + 1 == max_attempts;
@@ -465,12 +718,14 @@ async fn process_feed(
             Arc::clone(&translation),
             &feed,
             &events,
+            &latest_cache,
+            politeness,
             is_last,
         )
         .await;
 
         match outcome {
-            Ok(_) => {
+            Ok(stats) => {
                 // 成功：记录成功尝试次数（attempt 从 0 开始，展示为 attempt+1）
                 info!(
                     feed_id = feed.id,
@@ -479,7 +734,7 @@ async fn process_feed(
                     max_attempts,
                     "feed fetch succeeded"
                 );
-                result = Ok(());
+                result = Ok(stats);
                 break;
             }
             Err(err) => {
@@ -515,6 +770,18 @@ async fn process_feed(
     let release_result = feeds::release_processing_lock(&mut lock_conn, feed_id).await;
     drop(lock_conn);
 
+    let kind = match &result {
+        Ok(stats) => FetcherActivityKind::Finished {
+            entries: stats.entries,
+            inserted: stats.inserted,
+            duplicates: stats.duplicates,
+        },
+        Err(err) => FetcherActivityKind::Failed {
+            error: format!("{err:#}").chars().take(300).collect(),
+        },
+    };
+    publish_activity(&events, verbosity, &feed, kind);
+
     if let Err(err) = release_result {
         warn!(error = ?err, feed_id = feed.id, "failed to release feed lock");
         if result.is_ok() {
@@ -522,22 +789,107 @@ async fn process_feed(
         }
     }
 
-    result
+    result.map(|_| ())
+}
+
+/// 没有管理端在线时不读取设置，视为关闭
+async fn activity_verbosity(pool: &sqlx::PgPool, events: &EventsHub) -> ActivityVerbosity {
+    if !events.has_subscribers() {
+        return ActivityVerbosity::Off;
+    }
+    match settings_registry::get_value(pool, ACTIVITY_VERBOSITY_KEY).await {
+        Ok(value) => value
+            .as_deref()
+            .and_then(ActivityVerbosity::parse)
+            .unwrap_or(ActivityVerbosity::Results),
+        Err(err) => {
+            warn!(error = ?err, "failed to read fetcher activity verbosity");
+            ActivityVerbosity::Off
+        }
+    }
+}
+
+fn publish_activity(
+    events: &EventsHub,
+    verbosity: ActivityVerbosity,
+    feed: &DueFeedRow,
+    kind: FetcherActivityKind,
+) {
+    if !verbosity.allows(&kind) {
+        return;
+    }
+    events.publish_activity(FetcherActivity {
+        ts: Utc::now(),
+        feed_id: feed.id,
+        source_domain: feed.source_domain.clone(),
+        kind,
+    });
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn process_feed_locked(
     pool: sqlx::PgPool,
     client: Arc<Client>,
     translation: Arc<TranslationEngine>,
     feed: &DueFeedRow,
     events: &EventsHub,
+    latest_cache: &LatestArticlesCache,
+    politeness: &Politeness,
     persist_failure: bool,
-) -> anyhow::Result<()> {
-    let mut request = client.get(&feed.url);
-    if let Some(etag) = &feed.last_etag {
-        request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+) -> anyhow::Result<IngestStats> {
+    // 自定义请求头（含 User-Agent）覆盖客户端默认值
+    let request_headers = feed_headers::from_column(feed.request_headers.as_deref());
+    let mut request = client
+        .get(&feed.url)
+        .headers(feed_headers::header_map(&request_headers));
+    if !feed.ignore_conditional {
+        if let Some(etag) = feed.last_etag.as_ref() {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        if let Some(modified) = feed.last_modified {
+            request = request.header(
+                reqwest::header::IF_MODIFIED_SINCE,
+                httpdate::fmt_http_date(modified.into()),
+            );
+        }
+    }
+    if politeness.respect_robots_txt {
+        if let Ok(url) = url::Url::parse(&feed.url) {
+            let check = robots::shared().check(&client, &url).await;
+            if !check.allowed {
+                warn!(feed_id = feed.id, url = %feed.url, "feed url disallowed by robots.txt");
+                if check.fresh {
+                    // 规则按域名缓存一天，事件随缓存刷新最多每天上报一次
+                    Event::new(EventCode::FeedRobotsDisallowed)
+                        .attr("feed_id", feed.id)
+                        .attr("source_domain", feed.source_domain.as_str())
+                        .attr("url", feed.url.as_str())
+                        .detail(format!(
+                            "{} 的 robots.txt 禁止抓取 {}",
+                            feed.source_domain, feed.url
+                        ))
+                        .emit(&pool)
+                        .await;
+                }
+                let err = anyhow!("disallowed by robots.txt");
+                record_failure(&pool, events, feed.id, None, FetchErrorKind::Robots, &err, persist_failure).await?;
+                return Err(err);
+            }
+        }
+    }
+    if let Some(limiter) = politeness.rate_limiter.as_deref() {
+        // 同域名请求按令牌桶排队；持有处理锁等待，不影响其他域名的 feed
+        let waited = limiter.acquire(&feed.source_domain).await;
+        if !waited.is_zero() {
+            info!(
+                feed_id = feed.id,
+                source_domain = %feed.source_domain,
+                waited_ms = waited.as_millis() as u64,
+                "domain rate limit delayed request"
+            );
+        }
     }
-    // 使用 ETag 支持服务器端增量更新：未修改则快速跳过
+    // 使用 ETag / Last-Modified 支持服务器端增量更新：未修改则快速跳过
     let response = match request.send().await {
         Ok(resp) => resp,
         Err(err) => {
@@ -548,8 +900,10 @@ async fn process_feed_locked(
                 chain = %format_error_chain(&err),
                 "failed to fetch feed"
             );
-            record_failure(&pool, events, feed.id, err.status(), persist_failure).await?;
-            return Err(err.into());
+            let kind = FetchErrorKind::from_request_error(&err);
+            let err = anyhow::Error::new(err);
+            record_failure(&pool, events, feed.id, None, kind, &err, persist_failure).await?;
+            return Err(err);
         }
     };
 
@@ -562,12 +916,14 @@ async fn process_feed_locked(
             status = status.as_u16(),
             "feed not modified"
         );
-        return Ok(());
+        return Ok(IngestStats::default());
     }
 
     if !status.is_success() {
-        record_failure(&pool, events, feed.id, Some(status), persist_failure).await?;
-        return Err(anyhow!("unexpected status {}", status));
+        let err = anyhow!("unexpected status {}", status);
+        let kind = FetchErrorKind::from_status(status);
+        record_failure(&pool, events, feed.id, Some(status), kind, &err, persist_failure).await?;
+        return Err(err);
     }
 
     info!(
@@ -580,8 +936,10 @@ async fn process_feed_locked(
     let bytes = match response.bytes().await {
         Ok(bytes) => bytes,
         Err(err) => {
-            record_failure(&pool, events, feed.id, Some(status), persist_failure).await?;
-            return Err(err.into());
+            let kind = FetchErrorKind::from_request_error(&err);
+            let err = anyhow::Error::new(err);
+            record_failure(&pool, events, feed.id, Some(status), kind, &err, persist_failure).await?;
+            return Err(err);
         }
     };
 
@@ -591,7 +949,10 @@ async fn process_feed_locked(
         .and_then(|v| v.to_str().ok());
     let bytes_utf8 = transcode_to_utf8(&bytes, content_type_hdr);
 
-    let mut parsed_feed = match parser::parse(&bytes_utf8[..]) {
+    // 发布时间在解析阶段按 Feed 的时区 / 格式覆盖处理，convert_entry 拿到的已是修正后的时间
+    let date_overrides =
+        DateOverrides::from_columns(feed.date_timezone.as_deref(), feed.date_format.as_deref());
+    let mut parsed_feed = match parse_feed_body(&bytes_utf8[..], &date_overrides) {
         Ok(feed) => {
             let entry_count = feed.entries.len();
             info!(
@@ -604,495 +965,705 @@ async fn process_feed_locked(
             feed
         }
         Err(err) => {
-            record_failure(&pool, &events, feed.id, Some(status), persist_failure).await?;
-            return Err(err.into());
+            // 原始内容不是 UTF-8、经过转码后仍解析失败的，多半是编码声明或探测有误
+            let kind = if std::str::from_utf8(&bytes).is_err() {
+                FetchErrorKind::Encoding
+            } else {
+                FetchErrorKind::Parse
+            };
+            let err = anyhow::Error::new(err);
+            record_failure(&pool, events, feed.id, Some(status), kind, &err, persist_failure).await?;
+            return Err(err);
         }
     };
 
-    let recent_articles = articles::list_recent_articles(&pool, RECENT_ARTICLE_LIMIT).await?;
-    // 读取 AI 去重设置（简单每次请求一次；后续可缓存优化）
-    let ai_dedup_enabled = settings::get_setting(&pool, "ai_dedup.enabled")
-        .await?
-        .map(|v| v == "true")
-        .unwrap_or(false);
-    let ai_dedup_provider = settings::get_setting(&pool, "ai_dedup.provider").await?;
-    // 构造历史候选集合（近期文章做近似重复检测）
-    let mut historical_candidates = Vec::new();
-    for row in recent_articles {
-        let ArticleRow {
-            id,
-            title,
-            url,
-            description,
-            language: _,
-            source_domain,
-            published_at,
-            click_count: _,
-        } = row;
-
-        let (_, tokens) = prepare_title_signature(&title);
-        if tokens.is_empty() {
-            continue;
-        }
-        historical_candidates.push(CandidateArticle {
-            tokens,
-            summary: ArticleSummary {
-                article_id: id,
-                title,
-                source_domain,
-                url,
-                description,
-                published_at,
-            },
-        });
-    }
-
     let etag = headers
         .get(reqwest::header::ETAG)
         .and_then(|v| v.to_str().ok())
         .map(|s| s.to_string());
+    let last_modified = headers
+        .get(reqwest::header::LAST_MODIFIED)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| httpdate::parse_http_date(s).ok())
+        .map(DateTime::<Utc>::from);
 
     let entries = std::mem::take(&mut parsed_feed.entries);
+    let stats = ingest_entries(&pool, &translation, feed, events, latest_cache, entries).await?;
+
+    let title = parsed_feed.title.as_ref().map(|text| text.content.clone());
+
+    let site_url = parsed_feed.links.first().map(|link| link.href.clone());
+
+    info!(feed_id = feed.id, "marking feed success");
+    feeds::mark_success(
+        &pool,
+        feed.id,
+        status.as_u16() as i16,
+        etag,
+        last_modified,
+        title,
+        site_url,
+    )
+    .await?;
+
+    info!(
+        feed_id = feed.id,
+        status = status.as_u16(),
+        last_fetch_at = ?Utc::now(),
+        "feed fetch successful"
+    );
+
+    Ok(stats)
+}
+
+/// 一次入库的条目统计
+#[derive(Debug, Default, Clone, Copy)]
+struct IngestStats {
+    entries: usize,
+    inserted: usize,
+    /// 批内重复、与历史文章重复（作为簇成员入库）及 URL 已存在的条目
+    duplicates: usize,
+}
+
+/// 入库流水线：条目转换、指纹与标题去重、翻译、LLM 去重、入库与过滤，返回条目统计。
+/// 定时抓取与本地样例导入（见 `seed`）共用。
+async fn ingest_entries(
+    pool: &sqlx::PgPool,
+    translation: &Arc<TranslationEngine>,
+    feed: &DueFeedRow,
+    events: &EventsHub,
+    latest_cache: &LatestArticlesCache,
+    entries: Vec<Entry>,
+) -> anyhow::Result<IngestStats> {
+    let entry_count = entries.len();
+    let dedup_window_hours = settings_registry::get_i64(pool, "dedup.window_hours")
+        .await?
+        .unwrap_or(DEFAULT_DEDUP_WINDOW_HOURS);
+    // 读取 AI 去重设置（简单每次请求一次；后续可缓存优化）
+    // 静默时段开始时仍在进行的抓取轮次不再调用 LLM
+    let ai_dedup_enabled = settings_registry::get_bool(pool, "ai_dedup.enabled").await?
+        && !settings_registry::quiet_hours_active(pool, Utc::now()).await?;
+    let ai_dedup_provider = settings_registry::get_value(pool, "ai_dedup.provider").await?;
+    // 来源偏好规则与权重：重复判定时若新来源更优，则以新文章作为主文章
+    let source_ranking = SourceRanking {
+        weights: sources::weight_map(pool).await?,
+        preferences: sources::preference_set(pool).await?,
+    };
     let mut articles = Vec::new();
+    // 与历史文章重复的条目：作为所命中主文章的簇成员入库，不在公开接口中展示
+    let mut members: Vec<NewArticle> = Vec::new();
+    // 新文章 URL -> 被其取代的旧文章 ID（入库后执行归并）
+    let mut supersedes: HashMap<String, (i64, &'static str)> = HashMap::new();
     let mut seen_signatures: Vec<(BTreeSet<String>, String)> = Vec::new();
+    // 批内去重不落来源记录，仅计数写入每日统计
+    let mut intra_batch_dups: i64 = 0;
+
+    // 过长的摘要在翻译前截断，开启正文存储时保留原文
+    let description_max_chars = settings_registry::get_i64(pool, DESCRIPTION_MAX_CHARS_KEY)
+        .await?
+        .unwrap_or(0)
+        .max(0) as usize;
+    let keep_full_description =
+        description_max_chars > 0 && settings_registry::get_bool(pool, extraction::ENABLED_KEY).await?;
 
-    for entry in &entries {
-        if let Some(mut article) = convert_entry(&pool, &events, feed, &entry) {
-            let original_title = article.title.clone();
+    // 保留窗口之外的旧条目不再入库，否则清理任务删除后下一轮抓取又会把它们写回来
+    let retention_cutoff = retention::cutoff(pool).await?;
 
+    // 先转换全部条目并以有限并发翻译（buffered 保持原有顺序），再按原顺序逐条去重入库
+    let converted: Vec<NewArticle> = entries
+        .iter()
+        .filter_map(|entry| convert_entry(pool, events, feed, entry))
+        .filter(|article| {
+            let expired = retention_cutoff.is_some_and(|cutoff| article.published_at < cutoff);
+            if expired {
+                info!(feed_id = feed.id, url = %article.url, "skip entry outside retention window");
+            }
+            !expired
+        })
+        .map(|mut article| {
             // 提前归一化：空或全空白描述直接设为 None，避免后续重复判空
-            if let Some(desc) = &article.description {
-                if desc.trim().is_empty() {
-                    article.description = None;
+            if article.description.as_deref().is_some_and(|desc| desc.trim().is_empty()) {
+                article.description = None;
+            }
+            if description_max_chars > 0 {
+                if let Some(truncated) = article
+                    .description
+                    .as_deref()
+                    .and_then(|desc| truncate_summary(desc, description_max_chars))
+                {
+                    let full = article.description.replace(truncated);
+                    if keep_full_description {
+                        article.full_description = full;
+                    }
                 }
             }
+            article
+        })
+        .collect();
 
-            // 无论是否需要翻译，都记录一次判定结果日志
-            let need_translate = should_translate_title(&original_title);
+    // 完全相同的转载（指纹一致）直接在 SQL 中查出，无需进入翻译与 Jaccard/LLM 比较
+    let fingerprints: Vec<String> = converted
+        .iter()
+        .filter_map(|article| article.content_fingerprint.clone())
+        .collect();
+    let known_fingerprints = articles::find_by_fingerprints(pool, &fingerprints).await?;
+    // 标题 SimHash 与全部历史主文章比较，拦截只改动个别字词或标点的转载
+    let known_simhashes = if settings_registry::get_bool(pool, SIMHASH_ENABLED_KEY).await? {
+        let max_distance = settings_registry::get_i64(pool, SIMHASH_DISTANCE_KEY)
+            .await?
+            .unwrap_or(DEFAULT_SIMHASH_DISTANCE);
+        let simhashes: Vec<i64> = converted
+            .iter()
+            .filter_map(|article| article.title_simhash)
+            .collect();
+        articles::find_by_simhashes(pool, &simhashes, max_distance as i32).await?
+    } else {
+        HashMap::new()
+    };
+    let mut seen_fingerprints: HashSet<String> = HashSet::new();
+    let mut converted_fresh = Vec::with_capacity(converted.len());
+    for article in converted {
+        if let Some(fingerprint) = article.content_fingerprint.clone() {
+            if let Some(&existing_id) = known_fingerprints.get(&fingerprint) {
+                record_article_source(
+                    pool,
+                    feed,
+                    &article,
+                    existing_id,
+                    Some("fingerprint"),
+                    Some(1.0),
+                    DedupDetail::default(),
+                )
+                .await;
+                info!(
+                    feed_id = feed.id,
+                    url = %article.url,
+                    existing_article_id = existing_id,
+                    "cluster article under existing one due to matching content fingerprint"
+                );
+                members.push(NewArticle {
+                    canonical_id: Some(existing_id),
+                    ..article
+                });
+                continue;
+            }
+            if !seen_fingerprints.insert(fingerprint) {
+                intra_batch_dups += 1;
+                info!(feed_id = feed.id, url = %article.url, "skip article due to duplicate fingerprint in batch");
+                continue;
+            }
+        }
+        if let Some(&(existing_id, distance)) = article
+            .title_simhash
+            .and_then(|simhash| known_simhashes.get(&simhash))
+        {
+            record_article_source(
+                pool,
+                feed,
+                &article,
+                existing_id,
+                Some("simhash"),
+                Some(1.0 - distance as f32 / 64.0),
+                DedupDetail::default(),
+            )
+            .await;
             info!(
                 feed_id = feed.id,
                 url = %article.url,
-                need_translate,
-                title = %original_title,
-                "title translation decision"
+                existing_article_id = existing_id,
+                distance,
+                "cluster article under existing one due to near-identical title simhash"
             );
+            members.push(NewArticle {
+                canonical_id: Some(existing_id),
+                ..article
+            });
+            continue;
+        }
+        converted_fresh.push(article);
+    }
+
+    let translated: Vec<NewArticle> = stream::iter(converted_fresh)
+        .map(|article| translate_entry(pool, translation, feed, article))
+        .buffered(TRANSLATION_CONCURRENCY)
+        .collect()
+        .await;
 
-            // 进入条目处理主流程，便于定位卡点
-            info!(feed_id = feed.id, url = %article.url, "begin entry processing");
+    // 配置向量索引时按 embedding 检索近邻作为历史候选；生成或检索失败的条目退回与近期文章逐条比较
+    let semantics: Vec<Option<SemanticMatch>> = match embedding::index() {
+        Some(index) => {
+            // 先收集 future 再交给 buffered：借用条目的闭包若跨 await 保留，
+            // 外层 spawn 的 future 无法满足 Send 所需的生命周期约束
+            let lookups: Vec<_> = translated
+                .iter()
+                .map(|article| {
+                    semantic_match(index, translation, feed, article, dedup_window_hours)
+                })
+                .collect();
+            stream::iter(lookups)
+                .buffered(TRANSLATION_CONCURRENCY)
+                .collect()
+                .await
+        }
+        None => translated.iter().map(|_| None).collect(),
+    };
+    let neighbour_ids: Vec<i64> = semantics
+        .iter()
+        .flatten()
+        .flat_map(|semantic| semantic.neighbours.iter().flatten())
+        .map(|point| point.article_id)
+        .collect();
+    let neighbour_candidates: HashMap<i64, CandidateArticle> = if neighbour_ids.is_empty() {
+        HashMap::new()
+    } else {
+        articles::list_canonical_by_ids(pool, &neighbour_ids)
+            .await?
+            .into_iter()
+            .filter_map(candidate_from_row)
+            .map(|candidate| (candidate.summary.article_id, candidate))
+            .collect()
+    };
+    // 构造历史候选集合（近期文章做近似重复检测）
+    let needs_full_scan = semantics
+        .iter()
+        .any(|semantic| semantic.as_ref().is_none_or(|s| s.neighbours.is_none()));
+    let historical_candidates: Vec<CandidateArticle> = if needs_full_scan {
+        articles::list_recent_articles(pool, dedup_window_hours, DEDUP_CANDIDATE_CAP)
+            .await?
+            .into_iter()
+            .filter_map(candidate_from_row)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    // 新文章 URL -> embedding（入库后交给增强流水线的 embedding 步骤写入向量索引）
+    let mut vectors: HashMap<String, Vec<f32>> = HashMap::new();
+
+    for (article, semantic) in translated.into_iter().zip(semantics) {
+        // 向量近邻（附余弦相似度）或时间窗口内的全部近期文章
+        let candidates: Vec<(&CandidateArticle, Option<f32>)> =
+            match semantic.as_ref().and_then(|s| s.neighbours.as_ref()) {
+                Some(neighbours) => neighbours
+                    .iter()
+                    .filter_map(|point| {
+                        neighbour_candidates
+                            .get(&point.article_id)
+                            .map(|candidate| (candidate, Some(point.score)))
+                    })
+                    .collect(),
+                None => historical_candidates
+                    .iter()
+                    .map(|candidate| (candidate, None))
+                    .collect(),
+            };
+        // 为单条条目处理添加硬超时，防止个别条目卡住影响整批
+        let entry_timeout = Duration::from_secs(2);
+        let entry_url_clone = article.url.clone();
+        let mut superseded: Option<(i64, &'static str)> = None;
+        // 与历史文章判重时命中的主文章
+        let mut duplicate_of: Option<i64> = None;
+        let result = timeout(entry_timeout, async {
+            // 标记准备开始做标题签名，以区别于签名计算内部耗时
+            info!(feed_id = feed.id, url = %article.url, "preparing title signature");
+            let (normalized_title, tokens) = prepare_title_signature(&article.title);
+            info!(feed_id = feed.id, url = %article.url, "prepared title signature");
+
+            if tokens.is_empty() {
+                info!(feed_id = feed.id, url = %article.url, "skip entry: empty tokens after normalization");
+                return Ok::<bool, ()>(true); // treat as handled (skipped)
+            }
 
-            if need_translate {
-                if !translation.translation_enabled() {
+            let mut is_duplicate = false;
+            for (existing_tokens, existing_title) in &seen_signatures {
+                // 同一批次内部去重：严格 Jaccard + 归一化标题匹配
+                let similarity = jaccard_similarity(&tokens, existing_tokens);
+                if similarity >= STRICT_DUP_THRESHOLD {
+                    is_duplicate = true;
                     info!(
                         feed_id = feed.id,
-                        url = %article.url,
-                        "translation disabled globally, skipping"
+                        similarity,
+                        title = %article.title,
+                        "skip article due to high intra-feed title similarity"
                     );
-                    // 不进行翻译但保留原始标题/描述
-                } else {
-                // 翻译流程：始终翻译摘要（已取消单独开关）；若无可用 provider 返回 None
-                let has_original_desc = article.description.is_some();
-
-                info!(
-                    feed_id = feed.id,
-                    url = %article.url,
-                    has_original_description = has_original_desc,
-                    "pre-translation decision"
-                );
+                    break;
+                }
 
-                let desc_owned = article.description.clone();
+                if normalized_title == *existing_title {
+                    is_duplicate = true;
+                    info!(
+                        feed_id = feed.id,
+                        title = %article.title,
+                        "skip article due to identical normalized title"
+                    );
+                    break;
+                }
+            }
 
-                // 开始进行翻译调用：记录 provider 与摘要长度
-                let started = std::time::Instant::now();
-                let desc_in_len = desc_owned.as_ref().map(|s| s.len()).unwrap_or(0);
-                info!(
-                    feed_id = feed.id,
-                    url = %article.url,
-                    title = %original_title,
-                    provider = ?translation.current_provider(),
-                    desc_in_len,
-                    "translation start"
-                );
+            if is_duplicate {
+                intra_batch_dups += 1;
+                return Ok(true);
+            }
 
-                match translation
-                    .translate(&original_title, desc_owned.as_deref())
-                    .await
-                {
-                    Ok(Some(translated)) => {
-                        // 成功翻译：更新标题；仅在返回描述时覆盖原描述
-                        article.title = translated.title;
-                        if translated.description.is_some() {
-                            article.description = translated.description;
-                        }
-                        article.language = Some(TRANSLATION_LANG.to_string());
-
-                        if has_original_desc && desc_owned.is_some() && article.description.is_none() {
-                            let elapsed_ms = started.elapsed().as_millis() as u64;
-                            warn!(
-                                feed_id = feed.id,
-                                url = %article.url,
-                                provider = %translation.current_provider().as_str(),
-                                elapsed_ms,
-                                "translator returned no description while description translation is enabled"
-                            );
-                            // 不上报事件，仅记录日志（根据“仅失败重试后上报”的约定）
-                        }
+            // 批内比较结束
+            info!(feed_id = feed.id, url = %article.url, checked = seen_signatures.len(), "intra-batch compare done");
+
+            // 让出调度，避免长时间计算阻塞日志刷新
+            tokio::task::yield_now().await;
+
+            if !candidates.is_empty() {
+                info!(feed_id = feed.id, url = %article.url, candidates = candidates.len(), "start historical dedup compare");
+                let mut deepseek_checks = 0usize;
+                let mut candidate_counter = 0usize;
+                for &(candidate, semantic_score) in &candidates {
+                    candidate_counter += 1;
+                    let similarity = jaccard_similarity(&tokens, &candidate.tokens);
+                    if candidate_counter.is_multiple_of(25) {
+                        info!(feed_id = feed.id, url = %article.url, checked = candidate_counter, similarity_hint = similarity, "dedup progress");
                     }
-                    Ok(None) => {
-                        let provider = translation.current_provider().as_str();
-                        let provider_available = match provider {
-                            "deepseek" => translation.is_deepseek_available(),
-                            "ollama" => translation.ollama_client().is_some(),
-                            _ => false,
-                        };
+                let semantic_duplicate =
+                    semantic_score.is_some_and(|score| score >= embedding::DUP_THRESHOLD);
+                if similarity >= STRICT_DUP_THRESHOLD || semantic_duplicate {
+                    if let Some(decision) =
+                        source_ranking.prefers_new_source(&article, &candidate.summary)
+                    {
+                        superseded = Some((candidate.summary.article_id, decision));
                         info!(
                             feed_id = feed.id,
-                            url = %article.url,
-                            provider = provider,
-                            provider_available,
-                            "translation skipped (provider unavailable)"
+                            similarity,
+                            semantic_score,
+                            title = %article.title,
+                            existing_article_id = candidate.summary.article_id,
+                            existing_source = %candidate.summary.source_domain,
+                            decision,
+                            "duplicate from preferred source, keep as canonical"
                         );
-                        // event suppressed per request to cancel all bindings
+                        break;
                     }
-                    Err(err) => {
-                        // 第一次失败后短暂重试一次，降低瞬时网络抖动影响
-                        warn!(
-                            error = %err,
+                    // 与历史文章严格匹配：直接标记来源并跳过；标题相似度达标时仍记为 Jaccard 判定
+                    let (decision, confidence) = match semantic_score {
+                        Some(score) if similarity < STRICT_DUP_THRESHOLD => ("recent_embedding", score),
+                        _ => ("recent_jaccard", similarity),
+                    };
+                    record_article_source(
+                        pool,
+                        feed,
+                        &article,
+                        candidate.summary.article_id,
+                        Some(decision),
+                        Some(confidence),
+                        DedupDetail::jaccard(similarity, &tokens, &candidate.tokens),
+                    )
+                    .await;
+                    is_duplicate = true;
+                    duplicate_of = Some(candidate.summary.article_id);
+                    info!(
+                        feed_id = feed.id,
+                        similarity,
+                        semantic_score,
+                        decision,
+                        title = %article.title,
+                        existing_article_id = candidate.summary.article_id,
+                        existing_title = %candidate.summary.title,
+                        existing_url = %candidate.summary.url,
+                        existing_source = %candidate.summary.source_domain,
+                        "cluster article under matching recent article"
+                    );
+                    break;
+                }
+
+                let semantic_close =
+                    semantic_score.is_some_and(|score| score >= embedding::LLM_THRESHOLD);
+                if ai_dedup_enabled && (similarity >= DEEPSEEK_THRESHOLD || semantic_close) {
+                    // 根据配置选择模型客户端；首选 provider 持续失败时自动切换到备用 provider，
+                    // 不支持的 provider 直接跳过
+                    let preferred_provider = ai_dedup_provider
+                        .as_deref()
+                        .and_then(|name| name.parse::<TranslatorProvider>().ok());
+                    let llm_client = preferred_provider
+                        .and_then(|provider| translation.dedup_client(provider));
+                    let selected_provider = llm_client.as_ref().map(|c| c.provider().as_str());
+
+                    if selected_provider.is_none() {
+                        info!(
                             feed_id = feed.id,
-                            url = %article.url,
-                            "failed to translate article, will retry once"
+                            title = %article.title,
+                            similarity,
+                            ai_dedup_enabled,
+                            ai_dedup_provider = ai_dedup_provider.as_deref().unwrap_or(""),
+                            "llm dedup skipped (provider unavailable)"
                         );
-                        // 一次失败重试（短暂延迟后再试一次）
-                        sleep(Duration::from_millis(300)).await;
-                        match translation
-                            .translate(&original_title, desc_owned.as_deref())
-                            .await
-                        {
-                            Ok(Some(translated)) => {
-                                article.title = translated.title;
-                                if translated.description.is_some() {
-                                    article.description = translated.description;
-                                }
-                                article.language = Some(TRANSLATION_LANG.to_string());
-                            }
-                            Ok(None) => {
-                                info!(
-                                    feed_id = feed.id,
-                                    url = %article.url,
-                                    "translation skipped after retry (no provider configured)"
-                                );
-                            }
-                            Err(err2) => {
-                                warn!(
-                                    error = %err2,
-                                    feed_id = feed.id,
-                                    url = %article.url,
-                                    "failed to translate article after retry"
-                                );
-                                // 仅在重试后仍失败时上报事件
-                                let _ = repo_events::upsert_event(
-                                    &pool,
-                                    &repo_events::NewEvent {
-                                        level: "warn".to_string(),
-                                        code: "TRANSLATION_FAILED".to_string(),
-                                        addition_info: Some(format!("{}｜{}", feed.source_domain, original_title)),
-                                    },
-                                    0,
-                                ).await;
-                            }
-                        }
+                        continue;
                     }
-                }
-            }
-            }
-            // 为单条条目处理添加硬超时，防止个别条目卡住影响整批
-            let entry_timeout = Duration::from_secs(2);
-            let entry_url_clone = article.url.clone();
-            let result = timeout(entry_timeout, async {
-                // 标记准备开始做标题签名，以区别于签名计算内部耗时
-                info!(feed_id = feed.id, url = %article.url, "preparing title signature");
-                let (normalized_title, tokens) = prepare_title_signature(&article.title);
-                info!(feed_id = feed.id, url = %article.url, "prepared title signature");
-
-                if tokens.is_empty() {
-                    info!(feed_id = feed.id, url = %article.url, "skip entry: empty tokens after normalization");
-                    return Ok::<bool, ()>(true); // treat as handled (skipped)
-                }
 
-                let mut is_duplicate = false;
-                for (existing_tokens, existing_title) in &seen_signatures {
-                    // 同一批次内部去重：严格 Jaccard + 归一化标题匹配
-                    let similarity = jaccard_similarity(&tokens, existing_tokens);
-                    if similarity >= STRICT_DUP_THRESHOLD {
-                        is_duplicate = true;
-                        info!(
-                            feed_id = feed.id,
-                            similarity,
-                            title = %article.title,
-                            "skip article due to high intra-feed title similarity"
-                        );
+                    if deepseek_checks >= MAX_DEEPSEEK_CHECKS {
                         break;
                     }
+                    deepseek_checks += 1;
 
-                    if normalized_title == *existing_title {
-                        is_duplicate = true;
-                        info!(
-                            feed_id = feed.id,
-                            title = %article.title,
-                            "skip article due to identical normalized title"
-                        );
-                        break;
-                    }
-                }
+                        let published_new = article.published_at.to_rfc3339();
+                        let published_existing = candidate.summary.published_at.to_rfc3339();
 
-                if is_duplicate {
-                    return Ok(true);
-                }
+                        let new_snippet = ArticleSnippet {
+                            title: &article.title,
+                            source: Some(&article.source_domain),
+                            url: Some(&article.url),
+                            published_at: Some(&published_new),
+                            summary: article.description.as_deref(),
+                        };
 
-                // 批内比较结束
-                info!(feed_id = feed.id, url = %article.url, checked = seen_signatures.len(), "intra-batch compare done");
-
-                // 让出调度，避免长时间计算阻塞日志刷新
-                tokio::task::yield_now().await;
-
-                if !historical_candidates.is_empty() {
-                    info!(feed_id = feed.id, url = %article.url, candidates = historical_candidates.len(), "start historical dedup compare");
-                    let mut deepseek_checks = 0usize;
-                    let mut candidate_counter = 0usize;
-                    for candidate in &historical_candidates {
-                        candidate_counter += 1;
-                        let similarity = jaccard_similarity(&tokens, &candidate.tokens);
-                        if candidate_counter % 25 == 0 {
-                            info!(feed_id = feed.id, url = %article.url, checked = candidate_counter, similarity_hint = similarity, "dedup progress");
-                        }
-                    if similarity >= STRICT_DUP_THRESHOLD {
-                        // 与历史文章严格匹配：直接标记来源并跳过
-                        record_article_source(
-                            &pool,
-                            feed,
-                            &article,
-                            candidate.summary.article_id,
-                            Some("recent_jaccard"),
-                            Some(similarity),
-                        )
-                        .await;
-                        is_duplicate = true;
+                        let existing_summary_ref = candidate.summary.description.as_deref();
+                        let existing_snippet = ArticleSnippet {
+                            title: &candidate.summary.title,
+                            source: Some(&candidate.summary.source_domain),
+                            url: Some(&candidate.summary.url),
+                            published_at: Some(&published_existing),
+                            summary: existing_summary_ref,
+                        };
+
+                        let started = std::time::Instant::now();
                         info!(
                             feed_id = feed.id,
-                            similarity,
                             title = %article.title,
                             existing_article_id = candidate.summary.article_id,
-                            existing_title = %candidate.summary.title,
-                            existing_url = %candidate.summary.url,
-                            existing_source = %candidate.summary.source_domain,
-                            "skip article due to matching recent article"
+                            ai_dedup_enabled,
+                            ai_dedup_provider = selected_provider.unwrap_or(""),
+                            "llm dedup check start"
                         );
-                        break;
-                    }
-
-                    if ai_dedup_enabled && similarity >= DEEPSEEK_THRESHOLD {
-                        // 根据配置选择模型客户端（不做自动校验）
-                        let mut selected_provider = None;
-                        let mut client_ollama = None;
-                        let mut client_deepseek = None;
-                        if let Some(provider_name) = ai_dedup_provider.as_deref() {
-                            match provider_name {
-                                "deepseek" => {
-                                    client_deepseek = translation.deepseek_client();
-                                    if client_deepseek.is_some() { selected_provider = Some("deepseek"); }
-                                }
-                                "ollama" => {
-                                    client_ollama = translation.ollama_client();
-                                    if client_ollama.is_some() { selected_provider = Some("ollama"); }
-                                }
-                                _ => {
-                                    // 不支持的 provider，直接跳过
-                                }
+                        // Hard cap LLM check duration to avoid long hangs
+                        let timeout_secs: u64 = 10;
+                        let fut = async {
+                            match llm_client.as_ref() {
+                                Some(c) => c.judge_similarity(&new_snippet, &existing_snippet).await,
+                                None => Err(anyhow!("llm provider unavailable")),
                             }
-                        }
-
-                        if selected_provider.is_none() {
-                            info!(
-                                feed_id = feed.id,
-                                title = %article.title,
-                                similarity,
-                                ai_dedup_enabled,
-                                ai_dedup_provider = ai_dedup_provider.as_deref().unwrap_or(""),
-                                "llm dedup skipped (provider unavailable)"
-                            );
-                            continue;
-                        }
-
-                        if deepseek_checks >= MAX_DEEPSEEK_CHECKS {
-                            break;
-                        }
-                        deepseek_checks += 1;
-
-                            let published_new = article.published_at.to_rfc3339();
-                            let published_existing = candidate.summary.published_at.to_rfc3339();
-
-                            let new_snippet = ArticleSnippet {
-                                title: &article.title,
-                                source: Some(&article.source_domain),
-                                url: Some(&article.url),
-                                published_at: Some(&published_new),
-                                summary: article.description.as_deref(),
-                            };
-
-                            let existing_summary_ref = candidate.summary.description.as_deref();
-                            let existing_snippet = ArticleSnippet {
-                                title: &candidate.summary.title,
-                                source: Some(&candidate.summary.source_domain),
-                                url: Some(&candidate.summary.url),
-                                published_at: Some(&published_existing),
-                                summary: existing_summary_ref,
-                            };
-
-                            let started = std::time::Instant::now();
-                            info!(
-                                feed_id = feed.id,
-                                title = %article.title,
-                                existing_article_id = candidate.summary.article_id,
-                                ai_dedup_enabled,
-                                ai_dedup_provider = selected_provider.unwrap_or(""),
-                                "llm dedup check start"
-                            );
-                            // Hard cap LLM check duration to avoid long hangs
-                            let timeout_secs: u64 = 10;
-                            let fut = async {
-                                if selected_provider == Some("deepseek") {
-                                    if let Some(c) = client_deepseek.as_ref() {
-                                        c.judge_similarity(&new_snippet, &existing_snippet).await
-                                    } else {
-                                        Err(anyhow!("deepseek provider unavailable"))
-                                    }
-                                } else if selected_provider == Some("ollama") {
-                                    if let Some(c) = client_ollama.as_ref() {
-                                        c.judge_similarity(&new_snippet, &existing_snippet).await
-                                    } else {
-                                        Err(anyhow!("ollama provider unavailable"))
-                                    }
-                                } else {
-                                    Err(anyhow!("unknown provider"))
-                                }
-                            };
-                            match timeout(Duration::from_secs(timeout_secs), fut)
+                        };
+                        let outcome = timeout(Duration::from_secs(timeout_secs), fut)
                             .await
                             .map_err(|_| anyhow!("llm judge_similarity timed out in {}s", timeout_secs))
-                            .and_then(|r| r.map_err(anyhow::Error::from))
+                            .and_then(|r| r);
+                        if let (Some(preferred), Some(client)) = (preferred_provider, llm_client.as_ref()) {
+                            if let Some(switch) =
+                                translation.record_dedup_result(preferred, client.provider(), outcome.is_ok())
                             {
-                                Ok(decision) => {
-                                    let elapsed_ms = started.elapsed().as_millis() as u64;
+                                report_dedup_switch(pool, switch).await;
+                            }
+                        }
+                        match outcome {
+                            Ok(decision) => {
+                                let elapsed_ms = started.elapsed().as_millis() as u64;
+                                info!(
+                                    feed_id = feed.id,
+                                    title = %article.title,
+                                    existing_article_id = candidate.summary.article_id,
+                                    elapsed_ms,
+                                    is_duplicate = decision.is_duplicate,
+                                    ai_dedup_provider = selected_provider.unwrap_or(""),
+                                    "llm dedup check done"
+                                );
+                                let preferred = decision
+                                    .is_duplicate
+                                    .then(|| source_ranking.prefers_new_source(&article, &candidate.summary))
+                                    .flatten();
+                                if let Some(supersede_decision) = preferred {
+                                    superseded = Some((candidate.summary.article_id, supersede_decision));
                                     info!(
                                         feed_id = feed.id,
                                         title = %article.title,
                                         existing_article_id = candidate.summary.article_id,
-                                        elapsed_ms,
-                                        is_duplicate = decision.is_duplicate,
-                                        ai_dedup_provider = selected_provider.unwrap_or(""),
-                                        "llm dedup check done"
+                                        existing_source = %candidate.summary.source_domain,
+                                        decision = supersede_decision,
+                                        "llm duplicate from preferred source, keep as canonical"
                                     );
-                                    if decision.is_duplicate {
-                                        // LLM 判定重复：记录来源与理由（reason）
-                                        let reason = decision
-                                            .reason
-                                            .as_deref()
-                                            .unwrap_or("deepseek_duplicate");
-                                        record_article_source(
-                                            &pool,
-                                            feed,
-                                            &article,
-                                            candidate.summary.article_id,
-                                            Some(reason),
-                                            decision.confidence,
-                                        )
-                                        .await;
-                                        is_duplicate = true;
-                                        info!(
-                                            feed_id = feed.id,
-                                            title = %article.title,
-                                            existing_article_id = candidate.summary.article_id,
-                                            existing_title = %candidate.summary.title,
-                                            existing_url = %candidate.summary.url,
-                                            existing_source = %candidate.summary.source_domain,
-                                            reason = decision.reason.as_deref().unwrap_or(""),
-                                            ai_dedup_provider = selected_provider.unwrap_or(""),
-                                            "skip article due to llm duplicate judgment"
-                                        );
-                                        break;
-                                    }
+                                    break;
                                 }
-                                Err(err) => {
-                                    let elapsed_ms = started.elapsed().as_millis() as u64;
-                                    warn!(
-                                        error = ?err,
+                                if decision.is_duplicate {
+                                    // LLM 判定重复：记录来源与理由（reason）
+                                    let reason = decision
+                                        .reason
+                                        .as_deref()
+                                        .unwrap_or("deepseek_duplicate");
+                                    record_article_source(
+                                        pool,
+                                        feed,
+                                        &article,
+                                        candidate.summary.article_id,
+                                        Some(reason),
+                                        decision.confidence,
+                                        DedupDetail {
+                                            llm_raw: Some(decision.raw.clone()),
+                                            ..DedupDetail::jaccard(similarity, &tokens, &candidate.tokens)
+                                        },
+                                    )
+                                    .await;
+                                    is_duplicate = true;
+                                    duplicate_of = Some(candidate.summary.article_id);
+                                    info!(
                                         feed_id = feed.id,
-                                        elapsed_ms,
+                                        title = %article.title,
+                                        existing_article_id = candidate.summary.article_id,
+                                        existing_title = %candidate.summary.title,
+                                        existing_url = %candidate.summary.url,
+                                        existing_source = %candidate.summary.source_domain,
+                                        reason = decision.reason.as_deref().unwrap_or(""),
                                         ai_dedup_provider = selected_provider.unwrap_or(""),
-                                        "llm dedup check failed"
+                                        "cluster article under existing one due to llm duplicate judgment"
                                     );
+                                    break;
                                 }
                             }
+                            Err(err) => {
+                                let elapsed_ms = started.elapsed().as_millis() as u64;
+                                warn!(
+                                    error = ?err,
+                                    feed_id = feed.id,
+                                    elapsed_ms,
+                                    ai_dedup_provider = selected_provider.unwrap_or(""),
+                                    "llm dedup check failed"
+                                );
+                            }
                         }
                     }
-                } else {
-                    info!(feed_id = feed.id, url = %article.url, "no historical candidates; skipping hist compare");
                 }
+            } else {
+                info!(feed_id = feed.id, url = %article.url, "no historical candidates; skipping hist compare");
+            }
 
-                if is_duplicate {
-                    return Ok(true);
-                }
-                Ok(false)
-            }).await;
-            match result {
-                Ok(Ok(skipped)) => {
-                    if skipped { continue; }
-                }
-                Ok(Err(_)) => {
-                    warn!(feed_id = feed.id, url = %entry_url_clone, "entry processing aborted");
-                    // event suppressed per new minimal set
-                    continue;
-                }
-                Err(_) => {
-                    warn!(feed_id = feed.id, url = %entry_url_clone, "entry processing timed out; skip");
-                    // event suppressed per new minimal set
+            if is_duplicate {
+                return Ok(true);
+            }
+            Ok(false)
+        }).await;
+        match result {
+            Ok(Ok(skipped)) => {
+                if skipped {
+                    if let Some(canonical_id) = duplicate_of {
+                        members.push(NewArticle {
+                            canonical_id: Some(canonical_id),
+                            ..article
+                        });
+                    }
                     continue;
                 }
             }
+            Ok(Err(_)) => {
+                warn!(feed_id = feed.id, url = %entry_url_clone, "entry processing aborted");
+                // event suppressed per new minimal set
+                continue;
+            }
+            Err(_) => {
+                warn!(feed_id = feed.id, url = %entry_url_clone, "entry processing timed out; skip");
+                // event suppressed per new minimal set
+                continue;
+            }
+        }
 
-            info!(feed_id = feed.id, url = %article.url, "entry processing completed; proceeding to persist");
+        info!(feed_id = feed.id, url = %article.url, "entry processing completed; proceeding to persist");
 
-            // 入库前的数据快照（仅日志，不修改数据）
-            // 安全截断描述，按字符边界避免 UTF-8 切片 panic
-            let preview_desc_owned: String = article
-                .description
-                .as_deref()
-                .map(|s| s.chars().take(80).collect::<String>())
-                .unwrap_or_default();
-            info!(
-                feed_id = feed.id,
-                url = %article.url,
-                language = %article.language.as_deref().unwrap_or(""),
-                preview_desc = %preview_desc_owned,
-                "pre-insert article snapshot"
-            );
+        // 入库前的数据快照（仅日志，不修改数据）
+        // 安全截断描述，按字符边界避免 UTF-8 切片 panic
+        let preview_desc_owned: String = article
+            .description
+            .as_deref()
+            .map(|s| s.chars().take(80).collect::<String>())
+            .unwrap_or_default();
+        info!(
+            feed_id = feed.id,
+            url = %article.url,
+            language = %article.language.as_deref().unwrap_or(""),
+            preview_desc = %preview_desc_owned,
+            "pre-insert article snapshot"
+        );
 
-            let (normalized_title2, tokens2) = prepare_title_signature(&article.title);
-            seen_signatures.push((tokens2, normalized_title2));
-            articles.push(article);
-            info!(feed_id = feed.id, url = %articles.last().unwrap().url, "entry dedup finished");
+        let (normalized_title2, tokens2) = prepare_title_signature(&article.title);
+        seen_signatures.push((tokens2, normalized_title2));
+        if let Some(semantic) = semantic {
+            vectors.insert(article.url.clone(), semantic.vector);
         }
-        // close the for-entry loop
+        if let Some(superseded) = superseded {
+            supersedes.insert(article.url.clone(), superseded);
+        }
+        articles.push(article);
+        info!(feed_id = feed.id, url = %articles.last().unwrap().url, "entry dedup finished");
+    }
+
+    let mut duplicates = intra_batch_dups as usize + members.len();
+    // 簇成员先于新文章入库，之后的过滤条件同样作用于成员
+    if !members.is_empty() {
+        let member_count = members.len();
+        let outcome = articles::insert_articles(pool, members).await?;
+        info!(
+            feed_id = feed.id,
+            count = member_count,
+            inserted = outcome.inserted.len(),
+            "inserted duplicate articles as cluster members"
+        );
     }
 
     let article_count = articles.len();
+    let mut inserted_count = 0;
     if article_count > 0 {
+        if feed.require_review {
+            hold_for_review(feed, &mut articles, &mut supersedes);
+        } else {
+            apply_daily_cap(pool, feed, &mut articles, &mut supersedes).await?;
+        }
         info!(feed_id = feed.id, count = article_count, "about to insert parsed articles");
-        let inserted = articles::insert_articles(&pool, articles).await?;
-        let inserted_count = inserted.len();
-        info!(feed_id = feed.id, inserted = inserted_count, "articles insert finished");
+        let outcome = articles::insert_articles(pool, articles).await?;
+        let inserted = outcome.inserted;
+        inserted_count = inserted.len();
+        duplicates += outcome.existing.len();
+        info!(
+            feed_id = feed.id,
+            inserted = inserted_count,
+            existing = outcome.existing.len(),
+            "articles insert finished"
+        );
+        // 未插入的条目即 (feed_id, url) 已存在
+        record_dedup_count(pool, DEDUP_METHOD_URL, (article_count - inserted_count) as i64).await;
+        // 其他实例或上一次重试已写入同一文章：来源记录按 (article_id, source_url) 幂等，
+        // 补记一次可保证先写入方来不及记录来源时也不会缺失
+        for (article_id, article) in &outcome.existing {
+            record_article_source(
+                pool,
+                feed,
+                article,
+                *article_id,
+                Some("primary"),
+                None,
+                DedupDetail::default(),
+            )
+            .await;
+        }
         for (article_id, article) in &inserted {
             // primary 决策：来源于当前 feed 的主插入
-            record_article_source(&pool, feed, article, *article_id, Some("primary"), None).await;
+            record_article_source(
+                pool,
+                feed,
+                article,
+                *article_id,
+                Some("primary"),
+                None,
+                DedupDetail::default(),
+            )
+            .await;
+            if let Some((old_id, decision)) = supersedes.get(&article.url) {
+                match articles::supersede_article(pool, *old_id, *article_id, decision).await {
+                    Ok(()) => info!(
+                        feed_id = feed.id,
+                        article_id,
+                        superseded_article_id = old_id,
+                        decision,
+                        "merged duplicate into new canonical article from preferred source"
+                    ),
+                    Err(err) => warn!(
+                        error = ?err,
+                        feed_id = feed.id,
+                        article_id,
+                        superseded_article_id = old_id,
+                        "failed to merge superseded article"
+                    ),
+                }
+            }
         }
         if let Some(condition) = feed
             .filter_condition
@@ -1101,7 +1672,7 @@ async fn process_feed_locked(
             .filter(|value| !value.is_empty())
         {
             info!(feed_id = feed.id, "applying feed filter condition");
-            match articles::apply_filter_condition(&pool, feed.id, condition).await {
+            match articles::apply_filter_condition(pool, feed.id, condition).await {
                 Ok(deleted) => {
                     if deleted > 0 {
                         info!(
@@ -1121,6 +1692,21 @@ async fn process_feed_locked(
                 }
             }
         }
+        // 新文章入库（含归并与过滤）后使首页缓存失效
+        latest_cache.clear();
+        // 摘要、标签、向量索引等增强步骤在后台执行，启用与顺序见设置 enrichment.pipeline
+        let pipeline_articles = inserted
+            .into_iter()
+            .map(|(article_id, article)| PipelineArticle {
+                id: article_id,
+                vector: vectors.remove(&article.url),
+                title: article.title,
+                description: article.description,
+                source_domain: article.source_domain,
+                published_at: article.published_at,
+            })
+            .collect();
+        pipeline::spawn_run(pool.clone(), Arc::clone(translation), pipeline_articles);
         info!(
             feed_id = feed.id,
             count = article_count,
@@ -1130,29 +1716,25 @@ async fn process_feed_locked(
         info!(feed_id = feed.id, "no new articles parsed");
     }
 
-    let title = parsed_feed.title.as_ref().map(|text| text.content.clone());
+    record_dedup_count(pool, DEDUP_METHOD_INTRA_BATCH, intra_batch_dups).await;
 
-    let site_url = parsed_feed.links.first().map(|link| link.href.clone());
-
-    info!(feed_id = feed.id, "marking feed success");
-    feeds::mark_success(
-        &pool,
-        feed.id,
-        status.as_u16() as i16,
-        etag,
-        title,
-        site_url,
-    )
-    .await?;
-
-    info!(
-        feed_id = feed.id,
-        status = status.as_u16(),
-        last_fetch_at = ?Utc::now(),
-        "feed fetch successful"
-    );
+    Ok(IngestStats {
+        entries: entry_count,
+        inserted: inserted_count,
+        duplicates,
+    })
+}
 
-    Ok(())
+/// 解析订阅源内容；JSON Feed 额外补全缺失的标题与摘要
+fn parse_feed_body(
+    bytes: &[u8],
+    overrides: &DateOverrides,
+) -> Result<feed_rs::model::Feed, feed_rs::parser::ParseFeedError> {
+    let mut parsed = parse_feed(bytes, overrides)?;
+    if parsed.feed_type == FeedType::JSON {
+        fill_json_feed_entries(&mut parsed.entries);
+    }
+    Ok(parsed)
 }
 
 fn format_error_chain(err: &(dyn std::error::Error + 'static)) -> String {
@@ -1168,6 +1750,313 @@ fn format_error_chain(err: &(dyn std::error::Error + 'static)) -> String {
     parts.join(" -> ")
 }
 
+// 决定重复文章中哪一篇作为主文章：先看管理员配置的偏好规则，再比较来源权重
+struct SourceRanking {
+    weights: HashMap<String, f32>,
+    // (preferred_domain, over_domain)，over_domain 可为通配符 `*`
+    preferences: HashSet<(String, String)>,
+}
+
+impl SourceRanking {
+    fn weight(&self, source_domain: &str) -> f32 {
+        self.weights
+            .get(source_domain)
+            .copied()
+            .unwrap_or(DEFAULT_SOURCE_WEIGHT)
+    }
+
+    fn prefers(&self, preferred: &str, over: &str) -> bool {
+        self.preferences
+            .contains(&(preferred.to_string(), over.to_string()))
+    }
+
+    // 显式规则优先于通配规则；双方都只有通配规则时视为无规则
+    fn preference(&self, new: &str, existing: &str) -> Option<bool> {
+        if self.prefers(new, existing) {
+            return Some(true);
+        }
+        if self.prefers(existing, new) {
+            return Some(false);
+        }
+        match (self.prefers(new, ANY_SOURCE), self.prefers(existing, ANY_SOURCE)) {
+            (true, false) => Some(true),
+            (false, true) => Some(false),
+            _ => None,
+        }
+    }
+
+    /// 新文章应取代已有主文章时返回归并决策名；没有规则命中时仅当新来源权重严格更高才替换，
+    /// 权重相同保持先到先得
+    fn prefers_new_source(&self, article: &NewArticle, existing: &ArticleSummary) -> Option<&'static str> {
+        if article.source_domain == existing.source_domain {
+            return None;
+        }
+        match self.preference(&article.source_domain, &existing.source_domain) {
+            Some(true) => Some("superseded_by_preference"),
+            Some(false) => None,
+            None => (self.weight(&article.source_domain) > self.weight(&existing.source_domain))
+                .then_some("superseded_by_weight"),
+        }
+    }
+}
+
+/// 单条目翻译：成功则替换标题/摘要，失败时保留原文并标记待补偿翻译。
+async fn translate_entry(
+    pool: &sqlx::PgPool,
+    translation: &TranslationEngine,
+    feed: &DueFeedRow,
+    mut article: NewArticle,
+) -> NewArticle {
+    let original_title = article.title.clone();
+
+    // 按原文语种匹配翻译规则；无论是否需要翻译，都记录一次判定结果日志
+    let need_translate = translation.should_translate(article.original_language.as_deref());
+    info!(
+        feed_id = feed.id,
+        url = %article.url,
+        need_translate,
+        original_language = article.original_language.as_deref().unwrap_or("-"),
+        title = %original_title,
+        "title translation decision"
+    );
+
+    // 进入条目处理主流程，便于定位卡点
+    info!(feed_id = feed.id, url = %article.url, "begin entry processing");
+
+    if !need_translate {
+        // 不进行翻译但保留原始标题/描述
+        return article;
+    }
+
+    // 翻译流程：始终翻译摘要（已取消单独开关）；若无可用 provider 返回 None
+    let has_original_desc = article.description.is_some();
+
+    info!(
+        feed_id = feed.id,
+        url = %article.url,
+        has_original_description = has_original_desc,
+        "pre-translation decision"
+    );
+
+    let desc_owned = article.description.clone();
+
+    // 开始进行翻译调用：记录 provider 与摘要长度
+    let started = std::time::Instant::now();
+    let desc_in_len = desc_owned.as_ref().map(|s| s.len()).unwrap_or(0);
+    info!(
+        feed_id = feed.id,
+        url = %article.url,
+        title = %original_title,
+        provider = ?translation.current_provider(),
+        desc_in_len,
+        "translation start"
+    );
+
+    match translation
+        .translate(&original_title, desc_owned.as_deref())
+        .await
+    {
+        Ok(Some(translated)) => {
+            // 成功翻译：更新标题；仅在返回描述时覆盖原描述
+            article.title = translated.title;
+            if translated.description.is_some() {
+                article.description = translated.description;
+            }
+            article.language = Some(TRANSLATION_LANG.to_string());
+
+            if has_original_desc && desc_owned.is_some() && article.description.is_none() {
+                let elapsed_ms = started.elapsed().as_millis() as u64;
+                warn!(
+                    feed_id = feed.id,
+                    url = %article.url,
+                    provider = %translation.current_provider().as_str(),
+                    elapsed_ms,
+                    "translator returned no description while description translation is enabled"
+                );
+                // 不上报事件，仅记录日志（根据“仅失败重试后上报”的约定）
+            }
+        }
+        Ok(None) => {
+            let current = translation.current_provider();
+            let provider = current.as_str();
+            let provider_available = translation.llm_client(current).is_some();
+            info!(
+                feed_id = feed.id,
+                url = %article.url,
+                provider = provider,
+                provider_available,
+                "translation skipped (provider unavailable)"
+            );
+            // event suppressed per request to cancel all bindings
+        }
+        Err(err) => {
+            // 翻译失败：保留原文入库并标记待翻译，由后台补偿任务稍后重试，
+            // 避免服务商短暂故障导致原文标题被永久保留
+            warn!(
+                error = %err,
+                feed_id = feed.id,
+                url = %article.url,
+                "failed to translate article, queued for backlog retry"
+            );
+            report_translation_failure(pool, &err).await;
+            article.translation_pending = true;
+        }
+    }
+    article
+}
+
+/// provider 调用失败（额度耗尽、鉴权失败、超时等）时上报运维事件，限频由翻译引擎按 provider 决定
+// 静音到期的 feed 自动解除，并逐个记录事件，随后的轮次照常调度
+async fn release_expired_mutes(pool: &sqlx::PgPool) {
+    let released = match feeds::clear_expired_mutes(pool).await {
+        Ok(released) => released,
+        Err(err) => {
+            warn!(error = ?err, "failed to release expired feed mutes");
+            return;
+        }
+    };
+    for (feed_id, source_domain) in released {
+        info!(feed_id, "feed mute expired");
+        Event::new(EventCode::FeedUnmuted)
+            .attr("feed_id", feed_id)
+            .attr("source_domain", source_domain.as_str())
+            .attr("reason", "expired")
+            .detail(format!("{source_domain} 静音到期，已自动恢复抓取"))
+            .emit(pool)
+            .await;
+    }
+}
+
+pub(crate) async fn report_translation_failure(pool: &sqlx::PgPool, err: &anyhow::Error) {
+    let Some(failed) = err.downcast_ref::<TranslationFailed>() else {
+        return;
+    };
+    let Some(suppressed) = failed.report else {
+        return;
+    };
+    let chain: String = format!("{err:#}").chars().take(500).collect();
+    // 错误链以 `<provider> translation failed: ...` 开头，已包含 provider
+    let mut info = chain;
+    if suppressed > 0 {
+        info.push_str(&format!("（上次上报后另有 {suppressed} 次失败）"));
+    }
+    Event::new(EventCode::TranslationProviderFailed)
+        .attr("suppressed", suppressed)
+        .detail(info)
+        .emit(pool)
+        .await;
+}
+
+async fn report_dedup_switch(pool: &sqlx::PgPool, switch: DedupSwitch) {
+    let event = match switch {
+        DedupSwitch::FailedOver { from, to } => {
+            warn!(from = from.as_str(), to = to.as_str(), "ai dedup provider failed over");
+            Event::new(EventCode::AiDedupFailover)
+                .attr("from", from.as_str())
+                .attr("to", to.as_str())
+                .detail(format!("{}→{}", from.as_str(), to.as_str()))
+        }
+        DedupSwitch::Recovered { to } => {
+            info!(provider = to.as_str(), "ai dedup provider recovered");
+            Event::new(EventCode::AiDedupRecovered)
+                .attr("provider", to.as_str())
+                .detail(to.as_str())
+        }
+    };
+    event.emit(pool).await;
+}
+
+// 新文章的 embedding 与向量近邻；检索失败时 `neighbours` 为 None，仍保留向量供入库后建立索引
+struct SemanticMatch {
+    vector: Vec<f32>,
+    neighbours: Option<Vec<ScoredPoint>>,
+}
+
+async fn semantic_match(
+    index: &EmbeddingIndex,
+    translation: &TranslationEngine,
+    feed: &DueFeedRow,
+    article: &NewArticle,
+    window_hours: i64,
+) -> Option<SemanticMatch> {
+    let vector = match index
+        .embed(translation, &article.title, article.description.as_deref())
+        .await
+    {
+        Ok(vector) => vector,
+        Err(err) => {
+            warn!(error = ?err, feed_id = feed.id, url = %article.url, "article embedding failed; fall back to full dedup scan");
+            return None;
+        }
+    };
+    let neighbours = match index.neighbours(&vector, window_hours).await {
+        Ok(neighbours) => Some(neighbours),
+        Err(err) => {
+            warn!(error = ?err, feed_id = feed.id, url = %article.url, "embedding neighbour search failed; fall back to full dedup scan");
+            None
+        }
+    };
+    Some(SemanticMatch { vector, neighbours })
+}
+
+fn candidate_from_row(row: ArticleRow) -> Option<CandidateArticle> {
+    let ArticleRow {
+        id,
+        title,
+        url,
+        description,
+        language: _,
+        original_language: _,
+        source_domain,
+        published_at,
+        click_count: _,
+        tags: _,
+    } = row;
+
+    let (_, tokens) = prepare_title_signature(&title);
+    if tokens.is_empty() {
+        return None;
+    }
+    Some(CandidateArticle {
+        tokens,
+        summary: ArticleSummary {
+            article_id: id,
+            title,
+            source_domain,
+            url,
+            description,
+            published_at,
+        },
+    })
+}
+
+// 去重判定的解释信息，随来源记录一起保存，便于管理员排查与调参
+#[derive(Default)]
+struct DedupDetail {
+    jaccard: Option<f32>,
+    matched_tokens: Option<Vec<String>>,
+    llm_raw: Option<String>,
+}
+
+impl DedupDetail {
+    fn jaccard(similarity: f32, a: &BTreeSet<String>, b: &BTreeSet<String>) -> Self {
+        Self {
+            jaccard: Some(similarity),
+            matched_tokens: Some(a.intersection(b).cloned().collect()),
+            llm_raw: None,
+        }
+    }
+}
+
+async fn record_dedup_count(pool: &sqlx::PgPool, method: &str, count: i64) {
+    if count <= 0 {
+        return;
+    }
+    if let Err(err) = analytics::bump_dedup_counter(pool, method, count).await {
+        warn!(error = ?err, method, "failed to record dedup counter");
+    }
+}
+
 async fn record_article_source(
     pool: &sqlx::PgPool,
     feed: &DueFeedRow,
@@ -1175,6 +2064,7 @@ async fn record_article_source(
     article_id: i64,
     decision: Option<&str>,
     confidence: Option<f32>,
+    detail: DedupDetail,
 ) {
     let record = ArticleSourceRecord {
         article_id,
@@ -1184,6 +2074,9 @@ async fn record_article_source(
         published_at: article.published_at,
         decision: decision.map(|s| s.to_string()),
         confidence,
+        jaccard: detail.jaccard,
+        matched_tokens: detail.matched_tokens,
+        llm_raw: detail.llm_raw,
     };
 
     if let Err(err) = article_sources::insert_source(pool, record).await {
@@ -1196,6 +2089,57 @@ async fn record_article_source(
     }
 }
 
+// JSON Feed 条目的标题可省略（多见于微博客），正文可能只在 content_html / content_text 中：
+// 缺少摘要时取正文，缺少标题时取正文开头，之后与 RSS/Atom 条目走同一套 convert_entry 流程
+fn fill_json_feed_entries(entries: &mut [Entry]) {
+    for entry in entries {
+        let body = entry
+            .content
+            .as_ref()
+            .and_then(|content| content.body.as_deref())
+            .map(strip_html_basic)
+            .filter(|text| !text.is_empty());
+        if entry.summary.is_none() {
+            entry.summary = body.clone().map(plain_text);
+        }
+        let has_title = entry
+            .title
+            .as_ref()
+            .is_some_and(|title| !title.content.trim().is_empty());
+        if has_title {
+            continue;
+        }
+        let source = body.or_else(|| {
+            entry
+                .summary
+                .as_ref()
+                .map(|summary| strip_html_basic(&summary.content))
+        });
+        if let Some(title) = source.as_deref().and_then(derive_title) {
+            entry.title = Some(plain_text(title));
+        }
+    }
+}
+
+fn plain_text(content: String) -> Text {
+    Text {
+        content_type: mime::TEXT_PLAIN,
+        src: None,
+        content,
+    }
+}
+
+// 取正文第一行的前 JSON_FEED_TITLE_CHARS 个字符作为标题
+fn derive_title(text: &str) -> Option<String> {
+    let line = text.lines().map(str::trim).find(|line| !line.is_empty())?;
+    let mut chars = line.chars();
+    let mut title: String = chars.by_ref().take(JSON_FEED_TITLE_CHARS).collect();
+    if chars.next().is_some() {
+        title.push('…');
+    }
+    Some(title)
+}
+
 fn convert_entry(_pool: &sqlx::PgPool, _events: &EventsHub, feed: &DueFeedRow, entry: &Entry) -> Option<NewArticle> {
     // 将 feed_rs 的 Entry 转换为内部 NewArticle 结构
     // 处理标题、链接、描述、语言与发布时间（优先 published，其次 updated，最后当前时间）
@@ -1224,12 +2168,12 @@ fn convert_entry(_pool: &sqlx::PgPool, _events: &EventsHub, feed: &DueFeedRow, e
         .map(|summary| summary.content.clone())
         .filter(|s| !s.trim().is_empty());
 
-    let language = entry.language.clone();
+    // 源未声明语言时以 feed 的语言提示补上
+    let language = entry.language.clone().or_else(|| feed.language_hint.clone());
 
     let published_at = entry
         .published
-        .clone()
-        .or_else(|| entry.updated.clone())
+        .or(entry.updated)
         .map(|dt| dt.with_timezone(&Utc))
         .unwrap_or_else(Utc::now);
 
@@ -1243,34 +2187,136 @@ fn convert_entry(_pool: &sqlx::PgPool, _events: &EventsHub, feed: &DueFeedRow, e
         html_unescape_minimal(stripped.as_str())
     });
 
+    // 原文语种：feed 配置了语言提示时直接采用，避免混排标题（如中文源的英文专名）误判；
+    // 否则优先按标题/摘要文字判定，无法判定时退回源声明的语言标签
+    let original_language = feed.language_hint.clone().or_else(|| {
+        detect_language(&title)
+            .or_else(|| description.as_deref().and_then(detect_language))
+            .or_else(|| language.as_deref().and_then(normalize_language_tag))
+            .map(str::to_string)
+    });
+
+    // 指纹基于翻译前的标题计算，同一篇转载无论翻译结果如何都能命中
+    let content_fingerprint = content_fingerprint(&title, &url, published_at);
+    let title_simhash = title_simhash(&title);
+
     Some(NewArticle {
         feed_id: Some(feed.id),
         title,
         url,
         description,
         language,
+        original_language,
         source_domain: feed.source_domain.clone(),
         published_at,
+        translation_pending: false,
+        content_fingerprint,
+        title_simhash,
+        hidden_reason: None,
+        canonical_id: None,
+        full_description: None,
     })
 }
 
+// 需审核的源：本批新文章全部以待审核状态入库（不计入来源每日上限），批准前不取代已公开的主文章
+fn hold_for_review(
+    feed: &DueFeedRow,
+    articles: &mut [NewArticle],
+    supersedes: &mut HashMap<String, (i64, &'static str)>,
+) {
+    for article in articles.iter_mut() {
+        article.hidden_reason = Some(PENDING_REVIEW_HIDDEN_REASON.to_string());
+        supersedes.remove(&article.url);
+    }
+    info!(
+        feed_id = feed.id,
+        count = articles.len(),
+        "feed requires review, holding new articles as pending"
+    );
+}
+
+// 来源每日上限：今日已公开的文章数达到上限后，本批多出的文章标记为入库即隐藏，
+// 避免单个高频来源刷屏；被隐藏的文章不参与按权重归并
+async fn apply_daily_cap(
+    pool: &sqlx::PgPool,
+    feed: &DueFeedRow,
+    articles: &mut [NewArticle],
+    supersedes: &mut HashMap<String, (i64, &'static str)>,
+) -> anyhow::Result<()> {
+    let Some(cap) = sources::daily_cap(pool, &feed.source_domain).await? else {
+        return Ok(());
+    };
+    let accepted = articles::count_visible_today(pool, &feed.source_domain).await?;
+    let remaining = usize::try_from((i64::from(cap) - accepted).max(0)).unwrap_or(0);
+    if articles.len() <= remaining {
+        return Ok(());
+    }
+    for article in articles.iter_mut().skip(remaining) {
+        article.hidden_reason = Some(DAILY_CAP_HIDDEN_REASON.to_string());
+        supersedes.remove(&article.url);
+    }
+    info!(
+        feed_id = feed.id,
+        source_domain = %feed.source_domain,
+        daily_cap = cap,
+        accepted_today = accepted,
+        hidden = articles.len() - remaining,
+        "source daily cap reached, hiding overflow articles"
+    );
+    Ok(())
+}
+
+/// 连续失败次数超过 `fetcher.max_fail_count` 时自动停用 feed，避免其长期静默失败
+async fn auto_disable_if_exhausted(pool: &sqlx::PgPool, feed_id: i64) {
+    let max_fail_count = match settings_registry::get_i64(pool, settings_registry::MAX_FAIL_COUNT_KEY).await {
+        Ok(Some(max)) if max > 0 => max as i32,
+        Ok(_) => return,
+        Err(err) => {
+            warn!(feed_id, error = ?err, "failed to read max fail count setting");
+            return;
+        }
+    };
+    match feeds::auto_disable_failing(pool, feed_id, max_fail_count).await {
+        Ok(Some((source_domain, fail_count))) => {
+            warn!(feed_id, fail_count, max_fail_count, "feed auto-disabled after repeated failures");
+            Event::new(EventCode::FeedAutoDisabled)
+                .attr("feed_id", feed_id)
+                .attr("source_domain", source_domain.as_str())
+                .attr("fail_count", fail_count)
+                .detail(format!(
+                    "{source_domain}（feed #{feed_id}）连续失败 {fail_count} 次，已自动停用"
+                ))
+                .emit(pool)
+                .await;
+        }
+        Ok(None) => {}
+        Err(err) => warn!(feed_id, error = ?err, "failed to auto-disable feed"),
+    }
+}
+
 async fn record_failure(
     pool: &sqlx::PgPool,
     _events: &EventsHub,
     feed_id: i64,
     http_status: Option<StatusCode>,
+    kind: FetchErrorKind,
+    error: &anyhow::Error,
     persist: bool,
 ) -> anyhow::Result<()> {
     let status = http_status.map(|s| s.as_u16() as i16).unwrap_or(0);
     if persist {
         // 持久记录失败（超过快速重试次数或不再重试）
-        feeds::mark_failure(pool, feed_id, status).await?;
-        warn!(feed_id, status, "marked feed fetch failure");
+        let message: String = format!("{error:#}").chars().take(FETCH_ERROR_MAX_CHARS).collect();
+        feeds::mark_failure(pool, feed_id, status, kind.as_str(), &message).await?;
+        warn!(feed_id, status, error_kind = kind.as_str(), "marked feed fetch failure");
         // event suppressed per new minimal set
+        auto_disable_if_exhausted(pool, feed_id).await;
     } else {
         info!(
             feed_id,
-            status, "feed fetch failed, will attempt quick retry"
+            status,
+            error_kind = kind.as_str(),
+            "feed fetch failed, will attempt quick retry"
         );
     }
     Ok(())
@@ -0,0 +1,63 @@
+//! 多实例分片：各实例每轮抓取前登记心跳，到期的 feed 按 rendezvous 哈希分给存活实例，
+//! 实例增减时只迁移受影响的 feed。feed 处理锁（advisory lock）仍作为兜底，
+//! 各实例对存活列表的视图短暂不一致时也不会同时抓取同一个 feed。
+
+use chrono::{DateTime, Utc};
+use tracing::warn;
+
+use crate::repo::fetcher_instances;
+
+// 心跳有效期至少为若干个抓取间隔，单轮耗时较长也不会被误判为下线
+const TTL_INTERVALS: u64 = 3;
+const MIN_TTL_SECS: u64 = 60;
+
+/// 配置的实例标识（去掉首尾空白），未配置时为主机名。标识在重启后保持不变，
+/// 重启的实例沿用原有登记，不会留下仍占着分片、直到过期才释放的旧记录
+pub fn resolve_instance_id(configured: Option<&str>) -> String {
+    if let Some(id) = configured.map(str::trim).filter(|id| !id.is_empty()) {
+        return id.to_string();
+    }
+    std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| std::fs::read_to_string("/proc/sys/kernel/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "backend".to_string())
+}
+
+pub struct ShardMember {
+    instance_id: String,
+    started_at: DateTime<Utc>,
+    ttl_secs: i64,
+}
+
+impl ShardMember {
+    pub fn new(instance_id: String, interval_secs: u64) -> Self {
+        Self {
+            instance_id,
+            started_at: Utc::now(),
+            ttl_secs: (interval_secs * TTL_INTERVALS).max(MIN_TTL_SECS) as i64,
+        }
+    }
+
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    pub async fn heartbeat(&self, pool: &sqlx::PgPool) {
+        if let Err(err) =
+            fetcher_instances::heartbeat(pool, &self.instance_id, self.started_at, self.ttl_secs)
+                .await
+        {
+            warn!(error = ?err, instance_id = %self.instance_id, "failed to record fetcher heartbeat");
+        }
+    }
+
+    pub async fn record_round(&self, pool: &sqlx::PgPool, feeds: usize) {
+        if let Err(err) =
+            fetcher_instances::record_round(pool, &self.instance_id, feeds as i32).await
+        {
+            warn!(error = ?err, instance_id = %self.instance_id, "failed to record fetcher round");
+        }
+    }
+}
@@ -11,7 +11,11 @@ mod service;
 mod util;
 
 use anyhow::Context;
-use std::{net::SocketAddr, path::Path, sync::OnceLock};
+use std::{
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
 use tokio::net::TcpListener;
 use tracing_appender::rolling;
 use tracing_subscriber::{
@@ -21,8 +25,19 @@ use tracing_subscriber::{
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    // `--hash-password` 从标准输入读取密码，输出可填入 `admin.password_hash` 的哈希后退出，无需配置文件
+    if std::env::args().skip(1).any(|arg| arg == "--hash-password") {
+        return print_password_hash().await;
+    }
+
     let config = config::AppConfig::load().context("failed to load configuration")?;
     setup_tracing(&config)?;
+
+    // 开发用：`--seed-from <dir>` 导入本地样例订阅文件后退出，不启动服务
+    if let Some(dir) = seed_dir_arg()? {
+        return seed_fixtures(&config, &dir).await;
+    }
+
     let addr: SocketAddr = config
         .server
         .bind
@@ -34,8 +49,54 @@ async fn main() -> anyhow::Result<()> {
     let app = app::build_router(&config).await?;
     let listener = TcpListener::bind(addr).await?;
 
-    axum::serve(listener, app).await.context("server failed")?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await.context("server failed")?;
+
+    Ok(())
+}
 
+fn seed_dir_arg() -> anyhow::Result<Option<PathBuf>> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--seed-from" {
+            let dir = args
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("--seed-from requires a directory"))?;
+            return Ok(Some(PathBuf::from(dir)));
+        }
+        if let Some(dir) = arg.strip_prefix("--seed-from=") {
+            return Ok(Some(PathBuf::from(dir)));
+        }
+    }
+    Ok(None)
+}
+
+async fn seed_fixtures(config: &config::AppConfig, dir: &Path) -> anyhow::Result<()> {
+    tracing::info!(dir = %dir.display(), "seeding fixtures");
+    let pool = app::connect_db(config).await?;
+    let results = fetcher::seed::seed_from_dir(&pool, &config.http_client, dir).await?;
+    for result in &results {
+        println!(
+            "{}: feed #{} entries={} inserted={}",
+            result.file, result.feed_id, result.entries, result.inserted
+        );
+    }
+    Ok(())
+}
+
+async fn print_password_hash() -> anyhow::Result<()> {
+    let mut password = String::new();
+    std::io::stdin()
+        .read_line(&mut password)
+        .context("failed to read password from stdin")?;
+    let password = password.trim_end_matches(['\r', '\n']);
+    if password.is_empty() {
+        anyhow::bail!("password must not be empty");
+    }
+    println!("{}", util::password::hash_password(password).await?);
     Ok(())
 }
 
@@ -98,6 +159,7 @@ fn setup_tracing(config: &config::AppConfig) -> anyhow::Result<()> {
         .with(stdout_backend)
         .with(stdout_general)
         .with(file_layer)
+        .with(ops::db_monitor::SlowQueryLayer)
         .try_init()
         .context("failed to init tracing subscriber")?;
 
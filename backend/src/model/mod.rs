@@ -7,9 +7,319 @@ pub struct ArticleOut {
     pub url: String,
     pub description: Option<String>,
     pub language: Option<String>,
+    pub original_language: Option<String>,
     pub source_domain: String,
     pub published_at: String,
     pub click_count: i64,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArticleChangesQuery {
+    #[serde(default)]
+    pub since_id: i64,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArticleChangesOut {
+    /// 按 ID 升序
+    pub items: Vec<ArticleOut>,
+    /// 下次请求的 since_id；本批扫描到的隐藏或已归并文章也计入，无新文章时等于请求值
+    pub next_since_id: i64,
+    /// 为 true 时还有更多文章，可立即以 next_since_id 继续请求
+    pub has_more: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArticleAttrsOut {
+    pub article_id: i64,
+    /// 外部评分服务返回的 JSON 对象，尚未评分时为 null
+    pub attrs: Option<serde_json::Value>,
+    pub enriched_at: Option<String>,
+    pub enrichment_attempts: i32,
+    /// 增强流水线各步骤的输出（按步骤名），见 `service::enrichment::pipeline`
+    pub steps: std::collections::BTreeMap<String, EnrichmentStepOut>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EnrichmentStepOut {
+    pub output: serde_json::Value,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArticleContentOut {
+    pub article_id: i64,
+    /// 抽取出的正文，段落之间以空行分隔；尚未抽取或抽取失败时为 null
+    pub content: Option<String>,
+    /// 正文中的图片地址（绝对 URL），按出现顺序
+    pub images: Vec<String>,
+    pub extracted_at: Option<String>,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    /// 入库时被截断的完整摘要（见设置 `ingest.description_max_chars`），未截断或未开启正文存储时为 null
+    pub full_description: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArticleReaderOut {
+    pub id: i64,
+    pub title: String,
+    pub url: String,
+    pub source_domain: String,
+    pub published_at: String,
+    /// 段落之间以空行分隔；尚未抽取到正文时为 null
+    pub content: Option<String>,
+    pub images: Vec<String>,
+}
+
+/// 公开的来源记录：同一事件的各家报道及其被归并的依据
+#[derive(Debug, Serialize)]
+pub struct PublicArticleSourceOut {
+    pub feed_id: Option<i64>,
+    pub feed_title: Option<String>,
+    pub source_name: Option<String>,
+    pub url: String,
+    pub published_at: Option<String>,
+    /// `primary`（主文章本身）或去重判定方式，如 `fingerprint`、`recent_jaccard`、`superseded_by_weight`
+    pub decision: Option<String>,
+    pub confidence: Option<f32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArticleSourceOut {
+    pub id: i64,
+    pub article_id: i64,
+    pub feed_id: Option<i64>,
+    pub source_name: Option<String>,
+    pub source_url: String,
+    pub published_at: Option<String>,
+    pub inserted_at: String,
+    pub decision: Option<String>,
+    pub confidence: Option<f32>,
+    pub jaccard: Option<f32>,
+    pub matched_tokens: Vec<String>,
+    pub llm_raw: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SimilarArticlesQuery {
+    /// `jaccard`（缺省）或 `embedding`
+    pub method: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SimilarArticlesOut {
+    pub article_id: i64,
+    pub method: String,
+    /// 候选范围：目标文章发布时间前后的小时数（与 `dedup.window_hours` 一致）
+    pub window_hours: i64,
+    /// 实际参与比较的候选数量
+    pub scanned: usize,
+    pub strict_threshold: f32,
+    pub llm_threshold: f32,
+    pub items: Vec<SimilarArticleOut>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SimilarArticleOut {
+    pub article_id: i64,
+    pub title: String,
+    pub url: String,
+    pub source_domain: String,
+    pub published_at: String,
+    pub score: f32,
+    pub matched_tokens: Vec<String>,
+    /// 按当前阈值的判定：`strict_duplicate`、`llm_check` 或 `distinct`
+    pub verdict: String,
+    pub canonical_id: i64,
+    /// 是否已与目标文章归并在同一簇
+    pub same_cluster: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct ClusterListQuery {
+    /// 主文章发布于最近多少小时内，默认 72，上限 720
+    pub hours: Option<i64>,
+    /// 簇内最少文章数（含主文章），默认 2 即只列出有重复的簇
+    pub min_size: Option<i64>,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClusterOut {
+    pub canonical_id: i64,
+    pub title: String,
+    pub url: String,
+    pub source_domain: String,
+    pub published_at: String,
+    pub hidden: bool,
+    pub size: i64,
+    /// 簇内文章的来源域名（去重）
+    pub source_domains: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArticleClusterOut {
+    pub article_id: i64,
+    pub canonical_id: i64,
+    /// 主文章在前
+    pub items: Vec<ClusterMemberOut>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClusterMemberOut {
+    pub article_id: i64,
+    pub feed_id: Option<i64>,
+    pub title: String,
+    pub url: String,
+    pub source_domain: String,
+    pub published_at: String,
+    pub fetched_at: String,
+    pub hidden: bool,
+    pub is_canonical: bool,
+    /// 成员并入时的判重依据（`fingerprint`、`recent_jaccard` 等），主文章为 `primary`
+    pub decision: Option<String>,
+    pub confidence: Option<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArticleHidePayload {
+    pub reason: String,
+}
+
+/// 管理员修正文章字段：未提供的字段保持不变；description / language / original_language 传空串表示清空
+#[derive(Debug, Deserialize)]
+pub struct ArticleUpdatePayload {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub language: Option<String>,
+    pub original_language: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct ReviewQueueQuery {
+    pub feed_id: Option<i64>,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+impl Default for ReviewQueueQuery {
+    fn default() -> Self {
+        Self {
+            feed_id: None,
+            page: 1,
+            page_size: 20,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PendingArticleOut {
+    pub id: i64,
+    pub feed_id: Option<i64>,
+    pub title: String,
+    pub url: String,
+    pub description: Option<String>,
+    pub language: Option<String>,
+    pub original_language: Option<String>,
+    pub source_domain: String,
+    pub published_at: String,
+    pub fetched_at: String,
+}
+
+/// 标记已读/收藏的请求体，省略时为 true（标记），传 false 取消
+#[derive(Debug, Deserialize)]
+pub struct ArticleReadPayload {
+    pub read: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArticleBookmarkPayload {
+    pub bookmarked: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArticleTranslateQuery {
+    /// 目标语言：en|zh|ja|ko（`en-US` 这类标签会被归一）
+    pub lang: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArticleTranslationOut {
+    pub article_id: i64,
+    pub lang: String,
+    pub title: String,
+    pub description: Option<String>,
+    /// 为 false 时文章本身已是目标语言，返回原文
+    pub translated: bool,
+    /// 是否命中已缓存的译文
+    pub cached: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArticleStateOut {
+    pub article_id: i64,
+    pub read_at: Option<String>,
+    pub bookmarked_at: Option<String>,
+}
+
+/// 批量批准：`ids` 与 `feed_id`（批准该源全部待审核文章）二选一
+#[derive(Debug, Deserialize)]
+pub struct ArticleApprovePayload {
+    pub ids: Option<Vec<i64>>,
+    pub feed_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArticleApproveResult {
+    pub approved: Vec<i64>,
+}
+
+/// 批量操作：`ids` 与 `filter` 二选一
+#[derive(Debug, Deserialize)]
+pub struct ArticleBulkPayload {
+    /// delete | retranslate | resource
+    pub action: String,
+    pub ids: Option<Vec<i64>>,
+    pub filter: Option<ArticleBulkFilter>,
+    /// resource 的目标来源域名
+    pub source_domain: Option<String>,
+}
+
+/// 按发布时间范围（RFC3339）与来源域名筛选，至少提供一项
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ArticleBulkFilter {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub source_domain: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArticleBulkResult {
+    pub action: String,
+    /// 命中的文章数
+    pub matched: u64,
+    /// 实际发生变化的文章数（如 retranslate 跳过已翻译的文章）
+    pub affected: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModerationLogOut {
+    pub id: i64,
+    pub article_id: i64,
+    /// hide | unhide | edit（edit 的 reason 为被修改的字段列表）| approve
+    pub action: String,
+    pub reason: Option<String>,
+    /// 操作人：后台用户名或 `api-key:<名称>`；系统操作（如入库时自动隐藏）为 null
+    pub actor: Option<String>,
+    pub created_at: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -22,9 +332,85 @@ pub struct FeedOut {
     pub enabled: bool,
     pub fetch_interval_seconds: i32,
     pub filter_condition: Option<String>,
+    /// 5 段（或带秒的 6/7 段）cron 表达式，按 UTC 计算；设置后取代固定间隔调度
+    pub schedule_cron: Option<String>,
+    /// 为 true 时抓取不发送条件请求头，用于绕过返回错误 304 的 CDN
+    pub ignore_conditional: bool,
+    pub date_timezone: Option<String>,
+    pub date_format: Option<String>,
+    /// 源的固定语种（`en`/`zh`/`ja`/`ko`），设置后入库时不再按文字检测
+    pub language_hint: Option<String>,
+    /// 新文章以待审核状态入库，经管理员批准后才公开
+    pub require_review: bool,
+    /// 抓取时附加的请求头，名称为小写；含凭据的值（Authorization、*token*、*key* 等）已掩码
+    pub request_headers: std::collections::BTreeMap<String, String>,
+    /// 静音截止时间：在此之前跳过定时抓取，到期后自动解除
+    pub muted_until: Option<String>,
+    /// 因连续失败被自动停用的时间，重新启用后为 null
+    pub auto_disabled_at: Option<String>,
+    pub last_etag: Option<String>,
+    /// 上次成功抓取时服务器返回的 Last-Modified，下次抓取作为 If-Modified-Since 发送
+    pub last_modified: Option<String>,
+    pub last_fetch_at: Option<String>,
+    pub last_fetch_status: Option<i32>,
+    pub fail_count: i32,
+    /// 最近一次失败的分类：dns | tls | timeout | connection | http_4xx | http_5xx | parse | encoding | robots | other，
+    /// 抓取成功后为 null
+    pub last_error_kind: Option<String>,
+    /// 最近一次失败的错误信息（截断到 500 字符）
+    pub last_error: Option<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QueueFeedOut {
+    pub feed_id: i64,
+    pub url: String,
+    pub source_domain: String,
     pub last_fetch_at: Option<String>,
     pub last_fetch_status: Option<i32>,
     pub fail_count: i32,
+    /// 同 `FeedOut::last_error_kind`
+    pub last_error_kind: Option<String>,
+    pub next_fetch_at: String,
+    pub muted_until: Option<String>,
+    /// fetching | never_fetched | interval_elapsed | schedule_elapsed | retry_after_failure | waiting_interval | waiting_schedule | backoff_after_failure | muted
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct FetcherQueueOut {
+    /// 处于静默时段时调度器不会发起新的抓取轮次
+    pub quiet_hours_active: bool,
+    pub in_flight: Vec<QueueFeedOut>,
+    pub due_now: Vec<QueueFeedOut>,
+    pub due_next_hour: Vec<QueueFeedOut>,
+    /// 静音中的 feed，按解除时间排序
+    pub muted: Vec<QueueFeedOut>,
+    /// 已登记的抓取实例（含刚过期、尚未清理的），到期的 feed 按哈希分给其中存活的实例
+    pub instances: Vec<FetcherInstanceOut>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FetcherInstanceOut {
+    pub instance_id: String,
+    /// 处理本次请求的实例
+    pub current: bool,
+    /// 心跳未过期，参与分片
+    pub alive: bool,
+    pub started_at: String,
+    pub last_seen_at: String,
+    pub expires_at: String,
+    pub last_round_at: Option<String>,
+    pub last_round_feeds: Option<i32>,
+    /// 按哈希当前归属该实例的启用 feed 数
+    pub owned_feeds: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FeedMuteQuery {
+    /// RFC3339 时间，须晚于当前时间
+    pub until: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -32,9 +418,22 @@ pub struct PageResp<T> {
     pub page: u32,
     pub page_size: u32,
     pub total_hint: u64,
+    /// total_hint 为估算值（`count=estimate`）时为 true，精确计数时省略
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub total_estimated: bool,
+    /// 游标分页的下一页位置，本页未满（已到末尾）或接口不支持游标时省略
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<PageCursor>,
     pub items: Vec<T>,
 }
 
+/// 键集分页游标：原样作为 `after_published_at` / `after_id` 传回即可取下一页
+#[derive(Debug, Serialize)]
+pub struct PageCursor {
+    pub after_published_at: String,
+    pub after_id: i64,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(default)]
 pub struct ArticleListQuery {
@@ -43,6 +442,90 @@ pub struct ArticleListQuery {
     pub page: u32,
     pub page_size: u32,
     pub keyword: Option<String>,
+    pub original_language: Option<String>,
+    pub feed_tag: Option<String>,
+    pub source_domain: Option<String>,
+    /// 标题或摘要包含的关键词（`keyword` 只匹配标题），与搜索接口的 `q` 相同
+    pub q: Option<String>,
+    /// `exact`（默认）或 `estimate`：大表上以估算总数换取稳定的响应时间
+    pub count: Option<String>,
+    /// 键集分页游标（需与 after_id 同时给出），取排在该位置之后的文章，忽略 page；
+    /// 只支持默认排序
+    pub after_published_at: Option<String>,
+    pub after_id: Option<i64>,
+    /// 排序字段：`published_at`（默认）、`click_count` 或 `fetched_at`
+    pub sort: Option<String>,
+    /// `desc`（默认）或 `asc`
+    pub order: Option<String>,
+    /// 按读者状态筛选，需同时携带 `X-Reader-Id` 请求头
+    pub read: Option<bool>,
+    pub bookmarked: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct SearchQuery {
+    pub q: Option<String>,
+    pub page: u32,
+    pub page_size: u32,
+    pub source_domain: Option<String>,
+    pub tag: Option<String>,
+    /// 按发布日期（UTC，`YYYY-MM-DD`）过滤
+    pub day: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FacetCount {
+    pub value: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct SearchFacets {
+    pub source_domain: Vec<FacetCount>,
+    pub tag: Vec<FacetCount>,
+    pub day: Vec<FacetCount>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchResp {
+    pub page: u32,
+    pub page_size: u32,
+    pub total_hint: u64,
+    pub items: Vec<ArticleOut>,
+    pub facets: SearchFacets,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct ArchiveQuery {
+    /// 年与月须同时给出，均省略时为当前月份（UTC）
+    pub year: Option<i32>,
+    pub month: Option<u32>,
+    /// 当月中要列出文章的日期（`YYYY-MM-DD`，UTC），默认为当月最近一个有文章的日期
+    pub day: Option<String>,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArchiveDayOut {
+    /// `YYYY-MM-DD`（UTC）
+    pub day: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArchiveResp {
+    pub year: i32,
+    pub month: u32,
+    /// 当月公开可见的主文章总数
+    pub total: i64,
+    /// 当月每一天的文章数，没有文章的日期计为 0
+    pub days: Vec<ArchiveDayOut>,
+    /// `articles` 对应的日期；当月没有文章且未指定 `day` 时为 null
+    pub day: Option<String>,
+    pub articles: PageResp<ArticleOut>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -51,6 +534,15 @@ pub struct AdminLoginPayload {
     pub password: String,
 }
 
+/// 身份提供方回调携带的参数：成功时为 `code` 与 `state`，用户拒绝授权等情况下为 `error`
+#[derive(Debug, Deserialize)]
+pub struct OidcCallbackQuery {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct AdminLogoutPayload {
     pub token: String,
@@ -60,6 +552,105 @@ pub struct AdminLogoutPayload {
 pub struct AdminLoginResponse {
     pub token: String,
     pub expires_in: u64,
+    pub username: String,
+    /// `admin`、`editor` 或 `viewer`，前端据此隐藏无权使用的功能
+    pub role: String,
+}
+
+/// 当前登录账号
+#[derive(Debug, Serialize)]
+pub struct AdminMeOut {
+    /// `news.users` 中的账号 ID；配置文件中的管理员与 API 密钥为 null
+    pub user_id: Option<i64>,
+    pub username: String,
+    pub role: String,
+    pub scopes: Vec<String>,
+    /// 是否为配置文件中的管理员（不在 `news.users` 中，无法通过接口修改）
+    pub builtin: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserOut {
+    pub id: i64,
+    pub username: String,
+    pub role: String,
+    /// `password` 或 `oidc`（单点登录自动创建）
+    pub auth_source: String,
+    pub disabled: bool,
+    pub disabled_at: Option<String>,
+    pub last_login_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserCreatePayload {
+    pub username: String,
+    pub password: String,
+    pub role: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiKeyCreatePayload {
+    /// 用途说明，如 `ci-import`
+    pub name: String,
+    /// 默认 `viewer`
+    #[serde(default)]
+    pub role: Option<String>,
+    /// 有效天数，省略时长期有效
+    #[serde(default)]
+    pub expires_in_days: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApiKeyOut {
+    pub id: i64,
+    pub name: String,
+    /// 密钥开头几位，用于辨认，完整密钥只在创建时返回一次
+    pub key_prefix: String,
+    pub role: String,
+    pub created_by: String,
+    pub created_at: String,
+    pub last_used_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub revoked_at: Option<String>,
+    /// 未吊销且未过期
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApiKeyCreatedOut {
+    /// 完整密钥，仅此一次返回，服务端只保存摘要
+    pub key: String,
+    #[serde(flatten)]
+    pub api_key: ApiKeyOut,
+}
+
+/// 只修改给出的字段
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct UserUpdatePayload {
+    pub role: Option<String>,
+    pub password: Option<String>,
+    pub disabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdminSessionOut {
+    pub id: i64,
+    pub username: String,
+    pub client_ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub created_at: String,
+    pub last_seen_at: String,
+    pub expires_at: String,
+    /// 是否为发起本次请求的会话
+    pub current: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdminSessionRevokeOut {
+    pub revoked: u64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -72,11 +663,37 @@ pub struct FeedUpsertPayload {
     pub title: Option<String>,
     pub site_url: Option<String>,
     pub filter_condition: Option<String>,
+    pub schedule_cron: Option<String>,
+    pub ignore_conditional: Option<bool>,
+    /// 发布时间按此时区（UTC 或 +08:00 形式）解释；未提交保持原值，空字符串清除
+    pub date_timezone: Option<String>,
+    /// 优先尝试的 chrono 日期格式，如 `%Y-%m-%d %H:%M:%S`；未提交保持原值，空字符串清除
+    pub date_format: Option<String>,
+    /// 源的固定语种，如 `zh`、`en-US`（保存为 `en`）；未提交保持原值，空字符串清除
+    pub language_hint: Option<String>,
+    /// 新文章需审核后才公开；未提交保持原值，新建时默认关闭
+    pub require_review: Option<bool>,
+    /// 抓取时附加的请求头（如 `{"Authorization": "Bearer ...", "User-Agent": "..."}`），整体替换；
+    /// 未提交保持原值，空对象清除；回传掩码后的值表示保留原值
+    pub request_headers: Option<std::collections::BTreeMap<String, String>>,
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FeedClonePayload {
+    pub url: String,
+    pub title: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct FeedTestPayload {
     pub url: String,
+    /// 预览发布时间解析覆盖的效果，与 `FeedUpsertPayload` 中同名字段含义一致
+    pub date_timezone: Option<String>,
+    pub date_format: Option<String>,
+    /// 测试请求附加的请求头；提交 `feed_id` 时回传的掩码值按该订阅源已保存的值还原
+    pub request_headers: Option<std::collections::BTreeMap<String, String>>,
+    pub feed_id: Option<i64>,
 }
 
 #[derive(Debug, Serialize)]
@@ -85,12 +702,140 @@ pub struct FeedTestResult {
     pub title: Option<String>,
     pub site_url: Option<String>,
     pub entry_count: usize,
+    /// 前若干条目默认解析与按覆盖解析得到的发布时间
+    pub date_preview: Vec<FeedDatePreviewOut>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FeedDiscoverPayload {
+    /// 网站地址，可省略协议（默认 https）
+    pub url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeedDiscoverOut {
+    /// 跟随跳转后的页面地址
+    pub page_url: String,
+    pub candidates: Vec<DiscoveredFeedOut>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiscoveredFeedOut {
+    pub url: String,
+    /// 页面 `<link>` 上声明的标题
+    pub declared_title: Option<String>,
+    /// 订阅源自身的标题，无法解析时为 null
+    pub title: Option<String>,
+    pub entry_count: Option<usize>,
+    /// 验证失败的原因，可正常解析时为 null
+    pub error: Option<String>,
+    /// 已订阅时为对应的订阅源 ID
+    pub existing_feed_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeedDatePreviewOut {
+    pub title: Option<String>,
+    /// 默认解析结果，无法解析时为 null（入库时按抓取时间）
+    pub published_at_default: Option<String>,
+    /// 应用覆盖后的结果
+    pub published_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BookmarkImportPayload {
+    /// 浏览器导出的书签 HTML 全文
+    pub html: String,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BookmarkFeedOut {
+    pub bookmark_url: String,
+    pub bookmark_title: Option<String>,
+    pub feed_url: String,
+    pub feed_id: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BookmarkMissOut {
+    pub bookmark_url: String,
+    pub bookmark_title: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BookmarkImportOut {
+    pub total: usize,
+    pub created: Vec<BookmarkFeedOut>,
+    /// 发现的订阅源已存在（含同一次导入中重复发现的）
+    pub existing: Vec<BookmarkFeedOut>,
+    pub no_feed: Vec<BookmarkMissOut>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceImportPayload {
+    /// 来源清单全文，每行 `<来源> [标题]`；来源可为 `github:<owner>/<repo>`、GitHub 仓库地址、
+    /// X 列表地址、订阅源或网站地址，`#` 开头的行为注释
+    pub list: String,
+    pub enabled: Option<bool>,
+    /// 为本次新建的订阅源统一设置的标签
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SourceFeedOut {
+    pub source: String,
+    /// github_releases | x_list | url
+    pub kind: String,
+    pub feed_url: String,
+    pub feed_id: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SourceMissOut {
+    pub source: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SourceImportOut {
+    pub total: usize,
+    pub created: Vec<SourceFeedOut>,
+    /// 映射出的订阅源已存在（含同一次导入中重复的）
+    pub existing: Vec<SourceFeedOut>,
+    pub failed: Vec<SourceMissOut>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FilterSuggestionOut {
+    /// url | title
+    pub field: String,
+    pub pattern: String,
+    pub reason: Option<String>,
+    /// 可直接写入 `filter_condition` 的 SQL 片段
+    pub condition: String,
+    /// 采样条目中命中该规则（将被排除）的数量
+    pub matched: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FilterSuggestionsOut {
+    pub feed_id: i64,
+    pub provider: String,
+    pub sample_size: usize,
+    pub current_condition: Option<String>,
+    pub suggestions: Vec<FilterSuggestionOut>,
+    /// 现有条件与全部建议合并后的条件，一键应用时作为 `filter_condition` 提交
+    pub combined_condition: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct TranslationSettingsOut {
     pub provider: String,
+    /// 是否有任一语言启用翻译，由 `translation_rules` 推导
     pub translation_enabled: bool,
+    pub translation_rules: Vec<crate::util::translation_rules::TranslationRule>,
     pub deepseek_configured: bool,
     pub ollama_configured: bool,
     pub deepseek_api_key_masked: Option<String>,
@@ -118,8 +863,12 @@ pub struct ModelSettingsUpdate {
 pub struct TranslationSettingsUpdate {
     #[serde(default)]
     pub provider: Option<String>,
+    /// 兼容旧开关：开启时沿用已有规则（没有则为 en→zh），关闭时清空规则
     #[serde(default)]
     pub translation_enabled: Option<bool>,
+    /// 按语种的翻译规则，见 `util::translation_rules`
+    #[serde(default)]
+    pub translation_rules: Option<serde_json::Value>,
     #[serde(default)]
     pub deepseek_api_key: Option<String>,
     #[serde(default)]
@@ -144,6 +893,207 @@ pub struct AiDedupSettingsUpdate {
     pub provider: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct SettingValueOut {
+    pub key: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// 敏感项返回掩码字符串；未设置且无默认值时为 null
+    pub value: serde_json::Value,
+    pub default: serde_json::Value,
+    pub is_default: bool,
+    pub secret: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SettingUpdate {
+    #[serde(default)]
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SettingEntry {
+    pub key: String,
+    /// 导出时敏感项为 None（仅给出 `masked`）；导入时为 None 的项会被跳过
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub masked: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SettingsExport {
+    pub exported_at: String,
+    pub settings: Vec<SettingEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SettingsImport {
+    pub settings: Vec<SettingEntry>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SettingsImportResult {
+    pub imported: Vec<String>,
+    pub skipped: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublicSourceOut {
+    pub source_domain: String,
+    /// 该来源订阅源的标题
+    pub title: Option<String>,
+    pub site_url: Option<String>,
+    /// 站点根目录的 `/favicon.ico`，由前端加载，失败时自行降级
+    pub favicon_url: Option<String>,
+    pub article_count: i64,
+    pub last_article_at: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SourceWeightOut {
+    pub source_domain: String,
+    pub weight: f32,
+    pub daily_cap: Option<i32>,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DigestOut {
+    pub id: i64,
+    /// 所属自然周的周一（UTC），`YYYY-MM-DD`
+    pub week_start: String,
+    pub period_start: String,
+    pub period_end: String,
+    pub title: String,
+    pub created_at: String,
+    pub emailed_at: Option<String>,
+    /// 邮件发送次数（含失败），失败 3 次后不再自动重试
+    pub email_attempts: i32,
+    pub items: Vec<DigestItemOut>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DigestItemOut {
+    pub rank: i32,
+    /// 文章已被删除时为 null，标题与链接仍为生成时的快照
+    pub article_id: Option<i64>,
+    pub title: String,
+    pub url: String,
+    pub source_domain: String,
+    pub published_at: String,
+    pub click_count: i64,
+    pub cluster_size: i32,
+    pub score: f32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct DigestGeneratePayload {
+    /// 任意一天（`YYYY-MM-DD`），按其所在自然周生成；缺省为上一周
+    pub week_start: Option<String>,
+    /// 生成后立即发送邮件（需配置 `digest.smtp`）
+    pub email: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignedUrlPayload {
+    /// 要分享的接口：`/articles/feed.xml`、`/articles/feed.rss`、`/digest.rss` 或 `/alerts/export`
+    pub path: String,
+    /// 附带的查询参数（如 `feed_tag=tech&limit=20`），一并纳入签名
+    pub query: Option<String>,
+    /// 有效期（秒），缺省 7 天
+    pub ttl_secs: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SignedUrlOut {
+    pub url: String,
+    pub expires_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BackupOut {
+    pub id: i64,
+    /// `manual`（后台触发）或 `schedule`（定时）
+    pub trigger: String,
+    /// `command`（外部命令）或 `copy`（COPY 导出 CSV）
+    pub mode: String,
+    /// `running` | `succeeded` | `failed`
+    pub status: String,
+    /// 备份文件或目录路径；外部命令可能把备份写到别处（如 S3）
+    pub location: String,
+    /// 本地备份的大小，文件不存在时为 null
+    pub size_bytes: Option<i64>,
+    pub error: Option<String>,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SourcePreferenceOut {
+    pub id: i64,
+    pub preferred_domain: String,
+    /// 被压过的来源，`*` 表示任意来源
+    pub over_domain: String,
+    pub note: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourcePreferenceUpdate {
+    pub preferred_domain: String,
+    pub over_domain: String,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceWeightUpdate {
+    pub source_domain: String,
+    pub weight: f32,
+    /// 每日（UTC）公开入库上限，缺省或 null 表示不限制
+    #[serde(default)]
+    pub daily_cap: Option<i32>,
+}
+
+impl Default for SearchQuery {
+    fn default() -> Self {
+        Self {
+            q: None,
+            page: 1,
+            page_size: 20,
+            source_domain: None,
+            tag: None,
+            day: None,
+        }
+    }
+}
+
+impl Default for ClusterListQuery {
+    fn default() -> Self {
+        Self {
+            hours: None,
+            min_size: None,
+            page: 1,
+            page_size: 20,
+        }
+    }
+}
+
+impl Default for ArchiveQuery {
+    fn default() -> Self {
+        Self {
+            year: None,
+            month: None,
+            day: None,
+            page: 1,
+            page_size: 20,
+        }
+    }
+}
+
 impl Default for ArticleListQuery {
     fn default() -> Self {
         Self {
@@ -152,6 +1102,251 @@ impl Default for ArticleListQuery {
             page: 1,
             page_size: 20,
             keyword: None,
+            original_language: None,
+            feed_tag: None,
+            source_domain: None,
+            q: None,
+            count: None,
+            after_published_at: None,
+            after_id: None,
+            sort: None,
+            order: None,
+            read: None,
+            bookmarked: None,
         }
     }
 }
+
+/// Atom / RSS 输出的筛选条件，含义与文章列表相同；按发布时间倒序取最新的 `limit` 篇
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ArticleFeedQuery {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub keyword: Option<String>,
+    pub original_language: Option<String>,
+    pub feed_tag: Option<String>,
+    /// 与 `feed_tag` 相同，沿用搜索接口的参数名
+    pub tag: Option<String>,
+    pub source_domain: Option<String>,
+    pub q: Option<String>,
+    /// 默认 30，最多 50
+    pub limit: Option<u32>,
+}
+
+/// 前台首页布局：按顺序渲染的分区列表，保存在设置项 `frontend.layout` 中
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrontendLayout {
+    pub sections: Vec<LayoutSection>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutSection {
+    pub key: String,
+    pub title: String,
+    /// latest | featured | tag | source
+    pub kind: String,
+    /// kind 为 tag 时是标签，为 source 时是来源域名
+    #[serde(default)]
+    pub value: Option<String>,
+    pub limit: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrontendConfigOut {
+    #[serde(flatten)]
+    pub public: crate::config::FrontendPublicConfig,
+    pub layout: FrontendLayout,
+    pub features: FrontendFeatures,
+    /// 已配置单点登录时后台登录页显示对应入口
+    pub admin_oidc: bool,
+}
+
+/// 前台功能开关，由设置项下发，切换后无需重新部署前端
+#[derive(Debug, Serialize)]
+pub struct FrontendFeatures {
+    /// 是否展示语义搜索入口
+    pub semantic_search: bool,
+    /// 是否展示标签筛选
+    pub tags: bool,
+    /// 是否展示每周精选入口
+    pub digest: bool,
+    /// 是否展示文章的按需翻译按钮
+    pub translate: bool,
+    /// 列表与搜索的默认每页条数
+    pub default_page_size: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NormalizePreviewPayload {
+    pub title: String,
+    pub summary: Option<String>,
+    pub url: Option<String>,
+    /// RFC 3339，缺省按当前时间计算内容指纹
+    pub published_at: Option<String>,
+    /// 可选的对比标题，返回与之的相似度判定
+    pub compare_title: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NormalizePreviewOut {
+    pub title: TitleStepsOut,
+    pub summary: Option<SummaryStepsOut>,
+    pub url: Option<UrlStepsOut>,
+    pub content_fingerprint: Option<String>,
+    pub comparison: Option<TitleComparisonOut>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TitleStepsOut {
+    pub raw: String,
+    pub entity_decoded: String,
+    pub normalized: String,
+    pub tokens: Vec<String>,
+    pub detected_language: Option<String>,
+    pub needs_translation: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SummaryStepsOut {
+    pub raw: String,
+    pub html_stripped: String,
+    pub entity_decoded: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UrlStepsOut {
+    pub raw: String,
+    pub normalized: Option<String>,
+    pub error: Option<String>,
+    pub source_domain: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TitleComparisonOut {
+    pub normalized: String,
+    pub tokens: Vec<String>,
+    pub matched_tokens: Vec<String>,
+    pub jaccard: f32,
+    pub identical_normalized: bool,
+    /// `strict_duplicate`（直接判重）、`llm_check`（开启 AI 去重时交给模型判定）或 `distinct`
+    pub verdict: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatsSummaryOut {
+    pub total_articles: i64,
+    /// 今日（UTC）入库的文章数
+    pub articles_today: i64,
+    pub active_sources: i64,
+    pub last_updated_at: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FilteredArticleOut {
+    /// 隔离记录 ID，恢复时使用
+    pub id: i64,
+    pub article_id: i64,
+    pub feed_id: i64,
+    pub title: String,
+    pub url: String,
+    pub condition: String,
+    pub filtered_at: String,
+    pub expires_at: String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FilteredRestorePayload {
+    /// 缺省时恢复该 Feed 全部未过期的隔离记录
+    pub ids: Option<Vec<i64>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FilteredRestoreOut {
+    pub restored: u64,
+    /// URL 已重新入库等原因未能恢复的记录数（记录同样被移除）
+    pub skipped: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LanguageBackfillOut {
+    pub scanned: u64,
+    pub updated: u64,
+    /// 标题与摘要都无法判定语言的文章数
+    pub undetected: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RetentionArchiveOut {
+    /// 当前保留天数，0 表示未开启
+    pub retention_days: i64,
+    /// 保留窗口起点，早于它发布的文章被移入冷表；未开启时为 null
+    pub cutoff: Option<String>,
+    pub archived_articles: u64,
+    pub deleted_article_sources: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RankingRecomputeOut {
+    pub window_hours: i64,
+    /// 活跃窗口起点，早于它发布的文章分数清零
+    pub since: String,
+    pub scored_articles: i64,
+    /// 分数有变化的篇数
+    pub updated_articles: i64,
+    pub reset_articles: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DedupAnalyticsQuery {
+    /// 统计最近多少天（UTC，含今天），默认 30
+    pub days: Option<i64>,
+    /// 返回的来源组合数量，默认 10
+    pub top: Option<i64>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct DedupMethodCounts {
+    /// 同一批次内标题或指纹重复
+    pub intra_batch: i64,
+    /// 内容指纹与已有文章一致
+    pub fingerprint: i64,
+    /// 标题 SimHash 与历史文章的汉明距离在阈值内
+    pub simhash: i64,
+    /// 与近期文章标题 Jaccard 相似度超过严格阈值
+    pub jaccard: i64,
+    /// 与向量索引中的近邻文章 embedding 相似度超过严格阈值
+    pub embedding: i64,
+    /// 大模型判定重复
+    pub llm: i64,
+    /// 同一 Feed 下 URL 已存在
+    pub url: i64,
+    /// 新来源权重更高，旧文章被归并到新文章
+    pub superseded: i64,
+    /// 管理员手动归并
+    pub manual: i64,
+    pub total: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DedupDayOut {
+    pub day: String,
+    #[serde(flatten)]
+    pub counts: DedupMethodCounts,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DedupSourcePairOut {
+    pub duplicate_source: String,
+    pub canonical_source: String,
+    pub count: i64,
+    pub llm_count: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DedupAnalyticsOut {
+    pub days: i64,
+    pub totals: DedupMethodCounts,
+    pub daily: Vec<DedupDayOut>,
+    pub top_pairs: Vec<DedupSourcePairOut>,
+}
@@ -0,0 +1,212 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
+
+use sqlx::PgPool;
+use tokio::sync::mpsc;
+use tracing::{
+    field::{Field, Visit},
+    Event, Level, Subscriber,
+};
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::{
+    config::DbConfig,
+    ops::event_catalog::{Event as OpsEvent, EventCode, EventLevel},
+};
+
+// sqlx 记录语句日志使用的 target
+const SQLX_QUERY_TARGET: &str = "sqlx::query";
+// 同一类事件的最小上报间隔，避免数据库持续吃紧时刷屏
+const EVENT_COOLDOWN: Duration = Duration::from_secs(60);
+const MAX_SQL_LEN: usize = 500;
+
+static SLOW_QUERY_TX: OnceLock<mpsc::Sender<SlowQuery>> = OnceLock::new();
+
+#[derive(Debug)]
+struct SlowQuery {
+    sql: String,
+    elapsed_secs: f64,
+}
+
+/// 捕获 sqlx 的慢语句日志（需在连接参数中开启 `log_slow_statements`），
+/// 转交给 `spawn` 启动的后台任务上报事件。
+pub struct SlowQueryLayer;
+
+impl<S: Subscriber> Layer<S> for SlowQueryLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let meta = event.metadata();
+        if meta.target() != SQLX_QUERY_TARGET || *meta.level() != Level::WARN {
+            return;
+        }
+        let Some(tx) = SLOW_QUERY_TX.get() else {
+            return;
+        };
+        let mut visitor = SlowQueryVisitor::default();
+        event.record(&mut visitor);
+        let Some(elapsed_secs) = visitor.elapsed_secs else {
+            return;
+        };
+        let sql = visitor
+            .statement
+            .filter(|s| !s.trim().is_empty())
+            .or(visitor.summary)
+            .unwrap_or_default();
+        // 队列满时直接丢弃，不能阻塞业务查询
+        let _ = tx.try_send(SlowQuery { sql, elapsed_secs });
+    }
+}
+
+#[derive(Default)]
+struct SlowQueryVisitor {
+    summary: Option<String>,
+    statement: Option<String>,
+    elapsed_secs: Option<f64>,
+}
+
+impl Visit for SlowQueryVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "summary" => self.summary = Some(value.to_string()),
+            "db.statement" => self.statement = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if field.name() == "elapsed_secs" {
+            self.elapsed_secs = Some(value);
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn fmt::Debug) {}
+}
+
+/// 启动慢查询上报与连接池探测两个后台任务。
+pub fn spawn(pool: PgPool, config: &DbConfig) {
+    let (tx, rx) = mpsc::channel(64);
+    if SLOW_QUERY_TX.set(tx).is_ok() {
+        tokio::spawn(report_slow_queries(pool.clone(), rx));
+    }
+    let threshold = Duration::from_millis(config.acquire_warn_ms.max(1));
+    let interval = Duration::from_secs(config.pool_probe_secs.max(1));
+    tokio::spawn(probe_pool(pool, config.max_connections, threshold, interval));
+}
+
+async fn report_slow_queries(pool: PgPool, mut rx: mpsc::Receiver<SlowQuery>) {
+    let mut last_reported: Option<Instant> = None;
+    while let Some(query) = rx.recv().await {
+        let sql = redact_sql(&query.sql);
+        tracing::warn!(elapsed_secs = query.elapsed_secs, sql = %sql, "slow database query");
+        if last_reported.is_some_and(|at| at.elapsed() < EVENT_COOLDOWN) {
+            continue;
+        }
+        last_reported = Some(Instant::now());
+        OpsEvent::new(EventCode::DbSlowQuery)
+            .attr("elapsed_secs", query.elapsed_secs)
+            .attr("sql", sql.as_str())
+            .detail(format!("{:.2}s｜{}", query.elapsed_secs, sql))
+            .emit(&pool)
+            .await;
+    }
+}
+
+// 定期实际获取一次连接并计时：等待过久或超时说明连接池已被占满
+async fn probe_pool(pool: PgPool, max_connections: u32, threshold: Duration, interval: Duration) {
+    let mut last_reported: HashMap<&'static str, Instant> = HashMap::new();
+    loop {
+        tokio::time::sleep(interval).await;
+        let started = Instant::now();
+        let result = pool.acquire().await;
+        let waited = started.elapsed();
+        let (level, detail) = match result {
+            Ok(conn) => {
+                drop(conn);
+                if waited < threshold {
+                    continue;
+                }
+                (EventLevel::Warn, format!("acquire waited {}ms", waited.as_millis()))
+            }
+            Err(err) => (
+                EventLevel::Error,
+                format!("acquire failed after {}ms: {err}", waited.as_millis()),
+            ),
+        };
+        let size = pool.size();
+        let idle = pool.num_idle();
+        tracing::warn!(
+            waited_ms = waited.as_millis() as u64,
+            size,
+            idle,
+            max_connections,
+            detail = %detail,
+            "database pool under pressure"
+        );
+        if last_reported
+            .get(level.as_str())
+            .is_some_and(|at| at.elapsed() < EVENT_COOLDOWN)
+        {
+            continue;
+        }
+        last_reported.insert(level.as_str(), Instant::now());
+        OpsEvent::new(EventCode::DbPoolPressure)
+            .level(level)
+            .attr("waited_ms", waited.as_millis() as u64)
+            .attr("size", size)
+            .attr("idle", idle)
+            .attr("max_connections", max_connections)
+            .detail(format!("{detail}｜size={size} idle={idle} max={max_connections}"))
+            .emit(&pool)
+            .await;
+    }
+}
+
+/// 去掉 SQL 中的字符串与数字字面量并压缩空白，避免把内容写进日志和事件。
+fn redact_sql(sql: &str) -> String {
+    let mut out = String::with_capacity(sql.len().min(MAX_SQL_LEN));
+    let mut chars = sql.chars().peekable();
+    let mut prev_word = false;
+    let mut pending_space = false;
+    while let Some(ch) = chars.next() {
+        if ch.is_whitespace() {
+            pending_space = !out.is_empty();
+            continue;
+        }
+        if pending_space {
+            out.push(' ');
+            pending_space = false;
+        }
+        if ch == '\'' {
+            // 跳过字符串字面量（'' 为转义的单引号）
+            while let Some(c) = chars.next() {
+                if c == '\'' {
+                    if chars.peek() == Some(&'\'') {
+                        chars.next();
+                        continue;
+                    }
+                    break;
+                }
+            }
+            out.push_str("'?'");
+            prev_word = false;
+        } else if ch.is_ascii_digit() && !prev_word {
+            while chars.peek().is_some_and(|c| c.is_ascii_digit() || *c == '.') {
+                chars.next();
+            }
+            out.push('?');
+            prev_word = false;
+        } else {
+            out.push(ch);
+            // $1 等占位符与标识符中的数字保持原样
+            prev_word = ch.is_alphanumeric() || ch == '_' || ch == '$';
+        }
+        if out.len() >= MAX_SQL_LEN {
+            out.push('…');
+            break;
+        }
+    }
+    out
+}
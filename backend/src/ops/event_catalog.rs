@@ -0,0 +1,303 @@
+//! 运维事件目录：所有写入 `news.events` 的事件码、默认级别与必填结构化属性集中在此定义，
+//! 各模块通过 `Event` 构造器上报，不再各自拼装 `NewEvent`。属性写入 `attrs`（JSONB），
+//! `addition_info` 仅保留给人看的说明文字。
+
+use serde_json::{Map, Value};
+use sqlx::PgPool;
+
+use crate::repo::events::{self as repo_events, NewEvent};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl EventLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EventLevel::Info => "info",
+            EventLevel::Warn => "warn",
+            EventLevel::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventCode {
+    SystemStarted,
+    AdminLogin,
+    AdminLogout,
+    AdminSessionEvicted,
+    AdminSessionRevoked,
+    UserChanged,
+    ApiKeyCreated,
+    ApiKeyRevoked,
+    TranslationEnabled,
+    TranslationDisabled,
+    TranslationProviderSet,
+    ModelSettingsUpdated,
+    SettingsImported,
+    FeedAutoDisabled,
+    FeedReenabled,
+    FeedMuted,
+    FeedUnmuted,
+    FeedRobotsDisallowed,
+    TranslationFailed,
+    TranslationProviderFailed,
+    AiDedupFailover,
+    AiDedupRecovered,
+    EnrichmentFailed,
+    ArticleDeleted,
+    ArticlesBulkUpdated,
+    ArticlesArchived,
+    DigestPublished,
+    DigestEmailFailed,
+    DbSlowQuery,
+    DbPoolPressure,
+    BackupFailed,
+}
+
+impl EventCode {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            EventCode::SystemStarted => "SYSTEM_STARTED",
+            EventCode::AdminLogin => "ADMIN_LOGIN",
+            EventCode::AdminLogout => "ADMIN_LOGOUT",
+            EventCode::AdminSessionEvicted => "ADMIN_SESSION_EVICTED",
+            EventCode::AdminSessionRevoked => "ADMIN_SESSION_REVOKED",
+            EventCode::UserChanged => "USER_CHANGED",
+            EventCode::ApiKeyCreated => "API_KEY_CREATED",
+            EventCode::ApiKeyRevoked => "API_KEY_REVOKED",
+            EventCode::TranslationEnabled => "TRANSLATION_ENABLED",
+            EventCode::TranslationDisabled => "TRANSLATION_DISABLED",
+            EventCode::TranslationProviderSet => "TRANSLATION_PROVIDER_SET",
+            EventCode::ModelSettingsUpdated => "MODEL_SETTINGS_UPDATED",
+            EventCode::SettingsImported => "SETTINGS_IMPORTED",
+            EventCode::FeedAutoDisabled => "FEED_AUTO_DISABLED",
+            EventCode::FeedReenabled => "FEED_REENABLED",
+            EventCode::FeedMuted => "FEED_MUTED",
+            EventCode::FeedUnmuted => "FEED_UNMUTED",
+            EventCode::FeedRobotsDisallowed => "FEED_ROBOTS_DISALLOWED",
+            EventCode::TranslationFailed => "TRANSLATION_FAILED",
+            EventCode::TranslationProviderFailed => "TRANSLATION_PROVIDER_FAILED",
+            EventCode::AiDedupFailover => "AI_DEDUP_FAILOVER",
+            EventCode::AiDedupRecovered => "AI_DEDUP_RECOVERED",
+            EventCode::EnrichmentFailed => "ENRICHMENT_FAILED",
+            EventCode::ArticleDeleted => "ARTICLE_DELETED",
+            EventCode::ArticlesBulkUpdated => "ARTICLES_BULK_UPDATED",
+            EventCode::ArticlesArchived => "ARTICLES_ARCHIVED",
+            EventCode::DigestPublished => "DIGEST_PUBLISHED",
+            EventCode::DigestEmailFailed => "DIGEST_EMAIL_FAILED",
+            EventCode::DbSlowQuery => "DB_SLOW_QUERY",
+            EventCode::DbPoolPressure => "DB_POOL_PRESSURE",
+            EventCode::BackupFailed => "BACKUP_FAILED",
+        }
+    }
+
+    /// 未显式指定级别时使用；同一事件码在个别场景可通过 `Event::level` 升级
+    pub fn default_level(self) -> EventLevel {
+        match self {
+            EventCode::AdminSessionEvicted
+            | EventCode::FeedAutoDisabled
+            | EventCode::FeedRobotsDisallowed
+            | EventCode::TranslationFailed
+            | EventCode::TranslationProviderFailed
+            | EventCode::AiDedupFailover
+            | EventCode::EnrichmentFailed
+            | EventCode::DigestEmailFailed
+            | EventCode::DbSlowQuery
+            | EventCode::DbPoolPressure => EventLevel::Warn,
+            EventCode::BackupFailed => EventLevel::Error,
+            _ => EventLevel::Info,
+        }
+    }
+
+    /// 上报时必须携带的属性键，缺失时仍写入事件但记录错误日志
+    pub fn required_attrs(self) -> &'static [&'static str] {
+        match self {
+            EventCode::SystemStarted
+            | EventCode::TranslationEnabled
+            | EventCode::TranslationDisabled
+            | EventCode::ModelSettingsUpdated => &[],
+            EventCode::AdminLogin => &["client_ip"],
+            EventCode::AdminLogout => &["reason"],
+            EventCode::AdminSessionEvicted => &["evicted", "client_ip"],
+            EventCode::AdminSessionRevoked => &["revoked"],
+            EventCode::UserChanged => &["action", "username"],
+            EventCode::ApiKeyCreated => &["key_id", "name", "role"],
+            EventCode::ApiKeyRevoked => &["key_id", "name"],
+            EventCode::TranslationProviderSet => &["provider"],
+            EventCode::SettingsImported => &["imported", "skipped"],
+            EventCode::FeedAutoDisabled => &["feed_id", "source_domain", "fail_count"],
+            EventCode::FeedReenabled | EventCode::FeedUnmuted => &["feed_id", "source_domain"],
+            EventCode::FeedMuted => &["feed_id", "source_domain", "until"],
+            EventCode::FeedRobotsDisallowed => &["feed_id", "source_domain", "url"],
+            EventCode::TranslationFailed => &["article_id", "source_domain"],
+            EventCode::TranslationProviderFailed => &["suppressed"],
+            EventCode::AiDedupFailover => &["from", "to"],
+            EventCode::AiDedupRecovered => &["provider"],
+            EventCode::EnrichmentFailed => &["suppressed"],
+            EventCode::ArticleDeleted => &["article_id", "source_domain", "url", "actor"],
+            EventCode::ArticlesBulkUpdated => &["action", "matched", "affected", "actor"],
+            EventCode::ArticlesArchived => &["archived", "cutoff", "retention_days"],
+            EventCode::DigestPublished => &["digest_id", "items"],
+            EventCode::DigestEmailFailed => &["digest_id", "attempts"],
+            EventCode::DbSlowQuery => &["elapsed_secs", "sql"],
+            EventCode::DbPoolPressure => &["waited_ms", "size", "idle", "max_connections"],
+            EventCode::BackupFailed => &["backup_id", "trigger"],
+        }
+    }
+}
+
+/// 事件缺少目录约定的必填属性
+#[derive(Debug, thiserror::Error)]
+#[error("event {code} missing required attrs: {missing:?}")]
+pub struct MissingAttrs {
+    pub code: &'static str,
+    pub missing: Vec<&'static str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Event {
+    code: EventCode,
+    level: EventLevel,
+    attrs: Map<String, Value>,
+    detail: Option<String>,
+}
+
+impl Event {
+    pub fn new(code: EventCode) -> Self {
+        Self {
+            code,
+            level: code.default_level(),
+            attrs: Map::new(),
+            detail: None,
+        }
+    }
+
+    pub fn level(mut self, level: EventLevel) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn attr(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.attrs.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// 给人看的说明文字，写入 `addition_info`
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn build(self) -> Result<NewEvent, MissingAttrs> {
+        let missing: Vec<&'static str> = self
+            .code
+            .required_attrs()
+            .iter()
+            .copied()
+            .filter(|key| self.attrs.get(*key).is_none_or(Value::is_null))
+            .collect();
+        if !missing.is_empty() {
+            return Err(MissingAttrs {
+                code: self.code.as_str(),
+                missing,
+            });
+        }
+        Ok(self.into_new_event())
+    }
+
+    fn into_new_event(self) -> NewEvent {
+        NewEvent {
+            level: self.level.as_str().to_string(),
+            code: self.code.as_str().to_string(),
+            addition_info: self.detail,
+            attrs: (!self.attrs.is_empty()).then(|| Value::Object(self.attrs).to_string()),
+        }
+    }
+
+    /// 尽力写入，失败只记日志，不影响调用方主流程
+    pub async fn emit(self, pool: &PgPool) {
+        let event = match self.clone().build() {
+            Ok(event) => event,
+            Err(err) => {
+                tracing::error!(error = %err, "event emitted with incomplete attrs");
+                self.into_new_event()
+            }
+        };
+        if let Err(err) = repo_events::insert_event(pool, &event).await {
+            tracing::warn!(code = %event.code, error = ?err, "failed to record event");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn codes_and_levels_serialize_to_stored_strings() {
+        assert_eq!(EventCode::AdminLogin.as_str(), "ADMIN_LOGIN");
+        assert_eq!(EventCode::FeedAutoDisabled.as_str(), "FEED_AUTO_DISABLED");
+        assert_eq!(EventCode::BackupFailed.as_str(), "BACKUP_FAILED");
+        assert_eq!(EventLevel::Info.as_str(), "info");
+        assert_eq!(EventLevel::Warn.as_str(), "warn");
+        assert_eq!(EventLevel::Error.as_str(), "error");
+    }
+
+    #[test]
+    fn build_writes_attrs_as_json_object() {
+        let event = Event::new(EventCode::FeedAutoDisabled)
+            .attr("feed_id", 42)
+            .attr("source_domain", "example.com")
+            .attr("fail_count", 5)
+            .detail("连续失败 5 次")
+            .build()
+            .unwrap();
+        assert_eq!(event.code, "FEED_AUTO_DISABLED");
+        assert_eq!(event.level, "warn");
+        assert_eq!(event.addition_info.as_deref(), Some("连续失败 5 次"));
+        let attrs: Value = serde_json::from_str(event.attrs.as_deref().unwrap()).unwrap();
+        assert_eq!(
+            attrs,
+            json!({"feed_id": 42, "source_domain": "example.com", "fail_count": 5})
+        );
+    }
+
+    #[test]
+    fn build_without_attrs_leaves_column_empty() {
+        let event = Event::new(EventCode::SystemStarted).build().unwrap();
+        assert_eq!(event.level, "info");
+        assert!(event.attrs.is_none());
+    }
+
+    #[test]
+    fn build_reports_exactly_the_missing_attrs() {
+        let err = Event::new(EventCode::ApiKeyCreated)
+            .attr("name", "ci")
+            .attr("role", Value::Null)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.code, "API_KEY_CREATED");
+        assert_eq!(err.missing, vec!["key_id", "role"]);
+    }
+
+    #[test]
+    fn explicit_level_overrides_default() {
+        assert_eq!(EventCode::DbPoolPressure.default_level(), EventLevel::Warn);
+        let event = Event::new(EventCode::DbPoolPressure)
+            .level(EventLevel::Error)
+            .attr("waited_ms", 5000)
+            .attr("size", 10)
+            .attr("idle", 0)
+            .attr("max_connections", 10)
+            .build()
+            .unwrap();
+        assert_eq!(event.level, "error");
+    }
+}
@@ -1,36 +1,114 @@
 use std::time::Duration;
 
 use axum::response::sse::{Event as SseEvent, KeepAlive, Sse};
+use chrono::{DateTime, Utc};
 use futures::{Stream, StreamExt};
+use serde::Serialize;
 use tokio::sync::broadcast;
 
 use crate::repo::events as repo_events;
 
+/// 抓取进度：只推送给在线的管理端，不落库
+#[derive(Debug, Clone, Serialize)]
+pub struct FetcherActivity {
+    pub ts: DateTime<Utc>,
+    pub feed_id: i64,
+    pub source_domain: String,
+    #[serde(flatten)]
+    pub kind: FetcherActivityKind,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FetcherActivityKind {
+    Started,
+    /// `entries` 为解析出的条目数，未修改（304）时均为 0
+    Finished {
+        entries: usize,
+        inserted: usize,
+        duplicates: usize,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+/// 抓取进度推送的详细程度，对应设置 `fetcher.activity_verbosity`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityVerbosity {
+    Off,
+    /// 仅失败
+    Failures,
+    /// 完成与失败
+    Results,
+    /// 另含开始抓取
+    All,
+}
+
+impl ActivityVerbosity {
+    pub const CHOICES: &'static [&'static str] = &["off", "failures", "results", "all"];
+    pub const DEFAULT: &'static str = "results";
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "off" => Some(Self::Off),
+            "failures" => Some(Self::Failures),
+            "results" => Some(Self::Results),
+            "all" => Some(Self::All),
+            _ => None,
+        }
+    }
+
+    pub fn allows(self, kind: &FetcherActivityKind) -> bool {
+        match kind {
+            FetcherActivityKind::Started => self == Self::All,
+            FetcherActivityKind::Finished { .. } => matches!(self, Self::Results | Self::All),
+            FetcherActivityKind::Failed { .. } => self != Self::Off,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct EventsHub {
     sender: broadcast::Sender<repo_events::EventRecord>,
+    activity: broadcast::Sender<FetcherActivity>,
 }
 
 impl EventsHub {
     pub fn new(buffer: usize) -> Self {
         let (tx, _rx) = broadcast::channel(buffer);
-        Self { sender: tx }
+        let (activity, _rx) = broadcast::channel(buffer);
+        Self {
+            sender: tx,
+            activity,
+        }
+    }
+
+    /// 有管理端在线订阅时才值得读取设置并组装进度
+    pub fn has_subscribers(&self) -> bool {
+        self.activity.receiver_count() > 0
     }
 
+    pub fn publish_activity(&self, activity: FetcherActivity) {
+        // 没有订阅者时发送失败，直接丢弃
+        let _ = self.activity.send(activity);
+    }
+
+    /// 告警（`alert`）与抓取进度（`fetcher`）合并为一条 SSE 流；订阅端落后过多时丢弃积压的消息
     pub fn stream(&self) -> impl Stream<Item = Result<SseEvent, std::convert::Infallible>> {
-        let rx = self.sender.subscribe();
-        tokio_stream::wrappers::BroadcastStream::new(rx).filter_map(|item| async move {
-            match item {
-                Ok(ev) => {
-                    let json = serde_json::to_string(&ev).unwrap_or_else(|_| "{}".to_string());
-                    Some(Ok(SseEvent::default().event("alert").data(json)))
-                }
-                Err(_e) => None,
-            }
-        })
+        let alerts = tokio_stream::wrappers::BroadcastStream::new(self.sender.subscribe())
+            .filter_map(|item| async move { item.ok().map(|ev| sse_json("alert", &ev)) });
+        let activity = tokio_stream::wrappers::BroadcastStream::new(self.activity.subscribe())
+            .filter_map(|item| async move { item.ok().map(|ev| sse_json("fetcher", &ev)) });
+        futures::stream::select(alerts, activity)
     }
 }
 
+fn sse_json<T: Serialize>(event: &str, value: &T) -> Result<SseEvent, std::convert::Infallible> {
+    let json = serde_json::to_string(value).unwrap_or_else(|_| "{}".to_string());
+    Ok(SseEvent::default().event(event).data(json))
+}
+
 
 pub fn sse_response(hub: &EventsHub) -> Sse<impl Stream<Item = Result<SseEvent, std::convert::Infallible>>> {
     Sse::new(hub.stream()).keep_alive(KeepAlive::new().interval(Duration::from_secs(20)))
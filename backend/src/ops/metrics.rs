@@ -0,0 +1,192 @@
+//! Prometheus 文本格式的运维指标：把 warn/error 事件、持续失败的 feed 与数据库状态导出为 gauge，
+//! 现有 Alertmanager 可直接按 `newsagg_alert` 配置告警，无需额外的 webhook 转接。
+
+use std::fmt::Write;
+
+use sqlx::PgPool;
+
+use crate::{
+    ops::event_catalog::EventCode,
+    repo::{self, backups::BackupStatusRow, events::EventCountRow, feeds::FailingFeedRow},
+};
+
+// 事件上报普遍限频到每 30 分钟一次，窗口需长于该间隔，否则故障持续期间告警会反复恢复
+pub const ALERT_WINDOW_SECS: i64 = 3600;
+// 连续失败达到该次数的 feed 视为不可用
+pub const FEED_DOWN_FAIL_COUNT: i32 = 3;
+
+/// 告警名与触发它的事件类型；窗口内出现任一事件即为 1
+const EVENT_ALERTS: &[(&str, &[EventCode])] = &[
+    ("translation_provider_down", &[EventCode::TranslationProviderFailed]),
+    ("db_degraded", &[EventCode::DbPoolPressure]),
+    ("enrichment_down", &[EventCode::EnrichmentFailed]),
+    ("digest_email_failed", &[EventCode::DigestEmailFailed]),
+    ("feed_auto_disabled", &[EventCode::FeedAutoDisabled]),
+    ("backup_failed", &[EventCode::BackupFailed]),
+];
+
+pub async fn render(pool: &PgPool) -> String {
+    // 数据库不可用时仍返回其余指标，由 newsagg_db_up 与 db_degraded 告警体现
+    let events = repo::events::count_recent(pool, ALERT_WINDOW_SECS).await;
+    let feeds = repo::feeds::list_failing_feeds(pool, FEED_DOWN_FAIL_COUNT).await;
+    let backups = repo::backups::status(pool).await;
+    if let Some(err) = events
+        .as_ref()
+        .err()
+        .or(feeds.as_ref().err())
+        .or(backups.as_ref().err())
+    {
+        tracing::warn!(error = ?err, "failed to collect metrics");
+    }
+    let db_up = events.is_ok() && feeds.is_ok() && backups.is_ok();
+    let events = events.unwrap_or_default();
+    let feeds = feeds.unwrap_or_default();
+
+    let mut out = String::new();
+    gauge_header(&mut out, "newsagg_db_up", "Whether the metrics queries against PostgreSQL succeeded.");
+    sample(&mut out, "newsagg_db_up", &[], db_up as i64);
+    gauge_header(&mut out, "newsagg_db_pool_connections", "Connections held by the pool.");
+    sample(&mut out, "newsagg_db_pool_connections", &[("state", "idle")], pool.num_idle() as i64);
+    sample(&mut out, "newsagg_db_pool_connections", &[("state", "total")], pool.size() as i64);
+
+    write_events(&mut out, &events);
+    write_feeds(&mut out, &feeds);
+    if let Ok(backups) = &backups {
+        write_backups(&mut out, backups);
+    }
+
+    gauge_header(
+        &mut out,
+        "newsagg_alert",
+        "1 while the condition is active; event-based alerts stay active for the whole window.",
+    );
+    sample(&mut out, "newsagg_alert", &[("alert", "feed_down")], !feeds.is_empty() as i64);
+    for (alert, codes) in EVENT_ALERTS {
+        let mut active = events
+            .iter()
+            .any(|row| codes.iter().any(|code| code.as_str() == row.code));
+        if *alert == "db_degraded" {
+            active |= !db_up;
+        }
+        sample(&mut out, "newsagg_alert", &[("alert", alert)], active as i64);
+    }
+    let error_events = events.iter().any(|row| row.level == "error");
+    sample(&mut out, "newsagg_alert", &[("alert", "error_events")], error_events as i64);
+    out
+}
+
+fn write_events(out: &mut String, events: &[EventCountRow]) {
+    gauge_header(
+        out,
+        "newsagg_events_recent",
+        "warn/error events recorded within the alert window.",
+    );
+    let window = ALERT_WINDOW_SECS.to_string();
+    for row in events {
+        sample(
+            out,
+            "newsagg_events_recent",
+            &[("level", &row.level), ("code", &row.code), ("window_secs", &window)],
+            row.count,
+        );
+    }
+    gauge_header(
+        out,
+        "newsagg_event_last_timestamp_seconds",
+        "Unix time of the latest warn/error event of each code within the alert window.",
+    );
+    for row in events {
+        sample(
+            out,
+            "newsagg_event_last_timestamp_seconds",
+            &[("level", &row.level), ("code", &row.code)],
+            row.last_ts.timestamp(),
+        );
+    }
+}
+
+fn write_feeds(out: &mut String, feeds: &[FailingFeedRow]) {
+    gauge_header(out, "newsagg_feeds_down", "Enabled, unmuted feeds failing repeatedly.");
+    sample(out, "newsagg_feeds_down", &[], feeds.len() as i64);
+    gauge_header(out, "newsagg_feed_fail_count", "Consecutive fetch failures of feeds that are down.");
+    for feed in feeds {
+        let id = feed.id.to_string();
+        let status = feed
+            .last_fetch_status
+            .map(|status| status.to_string())
+            .unwrap_or_default();
+        let error_kind = feed.last_error_kind.as_deref().unwrap_or_default();
+        sample(
+            out,
+            "newsagg_feed_fail_count",
+            &[
+                ("feed_id", &id),
+                ("source_domain", &feed.source_domain),
+                ("status", &status),
+                ("error_kind", error_kind),
+            ],
+            feed.fail_count as i64,
+        );
+    }
+    // 按失败分类汇总，便于区分源站故障（http_5xx、timeout）与配置问题（dns、tls、parse）
+    gauge_header(out, "newsagg_feeds_down_by_kind", "Feeds that are down, grouped by the kind of their last failure.");
+    let mut by_kind: std::collections::BTreeMap<&str, i64> = std::collections::BTreeMap::new();
+    for feed in feeds {
+        *by_kind
+            .entry(feed.last_error_kind.as_deref().unwrap_or("other"))
+            .or_default() += 1;
+    }
+    for (kind, count) in by_kind {
+        sample(out, "newsagg_feeds_down_by_kind", &[("error_kind", kind)], count);
+    }
+}
+
+// 从未备份过时不输出时间戳，避免按「距上次成功多久」配置的告警误把 0 当作极旧的备份
+fn write_backups(out: &mut String, status: &BackupStatusRow) {
+    gauge_header(
+        out,
+        "newsagg_backup_last_success_timestamp_seconds",
+        "Unix time when the latest successful database backup finished.",
+    );
+    if let Some(at) = status.last_success_at {
+        sample(out, "newsagg_backup_last_success_timestamp_seconds", &[], at.timestamp());
+    }
+    gauge_header(out, "newsagg_backup_last_size_bytes", "Size of the latest successful local backup.");
+    if let Some(size) = status.last_success_bytes {
+        sample(out, "newsagg_backup_last_size_bytes", &[], size);
+    }
+    gauge_header(
+        out,
+        "newsagg_backup_last_run_succeeded",
+        "1 if the most recently finished backup succeeded, 0 if it failed.",
+    );
+    if let Some(last) = status.last_status.as_deref() {
+        sample(out, "newsagg_backup_last_run_succeeded", &[], (last == "succeeded") as i64);
+    }
+}
+
+fn gauge_header(out: &mut String, name: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} gauge");
+}
+
+fn sample(out: &mut String, name: &str, labels: &[(&str, &str)], value: i64) {
+    out.push_str(name);
+    if !labels.is_empty() {
+        out.push('{');
+        for (idx, (key, raw)) in labels.iter().enumerate() {
+            if idx > 0 {
+                out.push(',');
+            }
+            let _ = write!(out, "{key}=\"{}\"", escape_label(raw));
+        }
+        out.push('}');
+    }
+    let _ = writeln!(out, " {value}");
+}
+
+fn escape_label(raw: &str) -> String {
+    raw.replace('\\', r"\\")
+        .replace('"', "\\\"")
+        .replace('\n', r"\n")
+}
@@ -1,2 +1,4 @@
+pub mod db_monitor;
+pub mod event_catalog;
 pub mod events;
-
+pub mod metrics;
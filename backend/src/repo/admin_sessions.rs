@@ -0,0 +1,181 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct AdminSessionRow {
+    pub id: i64,
+    pub username: String,
+    pub client_ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub struct NewAdminSession<'a> {
+    pub token_hash: &'a str,
+    pub username: &'a str,
+    pub client_ip: Option<&'a str>,
+    pub user_agent: Option<&'a str>,
+    pub ttl_secs: i64,
+}
+
+pub async fn insert(pool: &PgPool, session: &NewAdminSession<'_>) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO news.admin_sessions (token_hash, username, client_ip, user_agent, expires_at)
+        VALUES ($1, $2, $3, $4, NOW() + make_interval(secs => $5))
+        RETURNING id
+        "#,
+    )
+    .bind(session.token_hash)
+    .bind(session.username)
+    .bind(session.client_ip)
+    .bind(session.user_agent)
+    .bind(session.ttl_secs as f64)
+    .fetch_one(pool)
+    .await
+}
+
+/// 校验并续期：有效时顺延过期时间并返回会话 id 与所属账号
+pub async fn touch(
+    pool: &PgPool,
+    token_hash: &str,
+    ttl_secs: i64,
+) -> Result<Option<(i64, String)>, sqlx::Error> {
+    sqlx::query_as::<_, (i64, String)>(
+        r#"
+        UPDATE news.admin_sessions
+        SET last_seen_at = NOW(),
+            expires_at = NOW() + make_interval(secs => $2)
+        WHERE token_hash = $1
+          AND expires_at > NOW()
+        RETURNING id, username
+        "#,
+    )
+    .bind(token_hash)
+    .bind(ttl_secs as f64)
+    .fetch_optional(pool)
+    .await
+}
+
+/// 删除指定 token 的会话，返回是否存在
+pub async fn delete_by_hash(pool: &PgPool, token_hash: &str) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM news.admin_sessions
+        WHERE token_hash = $1
+        "#,
+    )
+    .bind(token_hash)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn delete_by_id(pool: &PgPool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM news.admin_sessions
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// 删除某账号的全部会话（停用、删除账号或重置密码时），返回删除数量
+pub async fn delete_by_username(pool: &PgPool, username: &str) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM news.admin_sessions
+        WHERE username = $1
+        "#,
+    )
+    .bind(username)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// 删除该账号除 `keep_id` 外的会话
+pub async fn delete_others(
+    pool: &PgPool,
+    username: &str,
+    keep_id: i64,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM news.admin_sessions
+        WHERE username = $1 AND id <> $2
+        "#,
+    )
+    .bind(username)
+    .bind(keep_id)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+pub async fn delete_expired(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM news.admin_sessions
+        WHERE expires_at <= NOW()
+        "#,
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// 保留该账号最新的 `keep` 个有效会话，删除更早的会话，返回被删除的数量
+pub async fn evict_oldest(pool: &PgPool, username: &str, keep: i64) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM news.admin_sessions
+        WHERE id IN (
+            SELECT id
+            FROM news.admin_sessions
+            WHERE username = $1
+              AND expires_at > NOW()
+            ORDER BY created_at DESC, id DESC
+            OFFSET $2
+        )
+        "#,
+    )
+    .bind(username)
+    .bind(keep.max(0))
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+pub async fn count_active(pool: &PgPool, username: &str) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*)
+        FROM news.admin_sessions
+        WHERE username = $1
+          AND expires_at > NOW()
+        "#,
+    )
+    .bind(username)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn list_active(pool: &PgPool) -> Result<Vec<AdminSessionRow>, sqlx::Error> {
+    sqlx::query_as::<_, AdminSessionRow>(
+        r#"
+        SELECT id, username, client_ip, user_agent, created_at, last_seen_at, expires_at
+        FROM news.admin_sessions
+        WHERE expires_at > NOW()
+        ORDER BY last_seen_at DESC
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,99 @@
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+pub const DEDUP_METHOD_INTRA_BATCH: &str = "intra_batch";
+pub const DEDUP_METHOD_URL: &str = "url";
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct DedupDailyRow {
+    pub day: NaiveDate,
+    pub method: String,
+    pub count: i64,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct DedupPairRow {
+    pub duplicate_source: String,
+    pub canonical_source: String,
+    pub count: i64,
+    pub llm_count: i64,
+}
+
+pub async fn bump_dedup_counter(pool: &PgPool, method: &str, count: i64) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO news.dedup_counters (day, method, count)
+        VALUES ((NOW() AT TIME ZONE 'UTC')::date, $1, $2)
+        ON CONFLICT (day, method) DO UPDATE SET count = news.dedup_counters.count + EXCLUDED.count
+        "#,
+    )
+    .bind(method)
+    .bind(count)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 最近 `days` 天（UTC，含今天）按天、按方式的去重数量。
+/// 有来源记录的方式取自 `article_sources.decision`，批内重复与 URL 重复取自计数表。
+pub async fn dedup_daily(pool: &PgPool, days: i32) -> Result<Vec<DedupDailyRow>, sqlx::Error> {
+    sqlx::query_as::<_, DedupDailyRow>(
+        r#"
+        WITH sourced AS (
+            SELECT (inserted_at AT TIME ZONE 'UTC')::date AS day,
+                   CASE decision
+                       WHEN 'fingerprint' THEN 'fingerprint'
+                       WHEN 'simhash' THEN 'simhash'
+                       WHEN 'recent_jaccard' THEN 'jaccard'
+                       WHEN 'recent_embedding' THEN 'embedding'
+                       WHEN 'superseded_by_weight' THEN 'superseded'
+                       WHEN 'superseded_by_preference' THEN 'superseded'
+                       WHEN 'manual_merge' THEN 'manual'
+                       ELSE 'llm'
+                   END AS method
+            FROM news.article_sources
+            WHERE decision IS NOT NULL
+              AND decision <> 'primary'
+              AND (inserted_at AT TIME ZONE 'UTC')::date > (NOW() AT TIME ZONE 'UTC')::date - $1
+        )
+        SELECT day, method, COUNT(*)::bigint AS count
+        FROM sourced
+        GROUP BY day, method
+        UNION ALL
+        SELECT day, method, count
+        FROM news.dedup_counters
+        WHERE day > (NOW() AT TIME ZONE 'UTC')::date - $1
+        ORDER BY day, method
+        "#,
+    )
+    .bind(days)
+    .fetch_all(pool)
+    .await
+}
+
+/// 重复最多的「重复来源 → 主文章来源」组合
+pub async fn dedup_top_pairs(pool: &PgPool, days: i32, limit: i64) -> Result<Vec<DedupPairRow>, sqlx::Error> {
+    sqlx::query_as::<_, DedupPairRow>(
+        r#"
+        SELECT COALESCE(f.source_domain, s.source_name, '') AS duplicate_source,
+               a.source_domain AS canonical_source,
+               COUNT(*)::bigint AS count,
+               COUNT(*) FILTER (
+                   WHERE s.decision NOT IN ('fingerprint', 'simhash', 'recent_jaccard', 'recent_embedding', 'superseded_by_weight', 'superseded_by_preference', 'manual_merge')
+               )::bigint AS llm_count
+        FROM news.article_sources s
+        JOIN news.articles a ON a.id = s.article_id
+        LEFT JOIN news.feeds f ON f.id = s.feed_id
+        WHERE s.decision IS NOT NULL
+          AND s.decision <> 'primary'
+          AND (s.inserted_at AT TIME ZONE 'UTC')::date > (NOW() AT TIME ZONE 'UTC')::date - $1
+        GROUP BY 1, 2
+        ORDER BY count DESC, duplicate_source, canonical_source
+        LIMIT $2
+        "#,
+    )
+    .bind(days)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ApiKeyRow {
+    pub id: i64,
+    pub name: String,
+    pub key_prefix: String,
+    pub role: String,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+const API_KEY_COLUMNS: &str =
+    "id, name, key_prefix, role, created_by, created_at, last_used_at, expires_at, revoked_at";
+
+pub struct NewApiKey<'a> {
+    pub name: &'a str,
+    pub key_hash: &'a str,
+    pub key_prefix: &'a str,
+    pub role: &'a str,
+    pub created_by: &'a str,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+pub async fn insert(pool: &PgPool, key: &NewApiKey<'_>) -> Result<ApiKeyRow, sqlx::Error> {
+    let sql = format!(
+        r#"
+        INSERT INTO news.api_keys (name, key_hash, key_prefix, role, created_by, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING {API_KEY_COLUMNS}
+        "#
+    );
+    sqlx::query_as::<_, ApiKeyRow>(&sql)
+        .bind(key.name)
+        .bind(key.key_hash)
+        .bind(key.key_prefix)
+        .bind(key.role)
+        .bind(key.created_by)
+        .bind(key.expires_at)
+        .fetch_one(pool)
+        .await
+}
+
+/// 全部密钥，未吊销的在前
+pub async fn list(pool: &PgPool) -> Result<Vec<ApiKeyRow>, sqlx::Error> {
+    let sql = format!(
+        r#"
+        SELECT {API_KEY_COLUMNS}
+        FROM news.api_keys
+        ORDER BY revoked_at IS NOT NULL, id DESC
+        "#
+    );
+    sqlx::query_as::<_, ApiKeyRow>(&sql).fetch_all(pool).await
+}
+
+/// 校验密钥：未吊销且未过期时记录使用时间，返回密钥 id、名称与角色
+pub async fn touch(pool: &PgPool, key_hash: &str) -> Result<Option<(i64, String, String)>, sqlx::Error> {
+    sqlx::query_as::<_, (i64, String, String)>(
+        r#"
+        UPDATE news.api_keys
+        SET last_used_at = NOW()
+        WHERE key_hash = $1
+          AND revoked_at IS NULL
+          AND (expires_at IS NULL OR expires_at > NOW())
+        RETURNING id, name, role
+        "#,
+    )
+    .bind(key_hash)
+    .fetch_optional(pool)
+    .await
+}
+
+/// 吊销密钥（保留记录便于审计），返回被吊销的密钥；不存在或已吊销时为 None
+pub async fn revoke(pool: &PgPool, id: i64) -> Result<Option<ApiKeyRow>, sqlx::Error> {
+    let sql = format!(
+        r#"
+        UPDATE news.api_keys
+        SET revoked_at = NOW()
+        WHERE id = $1
+          AND revoked_at IS NULL
+        RETURNING {API_KEY_COLUMNS}
+        "#
+    );
+    sqlx::query_as::<_, ApiKeyRow>(&sql)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
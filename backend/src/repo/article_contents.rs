@@ -0,0 +1,154 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ContentPendingRow {
+    pub id: i64,
+    pub url: String,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct PublicContentRow {
+    pub id: i64,
+    pub title: String,
+    pub url: String,
+    pub source_domain: String,
+    pub published_at: DateTime<Utc>,
+    pub content: Option<String>,
+    pub images: Option<Vec<String>>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ArticleContentRow {
+    pub content: Option<String>,
+    pub images: Vec<String>,
+    pub extracted_at: Option<DateTime<Utc>>,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub full_description: Option<String>,
+}
+
+/// 待抽取正文的文章：最近 `lookback_hours` 小时内入库的主文章，尚无正文且未用尽重试次数，按 ID 顺序
+pub async fn list_pending(
+    pool: &PgPool,
+    lookback_hours: i64,
+    max_attempts: i32,
+    limit: i64,
+) -> Result<Vec<ContentPendingRow>, sqlx::Error> {
+    sqlx::query_as::<_, ContentPendingRow>(
+        r#"
+        SELECT a.id::bigint AS id,
+               a.url
+        FROM news.articles a
+        LEFT JOIN news.article_contents c ON c.article_id = a.id
+        WHERE COALESCE(a.canonical_id, a.id) = a.id
+          AND a.fetched_at > NOW() - make_interval(hours => $1::int)
+          AND (c.article_id IS NULL OR (c.content IS NULL AND c.attempts < $2))
+        ORDER BY a.id
+        LIMIT $3
+        "#,
+    )
+    .bind(lookback_hours)
+    .bind(max_attempts)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn store(
+    pool: &PgPool,
+    article_id: i64,
+    content: &str,
+    images: &[String],
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO news.article_contents (
+            article_id, content, images, extracted_at, last_error, updated_at
+        )
+        VALUES ($1, $2, $3, NOW(), NULL, NOW())
+        ON CONFLICT (article_id) DO UPDATE
+        SET content = EXCLUDED.content,
+            images = EXCLUDED.images,
+            extracted_at = EXCLUDED.extracted_at,
+            last_error = NULL,
+            updated_at = NOW()
+        "#,
+    )
+    .bind(article_id)
+    .bind(content)
+    .bind(images)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 记录一次抽取失败，返回累计失败次数；`final_attempt` 为 true 时直接用尽重试次数
+pub async fn record_failure(
+    pool: &PgPool,
+    article_id: i64,
+    error: &str,
+    max_attempts: i32,
+    final_attempt: bool,
+) -> Result<i32, sqlx::Error> {
+    sqlx::query_scalar::<_, i32>(
+        r#"
+        INSERT INTO news.article_contents (article_id, attempts, last_error, updated_at)
+        VALUES ($1, CASE WHEN $4 THEN $3 ELSE 1 END, $2, NOW())
+        ON CONFLICT (article_id) DO UPDATE
+        SET attempts = CASE WHEN $4 THEN $3 ELSE news.article_contents.attempts + 1 END,
+            last_error = EXCLUDED.last_error,
+            updated_at = NOW()
+        RETURNING attempts
+        "#,
+    )
+    .bind(article_id)
+    .bind(error)
+    .bind(max_attempts)
+    .bind(final_attempt)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn find(
+    pool: &PgPool,
+    article_id: i64,
+) -> Result<Option<ArticleContentRow>, sqlx::Error> {
+    sqlx::query_as::<_, ArticleContentRow>(
+        r#"
+        SELECT content,
+               images,
+               extracted_at,
+               attempts,
+               last_error,
+               full_description
+        FROM news.article_contents
+        WHERE article_id = $1
+        "#,
+    )
+    .bind(article_id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// 公开可见文章及其正文；文章不存在或已隐藏时返回 None，尚未抽取时正文字段为空
+pub async fn find_public(pool: &PgPool, id: i64) -> Result<Option<PublicContentRow>, sqlx::Error> {
+    sqlx::query_as::<_, PublicContentRow>(
+        r#"
+        SELECT a.id::bigint AS id,
+               a.title,
+               a.url,
+               a.source_domain,
+               a.published_at,
+               c.content,
+               c.images
+        FROM news.articles a
+        LEFT JOIN news.article_contents c ON c.article_id = a.id
+        WHERE a.id = $1
+          AND a.hidden_at IS NULL
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+}
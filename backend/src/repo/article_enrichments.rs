@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct EnrichmentStepRow {
+    pub step: String,
+    /// JSON 文本（查询时转换为 text，sqlx 未启用 json 特性）
+    pub output: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 写入或覆盖某篇文章某个步骤的输出
+pub async fn store(
+    pool: &PgPool,
+    article_id: i64,
+    step: &str,
+    output: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO news.article_enrichments (article_id, step, output)
+        VALUES ($1, $2, $3::jsonb)
+        ON CONFLICT (article_id, step) DO UPDATE
+        SET output = EXCLUDED.output,
+            updated_at = NOW()
+        "#,
+    )
+    .bind(article_id)
+    .bind(step)
+    .bind(output)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn list_for_article(
+    pool: &PgPool,
+    article_id: i64,
+) -> Result<Vec<EnrichmentStepRow>, sqlx::Error> {
+    sqlx::query_as::<_, EnrichmentStepRow>(
+        r#"
+        SELECT step,
+               output::text AS output,
+               updated_at
+        FROM news.article_enrichments
+        WHERE article_id = $1
+        ORDER BY step
+        "#,
+    )
+    .bind(article_id)
+    .fetch_all(pool)
+    .await
+}
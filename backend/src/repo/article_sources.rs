@@ -10,6 +10,25 @@ pub struct ArticleSourceRecord {
     pub published_at: DateTime<Utc>,
     pub decision: Option<String>,
     pub confidence: Option<f32>,
+    pub jaccard: Option<f32>,
+    pub matched_tokens: Option<Vec<String>>,
+    pub llm_raw: Option<String>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ArticleSourceRow {
+    pub id: i64,
+    pub article_id: i64,
+    pub feed_id: Option<i64>,
+    pub source_name: Option<String>,
+    pub source_url: String,
+    pub published_at: Option<DateTime<Utc>>,
+    pub inserted_at: DateTime<Utc>,
+    pub decision: Option<String>,
+    pub confidence: Option<f32>,
+    pub jaccard: Option<f32>,
+    pub matched_tokens: Option<Vec<String>>,
+    pub llm_raw: Option<String>,
 }
 
 pub async fn insert_source(pool: &PgPool, record: ArticleSourceRecord) -> Result<(), sqlx::Error> {
@@ -23,10 +42,13 @@ pub async fn insert_source(pool: &PgPool, record: ArticleSourceRecord) -> Result
             published_at,
             inserted_at,
             decision,
-            confidence
+            confidence,
+            jaccard,
+            matched_tokens,
+            llm_raw
         )
         VALUES (
-            $1, $2, $3, $4, $5, NOW(), $6, $7
+            $1, $2, $3, $4, $5, NOW(), $6, $7, $8, $9, $10
         )
         ON CONFLICT (article_id, source_url) DO NOTHING
         "#,
@@ -38,12 +60,79 @@ pub async fn insert_source(pool: &PgPool, record: ArticleSourceRecord) -> Result
     .bind(record.published_at)
     .bind(record.decision)
     .bind(record.confidence)
+    .bind(record.jaccard)
+    .bind(record.matched_tokens)
+    .bind(record.llm_raw)
     .execute(pool)
     .await?;
 
     Ok(())
 }
 
+#[derive(Debug, sqlx::FromRow)]
+pub struct PublicSourceRow {
+    pub feed_id: Option<i64>,
+    pub feed_title: Option<String>,
+    pub source_name: Option<String>,
+    pub source_url: String,
+    pub published_at: Option<DateTime<Utc>>,
+    pub decision: Option<String>,
+    pub confidence: Option<f32>,
+}
+
+/// 公开接口使用的来源列表：不含相似度明细与模型原始输出，主来源在前，其余按发布时间
+pub async fn list_public_by_article(
+    pool: &PgPool,
+    article_id: i64,
+) -> Result<Vec<PublicSourceRow>, sqlx::Error> {
+    sqlx::query_as::<_, PublicSourceRow>(
+        r#"
+        SELECT s.feed_id,
+               f.title AS feed_title,
+               s.source_name,
+               s.source_url,
+               s.published_at,
+               s.decision,
+               s.confidence
+        FROM news.article_sources s
+        LEFT JOIN news.feeds f ON f.id = s.feed_id
+        WHERE s.article_id = $1
+        ORDER BY (s.decision = 'primary') DESC NULLS LAST, s.published_at NULLS LAST, s.id
+        "#,
+    )
+    .bind(article_id)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn list_by_article(
+    pool: &PgPool,
+    article_id: i64,
+) -> Result<Vec<ArticleSourceRow>, sqlx::Error> {
+    sqlx::query_as::<_, ArticleSourceRow>(
+        r#"
+        SELECT id::bigint AS id,
+               article_id::bigint AS article_id,
+               feed_id,
+               source_name,
+               source_url,
+               published_at,
+               inserted_at,
+               decision,
+               confidence,
+               jaccard,
+               matched_tokens,
+               llm_raw
+        FROM news.article_sources
+        WHERE article_id = $1
+        ORDER BY inserted_at, id
+        "#,
+    )
+    .bind(article_id)
+    .fetch_all(pool)
+    .await
+}
+
 pub async fn delete_by_feed(
     tx: &mut Transaction<'_, Postgres>,
     feed_id: i64,
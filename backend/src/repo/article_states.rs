@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ArticleStateRow {
+    pub read_at: Option<DateTime<Utc>>,
+    pub bookmarked_at: Option<DateTime<Utc>>,
+}
+
+/// 标记已读或未读；已读时间保留首次标记的时刻
+pub async fn set_read(
+    pool: &PgPool,
+    reader_id: &str,
+    article_id: i64,
+    read: bool,
+) -> Result<ArticleStateRow, sqlx::Error> {
+    upsert(
+        pool,
+        reader_id,
+        article_id,
+        r#"
+        INSERT INTO news.user_article_state (reader_id, article_id, read_at)
+        VALUES ($1, $2, CASE WHEN $3 THEN NOW() END)
+        ON CONFLICT (reader_id, article_id) DO UPDATE
+        SET read_at = CASE
+                WHEN $3 THEN COALESCE(news.user_article_state.read_at, NOW())
+            END,
+            updated_at = NOW()
+        RETURNING read_at, bookmarked_at
+        "#,
+        read,
+    )
+    .await
+}
+
+/// 加入或移出稍后阅读列表
+pub async fn set_bookmarked(
+    pool: &PgPool,
+    reader_id: &str,
+    article_id: i64,
+    bookmarked: bool,
+) -> Result<ArticleStateRow, sqlx::Error> {
+    upsert(
+        pool,
+        reader_id,
+        article_id,
+        r#"
+        INSERT INTO news.user_article_state (reader_id, article_id, bookmarked_at)
+        VALUES ($1, $2, CASE WHEN $3 THEN NOW() END)
+        ON CONFLICT (reader_id, article_id) DO UPDATE
+        SET bookmarked_at = CASE
+                WHEN $3 THEN COALESCE(news.user_article_state.bookmarked_at, NOW())
+            END,
+            updated_at = NOW()
+        RETURNING read_at, bookmarked_at
+        "#,
+        bookmarked,
+    )
+    .await
+}
+
+// 两种状态都被清除的行随即删除，表中只保留有意义的记录
+async fn upsert(
+    pool: &PgPool,
+    reader_id: &str,
+    article_id: i64,
+    sql: &str,
+    flag: bool,
+) -> Result<ArticleStateRow, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let row = sqlx::query_as::<_, ArticleStateRow>(sql)
+        .bind(reader_id)
+        .bind(article_id)
+        .bind(flag)
+        .fetch_one(tx.as_mut())
+        .await?;
+    if row.read_at.is_none() && row.bookmarked_at.is_none() {
+        sqlx::query(
+            r#"
+            DELETE FROM news.user_article_state
+            WHERE reader_id = $1 AND article_id = $2
+            "#,
+        )
+        .bind(reader_id)
+        .bind(article_id)
+        .execute(tx.as_mut())
+        .await?;
+    }
+    tx.commit().await?;
+    Ok(row)
+}
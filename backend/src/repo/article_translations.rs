@@ -0,0 +1,62 @@
+use sqlx::PgPool;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ArticleTranslationRow {
+    pub title: String,
+    pub description: Option<String>,
+    /// 翻译时原文标题与摘要的摘要值，原文被修改或重新翻译后不再匹配
+    pub source_digest: String,
+}
+
+pub async fn find(
+    pool: &PgPool,
+    article_id: i64,
+    language: &str,
+) -> Result<Option<ArticleTranslationRow>, sqlx::Error> {
+    sqlx::query_as::<_, ArticleTranslationRow>(
+        r#"
+        SELECT title, description, source_digest
+        FROM news.article_translations
+        WHERE article_id = $1 AND language = $2
+        "#,
+    )
+    .bind(article_id)
+    .bind(language)
+    .fetch_optional(pool)
+    .await
+}
+
+pub struct NewArticleTranslation<'a> {
+    pub article_id: i64,
+    pub language: &'a str,
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+    pub source_digest: &'a str,
+    pub provider: &'a str,
+}
+
+/// 写入或覆盖（原文变化后重新翻译）一条译文缓存
+pub async fn upsert(pool: &PgPool, record: &NewArticleTranslation<'_>) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO news.article_translations
+            (article_id, language, title, description, source_digest, provider)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (article_id, language) DO UPDATE
+        SET title = EXCLUDED.title,
+            description = EXCLUDED.description,
+            source_digest = EXCLUDED.source_digest,
+            provider = EXCLUDED.provider,
+            created_at = NOW()
+        "#,
+    )
+    .bind(record.article_id)
+    .bind(record.language)
+    .bind(record.title)
+    .bind(record.description)
+    .bind(record.source_digest)
+    .bind(record.provider)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
@@ -1,4 +1,6 @@
-use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::{postgres::PgQueryResult, PgPool, Postgres, Row, Transaction};
 use tracing::warn;
 
@@ -9,17 +11,70 @@ pub struct ArticleRow {
     pub url: String,
     pub description: Option<String>,
     pub language: Option<String>,
+    pub original_language: Option<String>,
     pub source_domain: String,
     pub published_at: DateTime<Utc>,
     pub click_count: i64,
+    pub tags: Vec<String>,
 }
 
 pub struct ArticleListArgs {
     pub from: Option<DateTime<Utc>>,
     pub to: Option<DateTime<Utc>>,
     pub keyword: Option<String>,
+    pub original_language: Option<String>,
+    pub feed_tag: Option<String>,
+    pub source_domain: Option<String>,
+    /// 标题或摘要包含的关键词，匹配方式与搜索接口相同
+    pub query: Option<String>,
+    /// 读者 ID，`read` / `bookmarked` 按该读者的文章状态筛选（有筛选时必填）
+    pub reader_id: Option<String>,
+    pub read: Option<bool>,
+    pub bookmarked: Option<bool>,
+    /// 键集游标 (published_at, id)：只取排在其后的文章，仅用于发布时间降序
+    pub after: Option<(DateTime<Utc>, i64)>,
+    pub sort: ArticleSort,
+    pub descending: bool,
     pub limit: i64,
     pub offset: i64,
+    /// 为 true 时以统计信息或限量计数代替全表 COUNT(*)
+    pub estimate_total: bool,
+}
+
+/// 公开列表的排序字段，同值时按 id 同向排序以保证翻页稳定
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ArticleSort {
+    #[default]
+    PublishedAt,
+    ClickCount,
+    FetchedAt,
+    /// 随时间衰减的排行分数，由 `service::ranking` 定期重算
+    Rank,
+}
+
+impl ArticleSort {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "published_at" => Some(Self::PublishedAt),
+            "click_count" => Some(Self::ClickCount),
+            "fetched_at" => Some(Self::FetchedAt),
+            "rank" => Some(Self::Rank),
+            _ => None,
+        }
+    }
+
+    fn order_by(self, descending: bool) -> &'static str {
+        match (self, descending) {
+            (Self::PublishedAt, true) => "published_at DESC, id DESC",
+            (Self::PublishedAt, false) => "published_at ASC, id ASC",
+            (Self::ClickCount, true) => "click_count DESC, id DESC",
+            (Self::ClickCount, false) => "click_count ASC, id ASC",
+            (Self::FetchedAt, true) => "fetched_at DESC, id DESC",
+            (Self::FetchedAt, false) => "fetched_at ASC, id ASC",
+            (Self::Rank, true) => "rank_score DESC, id DESC",
+            (Self::Rank, false) => "rank_score ASC, id ASC",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -29,72 +84,549 @@ pub struct NewArticle {
     pub url: String,
     pub description: Option<String>,
     pub language: Option<String>,
+    pub original_language: Option<String>,
     pub source_domain: String,
     pub published_at: DateTime<Utc>,
+    pub translation_pending: bool,
+    /// 见 `util::title::content_fingerprint`
+    pub content_fingerprint: Option<String>,
+    /// 见 `util::title::title_simhash`
+    pub title_simhash: Option<i64>,
+    /// 非空时入库即隐藏（如超出来源每日上限），并写入审核记录
+    pub hidden_reason: Option<String>,
+    /// 去重命中的主文章：非空时作为该簇成员入库，公开接口只展示主文章
+    pub canonical_id: Option<i64>,
+    /// 摘要被截断时的原文，仅在开启正文存储时保留，入库时写入 `news.article_contents`
+    pub full_description: Option<String>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct EnrichmentRow {
+    /// JSON 文本（查询时转换为 text，sqlx 未启用 json 特性）
+    pub attrs: Option<String>,
+    pub enriched_at: Option<DateTime<Utc>>,
+    pub enrichment_attempts: i32,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct PendingTranslationRow {
+    pub id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    pub source_domain: String,
+    pub original_language: Option<String>,
+    pub translation_attempts: i32,
+}
+
+pub struct ArticleSearchArgs {
+    pub pattern: String,
+    pub source_domain: Option<String>,
+    pub tag: Option<String>,
+    pub day: Option<NaiveDate>,
+    pub limit: i64,
+    pub offset: i64,
 }
 
+#[derive(Debug, sqlx::FromRow)]
+pub struct FacetRow {
+    pub facet: String,
+    pub value: Option<String>,
+    pub count: i64,
+}
+
+// 公开列表的筛选条件，列表与计数查询共用
+const LIST_CONDITION: &str = r#"
+    (canonical_id IS NULL OR canonical_id = id)
+    AND hidden_at IS NULL
+    AND ($1::timestamptz IS NULL OR published_at >= $1)
+    AND ($2::timestamptz IS NULL OR published_at <= $2)
+    AND ($3::text IS NULL OR title ILIKE $3)
+    AND ($4::text IS NULL OR original_language = $4)
+    AND ($5::text IS NULL OR $5 = ANY(tags))
+    AND ($7::boolean IS NULL OR $7 = EXISTS (
+        SELECT 1 FROM news.user_article_state s
+        WHERE s.reader_id = $6 AND s.article_id = news.articles.id AND s.read_at IS NOT NULL
+    ))
+    AND ($8::boolean IS NULL OR $8 = EXISTS (
+        SELECT 1 FROM news.user_article_state s
+        WHERE s.reader_id = $6 AND s.article_id = news.articles.id AND s.bookmarked_at IS NOT NULL
+    ))
+    AND ($9::text IS NULL OR source_domain = $9)
+    AND ($10::text IS NULL OR title ILIKE $10 OR description ILIKE $10)
+"#;
+
+/// 估算模式下精确计数的上限，超出时 total 即为该值
+pub const ESTIMATE_COUNT_CAP: i64 = 10_000;
+
+/// 返回本页文章、总数，以及总数是否为估算值
 pub async fn list_articles(
     pool: &PgPool,
     args: ArticleListArgs,
-) -> Result<(Vec<ArticleRow>, i64), sqlx::Error> {
+) -> Result<(Vec<ArticleRow>, i64, bool), sqlx::Error> {
     let keyword = args.keyword.as_ref().map(|value| format!("%{}%", value));
+    let query = args.query.as_ref().map(|value| format!("%{}%", value));
+    let order_by = args.sort.order_by(args.descending);
 
-    let rows = sqlx::query_as::<_, ArticleRow>(
+    // 列表与总数在同一可重复读快照内查询，持续入库时分页条目与 total_hint 保持一致
+    let mut tx = pool.begin().await?;
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+        .execute(&mut *tx)
+        .await?;
+
+    let sql = format!(
         r#"
         SELECT id::bigint AS id,
                title,
                url,
                description,
                language,
+               original_language,
                source_domain,
                published_at,
-               click_count::bigint AS click_count
+               click_count::bigint AS click_count,
+               tags
         FROM news.articles
-        WHERE ($1::timestamptz IS NULL OR published_at >= $1)
-          AND ($2::timestamptz IS NULL OR published_at <= $2)
-          AND ($3::text IS NULL OR title ILIKE $3)
-        ORDER BY published_at DESC
-        LIMIT $4
-        OFFSET $5
+        WHERE {LIST_CONDITION}
+          AND ($13::timestamptz IS NULL OR (published_at, id) < ($13, $14::bigint))
+        ORDER BY {order_by}
+        LIMIT $11
+        OFFSET $12
+        "#
+    );
+    let rows = sqlx::query_as::<_, ArticleRow>(&sql)
+        .bind(args.from)
+        .bind(args.to)
+        .bind(keyword.as_deref())
+        .bind(args.original_language.as_deref())
+        .bind(args.feed_tag.as_deref())
+        .bind(args.reader_id.as_deref())
+        .bind(args.read)
+        .bind(args.bookmarked)
+        .bind(args.source_domain.as_deref())
+        .bind(query.as_deref())
+        .bind(args.limit)
+        .bind(args.offset)
+        .bind(args.after.map(|(published_at, _)| published_at))
+        .bind(args.after.map(|(_, id)| id))
+        .fetch_all(&mut *tx)
+        .await?;
+
+    if !args.estimate_total {
+        let sql = format!("SELECT COUNT(*)::bigint FROM news.articles WHERE {LIST_CONDITION}");
+        let total = sqlx::query_scalar::<_, i64>(&sql)
+            .bind(args.from)
+            .bind(args.to)
+            .bind(keyword.as_deref())
+            .bind(args.original_language.as_deref())
+            .bind(args.feed_tag.as_deref())
+            .bind(args.reader_id.as_deref())
+            .bind(args.read)
+            .bind(args.bookmarked)
+            .bind(args.source_domain.as_deref())
+            .bind(query.as_deref())
+            .fetch_one(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        return Ok((rows, total, false));
+    }
+
+    let unfiltered = args.from.is_none()
+        && args.to.is_none()
+        && keyword.is_none()
+        && args.original_language.is_none()
+        && args.feed_tag.is_none()
+        && args.read.is_none()
+        && args.bookmarked.is_none()
+        && args.source_domain.is_none()
+        && query.is_none();
+    if unfiltered {
+        // 统计信息中的行数（含已归并与隐藏的文章），表从未 ANALYZE 时为 -1
+        let reltuples = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT reltuples::bigint
+            FROM pg_class
+            WHERE oid = 'news.articles'::regclass
+            "#,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if reltuples >= 0 {
+            tx.commit().await?;
+            return Ok((rows, reltuples, true));
+        }
+    }
+
+    // 最多扫描 cap 行：未达上限时即为精确值
+    let sql = format!(
+        r#"
+        SELECT COUNT(*)::bigint
+        FROM (
+            SELECT 1
+            FROM news.articles
+            WHERE {LIST_CONDITION}
+            LIMIT $11
+        ) capped
+        "#
+    );
+    let total = sqlx::query_scalar::<_, i64>(&sql)
+        .bind(args.from)
+        .bind(args.to)
+        .bind(keyword.as_deref())
+        .bind(args.original_language.as_deref())
+        .bind(args.feed_tag.as_deref())
+        .bind(args.reader_id.as_deref())
+        .bind(args.read)
+        .bind(args.bookmarked)
+        .bind(args.source_domain.as_deref())
+        .bind(query.as_deref())
+        .bind(ESTIMATE_COUNT_CAP)
+        .fetch_one(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok((rows, total, total >= ESTIMATE_COUNT_CAP))
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ArchiveDayRow {
+    pub day: NaiveDate,
+    pub count: i64,
+}
+
+/// `[from, to)` 内公开可见的主文章按发布日期（UTC）计数，只返回有文章的日期
+pub async fn archive_day_counts(
+    pool: &PgPool,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<ArchiveDayRow>, sqlx::Error> {
+    sqlx::query_as::<_, ArchiveDayRow>(
+        r#"
+        SELECT (published_at AT TIME ZONE 'UTC')::date AS day,
+               COUNT(*)::bigint AS count
+        FROM news.articles
+        WHERE (canonical_id IS NULL OR canonical_id = id)
+          AND hidden_at IS NULL
+          AND published_at >= $1
+          AND published_at < $2
+        GROUP BY day
+        ORDER BY day
         "#,
     )
-    .bind(args.from)
-    .bind(args.to)
-    .bind(keyword.as_deref())
-    .bind(args.limit)
-    .bind(args.offset)
+    .bind(from)
+    .bind(to)
     .fetch_all(pool)
-    .await?;
+    .await
+}
+
+/// `[from, to)` 内公开可见的主文章，按发布时间倒序分页
+pub async fn list_archive_articles(
+    pool: &PgPool,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<ArticleRow>, sqlx::Error> {
+    sqlx::query_as::<_, ArticleRow>(
+        r#"
+        SELECT id::bigint AS id,
+               title,
+               url,
+               description,
+               language,
+               original_language,
+               source_domain,
+               published_at,
+               click_count::bigint AS click_count,
+               tags
+        FROM news.articles
+        WHERE (canonical_id IS NULL OR canonical_id = id)
+          AND hidden_at IS NULL
+          AND published_at >= $1
+          AND published_at < $2
+        ORDER BY published_at DESC, id DESC
+        LIMIT $3
+        OFFSET $4
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await
+}
+
+// 搜索的公共筛选条件：标题或摘要模糊匹配，仅未隐藏的主文章
+const SEARCH_CONDITION: &str = r#"
+    (canonical_id IS NULL OR canonical_id = id)
+    AND hidden_at IS NULL
+    AND (title ILIKE $1 OR description ILIKE $1)
+    AND ($2::text IS NULL OR source_domain = $2)
+    AND ($3::text IS NULL OR $3 = ANY(tags))
+    AND ($4::date IS NULL OR (published_at AT TIME ZONE 'UTC')::date = $4)
+"#;
+
+// 搜索结果与分面统计合并在同一结果集中：`kind = 'item'` 的行为当前页文章，`kind = 'facet'` 的行为分面计数
+#[derive(Debug, sqlx::FromRow)]
+struct SearchRow {
+    kind: String,
+    id: Option<i64>,
+    title: Option<String>,
+    url: Option<String>,
+    description: Option<String>,
+    language: Option<String>,
+    original_language: Option<String>,
+    source_domain: Option<String>,
+    published_at: Option<DateTime<Utc>>,
+    click_count: Option<i64>,
+    tags: Option<Vec<String>>,
+    facet: Option<String>,
+    value: Option<String>,
+    count: Option<i64>,
+}
+
+/// 搜索：一次查询同时得到当前页文章与分面统计。匹配结果先物化为 CTE，分页取自其中，
+/// 分面由 GROUPING SETS 对来源、标签、日期分别计数（facet = 'total' 的行即匹配总数），标签展开后按文章 ID 去重计数。
+pub async fn search_articles(
+    pool: &PgPool,
+    args: &ArticleSearchArgs,
+) -> Result<(Vec<ArticleRow>, Vec<FacetRow>), sqlx::Error> {
+    let sql = format!(
+        r#"
+        WITH matched AS MATERIALIZED (
+            SELECT id::bigint AS id,
+                   title,
+                   url,
+                   description,
+                   language,
+                   original_language,
+                   source_domain,
+                   published_at,
+                   click_count::bigint AS click_count,
+                   tags
+            FROM news.articles
+            WHERE {SEARCH_CONDITION}
+        ),
+        page AS (
+            SELECT *
+            FROM matched
+            ORDER BY published_at DESC
+            LIMIT $5
+            OFFSET $6
+        ),
+        facets AS (
+            SELECT CASE
+                       WHEN GROUPING(m.source_domain) = 0 THEN 'source_domain'
+                       WHEN GROUPING(t.tag) = 0 THEN 'tag'
+                       WHEN GROUPING(m.day) = 0 THEN 'day'
+                       ELSE 'total'
+                   END AS facet,
+                   COALESCE(m.source_domain, t.tag, m.day::text) AS value,
+                   COUNT(DISTINCT m.id)::bigint AS count
+            FROM (
+                SELECT id, source_domain, tags, (published_at AT TIME ZONE 'UTC')::date AS day
+                FROM matched
+            ) m
+            LEFT JOIN LATERAL unnest(m.tags) AS t(tag) ON TRUE
+            GROUP BY GROUPING SETS ((m.source_domain), (t.tag), (m.day), ())
+        )
+        SELECT 'item' AS kind,
+               p.id, p.title, p.url, p.description, p.language, p.original_language,
+               p.source_domain, p.published_at, p.click_count, p.tags,
+               NULL::text AS facet, NULL::text AS value, NULL::bigint AS count
+        FROM page p
+        UNION ALL
+        SELECT 'facet' AS kind,
+               NULL, NULL, NULL, NULL, NULL, NULL, NULL, NULL, NULL, NULL,
+               f.facet, f.value, f.count
+        FROM facets f
+        ORDER BY kind DESC, published_at DESC, count DESC, value
+        "#
+    );
+    let rows = sqlx::query_as::<_, SearchRow>(&sql)
+        .bind(&args.pattern)
+        .bind(args.source_domain.as_deref())
+        .bind(args.tag.as_deref())
+        .bind(args.day)
+        .bind(args.limit)
+        .bind(args.offset)
+        .fetch_all(pool)
+        .await?;
+
+    let mut items = Vec::new();
+    let mut facets = Vec::new();
+    for row in rows {
+        if row.kind == "item" {
+            items.push(ArticleRow {
+                id: row.id.unwrap_or_default(),
+                title: row.title.unwrap_or_default(),
+                url: row.url.unwrap_or_default(),
+                description: row.description,
+                language: row.language,
+                original_language: row.original_language,
+                source_domain: row.source_domain.unwrap_or_default(),
+                published_at: row.published_at.unwrap_or_default(),
+                click_count: row.click_count.unwrap_or_default(),
+                tags: row.tags.unwrap_or_default(),
+            });
+        } else if let Some(facet) = row.facet {
+            facets.push(FacetRow {
+                facet,
+                value: row.value,
+                count: row.count.unwrap_or_default(),
+            });
+        }
+    }
+    Ok((items, facets))
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct StatsSummaryRow {
+    pub total_articles: i64,
+    pub articles_today: i64,
+    pub active_sources: i64,
+    pub last_updated_at: Option<DateTime<Utc>>,
+}
+
+// 公开统计：口径与公开列表一致（仅主文章、未隐藏），活跃来源为启用中的订阅源所属域名数
+pub async fn stats_summary(pool: &PgPool) -> Result<StatsSummaryRow, sqlx::Error> {
+    sqlx::query_as::<_, StatsSummaryRow>(
+        r#"
+        SELECT
+            COUNT(*)::bigint AS total_articles,
+            COUNT(*) FILTER (
+                WHERE a.fetched_at >= date_trunc('day', NOW() AT TIME ZONE 'UTC') AT TIME ZONE 'UTC'
+            )::bigint AS articles_today,
+            (SELECT COUNT(DISTINCT source_domain) FROM news.feeds WHERE enabled)::bigint
+                AS active_sources,
+            MAX(a.fetched_at) AS last_updated_at
+        FROM news.articles a
+        WHERE (a.canonical_id IS NULL OR a.canonical_id = a.id)
+          AND a.hidden_at IS NULL
+        "#,
+    )
+    .fetch_one(pool)
+    .await
+}
 
-    let total = sqlx::query_scalar::<_, i64>(
+// 某来源今日（UTC）已公开入库的文章数，用于每日上限判断
+pub async fn count_visible_today(pool: &PgPool, source_domain: &str) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar::<_, i64>(
         r#"
         SELECT COUNT(*)::bigint
         FROM news.articles
-        WHERE ($1::timestamptz IS NULL OR published_at >= $1)
-          AND ($2::timestamptz IS NULL OR published_at <= $2)
-          AND ($3::text IS NULL OR title ILIKE $3)
+        WHERE source_domain = $1
+          AND fetched_at >= date_trunc('day', NOW() AT TIME ZONE 'UTC') AT TIME ZONE 'UTC'
+          AND hidden_at IS NULL
+          AND (canonical_id IS NULL OR canonical_id = id)
         "#,
     )
-    .bind(args.from)
-    .bind(args.to)
-    .bind(keyword.as_deref())
+    .bind(source_domain)
     .fetch_one(pool)
+    .await
+}
+
+// 按内容指纹查找已入库文章，返回指纹 -> 主文章 ID
+pub async fn find_by_fingerprints(
+    pool: &PgPool,
+    fingerprints: &[String],
+) -> Result<HashMap<String, i64>, sqlx::Error> {
+    if fingerprints.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let rows = sqlx::query(
+        r#"
+        SELECT DISTINCT ON (content_fingerprint)
+               content_fingerprint,
+               COALESCE(canonical_id, id) AS article_id
+        FROM news.articles
+        WHERE content_fingerprint = ANY($1)
+        ORDER BY content_fingerprint, id
+        "#,
+    )
+    .bind(fingerprints)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| (row.get("content_fingerprint"), row.get("article_id")))
+        .collect())
+}
+
+/// 按标题 SimHash 在全部历史主文章中查找近似重复，返回哈希 -> (主文章 ID, 汉明距离)，
+/// 每个哈希取距离最小者。依赖 4 段 16 位的分段索引，`max_distance` 不应超过 3
+pub async fn find_by_simhashes(
+    pool: &PgPool,
+    simhashes: &[i64],
+    max_distance: i32,
+) -> Result<HashMap<i64, (i64, i32)>, sqlx::Error> {
+    if simhashes.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let rows = sqlx::query(
+        r#"
+        SELECT DISTINCT ON (q.simhash)
+               q.simhash,
+               a.id::bigint AS article_id,
+               bit_count((a.title_simhash # q.simhash)::bit(64))::int AS distance
+        FROM unnest($1::bigint[]) AS q(simhash)
+        -- 每段单独查询，保证各自命中分段索引
+        CROSS JOIN LATERAL (
+            SELECT id, canonical_id, title_simhash FROM news.articles
+            WHERE title_simhash IS NOT NULL
+              AND ((title_simhash >> 48) & 65535) = ((q.simhash >> 48) & 65535)
+            UNION
+            SELECT id, canonical_id, title_simhash FROM news.articles
+            WHERE title_simhash IS NOT NULL
+              AND ((title_simhash >> 32) & 65535) = ((q.simhash >> 32) & 65535)
+            UNION
+            SELECT id, canonical_id, title_simhash FROM news.articles
+            WHERE title_simhash IS NOT NULL
+              AND ((title_simhash >> 16) & 65535) = ((q.simhash >> 16) & 65535)
+            UNION
+            SELECT id, canonical_id, title_simhash FROM news.articles
+            WHERE title_simhash IS NOT NULL
+              AND (title_simhash & 65535) = (q.simhash & 65535)
+        ) a
+        WHERE (a.canonical_id IS NULL OR a.canonical_id = a.id)
+          AND bit_count((a.title_simhash # q.simhash)::bit(64)) <= $2
+        ORDER BY q.simhash, distance, a.id
+        "#,
+    )
+    .bind(simhashes)
+    .bind(max_distance)
+    .fetch_all(pool)
     .await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            (
+                row.get("simhash"),
+                (row.get("article_id"), row.get("distance")),
+            )
+        })
+        .collect())
+}
 
-    Ok((rows, total))
+/// 写入结果：`existing` 为 (feed_id, url) 已存在的条目及其已有文章 ID，
+/// 多实例或快速重试并发写入同一文章时，后到者据此补记来源而不是当作新文章
+#[derive(Debug, Default)]
+pub struct InsertedArticles {
+    pub inserted: Vec<(i64, NewArticle)>,
+    pub existing: Vec<(i64, NewArticle)>,
 }
 
 pub async fn insert_articles(
     pool: &PgPool,
     articles: Vec<NewArticle>,
-) -> Result<Vec<(i64, NewArticle)>, sqlx::Error> {
+) -> Result<InsertedArticles, sqlx::Error> {
+    let mut result = InsertedArticles::default();
     if articles.is_empty() {
-        return Ok(Vec::new());
+        return Ok(result);
     }
 
-    let mut inserted = Vec::new();
-
     let mut tx = pool.begin().await?;
+    // 先于锁等待超时设置取得写入锁，批次较小，排队时间很短
+    lock_article_inserts(&mut tx).await?;
     // 防止因并发唯一键冲突等待导致卡住：限制锁等待与语句执行时间
     // 注意：不影响同事务内其它操作
     let _ = sqlx::query("SET LOCAL lock_timeout = '5s'")
@@ -112,16 +644,33 @@ pub async fn insert_articles(
                 url,
                 description,
                 language,
+                original_language,
                 source_domain,
                 published_at,
                 fetched_at,
-                click_count
+                click_count,
+                tags,
+                translation_pending,
+                content_fingerprint,
+                title_simhash,
+                hidden_at,
+                hidden_reason
             )
             VALUES (
-                $1, $2, $3, $4, $5, $6, $7, NOW(), 0
+                $1, $2, $3, $4, $5, $6, $7, $8, NOW(), 0,
+                COALESCE(
+                    (SELECT array_agg(tag ORDER BY tag) FROM news.feed_tags WHERE feed_id = $1),
+                    '{}'
+                ),
+                $9,
+                $10,
+                $11,
+                CASE WHEN $12::text IS NULL THEN NULL ELSE NOW() END,
+                $12
             )
-            ON CONFLICT (feed_id, url) DO NOTHING
-            RETURNING id::bigint AS id
+            -- 冲突时做一次无实际变更的更新以返回已有行；xmax = 0 表示本次新插入
+            ON CONFLICT (feed_id, url) DO UPDATE SET url = EXCLUDED.url
+            RETURNING id::bigint AS id, (xmax = 0) AS inserted
             "#,
         )
         .bind(article.feed_id)
@@ -129,9 +678,14 @@ pub async fn insert_articles(
         .bind(&article.url)
         .bind(&article.description)
         .bind(&article.language)
+        .bind(&article.original_language)
         .bind(&article.source_domain)
         .bind(article.published_at)
-        .fetch_optional(&mut *tx)
+        .bind(article.translation_pending)
+        .bind(&article.content_fingerprint)
+        .bind(article.title_simhash)
+        .bind(&article.hidden_reason)
+        .fetch_one(&mut *tx)
         .await;
         let row = match row_res {
             Ok(v) => v,
@@ -146,31 +700,231 @@ pub async fn insert_articles(
             }
         };
 
-        if let Some(row) = row {
-            let article_id: i64 = row.get("id");
+        let article_id: i64 = row.get("id");
+        if !row.get::<bool, _>("inserted") {
+            result.existing.push((article_id, article));
+            continue;
+        }
+        // 主文章在去重之后可能已被删除或归并到其他簇：取其当前所在簇，不存在时自成一簇
+        sqlx::query(
+            r#"
+            UPDATE news.articles
+            SET canonical_id = COALESCE(
+                (SELECT COALESCE(c.canonical_id, c.id) FROM news.articles c WHERE c.id = $2),
+                canonical_id,
+                id
+            )
+            WHERE id = $1
+            "#,
+        )
+        .bind(article_id)
+        .bind(article.canonical_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if let Some(full_description) = article.full_description.as_deref() {
             sqlx::query(
                 r#"
-                UPDATE news.articles
-                SET canonical_id = COALESCE(canonical_id, id)
-                WHERE id = $1
+                INSERT INTO news.article_contents (article_id, full_description)
+                VALUES ($1, $2)
+                ON CONFLICT (article_id) DO UPDATE
+                SET full_description = EXCLUDED.full_description,
+                    updated_at = NOW()
                 "#,
             )
             .bind(article_id)
+            .bind(full_description)
             .execute(&mut *tx)
             .await?;
+        }
 
-            inserted.push((article_id, article.clone()));
+        if let Some(reason) = article.hidden_reason.as_deref() {
+            sqlx::query(
+                r#"
+                INSERT INTO news.article_moderation (article_id, action, reason)
+                VALUES ($1, 'hide', $2)
+                "#,
+            )
+            .bind(article_id)
+            .bind(reason)
+            .execute(&mut *tx)
+            .await?;
         }
+
+        result.inserted.push((article_id, article));
     }
 
     tx.commit().await?;
-    Ok(inserted)
+    Ok(result)
+}
+
+/// 待外部评分的文章：最近 `lookback_hours` 小时内入库、尚无 attrs 且未用尽重试次数，按 ID 顺序
+pub async fn list_enrichment_pending(
+    pool: &PgPool,
+    lookback_hours: i64,
+    max_attempts: i32,
+    limit: i64,
+) -> Result<Vec<ArticleRow>, sqlx::Error> {
+    sqlx::query_as::<_, ArticleRow>(
+        r#"
+        SELECT id::bigint AS id,
+               title,
+               url,
+               description,
+               language,
+               original_language,
+               source_domain,
+               published_at,
+               click_count::bigint AS click_count,
+               tags
+        FROM news.articles
+        WHERE attrs IS NULL
+          AND (canonical_id IS NULL OR canonical_id = id)
+          AND enrichment_attempts < $2
+          AND fetched_at > NOW() - make_interval(hours => $1::int)
+        ORDER BY id
+        LIMIT $3
+        "#,
+    )
+    .bind(lookback_hours)
+    .bind(max_attempts)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn store_enrichment(pool: &PgPool, article_id: i64, attrs: &str) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET attrs = $2::jsonb,
+            enriched_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(article_id)
+    .bind(attrs)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 记录一次评分失败，返回累计失败次数
+pub async fn record_enrichment_failure(pool: &PgPool, article_id: i64) -> Result<i32, sqlx::Error> {
+    sqlx::query_scalar::<_, i32>(
+        r#"
+        UPDATE news.articles
+        SET enrichment_attempts = enrichment_attempts + 1
+        WHERE id = $1
+        RETURNING enrichment_attempts
+        "#,
+    )
+    .bind(article_id)
+    .fetch_optional(pool)
+    .await
+    .map(Option::unwrap_or_default)
+}
+
+pub async fn find_enrichment(pool: &PgPool, article_id: i64) -> Result<Option<EnrichmentRow>, sqlx::Error> {
+    sqlx::query_as::<_, EnrichmentRow>(
+        r#"
+        SELECT attrs::text AS attrs,
+               enriched_at,
+               enrichment_attempts
+        FROM news.articles
+        WHERE id = $1
+        "#,
+    )
+    .bind(article_id)
+    .fetch_optional(pool)
+    .await
+}
+
+// 文章写入锁（双 int4 键，与按 feed ID 的单 bigint 处理锁不在同一键空间）
+const ARTICLE_INSERT_LOCK: (i32, i32) = (0x6e657773, 1);
+
+/// 串行化新文章写入事务：并发批次各自分配 ID 后若先后提交，较大的 ID 可能先可见，
+/// 持锁到提交可保证 ID 按顺序可见，`since_id` 增量同步不会跳过较小的 ID
+async fn lock_article_inserts(tx: &mut Transaction<'_, Postgres>) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT pg_advisory_xact_lock($1, $2)")
+        .bind(ARTICLE_INSERT_LOCK.0)
+        .bind(ARTICLE_INSERT_LOCK.1)
+        .execute(tx.as_mut())
+        .await?;
+    Ok(())
+}
+
+/// 按 ID 增量读取：扫描 `since_id` 之后最多 `limit` 篇文章，返回其中公开可见的主文章
+/// 以及本次扫描到的最大 ID（无新文章时为 None）
+pub async fn list_changes(
+    pool: &PgPool,
+    since_id: i64,
+    limit: i64,
+) -> Result<(Vec<ArticleRow>, Option<i64>, i64), sqlx::Error> {
+    let (last_id, scanned): (Option<i64>, i64) = sqlx::query_as(
+        r#"
+        SELECT MAX(id)::bigint, COUNT(*)::bigint
+        FROM (
+            SELECT id
+            FROM news.articles
+            WHERE id > $1
+            ORDER BY id
+            LIMIT $2
+        ) s
+        "#,
+    )
+    .bind(since_id)
+    .bind(limit)
+    .fetch_one(pool)
+    .await?;
+    let Some(last_id) = last_id else {
+        return Ok((Vec::new(), None, 0));
+    };
+
+    let rows = sqlx::query_as::<_, ArticleRow>(
+        r#"
+        SELECT id::bigint AS id,
+               title,
+               url,
+               description,
+               language,
+               original_language,
+               source_domain,
+               published_at,
+               click_count::bigint AS click_count,
+               tags
+        FROM news.articles
+        WHERE id > $1
+          AND id <= $2
+          AND (canonical_id IS NULL OR canonical_id = id)
+          AND hidden_at IS NULL
+        ORDER BY id
+        "#,
+    )
+    .bind(since_id)
+    .bind(last_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok((rows, Some(last_id), scanned))
 }
 
 pub async fn delete_by_feed(
     tx: &mut Transaction<'_, Postgres>,
     feed_id: i64,
 ) -> Result<u64, sqlx::Error> {
+    let ids: Vec<i64> = sqlx::query_scalar(
+        r#"
+        SELECT id::bigint
+        FROM news.articles
+        WHERE feed_id = $1
+        "#,
+    )
+    .bind(feed_id)
+    .fetch_all(tx.as_mut())
+    .await?;
+    promote_cluster_heirs(tx, &ids).await?;
+
     let result: PgQueryResult = sqlx::query(
         r#"
         DELETE FROM news.articles
@@ -184,6 +938,34 @@ pub async fn delete_by_feed(
     Ok(result.rows_affected())
 }
 
+#[derive(Debug, sqlx::FromRow)]
+pub struct RedirectTargetRow {
+    pub url: String,
+    pub canonical_id: i64,
+    /// 所在簇的主文章是否已隐藏
+    pub hidden: bool,
+}
+
+/// 点击跳转的目标：文章自身的链接，以及计数所用的主文章
+pub async fn find_redirect_target(
+    pool: &PgPool,
+    id: i64,
+) -> Result<Option<RedirectTargetRow>, sqlx::Error> {
+    sqlx::query_as::<_, RedirectTargetRow>(
+        r#"
+        SELECT a.url,
+               c.id::bigint AS canonical_id,
+               (c.hidden_at IS NOT NULL) AS hidden
+        FROM news.articles a
+        JOIN news.articles c ON c.id = COALESCE(a.canonical_id, a.id)
+        WHERE a.id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+}
+
 pub async fn increment_click(pool: &PgPool, id: i64) -> Result<(), sqlx::Error> {
     sqlx::query(
         r#"
@@ -199,61 +981,1321 @@ pub async fn increment_click(pool: &PgPool, id: i64) -> Result<(), sqlx::Error>
     Ok(())
 }
 
-pub async fn list_top_articles(pool: &PgPool, limit: i64) -> Result<Vec<ArticleRow>, sqlx::Error> {
+/// 热门文章：按点击量乘以来源权重（news.source_weights，缺省 1.0）排序
+/// 热门文章查询参数，已校验；同时作为响应缓存的键
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FeaturedArgs {
+    pub limit: i64,
+    pub window_hours: i32,
+    /// 每个来源最多入选的篇数，None 表示不限制
+    pub max_per_source: Option<i64>,
+}
+
+pub async fn list_top_articles(pool: &PgPool, args: FeaturedArgs) -> Result<Vec<ArticleRow>, sqlx::Error> {
     sqlx::query_as::<_, ArticleRow>(
         r#"
+        WITH ranked AS (
+            SELECT a.id,
+                   a.title,
+                   a.url,
+                   a.description,
+                   a.language,
+                   a.original_language,
+                   a.source_domain,
+                   a.published_at,
+                   a.click_count,
+                   a.tags,
+                   a.click_count * COALESCE(w.weight, 1.0) AS score,
+                   ROW_NUMBER() OVER (
+                       PARTITION BY a.source_domain
+                       ORDER BY a.click_count * COALESCE(w.weight, 1.0) DESC, a.published_at DESC
+                   ) AS source_rank
+            FROM news.articles a
+            LEFT JOIN news.source_weights w ON w.source_domain = a.source_domain
+            WHERE a.published_at >= NOW() - make_interval(hours => $2)
+              AND (a.canonical_id IS NULL OR a.canonical_id = a.id)
+              AND a.hidden_at IS NULL
+        )
         SELECT id::bigint AS id,
                title,
                url,
                description,
                language,
+               original_language,
                source_domain,
                published_at,
-               click_count::bigint AS click_count
-        FROM news.articles
-        WHERE published_at >= NOW() - INTERVAL '24 HOURS'
-        ORDER BY click_count DESC, published_at DESC
+               click_count::bigint AS click_count,
+               tags
+        FROM ranked
+        WHERE ($3::bigint IS NULL OR source_rank <= $3)
+        ORDER BY score DESC, published_at DESC
         LIMIT $1
         "#,
     )
-    .bind(limit)
+    .bind(args.limit)
+    .bind(args.window_hours)
+    .bind(args.max_per_source)
     .fetch_all(pool)
     .await
 }
 
-pub async fn list_recent_articles(
+/// 重算 `published_at >= since` 且 id 大于 `after_id` 的一批文章（按 id 升序，最多 limit 篇）的排行分数：
+/// (1 + 点击数 + 簇内被归并的篇数) × 来源权重 ÷ (发布小时数 + 2)^gravity。
+/// 返回本批最大 id（无文章时为 None）、本批篇数与分数实际变化的篇数
+pub async fn recompute_rank_scores(
     pool: &PgPool,
+    since: DateTime<Utc>,
+    after_id: i64,
     limit: i64,
-) -> Result<Vec<ArticleRow>, sqlx::Error> {
-    sqlx::query_as::<_, ArticleRow>(
+    gravity: f64,
+) -> Result<(Option<i64>, i64, i64), sqlx::Error> {
+    let row = sqlx::query(
         r#"
-        SELECT id::bigint AS id,
+        WITH batch AS (
+            SELECT id
+            FROM news.articles
+            WHERE published_at >= $1
+              AND id > $2
+            ORDER BY id
+            LIMIT $3
+        ),
+        scored AS (
+            SELECT a.id,
+                   (1 + a.click_count + COALESCE(m.members, 0))::float8
+                     * COALESCE(w.weight, 1.0)::float8
+                     / power(
+                         GREATEST(EXTRACT(EPOCH FROM (NOW() - a.published_at))::float8 / 3600.0, 0) + 2,
+                         $4::float8
+                       ) AS score
+            FROM batch b
+            JOIN news.articles a ON a.id = b.id
+            LEFT JOIN news.source_weights w ON w.source_domain = a.source_domain
+            LEFT JOIN LATERAL (
+                SELECT COUNT(*) AS members
+                FROM news.articles d
+                WHERE d.canonical_id = a.id AND d.id <> a.id
+            ) m ON TRUE
+        ),
+        updated AS (
+            UPDATE news.articles a
+            SET rank_score = s.score
+            FROM scored s
+            WHERE a.id = s.id
+              AND a.rank_score IS DISTINCT FROM s.score
+            RETURNING 1
+        )
+        SELECT (SELECT MAX(id) FROM batch)::bigint AS last_id,
+               (SELECT COUNT(*) FROM batch)::bigint AS scanned,
+               (SELECT COUNT(*) FROM updated)::bigint AS updated
+        "#,
+    )
+    .bind(since)
+    .bind(after_id)
+    .bind(limit)
+    .bind(gravity)
+    .fetch_one(pool)
+    .await?;
+    Ok((
+        row.try_get("last_id")?,
+        row.try_get("scanned")?,
+        row.try_get("updated")?,
+    ))
+}
+
+/// 把发布时间早于 `before` 且仍有分数的文章清零，每次最多 limit 篇；返回清零的行数
+pub async fn reset_rank_scores_before(
+    pool: &PgPool,
+    before: DateTime<Utc>,
+    limit: i64,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET rank_score = 0
+        WHERE id IN (
+            SELECT id
+            FROM news.articles
+            WHERE published_at < $1
+              AND rank_score <> 0
+            LIMIT $2
+        )
+        "#,
+    )
+    .bind(before)
+    .bind(limit)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+pub async fn find_by_id(pool: &PgPool, id: i64) -> Result<Option<ArticleRow>, sqlx::Error> {
+    sqlx::query_as::<_, ArticleRow>(
+        r#"
+        SELECT id::bigint AS id,
+               title,
+               url,
+               description,
+               language,
+               original_language,
+               source_domain,
+               published_at,
+               click_count::bigint AS click_count,
+               tags
+        FROM news.articles
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// 待补偿翻译的文章（抓取时翻译失败），按入库顺序取出
+pub async fn list_translation_pending(
+    pool: &PgPool,
+    limit: i64,
+) -> Result<Vec<PendingTranslationRow>, sqlx::Error> {
+    sqlx::query_as::<_, PendingTranslationRow>(
+        r#"
+        SELECT id::bigint AS id,
+               title,
+               description,
+               source_domain,
+               original_language,
+               translation_attempts
+        FROM news.articles
+        WHERE translation_pending
+        ORDER BY id
+        LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn apply_translation(
+    pool: &PgPool,
+    id: i64,
+    title: &str,
+    description: Option<&str>,
+    language: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET title = $2,
+            description = COALESCE($3, description),
+            language = $4,
+            translation_pending = FALSE
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(title)
+    .bind(description)
+    .bind(language)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// 记录一次补偿翻译失败；达到上限后不再重试。返回是否仍处于待翻译状态。
+/// 翻译规则变更后不再需要翻译的文章，直接取消待翻译标记并保留原文
+pub async fn clear_translation_pending(pool: &PgPool, id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET translation_pending = FALSE
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn record_translation_failure(
+    pool: &PgPool,
+    id: i64,
+    max_attempts: i32,
+) -> Result<bool, sqlx::Error> {
+    let row = sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET translation_attempts = translation_attempts + 1,
+            translation_pending = translation_attempts + 1 < $2
+        WHERE id = $1
+        RETURNING translation_pending
+        "#,
+    )
+    .bind(id)
+    .bind(max_attempts)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|r| r.get::<bool, _>("translation_pending")).unwrap_or(false))
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct SimilarityCandidateRow {
+    pub id: i64,
+    pub title: String,
+    pub url: String,
+    pub source_domain: String,
+    pub published_at: DateTime<Utc>,
+    pub canonical_id: i64,
+}
+
+/// 相似度浏览的候选：发布时间在 `center` 前后 `window_hours` 小时内的全部文章（含已归并与已隐藏），
+/// 按与 `center` 的时间差由近到远，最多 `limit` 条。
+pub async fn list_similarity_candidates(
+    pool: &PgPool,
+    exclude_id: i64,
+    center: DateTime<Utc>,
+    window_hours: i64,
+    limit: i64,
+) -> Result<Vec<SimilarityCandidateRow>, sqlx::Error> {
+    sqlx::query_as::<_, SimilarityCandidateRow>(
+        r#"
+        SELECT id::bigint AS id,
+               title,
+               url,
+               source_domain,
+               published_at,
+               COALESCE(canonical_id, id)::bigint AS canonical_id
+        FROM news.articles
+        WHERE id <> $1
+          AND published_at BETWEEN $2 - make_interval(hours => $3)
+                               AND $2 + make_interval(hours => $3)
+        ORDER BY ABS(EXTRACT(EPOCH FROM (published_at - $2)))
+        LIMIT $4
+        "#,
+    )
+    .bind(exclude_id)
+    .bind(center)
+    .bind(window_hours as i32)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// 按 ID 取相似度浏览的候选行（向量检索给出的近邻），顺序不定
+pub async fn list_similarity_rows(
+    pool: &PgPool,
+    ids: &[i64],
+) -> Result<Vec<SimilarityCandidateRow>, sqlx::Error> {
+    sqlx::query_as::<_, SimilarityCandidateRow>(
+        r#"
+        SELECT id::bigint AS id,
+               title,
+               url,
+               source_domain,
+               published_at,
+               COALESCE(canonical_id, id)::bigint AS canonical_id
+        FROM news.articles
+        WHERE id = ANY($1)
+        "#,
+    )
+    .bind(ids)
+    .fetch_all(pool)
+    .await
+}
+
+/// 去重候选：`window_hours` 小时内发布的主文章，按发布时间倒序，最多 `limit` 条。
+pub async fn list_recent_articles(
+    pool: &PgPool,
+    window_hours: i64,
+    limit: i64,
+) -> Result<Vec<ArticleRow>, sqlx::Error> {
+    sqlx::query_as::<_, ArticleRow>(
+        r#"
+        SELECT id::bigint AS id,
+               title,
+               url,
+               description,
+               language,
+               original_language,
+               source_domain,
+               published_at,
+               click_count::bigint AS click_count,
+               tags
+        FROM news.articles
+        WHERE (canonical_id IS NULL OR canonical_id = id)
+          AND published_at >= NOW() - make_interval(hours => $1)
+        ORDER BY published_at DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(window_hours as i32)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// 按 ID 取去重候选（向量检索给出的近邻），只返回仍为主文章的行，顺序不定
+pub async fn list_canonical_by_ids(pool: &PgPool, ids: &[i64]) -> Result<Vec<ArticleRow>, sqlx::Error> {
+    sqlx::query_as::<_, ArticleRow>(
+        r#"
+        SELECT id::bigint AS id,
+               title,
+               url,
+               description,
+               language,
+               original_language,
+               source_domain,
+               published_at,
+               click_count::bigint AS click_count,
+               tags
+        FROM news.articles
+        WHERE id = ANY($1)
+          AND (canonical_id IS NULL OR canonical_id = id)
+        "#,
+    )
+    .bind(ids)
+    .fetch_all(pool)
+    .await
+}
+
+/// 新文章在去重中胜出（来源权重更高）时，将旧文章所在簇的 canonical 指向新文章，
+/// 并把旧文章作为新文章的一个来源记录下来。
+pub async fn supersede_article(
+    pool: &PgPool,
+    old_id: i64,
+    new_id: i64,
+    decision: &str,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET canonical_id = $2
+        WHERE id = $1 OR canonical_id = $1
+        "#,
+    )
+    .bind(old_id)
+    .bind(new_id)
+    .execute(tx.as_mut())
+    .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO news.article_sources (
+            article_id, feed_id, source_name, source_url, published_at, inserted_at, decision, confidence
+        )
+        SELECT $2, feed_id, source_domain, url, published_at, NOW(), $3, NULL
+        FROM news.articles
+        WHERE id = $1
+        ON CONFLICT (article_id, source_url) DO NOTHING
+        "#,
+    )
+    .bind(old_id)
+    .bind(new_id)
+    .bind(decision)
+    .execute(tx.as_mut())
+    .await?;
+
+    tx.commit().await
+}
+
+/// 删除 `ids` 前调用：其中仍有簇成员的主文章由一篇剩余成员接替（优先未隐藏、发布最早的），
+/// 其余成员与来源记录改挂到新主文章上。外键为 `ON DELETE SET NULL`，不处理时成员会各自变回主文章，
+/// 已被去重的转载重新出现在公开列表中。返回接替的簇数。
+pub async fn promote_cluster_heirs(
+    tx: &mut Transaction<'_, Postgres>,
+    ids: &[i64],
+) -> Result<u64, sqlx::Error> {
+    if ids.is_empty() {
+        return Ok(0);
+    }
+    let heirs = sqlx::query(
+        r#"
+        SELECT DISTINCT ON (m.canonical_id)
+               m.canonical_id::bigint AS old_id,
+               m.id::bigint AS new_id
+        FROM news.articles m
+        WHERE m.canonical_id = ANY($1)
+          AND m.id <> m.canonical_id
+          AND NOT (m.id = ANY($1))
+        ORDER BY m.canonical_id, (m.hidden_at IS NOT NULL), m.published_at, m.id
+        "#,
+    )
+    .bind(ids)
+    .fetch_all(tx.as_mut())
+    .await?;
+    if heirs.is_empty() {
+        return Ok(0);
+    }
+    let old_ids: Vec<i64> = heirs.iter().map(|row| row.get("old_id")).collect();
+    let new_ids: Vec<i64> = heirs.iter().map(|row| row.get("new_id")).collect();
+
+    sqlx::query(
+        r#"
+        UPDATE news.articles a
+        SET canonical_id = h.new_id
+        FROM unnest($1::bigint[], $2::bigint[]) AS h(old_id, new_id)
+        WHERE a.canonical_id = h.old_id
+          AND NOT (a.id = ANY($3))
+        "#,
+    )
+    .bind(&old_ids)
+    .bind(&new_ids)
+    .bind(ids)
+    .execute(tx.as_mut())
+    .await?;
+
+    // 被删除文章自身的来源记录随其删除，新主文章已有的同 URL 记录保留；
+    // 新主文章自身的那条改记为 primary
+    sqlx::query(
+        r#"
+        UPDATE news.article_sources s
+        SET article_id = h.new_id,
+            decision = CASE WHEN s.source_url = heir.url THEN 'primary' ELSE s.decision END,
+            confidence = CASE WHEN s.source_url = heir.url THEN NULL ELSE s.confidence END
+        FROM unnest($1::bigint[], $2::bigint[]) AS h(old_id, new_id),
+             news.articles old,
+             news.articles heir
+        WHERE s.article_id = h.old_id
+          AND old.id = h.old_id
+          AND heir.id = h.new_id
+          AND s.source_url <> old.url
+          AND NOT EXISTS (
+              SELECT 1 FROM news.article_sources x
+              WHERE x.article_id = h.new_id AND x.source_url = s.source_url
+          )
+        "#,
+    )
+    .bind(&old_ids)
+    .bind(&new_ids)
+    .execute(tx.as_mut())
+    .await?;
+
+    Ok(heirs.len() as u64)
+}
+
+/// 文章所在簇的主文章 ID（未归并时为自身）
+pub async fn find_canonical_id(pool: &PgPool, id: i64) -> Result<Option<i64>, sqlx::Error> {
+    let row = sqlx::query(
+        r#"
+        SELECT COALESCE(canonical_id, id)::bigint AS canonical_id
+        FROM news.articles
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|r| r.get::<i64, _>("canonical_id")))
+}
+
+/// 将文章从所在簇中拆出：恢复为独立主文章，并移除主文章上对应的来源记录。
+pub async fn unmerge_article(pool: &PgPool, id: i64, canonical_id: i64) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET canonical_id = id
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .execute(tx.as_mut())
+    .await?;
+
+    sqlx::query(
+        r#"
+        DELETE FROM news.article_sources
+        WHERE article_id = $2
+          AND source_url = (SELECT url FROM news.articles WHERE id = $1)
+        "#,
+    )
+    .bind(id)
+    .bind(canonical_id)
+    .execute(tx.as_mut())
+    .await?;
+
+    tx.commit().await
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ClusterRow {
+    pub canonical_id: i64,
+    pub title: String,
+    pub url: String,
+    pub source_domain: String,
+    pub published_at: DateTime<Utc>,
+    pub hidden: bool,
+    /// 簇内文章数（含主文章）
+    pub size: i64,
+    pub source_domains: Vec<String>,
+    /// 满足条件的簇总数（窗口函数，每行相同）
+    pub total: i64,
+}
+
+pub struct ClusterListArgs {
+    pub since: DateTime<Utc>,
+    pub min_size: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+/// 主文章发布于 `since` 之后、簇内文章数不少于 `min_size` 的簇，按簇大小与发布时间倒序
+pub async fn list_clusters(pool: &PgPool, args: &ClusterListArgs) -> Result<Vec<ClusterRow>, sqlx::Error> {
+    sqlx::query_as::<_, ClusterRow>(
+        r#"
+        SELECT c.id::bigint AS canonical_id,
+               c.title,
+               c.url,
+               c.source_domain,
+               c.published_at,
+               (c.hidden_at IS NOT NULL) AS hidden,
+               k.size,
+               k.source_domains,
+               COUNT(*) OVER ()::bigint AS total
+        FROM news.articles c
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*)::bigint AS size,
+                   array_agg(DISTINCT m.source_domain ORDER BY m.source_domain) AS source_domains
+            FROM news.articles m
+            WHERE m.canonical_id = c.id
+        ) k
+        WHERE (c.canonical_id IS NULL OR c.canonical_id = c.id)
+          AND c.published_at >= $1
+          AND k.size >= $2
+        ORDER BY k.size DESC, c.published_at DESC, c.id DESC
+        LIMIT $3
+        OFFSET $4
+        "#,
+    )
+    .bind(args.since)
+    .bind(args.min_size)
+    .bind(args.limit)
+    .bind(args.offset)
+    .fetch_all(pool)
+    .await
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ClusterMemberRow {
+    pub id: i64,
+    pub feed_id: Option<i64>,
+    pub title: String,
+    pub url: String,
+    pub source_domain: String,
+    pub published_at: DateTime<Utc>,
+    pub fetched_at: DateTime<Utc>,
+    pub hidden: bool,
+    /// 成员并入时主文章上记录的判重依据（来源记录中同 URL 的一条）
+    pub decision: Option<String>,
+    pub confidence: Option<f32>,
+}
+
+/// 簇内全部文章（含主文章），主文章在前，其余按发布时间排序
+pub async fn list_cluster_members(
+    pool: &PgPool,
+    canonical_id: i64,
+) -> Result<Vec<ClusterMemberRow>, sqlx::Error> {
+    sqlx::query_as::<_, ClusterMemberRow>(
+        r#"
+        SELECT a.id::bigint AS id,
+               a.feed_id,
+               a.title,
+               a.url,
+               a.source_domain,
+               a.published_at,
+               a.fetched_at,
+               (a.hidden_at IS NOT NULL) AS hidden,
+               s.decision,
+               s.confidence
+        FROM news.articles a
+        LEFT JOIN news.article_sources s
+          ON s.article_id = $1 AND s.source_url = a.url
+        WHERE a.id = $1 OR a.canonical_id = $1
+        ORDER BY (a.id = $1) DESC, a.published_at, a.id
+        "#,
+    )
+    .bind(canonical_id)
+    .fetch_all(pool)
+    .await
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ModerationRow {
+    pub id: i64,
+    pub article_id: i64,
+    pub action: String,
+    pub reason: Option<String>,
+    pub actor: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 文章的隐藏状态；`None` 表示文章不存在
+pub async fn find_hidden_at(
+    pool: &PgPool,
+    id: i64,
+) -> Result<Option<Option<DateTime<Utc>>>, sqlx::Error> {
+    sqlx::query_scalar::<_, Option<DateTime<Utc>>>(
+        r#"
+        SELECT hidden_at
+        FROM news.articles
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// 设置或清除文章的隐藏状态，并在同一事务内写入审计记录（action 为 hide / unhide）。
+pub async fn set_hidden(
+    pool: &PgPool,
+    id: i64,
+    hidden: bool,
+    reason: Option<&str>,
+    actor: &str,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET hidden_at = CASE WHEN $2 THEN NOW() ELSE NULL END,
+            hidden_reason = CASE WHEN $2 THEN $3 ELSE NULL END
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(hidden)
+    .bind(reason)
+    .execute(tx.as_mut())
+    .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO news.article_moderation (article_id, action, reason, actor)
+        VALUES ($1, $2, $3, $4)
+        "#,
+    )
+    .bind(id)
+    .bind(if hidden { "hide" } else { "unhide" })
+    .bind(reason)
+    .bind(actor)
+    .execute(tx.as_mut())
+    .await?;
+
+    tx.commit().await
+}
+
+/// 管理员修正的字段；外层 None 表示不修改，可空字段内层 None 表示清空
+#[derive(Debug, Default)]
+pub struct ArticleEdit<'a> {
+    pub title: Option<&'a str>,
+    /// 随标题一起更新，保持近似标题检测与新标题一致
+    pub title_simhash: Option<i64>,
+    pub description: Option<Option<&'a str>>,
+    pub language: Option<Option<&'a str>>,
+    pub original_language: Option<Option<&'a str>>,
+}
+
+/// 更新文章字段并写入一条 edit 审计记录，reason 为被修改的字段列表；返回是否命中文章
+pub async fn update_article(
+    pool: &PgPool,
+    id: i64,
+    edit: &ArticleEdit<'_>,
+    changed: &str,
+    actor: &str,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let result = sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET title = COALESCE($2, title),
+            title_simhash = CASE WHEN $2 IS NULL THEN title_simhash ELSE $3 END,
+            description = CASE WHEN $4 THEN $5 ELSE description END,
+            language = CASE WHEN $6 THEN $7 ELSE language END,
+            original_language = CASE WHEN $8 THEN $9 ELSE original_language END
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(edit.title)
+    .bind(edit.title_simhash)
+    .bind(edit.description.is_some())
+    .bind(edit.description.flatten())
+    .bind(edit.language.is_some())
+    .bind(edit.language.flatten())
+    .bind(edit.original_language.is_some())
+    .bind(edit.original_language.flatten())
+    .execute(tx.as_mut())
+    .await?;
+    if result.rows_affected() == 0 {
+        tx.rollback().await?;
+        return Ok(false);
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO news.article_moderation (article_id, action, reason, actor)
+        VALUES ($1, 'edit', $2, $3)
+        "#,
+    )
+    .bind(id)
+    .bind(changed)
+    .bind(actor)
+    .execute(tx.as_mut())
+    .await?;
+
+    tx.commit().await?;
+    Ok(true)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct DeletedArticleRow {
+    pub source_domain: String,
+    pub url: String,
+}
+
+/// 删除单篇文章：若它是簇主文章，先把主文章身份交给簇内下一篇，再删除本身；
+/// 来源、正文、富化与审计记录随外键级联删除
+pub async fn delete_article(
+    pool: &PgPool,
+    id: i64,
+) -> Result<Option<DeletedArticleRow>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    promote_cluster_heirs(&mut tx, &[id]).await?;
+
+    let deleted = sqlx::query_as::<_, DeletedArticleRow>(
+        r#"
+        DELETE FROM news.articles
+        WHERE id = $1
+        RETURNING source_domain, url
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(tx.as_mut())
+    .await?;
+
+    tx.commit().await?;
+    Ok(deleted)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct PendingArticleRow {
+    pub id: i64,
+    pub feed_id: Option<i64>,
+    pub title: String,
+    pub url: String,
+    pub description: Option<String>,
+    pub language: Option<String>,
+    pub original_language: Option<String>,
+    pub source_domain: String,
+    pub published_at: DateTime<Utc>,
+    pub fetched_at: DateTime<Utc>,
+    /// 满足条件的待审核文章总数（窗口函数，每行相同）
+    pub total: i64,
+}
+
+/// 以 `hidden_reason` 标记的待审核文章，按入库时间升序（先进先审）
+pub async fn list_pending_review(
+    pool: &PgPool,
+    reason: &str,
+    feed_id: Option<i64>,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<PendingArticleRow>, sqlx::Error> {
+    sqlx::query_as::<_, PendingArticleRow>(
+        r#"
+        SELECT id::bigint AS id,
+               feed_id,
                title,
                url,
                description,
                language,
+               original_language,
                source_domain,
                published_at,
-               click_count::bigint AS click_count
+               fetched_at,
+               COUNT(*) OVER ()::bigint AS total
+        FROM news.articles
+        WHERE hidden_reason = $1
+          AND hidden_at IS NOT NULL
+          AND ($2::bigint IS NULL OR feed_id = $2)
+        ORDER BY fetched_at, id
+        LIMIT $3
+        OFFSET $4
+        "#,
+    )
+    .bind(reason)
+    .bind(feed_id)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await
+}
+
+/// 批准待审核文章：清除隐藏标记并写入 approve 审核记录，返回实际批准的文章 ID。
+/// `ids` 与 `feed_id` 同时为空时不做任何修改
+pub async fn approve_pending(
+    pool: &PgPool,
+    reason: &str,
+    ids: Option<&[i64]>,
+    feed_id: Option<i64>,
+    actor: &str,
+) -> Result<Vec<i64>, sqlx::Error> {
+    if ids.is_none() && feed_id.is_none() {
+        return Ok(Vec::new());
+    }
+    let mut tx = pool.begin().await?;
+
+    let approved: Vec<i64> = sqlx::query_scalar(
+        r#"
+        UPDATE news.articles
+        SET hidden_at = NULL,
+            hidden_reason = NULL
+        WHERE hidden_reason = $1
+          AND hidden_at IS NOT NULL
+          AND ($2::bigint[] IS NULL OR id = ANY($2))
+          AND ($3::bigint IS NULL OR feed_id = $3)
+        RETURNING id::bigint
+        "#,
+    )
+    .bind(reason)
+    .bind(ids)
+    .bind(feed_id)
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    if !approved.is_empty() {
+        sqlx::query(
+            r#"
+            INSERT INTO news.article_moderation (article_id, action, reason, actor)
+            SELECT id, 'approve', NULL, $2
+            FROM unnest($1::bigint[]) AS t(id)
+            "#,
+        )
+        .bind(&approved)
+        .bind(actor)
+        .execute(tx.as_mut())
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(approved)
+}
+
+/// 批量操作的目标：显式 ID 列表，或按发布时间范围与来源域名筛选（含已归并、已隐藏的文章）
+pub enum BulkTarget<'a> {
+    Ids(&'a [i64]),
+    Filter {
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        source_domain: Option<&'a str>,
+    },
+}
+
+/// 在事务内锁定批量操作的目标文章，按 ID 升序返回；最多取 `limit` 条，调用方据此判断是否超出上限
+pub async fn lock_bulk_targets(
+    tx: &mut Transaction<'_, Postgres>,
+    target: &BulkTarget<'_>,
+    limit: i64,
+) -> Result<Vec<i64>, sqlx::Error> {
+    match target {
+        BulkTarget::Ids(ids) => {
+            sqlx::query_scalar(
+                r#"
+                SELECT id::bigint
+                FROM news.articles
+                WHERE id = ANY($1)
+                ORDER BY id
+                LIMIT $2
+                FOR UPDATE
+                "#,
+            )
+            .bind(*ids)
+            .bind(limit)
+            .fetch_all(tx.as_mut())
+            .await
+        }
+        BulkTarget::Filter {
+            from,
+            to,
+            source_domain,
+        } => {
+            sqlx::query_scalar(
+                r#"
+                SELECT id::bigint
+                FROM news.articles
+                WHERE ($1::timestamptz IS NULL OR published_at >= $1)
+                  AND ($2::timestamptz IS NULL OR published_at <= $2)
+                  AND ($3::text IS NULL OR source_domain = $3)
+                ORDER BY id
+                LIMIT $4
+                FOR UPDATE
+                "#,
+            )
+            .bind(*from)
+            .bind(*to)
+            .bind(*source_domain)
+            .bind(limit)
+            .fetch_all(tx.as_mut())
+            .await
+        }
+    }
+}
+
+/// 批量删除：先为被删除的簇主文章选出接替者（见 `promote_cluster_heirs`），再删除
+pub async fn delete_ids(
+    tx: &mut Transaction<'_, Postgres>,
+    ids: &[i64],
+) -> Result<u64, sqlx::Error> {
+    promote_cluster_heirs(tx, ids).await?;
+    let result = sqlx::query(
+        r#"
+        DELETE FROM news.articles
+        WHERE id = ANY($1)
+        "#,
+    )
+    .bind(ids)
+    .execute(tx.as_mut())
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// 重新标记为待翻译并清零重试次数，由补偿翻译任务按当前标题与摘要重新翻译；
+/// 已是目标语言（已翻译）的文章跳过
+pub async fn requeue_translation(
+    tx: &mut Transaction<'_, Postgres>,
+    ids: &[i64],
+    target_language: &str,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET translation_pending = TRUE,
+            translation_attempts = 0
+        WHERE id = ANY($1)
+          AND language IS DISTINCT FROM $2
+        "#,
+    )
+    .bind(ids)
+    .bind(target_language)
+    .execute(tx.as_mut())
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// 把文章改挂到另一个来源域名（影响来源权重、每日上限与来源页统计）
+pub async fn set_source_domain(
+    tx: &mut Transaction<'_, Postgres>,
+    ids: &[i64],
+    source_domain: &str,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET source_domain = $2
+        WHERE id = ANY($1)
+          AND source_domain <> $2
+        "#,
+    )
+    .bind(ids)
+    .bind(source_domain)
+    .execute(tx.as_mut())
+    .await?;
+    Ok(result.rows_affected())
+}
+
+pub async fn list_moderation(pool: &PgPool, id: i64) -> Result<Vec<ModerationRow>, sqlx::Error> {
+    sqlx::query_as::<_, ModerationRow>(
+        r#"
+        SELECT id::bigint AS id,
+               article_id::bigint AS article_id,
+               action,
+               reason,
+               actor,
+               created_at
+        FROM news.article_moderation
+        WHERE article_id = $1
+        ORDER BY created_at DESC, id DESC
+        "#,
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct FeedSampleRow {
+    pub title: String,
+    pub url: String,
+}
+
+/// 某个 feed 最近入库的条目（标题与链接），用于生成过滤规则建议。
+pub async fn list_feed_samples(
+    pool: &PgPool,
+    feed_id: i64,
+    limit: i64,
+) -> Result<Vec<FeedSampleRow>, sqlx::Error> {
+    sqlx::query_as::<_, FeedSampleRow>(
+        r#"
+        SELECT title, url
         FROM news.articles
+        WHERE feed_id = $1
         ORDER BY published_at DESC
-        LIMIT $1
+        LIMIT $2
+        "#,
+    )
+    .bind(feed_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct LanguageBackfillRow {
+    pub id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    /// 所属 feed 的语言提示，有则直接采用
+    pub language_hint: Option<String>,
+}
+
+// 语言列待补全的文章，按 ID 游标分批读取，检测不出语言的行不会被重复扫描
+pub async fn list_language_backfill_batch(
+    pool: &PgPool,
+    after_id: i64,
+    limit: i64,
+) -> Result<Vec<LanguageBackfillRow>, sqlx::Error> {
+    sqlx::query_as::<_, LanguageBackfillRow>(
+        r#"
+        SELECT a.id, a.title, a.description, f.language_hint
+        FROM news.articles a
+        LEFT JOIN news.feeds f ON f.id = a.feed_id
+        WHERE a.id > $1
+          AND (a.language IS NULL OR a.original_language IS NULL)
+        ORDER BY a.id
+        LIMIT $2
+        "#,
+    )
+    .bind(after_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+// 仅填充为空的列：已翻译文章的 language（zh-CN）保持不变
+pub async fn fill_languages(
+    pool: &PgPool,
+    ids: &[i64],
+    languages: &[String],
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        UPDATE news.articles a
+        SET language = COALESCE(a.language, v.lang),
+            original_language = COALESCE(a.original_language, v.lang)
+        FROM UNNEST($1::bigint[], $2::text[]) AS v(id, lang)
+        WHERE a.id = v.id
+        "#,
+    )
+    .bind(ids)
+    .bind(languages)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct TitleBackfillRow {
+    pub id: i64,
+    pub title: String,
+}
+
+// 尚无标题 SimHash 的文章，按 ID 游标分批读取
+pub async fn list_simhash_backfill_batch(
+    pool: &PgPool,
+    after_id: i64,
+    limit: i64,
+) -> Result<Vec<TitleBackfillRow>, sqlx::Error> {
+    sqlx::query_as::<_, TitleBackfillRow>(
+        r#"
+        SELECT id, title
+        FROM news.articles
+        WHERE id > $1
+          AND title_simhash IS NULL
+        ORDER BY id
+        LIMIT $2
         "#,
     )
+    .bind(after_id)
     .bind(limit)
     .fetch_all(pool)
     .await
 }
 
+pub async fn fill_simhashes(
+    pool: &PgPool,
+    ids: &[i64],
+    simhashes: &[i64],
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        UPDATE news.articles a
+        SET title_simhash = v.simhash
+        FROM UNNEST($1::bigint[], $2::bigint[]) AS v(id, simhash)
+        WHERE a.id = v.id
+          AND a.title_simhash IS NULL
+        "#,
+    )
+    .bind(ids)
+    .bind(simhashes)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// 过滤条件删除的文章在隔离表中保留的天数
+pub const FILTER_QUARANTINE_DAYS: i32 = 7;
+
+// 删除不满足过滤条件的文章；被删除的行连同条件文本一起写入 news.filtered_articles，
+// 条件写错时可在保留期内恢复
 pub async fn apply_filter_condition(
     pool: &PgPool,
     feed_id: i64,
     condition: &str,
 ) -> Result<u64, sqlx::Error> {
-    let sql = format!(
-        "DELETE FROM news.articles WHERE feed_id = $1 AND NOT ({})",
-        condition
-    );
-    let result = sqlx::query(&sql).bind(feed_id).execute(pool).await?;
+    let mut tx = pool.begin().await?;
+
+    let sql = format!("SELECT id::bigint FROM news.articles WHERE feed_id = $1 AND NOT ({condition})");
+    let ids: Vec<i64> = sqlx::query_scalar(&sql)
+        .bind(feed_id)
+        .fetch_all(tx.as_mut())
+        .await?;
+    if ids.is_empty() {
+        return Ok(0);
+    }
+    promote_cluster_heirs(&mut tx, &ids).await?;
+
+    let result = sqlx::query(
+        r#"
+        WITH removed AS (
+            DELETE FROM news.articles
+            WHERE id = ANY($4)
+            RETURNING *
+        )
+        INSERT INTO news.filtered_articles (article_id, feed_id, condition, payload, expires_at)
+        SELECT removed.id, $1, $2, to_jsonb(removed), NOW() + make_interval(days => $3)
+        FROM removed
+        "#,
+    )
+    .bind(feed_id)
+    .bind(condition)
+    .bind(FILTER_QUARANTINE_DAYS)
+    .bind(&ids)
+    .execute(tx.as_mut())
+    .await?;
+
+    tx.commit().await?;
     Ok(result.rows_affected())
 }
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct FilteredArticleRow {
+    pub id: i64,
+    pub article_id: i64,
+    pub feed_id: i64,
+    pub title: String,
+    pub url: String,
+    pub condition: String,
+    pub filtered_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub async fn list_filtered(
+    pool: &PgPool,
+    feed_id: i64,
+    limit: i64,
+) -> Result<Vec<FilteredArticleRow>, sqlx::Error> {
+    sqlx::query_as::<_, FilteredArticleRow>(
+        r#"
+        SELECT id,
+               article_id,
+               feed_id,
+               payload->>'title' AS title,
+               payload->>'url' AS url,
+               condition,
+               filtered_at,
+               expires_at
+        FROM news.filtered_articles
+        WHERE feed_id = $1
+          AND expires_at > NOW()
+        ORDER BY filtered_at DESC, id DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(feed_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// 将隔离记录还原为文章（保留原 ID），返回 (恢复数, 跳过数)。
+/// 同 feed 下已存在相同 URL（之后又被抓取入库）的记录会跳过；原主文章已不存在时改为指向自身。
+pub async fn restore_filtered(
+    pool: &PgPool,
+    feed_id: i64,
+    ids: Option<&[i64]>,
+) -> Result<(u64, u64), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let restored = sqlx::query(
+        r#"
+        INSERT INTO news.articles
+        SELECT r.*
+        FROM news.filtered_articles q,
+             jsonb_populate_record(
+                 NULL::news.articles,
+                 -- 隔离早于 enrichment_attempts 列加入时，快照中缺少该 NOT NULL 列
+                 '{"enrichment_attempts": 0}'::jsonb || q.payload || CASE
+                     WHEN EXISTS (
+                         SELECT 1 FROM news.articles c
+                         WHERE c.id = (q.payload->>'canonical_id')::bigint
+                     ) THEN '{}'::jsonb
+                     ELSE jsonb_build_object('canonical_id', q.payload->'id')
+                 END
+             ) r
+        WHERE q.feed_id = $1
+          AND q.expires_at > NOW()
+          AND ($2::bigint[] IS NULL OR q.id = ANY($2))
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(feed_id)
+    .bind(ids)
+    .execute(tx.as_mut())
+    .await?
+    .rows_affected();
+
+    let consumed = sqlx::query(
+        r#"
+        DELETE FROM news.filtered_articles
+        WHERE feed_id = $1
+          AND expires_at > NOW()
+          AND ($2::bigint[] IS NULL OR id = ANY($2))
+        "#,
+    )
+    .bind(feed_id)
+    .bind(ids)
+    .execute(tx.as_mut())
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+    Ok((restored, consumed.saturating_sub(restored)))
+}
@@ -0,0 +1,159 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct BackupRow {
+    pub id: i64,
+    pub trigger: String,
+    pub mode: String,
+    pub status: String,
+    pub location: String,
+    pub size_bytes: Option<i64>,
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// 运维指标用：最近一次结束的备份与最近一次成功的备份
+#[derive(Debug, sqlx::FromRow)]
+pub struct BackupStatusRow {
+    pub last_status: Option<String>,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_success_bytes: Option<i64>,
+}
+
+const BACKUP_COLUMNS: &str =
+    "id, trigger, mode, status, location, size_bytes, error, started_at, finished_at";
+
+/// 登记一次进行中的备份；已有备份在进行时返回 None
+pub async fn start(
+    pool: &PgPool,
+    trigger: &str,
+    mode: &str,
+    location: &str,
+) -> Result<Option<BackupRow>, sqlx::Error> {
+    let sql = format!(
+        r#"
+        INSERT INTO news.backups (trigger, mode, location)
+        VALUES ($1, $2, $3)
+        ON CONFLICT ((true)) WHERE status = 'running' DO NOTHING
+        RETURNING {BACKUP_COLUMNS}
+        "#
+    );
+    sqlx::query_as::<_, BackupRow>(&sql)
+        .bind(trigger)
+        .bind(mode)
+        .bind(location)
+        .fetch_optional(pool)
+        .await
+}
+
+pub async fn finish(
+    pool: &PgPool,
+    id: i64,
+    status: &str,
+    size_bytes: Option<i64>,
+    error: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE news.backups
+        SET status = $2,
+            size_bytes = $3,
+            error = $4,
+            finished_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(status)
+    .bind(size_bytes)
+    .bind(error)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 进程中途退出会留下永远 running 的记录：超过时限的视为失败，返回处理条数
+pub async fn fail_stale(pool: &PgPool, older_than_secs: i64) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        UPDATE news.backups
+        SET status = 'failed',
+            error = 'interrupted',
+            finished_at = NOW()
+        WHERE status = 'running'
+          AND started_at < NOW() - make_interval(secs => $1)
+        "#,
+    )
+    .bind(older_than_secs as f64)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+pub async fn list_recent(pool: &PgPool, limit: i64) -> Result<Vec<BackupRow>, sqlx::Error> {
+    let sql = format!(
+        r#"
+        SELECT {BACKUP_COLUMNS}
+        FROM news.backups
+        ORDER BY started_at DESC, id DESC
+        LIMIT $1
+        "#
+    );
+    sqlx::query_as::<_, BackupRow>(&sql)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+}
+
+/// 最近一次按计划触发的备份的开始时间，用于判断下一次定时备份是否到期
+pub async fn last_scheduled_start(pool: &PgPool) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+    sqlx::query_scalar::<_, Option<DateTime<Utc>>>(
+        "SELECT MAX(started_at) FROM news.backups WHERE trigger = 'schedule'",
+    )
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn status(pool: &PgPool) -> Result<BackupStatusRow, sqlx::Error> {
+    sqlx::query_as::<_, BackupStatusRow>(
+        r#"
+        SELECT last.status AS last_status,
+               ok.finished_at AS last_success_at,
+               ok.size_bytes AS last_success_bytes
+        FROM (SELECT 1) one
+        LEFT JOIN LATERAL (
+            SELECT status, finished_at
+            FROM news.backups
+            WHERE finished_at IS NOT NULL
+            ORDER BY finished_at DESC
+            LIMIT 1
+        ) last ON TRUE
+        LEFT JOIN LATERAL (
+            SELECT finished_at, size_bytes
+            FROM news.backups
+            WHERE status = 'succeeded'
+            ORDER BY finished_at DESC
+            LIMIT 1
+        ) ok ON TRUE
+        "#,
+    )
+    .fetch_one(pool)
+    .await
+}
+
+/// COPY 导出的表：news schema 下的全部普通表
+pub async fn list_tables(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT table_name::text
+        FROM information_schema.tables
+        WHERE table_schema = 'news'
+          AND table_type = 'BASE TABLE'
+        ORDER BY table_name
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+}
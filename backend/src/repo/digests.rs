@@ -0,0 +1,246 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct DigestRow {
+    pub id: i64,
+    pub week_start: NaiveDate,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    pub emailed_at: Option<DateTime<Utc>>,
+    pub email_attempts: i32,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct DigestItemRow {
+    pub digest_id: i64,
+    pub rank: i32,
+    pub article_id: Option<i64>,
+    pub title: String,
+    pub url: String,
+    pub source_domain: String,
+    pub published_at: DateTime<Utc>,
+    pub click_count: i64,
+    pub cluster_size: i32,
+    pub score: f32,
+}
+
+/// 精选候选：时间段内发布、未隐藏的主文章
+#[derive(Debug, sqlx::FromRow)]
+pub struct DigestCandidateRow {
+    pub id: i64,
+    pub title: String,
+    pub url: String,
+    pub source_domain: String,
+    pub published_at: DateTime<Utc>,
+    pub click_count: i64,
+    pub cluster_size: i32,
+    pub score: f32,
+}
+
+/// 按「点击量 × 来源权重 + 每多一个来源加 `cluster_bonus`」排序取前 `limit` 篇，
+/// 来源数取自 `article_sources`（含首次入库的来源），无记录时按 1 计。
+pub async fn list_candidates(
+    pool: &PgPool,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    cluster_bonus: f32,
+    limit: i64,
+) -> Result<Vec<DigestCandidateRow>, sqlx::Error> {
+    sqlx::query_as::<_, DigestCandidateRow>(
+        r#"
+        SELECT id, title, url, source_domain, published_at, click_count, cluster_size,
+               (click_count * weight + $3 * (cluster_size - 1))::real AS score
+        FROM (
+            SELECT a.id::bigint AS id,
+                   a.title,
+                   a.url,
+                   a.source_domain,
+                   a.published_at,
+                   a.click_count::bigint AS click_count,
+                   COALESCE(w.weight, 1.0) AS weight,
+                   GREATEST(
+                       (SELECT COUNT(*) FROM news.article_sources s WHERE s.article_id = a.id),
+                       1
+                   )::int AS cluster_size
+            FROM news.articles a
+            LEFT JOIN news.source_weights w ON w.source_domain = a.source_domain
+            WHERE a.published_at >= $1
+              AND a.published_at < $2
+              AND (a.canonical_id IS NULL OR a.canonical_id = a.id)
+              AND a.hidden_at IS NULL
+        ) ranked
+        ORDER BY score DESC, cluster_size DESC, published_at DESC
+        LIMIT $4
+        "#,
+    )
+    .bind(start)
+    .bind(end)
+    .bind(cluster_bonus)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn find_by_week(pool: &PgPool, week_start: NaiveDate) -> Result<Option<DigestRow>, sqlx::Error> {
+    sqlx::query_as::<_, DigestRow>(
+        r#"
+        SELECT id, week_start, period_start, period_end, title, created_at, emailed_at, email_attempts
+        FROM news.digests
+        WHERE week_start = $1
+        "#,
+    )
+    .bind(week_start)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn list_recent(pool: &PgPool, limit: i64) -> Result<Vec<DigestRow>, sqlx::Error> {
+    sqlx::query_as::<_, DigestRow>(
+        r#"
+        SELECT id, week_start, period_start, period_end, title, created_at, emailed_at, email_attempts
+        FROM news.digests
+        ORDER BY week_start DESC
+        LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn list_items(pool: &PgPool, digest_ids: &[i64]) -> Result<Vec<DigestItemRow>, sqlx::Error> {
+    sqlx::query_as::<_, DigestItemRow>(
+        r#"
+        SELECT digest_id, rank, article_id, title, url, source_domain, published_at,
+               click_count, cluster_size, score
+        FROM news.digest_items
+        WHERE digest_id = ANY($1)
+        ORDER BY digest_id, rank
+        "#,
+    )
+    .bind(digest_ids)
+    .fetch_all(pool)
+    .await
+}
+
+/// 写入（或重新生成）某周的精选：同一周已存在时替换条目，邮件状态一并重置
+pub async fn replace_digest(
+    pool: &PgPool,
+    week_start: NaiveDate,
+    period_start: DateTime<Utc>,
+    period_end: DateTime<Utc>,
+    title: &str,
+    items: &[DigestCandidateRow],
+) -> Result<DigestRow, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let digest = sqlx::query_as::<_, DigestRow>(
+        r#"
+        INSERT INTO news.digests (week_start, period_start, period_end, title)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (week_start) DO UPDATE
+        SET period_start = EXCLUDED.period_start,
+            period_end = EXCLUDED.period_end,
+            title = EXCLUDED.title,
+            created_at = NOW(),
+            emailed_at = NULL,
+            email_attempts = 0
+        RETURNING id, week_start, period_start, period_end, title, created_at, emailed_at, email_attempts
+        "#,
+    )
+    .bind(week_start)
+    .bind(period_start)
+    .bind(period_end)
+    .bind(title)
+    .fetch_one(tx.as_mut())
+    .await?;
+
+    sqlx::query("DELETE FROM news.digest_items WHERE digest_id = $1")
+        .bind(digest.id)
+        .execute(tx.as_mut())
+        .await?;
+    insert_items(&mut tx, digest.id, items).await?;
+
+    tx.commit().await?;
+    Ok(digest)
+}
+
+async fn insert_items(
+    tx: &mut Transaction<'_, Postgres>,
+    digest_id: i64,
+    items: &[DigestCandidateRow],
+) -> Result<(), sqlx::Error> {
+    for (index, item) in items.iter().enumerate() {
+        sqlx::query(
+            r#"
+            INSERT INTO news.digest_items (
+                digest_id, rank, article_id, title, url, source_domain, published_at,
+                click_count, cluster_size, score
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(digest_id)
+        .bind(index as i32 + 1)
+        .bind(item.id)
+        .bind(&item.title)
+        .bind(&item.url)
+        .bind(&item.source_domain)
+        .bind(item.published_at)
+        .bind(item.click_count)
+        .bind(item.cluster_size)
+        .bind(item.score)
+        .execute(tx.as_mut())
+        .await?;
+    }
+    Ok(())
+}
+
+pub async fn mark_emailed(pool: &PgPool, id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE news.digests
+        SET emailed_at = NOW(),
+            email_attempts = email_attempts + 1
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn record_email_failure(pool: &PgPool, id: i64) -> Result<i32, sqlx::Error> {
+    sqlx::query_scalar::<_, i32>(
+        r#"
+        UPDATE news.digests
+        SET email_attempts = email_attempts + 1
+        WHERE id = $1
+        RETURNING email_attempts
+        "#,
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await
+}
+
+/// 最近 7 天生成、尚未成功发送邮件且重试次数未用尽的精选；
+/// 之后才配置 SMTP 时不会补发历史精选
+pub async fn list_pending_email(pool: &PgPool, max_attempts: i32) -> Result<Vec<DigestRow>, sqlx::Error> {
+    sqlx::query_as::<_, DigestRow>(
+        r#"
+        SELECT id, week_start, period_start, period_end, title, created_at, emailed_at, email_attempts
+        FROM news.digests
+        WHERE emailed_at IS NULL
+          AND email_attempts < $1
+          AND created_at >= NOW() - INTERVAL '7 days'
+        ORDER BY week_start
+        "#,
+    )
+    .bind(max_attempts)
+    .fetch_all(pool)
+    .await
+}
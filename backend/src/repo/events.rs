@@ -9,28 +9,35 @@ pub struct EventRecord {
     pub level: String,
     pub code: String,
     pub addition_info: Option<String>,
+    pub attrs: Option<serde_json::Value>,
 }
 
+/// 由 `ops::event_catalog::Event` 构造；`attrs` 为 JSON 对象文本
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewEvent {
     pub level: String,
     pub code: String,
     pub addition_info: Option<String>,
+    pub attrs: Option<String>,
 }
 
-pub async fn upsert_event(pool: &PgPool, ev: &NewEvent, _window_seconds: i64) -> Result<EventRecord, sqlx::Error> {
-    let inserted = sqlx::query(
+const EVENT_COLUMNS: &str = "id, ts, level, code, addition_info, attrs::text AS attrs";
+
+pub async fn insert_event(pool: &PgPool, ev: &NewEvent) -> Result<EventRecord, sqlx::Error> {
+    let sql = format!(
         r#"
-        INSERT INTO news.events (level, code, addition_info)
-        VALUES ($1,$2,$3)
-        RETURNING id, ts, level, code, addition_info
-        "#,
-    )
-    .bind(&ev.level)
-    .bind(&ev.code)
-    .bind(&ev.addition_info)
-    .fetch_one(pool)
-    .await?;
+        INSERT INTO news.events (level, code, addition_info, attrs)
+        VALUES ($1, $2, $3, $4::jsonb)
+        RETURNING {EVENT_COLUMNS}
+        "#
+    );
+    let inserted = sqlx::query(&sql)
+        .bind(&ev.level)
+        .bind(&ev.code)
+        .bind(&ev.addition_info)
+        .bind(&ev.attrs)
+        .fetch_one(pool)
+        .await?;
     Ok(row_to_record(inserted))
 }
 
@@ -41,6 +48,9 @@ fn row_to_record(row: sqlx::postgres::PgRow) -> EventRecord {
         level: row.get("level"),
         code: row.get("code"),
         addition_info: row.get("addition_info"),
+        attrs: row
+            .get::<Option<String>, _>("attrs")
+            .and_then(|text| serde_json::from_str(&text).ok()),
     }
 }
 
@@ -52,14 +62,55 @@ pub struct ListParams {
     pub from: Option<DateTime<Utc>>,
     pub to: Option<DateTime<Utc>>,
     pub since_id: Option<i64>,
+    /// 游标：只取 id 小于该值的事件，取值为上一页的 `next_before_id`
+    pub before_id: Option<i64>,
+    /// 在事件码、说明文字与 attrs 中做不区分大小写的子串匹配
+    pub q: Option<String>,
     pub limit: Option<i64>,
 }
 
 pub async fn list_events(pool: &PgPool, params: &ListParams) -> Result<Vec<EventRecord>, sqlx::Error> {
-    let mut qb = QueryBuilder::<Postgres>::new(
-        "SELECT id, ts, level, code, addition_info FROM news.events WHERE 1=1",
-    );
+    let mut qb = QueryBuilder::<Postgres>::new(format!(
+        "SELECT {EVENT_COLUMNS} FROM news.events WHERE 1=1"
+    ));
+    push_filters(&mut qb, params);
+    if let Some(since_id) = &params.since_id {
+        qb.push(" AND id > ").push_bind(since_id);
+    }
+    if let Some(before_id) = &params.before_id {
+        qb.push(" AND id < ").push_bind(before_id);
+    }
+
+    // id 随写入递增，与 ts 同序，按主键倒序才能用 before_id 稳定翻页
+    qb.push(" ORDER BY id DESC LIMIT ")
+        .push_bind(params.limit.unwrap_or(50).clamp(1, 200));
+
+    let rows = qb.build().fetch_all(pool).await?;
+    Ok(rows.into_iter().map(row_to_record).collect())
+}
+
+/// 导出用的分页读取：按 id 升序、从 `after_id` 之后取一批，筛选条件与 `list_events` 一致（忽略 limit）。
+pub async fn export_batch(
+    pool: &PgPool,
+    params: &ListParams,
+    after_id: Option<i64>,
+    batch_size: i64,
+) -> Result<Vec<EventRecord>, sqlx::Error> {
+    let mut qb = QueryBuilder::<Postgres>::new(format!(
+        "SELECT {EVENT_COLUMNS} FROM news.events WHERE 1=1"
+    ));
+    push_filters(&mut qb, params);
+    if let Some(after) = after_id.or(params.since_id) {
+        qb.push(" AND id > ").push_bind(after);
+    }
+
+    qb.push(" ORDER BY id ASC LIMIT ").push_bind(batch_size);
+
+    let rows = qb.build().fetch_all(pool).await?;
+    Ok(rows.into_iter().map(row_to_record).collect())
+}
 
+fn push_filters<'a>(qb: &mut QueryBuilder<'a, Postgres>, params: &'a ListParams) {
     if let Some(level) = &params.level {
         qb.push(" AND level = ").push_bind(level);
     }
@@ -67,7 +118,8 @@ pub async fn list_events(pool: &PgPool, params: &ListParams) -> Result<Vec<Event
         qb.push(" AND code = ").push_bind(code);
     }
     if let Some(source) = &params.source {
-        qb.push(" AND source = ").push_bind(source);
+        // 来源域名记录在 attrs 中，仅 feed 与文章相关的事件携带
+        qb.push(" AND attrs->>'source_domain' = ").push_bind(source);
     }
     if let Some(from) = &params.from {
         qb.push(" AND ts >= ").push_bind(from);
@@ -75,15 +127,70 @@ pub async fn list_events(pool: &PgPool, params: &ListParams) -> Result<Vec<Event
     if let Some(to) = &params.to {
         qb.push(" AND ts <= ").push_bind(to);
     }
-    if let Some(since_id) = &params.since_id {
-        qb.push(" AND id > ").push_bind(since_id);
+    if let Some(q) = &params.q {
+        let pattern = format!("%{q}%");
+        qb.push(" AND (code ILIKE ")
+            .push_bind(pattern.clone())
+            .push(" OR addition_info ILIKE ")
+            .push_bind(pattern.clone())
+            .push(" OR attrs::text ILIKE ")
+            .push_bind(pattern)
+            .push(")");
     }
+}
 
-    qb.push(" ORDER BY ts DESC LIMIT ")
-        .push_bind(params.limit.unwrap_or(50).clamp(1, 200));
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct EventCodeCount {
+    pub code: String,
+    pub count: i64,
+    /// 该事件码下最严重的级别
+    pub level: String,
+    pub last_ts: DateTime<Utc>,
+}
 
-    let rows = qb.build().fetch_all(pool).await?;
-    Ok(rows.into_iter().map(row_to_record).collect())
+/// 按事件码分组计数，筛选条件与 `list_events` 一致（忽略游标与 limit），按数量降序
+pub async fn count_by_code(pool: &PgPool, params: &ListParams) -> Result<Vec<EventCodeCount>, sqlx::Error> {
+    let mut qb = QueryBuilder::<Postgres>::new(
+        r#"
+        SELECT code,
+               COUNT(*) AS count,
+               CASE MAX(CASE level WHEN 'error' THEN 2 WHEN 'warn' THEN 1 ELSE 0 END)
+                 WHEN 2 THEN 'error' WHEN 1 THEN 'warn' ELSE 'info'
+               END AS level,
+               MAX(ts) AS last_ts
+        FROM news.events
+        WHERE 1=1
+        "#,
+    );
+    push_filters(&mut qb, params);
+    qb.push(" GROUP BY code ORDER BY count DESC, code");
+
+    qb.build_query_as::<EventCodeCount>().fetch_all(pool).await
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct EventCountRow {
+    pub level: String,
+    pub code: String,
+    pub count: i64,
+    pub last_ts: DateTime<Utc>,
+}
+
+/// 最近 `window_secs` 秒内 warn/error 事件按级别与类型的计数及最近一次时间
+pub async fn count_recent(pool: &PgPool, window_secs: i64) -> Result<Vec<EventCountRow>, sqlx::Error> {
+    sqlx::query_as::<_, EventCountRow>(
+        r#"
+        SELECT level, code, COUNT(*) AS count, MAX(ts) AS last_ts
+        FROM news.events
+        WHERE ts > NOW() - make_interval(secs => $1)
+          AND level IN ('warn', 'error')
+        GROUP BY level, code
+        ORDER BY level, code
+        "#,
+    )
+    .bind(window_secs as f64)
+    .fetch_all(pool)
+    .await
 }
 
 // Deletion API removed per read-only alerts design
@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use sqlx::{postgres::PgQueryResult, PgConnection, PgPool, Postgres, Transaction};
 
+use super::fetcher_instances;
+
 #[derive(Debug, sqlx::FromRow)]
 pub struct FeedRow {
     pub id: i64,
@@ -11,9 +13,27 @@ pub struct FeedRow {
     pub enabled: bool,
     pub fetch_interval_seconds: i32,
     pub filter_condition: Option<String>,
+    pub schedule_cron: Option<String>,
+    pub ignore_conditional: bool,
+    pub date_timezone: Option<String>,
+    pub date_format: Option<String>,
+    /// 已知且固定的原文语种，设置后跳过语种检测
+    pub language_hint: Option<String>,
+    /// 新文章以待审核状态入库，管理员批准后才公开
+    pub require_review: bool,
+    /// JSONB 文本，见 `util::feed_headers`
+    pub request_headers: Option<String>,
+    pub muted_until: Option<DateTime<Utc>>,
+    pub auto_disabled_at: Option<DateTime<Utc>>,
+    pub last_etag: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
     pub last_fetch_at: Option<DateTime<Utc>>,
     pub last_fetch_status: Option<i16>,
     pub fail_count: i32,
+    /// 最近一次失败的分类（见 `fetcher::failure::FetchErrorKind`），成功后清空
+    pub last_error_kind: Option<String>,
+    pub last_error: Option<String>,
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -22,7 +42,40 @@ pub struct DueFeedRow {
     pub url: String,
     pub source_domain: String,
     pub last_etag: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub ignore_conditional: bool,
+    pub date_timezone: Option<String>,
+    pub date_format: Option<String>,
+    pub language_hint: Option<String>,
+    pub require_review: bool,
+    pub request_headers: Option<String>,
     pub filter_condition: Option<String>,
+    pub schedule_cron: Option<String>,
+    pub last_fetch_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct QueueFeedRow {
+    pub id: i64,
+    pub url: String,
+    pub source_domain: String,
+    pub last_fetch_at: Option<DateTime<Utc>>,
+    pub last_fetch_status: Option<i16>,
+    pub fail_count: i32,
+    pub last_error_kind: Option<String>,
+    pub schedule_cron: Option<String>,
+    pub next_fetch_at: DateTime<Utc>,
+    pub muted_until: Option<DateTime<Utc>>,
+    pub in_flight: bool,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct FailingFeedRow {
+    pub id: i64,
+    pub source_domain: String,
+    pub fail_count: i32,
+    pub last_fetch_status: Option<i16>,
+    pub last_error_kind: Option<String>,
 }
 
 pub struct FeedUpsertRecord {
@@ -33,6 +86,16 @@ pub struct FeedUpsertRecord {
     pub enabled: Option<bool>,
     pub fetch_interval_seconds: Option<i32>,
     pub filter_condition: Option<String>,
+    pub schedule_cron: Option<String>,
+    pub ignore_conditional: Option<bool>,
+    /// None 保持原值，空字符串清除
+    pub date_timezone: Option<String>,
+    pub date_format: Option<String>,
+    /// None 保持原值，空字符串清除
+    pub language_hint: Option<String>,
+    pub require_review: Option<bool>,
+    /// None 保持原值，空字符串清除，否则为 JSON 对象文本
+    pub request_headers: Option<String>,
 }
 
 pub async fn list_feeds(pool: &PgPool) -> Result<Vec<FeedRow>, sqlx::Error> {
@@ -46,9 +109,26 @@ pub async fn list_feeds(pool: &PgPool) -> Result<Vec<FeedRow>, sqlx::Error> {
                enabled,
                fetch_interval_seconds,
                filter_condition,
+               schedule_cron,
+               ignore_conditional,
+               date_timezone,
+               date_format,
+               language_hint,
+               require_review,
+               request_headers::text AS request_headers,
+               muted_until,
+               auto_disabled_at,
+               last_etag,
+               last_modified,
                last_fetch_at,
                last_fetch_status,
-               fail_count
+               fail_count,
+               last_error_kind,
+               last_error,
+               COALESCE(
+                   (SELECT array_agg(t.tag ORDER BY t.tag) FROM news.feed_tags t WHERE t.feed_id = news.feeds.id),
+                   '{}'
+               ) AS tags
         FROM news.feeds
         ORDER BY id DESC
         "#,
@@ -57,25 +137,178 @@ pub async fn list_feeds(pool: &PgPool) -> Result<Vec<FeedRow>, sqlx::Error> {
     .await
 }
 
-pub async fn list_due_feeds(pool: &PgPool, limit: i64) -> Result<Vec<DueFeedRow>, sqlx::Error> {
-    sqlx::query_as::<_, DueFeedRow>(
+/// 按固定间隔到期的 feed（最多 `limit` 条），外加全部配置了 `schedule_cron` 的启用 feed；
+/// 后者是否到期由调用方按 cron 表达式判断。静音中的 feed 不参与调度。
+/// 给定 `instance_id` 时只返回按分片哈希归属于该实例的 feed（没有存活实例登记时不过滤）。
+pub async fn list_due_feeds(
+    pool: &PgPool,
+    limit: i64,
+    instance_id: Option<&str>,
+) -> Result<Vec<DueFeedRow>, sqlx::Error> {
+    let owner = fetcher_instances::SHARD_OWNER.replace("{feed_id}", "feeds.id");
+    let sql = format!(
+        r#"
+        (
+            SELECT id::bigint AS id,
+                   url,
+                   source_domain,
+                   last_etag,
+                   last_modified,
+                   ignore_conditional,
+                   date_timezone,
+                   date_format,
+                   language_hint,
+                   require_review,
+                   request_headers::text AS request_headers,
+                   filter_condition,
+                   schedule_cron,
+                   last_fetch_at
+            FROM news.feeds
+            WHERE enabled = TRUE
+              AND (muted_until IS NULL OR muted_until <= NOW())
+              AND schedule_cron IS NULL
+              AND ($2::text IS NULL OR COALESCE({owner}, $2) = $2)
+              AND (
+                  last_fetch_at IS NULL OR
+                  last_fetch_at <= NOW() - make_interval(secs => fetch_interval_seconds)
+              )
+            ORDER BY last_fetch_at NULLS FIRST
+            LIMIT $1
+        )
+        UNION ALL
+        (
+            SELECT id::bigint AS id,
+                   url,
+                   source_domain,
+                   last_etag,
+                   last_modified,
+                   ignore_conditional,
+                   date_timezone,
+                   date_format,
+                   language_hint,
+                   require_review,
+                   request_headers::text AS request_headers,
+                   filter_condition,
+                   schedule_cron,
+                   last_fetch_at
+            FROM news.feeds
+            WHERE enabled = TRUE
+              AND (muted_until IS NULL OR muted_until <= NOW())
+              AND schedule_cron IS NOT NULL
+              AND ($2::text IS NULL OR COALESCE({owner}, $2) = $2)
+        )
+        "#
+    );
+    sqlx::query_as::<_, DueFeedRow>(&sql)
+        .bind(limit)
+        .bind(instance_id)
+        .fetch_all(pool)
+        .await
+}
+
+/// 从给定 feed 中挑出需要走慢车道的部分：开启翻译时近 7 天有外文文章入库，
+/// 或开启 LLM 去重时近 7 天有来源记录经过大模型判定。从未抓取过的 feed 视为普通 feed。
+pub async fn llm_heavy_feed_ids(
+    pool: &PgPool,
+    feed_ids: &[i64],
+    translation_enabled: bool,
+    ai_dedup_enabled: bool,
+) -> Result<Vec<i64>, sqlx::Error> {
+    if feed_ids.is_empty() || (!translation_enabled && !ai_dedup_enabled) {
+        return Ok(Vec::new());
+    }
+    sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT f.id
+        FROM UNNEST($1::bigint[]) AS f(id)
+        WHERE (
+            $2 AND EXISTS (
+                SELECT 1
+                FROM news.articles a
+                WHERE a.feed_id = f.id
+                  AND a.fetched_at > NOW() - INTERVAL '7 days'
+                  AND a.original_language IS NOT NULL
+                  AND a.original_language <> 'zh'
+            )
+        ) OR (
+            $3 AND EXISTS (
+                SELECT 1
+                FROM news.article_sources s
+                WHERE s.feed_id = f.id
+                  AND s.inserted_at > NOW() - INTERVAL '7 days'
+                  AND s.llm_raw IS NOT NULL
+            )
+        )
+        "#,
+    )
+    .bind(feed_ids)
+    .bind(translation_enabled)
+    .bind(ai_dedup_enabled)
+    .fetch_all(pool)
+    .await
+}
+
+/// 调度视图：启用中的 feed 中正在抓取（持有处理锁）、静音中或在 `horizon_secs` 内到期的部分，
+/// 按与 `list_due_feeds` 相同的顺序排列。配置了 cron 的 feed 全部返回，
+/// 其 `next_fetch_at` 按固定间隔估算，需由调用方按 cron 重新计算。
+pub async fn list_queue_feeds(pool: &PgPool, horizon_secs: i64) -> Result<Vec<QueueFeedRow>, sqlx::Error> {
+    sqlx::query_as::<_, QueueFeedRow>(
+        r#"
+        SELECT *
+        FROM (
+            SELECT f.id::bigint AS id,
+                   f.url,
+                   f.source_domain,
+                   f.last_fetch_at,
+                   f.last_fetch_status,
+                   f.fail_count,
+                   f.last_error_kind,
+                   f.schedule_cron,
+                   COALESCE(
+                       f.last_fetch_at + make_interval(secs => f.fetch_interval_seconds),
+                       NOW()
+                   ) AS next_fetch_at,
+                   CASE WHEN f.muted_until > NOW() THEN f.muted_until END AS muted_until,
+                   EXISTS (
+                       SELECT 1
+                       FROM pg_locks l
+                       WHERE l.locktype = 'advisory'
+                         AND l.granted
+                         AND l.objsubid = 1
+                         AND ((l.classid::bigint << 32) | l.objid::bigint) = f.id
+                   ) AS in_flight
+            FROM news.feeds f
+            WHERE f.enabled = TRUE
+        ) q
+        WHERE in_flight
+           OR muted_until IS NOT NULL
+           OR schedule_cron IS NOT NULL
+           OR next_fetch_at <= NOW() + make_interval(secs => $1)
+        ORDER BY last_fetch_at NULLS FIRST
+        "#,
+    )
+    .bind(horizon_secs as f64)
+    .fetch_all(pool)
+    .await
+}
+
+/// 启用且未静音、连续失败次数达到 `min_fail_count` 的 feed，供 `/metrics` 导出告警
+pub async fn list_failing_feeds(pool: &PgPool, min_fail_count: i32) -> Result<Vec<FailingFeedRow>, sqlx::Error> {
+    sqlx::query_as::<_, FailingFeedRow>(
         r#"
         SELECT id::bigint AS id,
-               url,
                source_domain,
-               last_etag,
-               filter_condition
+               fail_count,
+               last_fetch_status,
+               last_error_kind
         FROM news.feeds
         WHERE enabled = TRUE
-          AND (
-              last_fetch_at IS NULL OR
-              last_fetch_at <= NOW() - make_interval(secs => fetch_interval_seconds)
-          )
-        ORDER BY last_fetch_at NULLS FIRST
-        LIMIT $1
+          AND fail_count >= $1
+          AND (muted_until IS NULL OR muted_until <= NOW())
+        ORDER BY id
         "#,
     )
-    .bind(limit)
+    .bind(min_fail_count)
     .fetch_all(pool)
     .await
 }
@@ -87,7 +320,56 @@ pub async fn find_due_feed(pool: &PgPool, id: i64) -> Result<Option<DueFeedRow>,
                url,
                source_domain,
                last_etag,
-               filter_condition
+               last_modified,
+               ignore_conditional,
+               date_timezone,
+               date_format,
+               language_hint,
+               require_review,
+               request_headers::text AS request_headers,
+               filter_condition,
+               schedule_cron,
+               last_fetch_at
+        FROM news.feeds
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn find_by_id(pool: &PgPool, id: i64) -> Result<Option<FeedRow>, sqlx::Error> {
+    sqlx::query_as::<_, FeedRow>(
+        r#"
+        SELECT id::bigint AS id,
+               url,
+               title,
+               site_url,
+               source_domain,
+               enabled,
+               fetch_interval_seconds,
+               filter_condition,
+               schedule_cron,
+               ignore_conditional,
+               date_timezone,
+               date_format,
+               language_hint,
+               require_review,
+               request_headers::text AS request_headers,
+               muted_until,
+               auto_disabled_at,
+               last_etag,
+               last_modified,
+               last_fetch_at,
+               last_fetch_status,
+               fail_count,
+               last_error_kind,
+               last_error,
+               COALESCE(
+                   (SELECT array_agg(t.tag ORDER BY t.tag) FROM news.feed_tags t WHERE t.feed_id = news.feeds.id),
+                   '{}'
+               ) AS tags
         FROM news.feeds
         WHERE id = $1
         "#,
@@ -108,9 +390,26 @@ pub async fn find_by_url(pool: &PgPool, url: &str) -> Result<Option<FeedRow>, sq
                enabled,
                fetch_interval_seconds,
                filter_condition,
+               schedule_cron,
+               ignore_conditional,
+               date_timezone,
+               date_format,
+               language_hint,
+               require_review,
+               request_headers::text AS request_headers,
+               muted_until,
+               auto_disabled_at,
+               last_etag,
+               last_modified,
                last_fetch_at,
                last_fetch_status,
-               fail_count
+               fail_count,
+               last_error_kind,
+               last_error,
+               COALESCE(
+                   (SELECT array_agg(t.tag ORDER BY t.tag) FROM news.feed_tags t WHERE t.feed_id = news.feeds.id),
+                   '{}'
+               ) AS tags
         FROM news.feeds
         WHERE url = $1
         "#,
@@ -130,7 +429,14 @@ pub async fn upsert_feed(pool: &PgPool, record: FeedUpsertRecord) -> Result<Feed
             source_domain,
             enabled,
             fetch_interval_seconds,
-            filter_condition
+            filter_condition,
+            schedule_cron,
+            ignore_conditional,
+            date_timezone,
+            date_format,
+            request_headers,
+            language_hint,
+            require_review
         )
         VALUES (
             $1,
@@ -139,15 +445,30 @@ pub async fn upsert_feed(pool: &PgPool, record: FeedUpsertRecord) -> Result<Feed
             $4,
             COALESCE($5, TRUE),
             COALESCE($6, 600),
-            NULLIF(trim($7), '')
+            NULLIF(trim($7), ''),
+            NULLIF(trim($8), ''),
+            COALESCE($9, FALSE),
+            NULLIF(trim($10), ''),
+            NULLIF(trim($11), ''),
+            NULLIF($12, '')::jsonb,
+            NULLIF(trim($13), ''),
+            COALESCE($14, FALSE)
         )
         ON CONFLICT (url) DO UPDATE SET
             title = COALESCE(EXCLUDED.title, news.feeds.title),
             site_url = COALESCE(EXCLUDED.site_url, news.feeds.site_url),
             source_domain = EXCLUDED.source_domain,
             enabled = COALESCE(EXCLUDED.enabled, news.feeds.enabled),
+            auto_disabled_at = CASE WHEN $5 THEN NULL ELSE news.feeds.auto_disabled_at END,
             fetch_interval_seconds = COALESCE(EXCLUDED.fetch_interval_seconds, news.feeds.fetch_interval_seconds),
             filter_condition = EXCLUDED.filter_condition,
+            schedule_cron = EXCLUDED.schedule_cron,
+            ignore_conditional = COALESCE($9, news.feeds.ignore_conditional),
+            date_timezone = CASE WHEN $10 IS NULL THEN news.feeds.date_timezone ELSE NULLIF(trim($10), '') END,
+            date_format = CASE WHEN $11 IS NULL THEN news.feeds.date_format ELSE NULLIF(trim($11), '') END,
+            request_headers = CASE WHEN $12 IS NULL THEN news.feeds.request_headers ELSE NULLIF($12, '')::jsonb END,
+            language_hint = CASE WHEN $13 IS NULL THEN news.feeds.language_hint ELSE NULLIF(trim($13), '') END,
+            require_review = COALESCE($14, news.feeds.require_review),
             updated_at = NOW()
         RETURNING id::bigint AS id,
                   url,
@@ -157,9 +478,26 @@ pub async fn upsert_feed(pool: &PgPool, record: FeedUpsertRecord) -> Result<Feed
                   enabled,
                   fetch_interval_seconds,
                   filter_condition,
+                  schedule_cron,
+                  ignore_conditional,
+                  date_timezone,
+                  date_format,
+                  language_hint,
+                  require_review,
+                  request_headers::text AS request_headers,
+                  muted_until,
+                  auto_disabled_at,
+                  last_etag,
+                  last_modified,
                   last_fetch_at,
                   last_fetch_status,
-                  fail_count
+                  fail_count,
+                  last_error_kind,
+                  last_error,
+                  COALESCE(
+                      (SELECT array_agg(t.tag ORDER BY t.tag) FROM news.feed_tags t WHERE t.feed_id = news.feeds.id),
+                      '{}'
+                  ) AS tags
         "#,
     )
     .bind(record.url)
@@ -169,10 +507,58 @@ pub async fn upsert_feed(pool: &PgPool, record: FeedUpsertRecord) -> Result<Feed
     .bind(record.enabled)
     .bind(record.fetch_interval_seconds)
     .bind(record.filter_condition)
+    .bind(record.schedule_cron)
+    .bind(record.ignore_conditional)
+    .bind(record.date_timezone)
+    .bind(record.date_format)
+    .bind(record.request_headers)
+    .bind(record.language_hint)
+    .bind(record.require_review)
     .fetch_one(pool)
     .await
 }
 
+/// Replace the full tag set of a feed and re-tag the feed's existing articles.
+pub async fn set_feed_tags(pool: &PgPool, feed_id: i64, tags: &[String]) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        r#"
+        DELETE FROM news.feed_tags
+        WHERE feed_id = $1
+        "#,
+    )
+    .bind(feed_id)
+    .execute(tx.as_mut())
+    .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO news.feed_tags (feed_id, tag)
+        SELECT $1, UNNEST($2::text[])
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(feed_id)
+    .bind(tags)
+    .execute(tx.as_mut())
+    .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE news.articles
+        SET tags = $2
+        WHERE feed_id = $1
+        "#,
+    )
+    .bind(feed_id)
+    .bind(tags)
+    .execute(tx.as_mut())
+    .await?;
+
+    tx.commit().await
+}
+
 pub async fn delete_feed(tx: &mut Transaction<'_, Postgres>, id: i64) -> Result<u64, sqlx::Error> {
     let result = sqlx::query(
         r#"
@@ -187,6 +573,62 @@ pub async fn delete_feed(tx: &mut Transaction<'_, Postgres>, id: i64) -> Result<
     Ok(result.rows_affected())
 }
 
+/// 设置或清除静音截止时间，返回受影响行数
+pub async fn set_muted_until(
+    pool: &PgPool,
+    feed_id: i64,
+    until: Option<DateTime<Utc>>,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        UPDATE news.feeds
+        SET muted_until = $2,
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(feed_id)
+    .bind(until)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// 清除已到期的静音，返回被自动解除静音的 feed
+pub async fn clear_expired_mutes(pool: &PgPool) -> Result<Vec<(i64, String)>, sqlx::Error> {
+    sqlx::query_as::<_, (i64, String)>(
+        r#"
+        UPDATE news.feeds
+        SET muted_until = NULL,
+            updated_at = NOW()
+        WHERE muted_until IS NOT NULL
+          AND muted_until <= NOW()
+        RETURNING id::bigint, source_domain
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// 清除保存的 ETag 与 Last-Modified，下次抓取将发起完整请求
+pub async fn clear_etag(pool: &PgPool, feed_id: i64) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        UPDATE news.feeds
+        SET last_etag = NULL,
+            last_modified = NULL,
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(feed_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
 pub async fn mark_not_modified(
     pool: &PgPool,
     feed_id: i64,
@@ -198,6 +640,8 @@ pub async fn mark_not_modified(
         SET last_fetch_at = NOW(),
             last_fetch_status = $2,
             fail_count = 0,
+            last_error_kind = NULL,
+            last_error = NULL,
             updated_at = NOW()
         WHERE id = $1
         "#,
@@ -210,30 +654,84 @@ pub async fn mark_not_modified(
     Ok(())
 }
 
-pub async fn mark_failure(pool: &PgPool, feed_id: i64, status: i16) -> Result<(), sqlx::Error> {
+pub async fn mark_failure(
+    pool: &PgPool,
+    feed_id: i64,
+    status: i16,
+    error_kind: &str,
+    error: &str,
+) -> Result<(), sqlx::Error> {
     sqlx::query(
         r#"
         UPDATE news.feeds
         SET last_fetch_at = NOW(),
             last_fetch_status = $2,
             fail_count = fail_count + 1,
+            last_error_kind = $3,
+            last_error = $4,
             updated_at = NOW()
         WHERE id = $1
         "#,
     )
     .bind(feed_id)
     .bind(status)
+    .bind(error_kind)
+    .bind(error)
     .execute(pool)
     .await?;
 
     Ok(())
 }
 
+/// 连续失败次数达到 `max_fail_count` 时停用 feed；返回被停用 feed 的来源域名与失败次数
+pub async fn auto_disable_failing(
+    pool: &PgPool,
+    feed_id: i64,
+    max_fail_count: i32,
+) -> Result<Option<(String, i32)>, sqlx::Error> {
+    sqlx::query_as::<_, (String, i32)>(
+        r#"
+        UPDATE news.feeds
+        SET enabled = FALSE,
+            auto_disabled_at = NOW(),
+            updated_at = NOW()
+        WHERE id = $1
+          AND enabled
+          AND fail_count >= $2
+        RETURNING source_domain, fail_count
+        "#,
+    )
+    .bind(feed_id)
+    .bind(max_fail_count)
+    .fetch_optional(pool)
+    .await
+}
+
+/// 重新启用 feed 并清零失败计数，下一轮调度即可抓取
+pub async fn reenable(pool: &PgPool, feed_id: i64) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        UPDATE news.feeds
+        SET enabled = TRUE,
+            auto_disabled_at = NULL,
+            fail_count = 0,
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(feed_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
 pub async fn mark_success(
     pool: &PgPool,
     feed_id: i64,
     status: i16,
     etag: Option<String>,
+    last_modified: Option<DateTime<Utc>>,
     title: Option<String>,
     site_url: Option<String>,
 ) -> Result<(), sqlx::Error> {
@@ -243,9 +741,12 @@ pub async fn mark_success(
         SET last_fetch_at = NOW(),
             last_fetch_status = $2,
             last_etag = $3,
+            last_modified = $6,
             title = COALESCE($4, title),
             site_url = COALESCE($5, site_url),
             fail_count = 0,
+            last_error_kind = NULL,
+            last_error = NULL,
             updated_at = NOW()
         WHERE id = $1
         "#,
@@ -255,6 +756,7 @@ pub async fn mark_success(
     .bind(etag)
     .bind(title)
     .bind(site_url)
+    .bind(last_modified)
     .execute(pool)
     .await?;
 
@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+/// 按 feed 选出负责的存活实例（rendezvous 哈希：每个实例对 feed ID 打分，取最高者）。
+/// 实例增减时只有归属于该实例的 feed 会迁移；没有存活实例时为 NULL。`{feed_id}` 需替换为 feed ID 列。
+pub const SHARD_OWNER: &str = r#"(
+    SELECT s.instance_id
+    FROM news.fetcher_instances s
+    WHERE s.expires_at > NOW()
+    ORDER BY hashtextextended(s.instance_id || ':' || {feed_id}::text, 0) DESC, s.instance_id
+    LIMIT 1
+)"#;
+
+// 过期超过一天的实例记录在心跳时清理
+const STALE_RETENTION_HOURS: i32 = 24;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct FetcherInstanceRow {
+    pub instance_id: String,
+    pub started_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub alive: bool,
+    pub last_round_at: Option<DateTime<Utc>>,
+    pub last_round_feeds: Option<i32>,
+    pub owned_feeds: i64,
+}
+
+/// 登记本实例存活，`ttl_secs` 内没有下一次心跳即视为下线，其负责的 feed 由其余实例接管
+pub async fn heartbeat(
+    pool: &PgPool,
+    instance_id: &str,
+    started_at: DateTime<Utc>,
+    ttl_secs: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO news.fetcher_instances (instance_id, started_at, last_seen_at, expires_at)
+        VALUES ($1, $2, NOW(), NOW() + make_interval(secs => $3))
+        ON CONFLICT (instance_id) DO UPDATE
+        SET started_at = EXCLUDED.started_at,
+            last_seen_at = EXCLUDED.last_seen_at,
+            expires_at = EXCLUDED.expires_at
+        "#,
+    )
+    .bind(instance_id)
+    .bind(started_at)
+    .bind(ttl_secs as f64)
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        DELETE FROM news.fetcher_instances
+        WHERE expires_at < NOW() - make_interval(hours => $1)
+        "#,
+    )
+    .bind(STALE_RETENTION_HOURS)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn record_round(pool: &PgPool, instance_id: &str, feeds: i32) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE news.fetcher_instances
+        SET last_round_at = NOW(),
+            last_round_feeds = $2
+        WHERE instance_id = $1
+        "#,
+    )
+    .bind(instance_id)
+    .bind(feeds)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 全部已登记实例（含已过期、尚未清理的），附当前按哈希分到的启用 feed 数
+pub async fn list_instances(pool: &PgPool) -> Result<Vec<FetcherInstanceRow>, sqlx::Error> {
+    let owner = SHARD_OWNER.replace("{feed_id}", "f.id");
+    let sql = format!(
+        r#"
+        WITH owners AS (
+            SELECT {owner} AS instance_id
+            FROM news.feeds f
+            WHERE f.enabled = TRUE
+        )
+        SELECT i.instance_id,
+               i.started_at,
+               i.last_seen_at,
+               i.expires_at,
+               i.expires_at > NOW() AS alive,
+               i.last_round_at,
+               i.last_round_feeds,
+               (SELECT COUNT(*) FROM owners o WHERE o.instance_id = i.instance_id) AS owned_feeds
+        FROM news.fetcher_instances i
+        ORDER BY i.started_at, i.instance_id
+        "#
+    );
+    sqlx::query_as::<_, FetcherInstanceRow>(&sql)
+        .fetch_all(pool)
+        .await
+}
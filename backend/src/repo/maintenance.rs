@@ -1,6 +1,9 @@
+use chrono::{DateTime, Utc};
 use sqlx::{PgPool, Postgres, Transaction};
 use tracing::info;
 
+use super::articles;
+
 pub async fn cleanup_orphan_content(pool: &PgPool) -> Result<(u64, u64), sqlx::Error> {
     let mut tx: Transaction<'_, Postgres> = pool.begin().await?;
 
@@ -14,6 +17,17 @@ pub async fn cleanup_orphan_content(pool: &PgPool) -> Result<(u64, u64), sqlx::E
     .await?
     .rows_affected();
 
+    let orphan_ids: Vec<i64> = sqlx::query_scalar(
+        r#"
+        SELECT id::bigint
+        FROM news.articles
+        WHERE feed_id IS NULL
+        "#,
+    )
+    .fetch_all(tx.as_mut())
+    .await?;
+    articles::promote_cluster_heirs(&mut tx, &orphan_ids).await?;
+
     let deleted_articles = sqlx::query(
         r#"
         DELETE FROM news.articles
@@ -35,3 +49,107 @@ pub async fn cleanup_orphan_content(pool: &PgPool) -> Result<(u64, u64), sqlx::E
 
     Ok((deleted_articles, deleted_article_sources))
 }
+
+// 清理超过保留期的过滤隔离记录
+pub async fn purge_expired_filtered(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let purged = sqlx::query(
+        r#"
+        DELETE FROM news.filtered_articles
+        WHERE expires_at <= NOW()
+        "#,
+    )
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    if purged > 0 {
+        info!(purged, "purged expired filtered article snapshots");
+    }
+
+    Ok(purged)
+}
+
+/// 把一批发布时间早于 `cutoff` 的文章移入 `news.articles_archive`，并删除其来源记录，
+/// 返回 `(文章数, 来源记录数)`；被移走的主文章由簇内未过期的成员接替。每批单独提交，避免长事务锁住文章表
+pub async fn archive_articles_before(
+    pool: &PgPool,
+    cutoff: DateTime<Utc>,
+    limit: i64,
+) -> Result<(u64, u64), sqlx::Error> {
+    let mut tx: Transaction<'_, Postgres> = pool.begin().await?;
+
+    let ids: Vec<i64> = sqlx::query_scalar(
+        r#"
+        SELECT id::bigint
+        FROM news.articles
+        WHERE published_at < $1
+        ORDER BY id
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+        "#,
+    )
+    .bind(cutoff)
+    .bind(limit)
+    .fetch_all(tx.as_mut())
+    .await?;
+    if ids.is_empty() {
+        return Ok((0, 0));
+    }
+    articles::promote_cluster_heirs(&mut tx, &ids).await?;
+
+    // 按两表共有的列名复制，不依赖列顺序；冷表自身的 archived_at 取默认值
+    let columns: Vec<String> = sqlx::query_scalar(
+        r#"
+        SELECT quote_ident(a.attname::text)
+        FROM pg_attribute a
+        JOIN pg_attribute b
+          ON b.attrelid = 'news.articles_archive'::regclass
+         AND b.attname = a.attname
+         AND NOT b.attisdropped
+        WHERE a.attrelid = 'news.articles'::regclass
+          AND a.attnum > 0
+          AND NOT a.attisdropped
+        ORDER BY a.attnum
+        "#,
+    )
+    .fetch_all(tx.as_mut())
+    .await?;
+    let columns = columns.join(", ");
+    sqlx::query(&format!(
+        r#"
+        INSERT INTO news.articles_archive ({columns})
+        SELECT {columns}
+        FROM news.articles
+        WHERE id = ANY($1)
+        ON CONFLICT (id) DO NOTHING
+        "#
+    ))
+    .bind(&ids)
+    .execute(tx.as_mut())
+    .await?;
+
+    let deleted_article_sources = sqlx::query(
+        r#"
+        DELETE FROM news.article_sources
+        WHERE article_id = ANY($1)
+        "#,
+    )
+    .bind(&ids)
+    .execute(tx.as_mut())
+    .await?
+    .rows_affected();
+
+    let deleted_articles = sqlx::query(
+        r#"
+        DELETE FROM news.articles
+        WHERE id = ANY($1)
+        "#,
+    )
+    .bind(&ids)
+    .execute(tx.as_mut())
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+    Ok((deleted_articles, deleted_article_sources))
+}
@@ -39,8 +39,7 @@ pub async fn ensure_schema(pool: &PgPool) -> Result<(), sqlx::Error> {
         ALTER TABLE news.feeds
           DROP COLUMN IF EXISTS source_display_name,
           DROP COLUMN IF EXISTS country,
-          DROP COLUMN IF EXISTS language,
-          DROP COLUMN IF EXISTS last_modified;
+          DROP COLUMN IF EXISTS language;
         "#,
     )
     .await?;
@@ -53,6 +52,32 @@ pub async fn ensure_schema(pool: &PgPool) -> Result<(), sqlx::Error> {
     )
     .await?;
 
+    tx.execute(
+        r#"
+        ALTER TABLE news.feeds
+          ADD COLUMN IF NOT EXISTS schedule_cron TEXT;
+        "#,
+    )
+    .await?;
+
+    // 不提供 ETag 的源依靠 Last-Modified / If-Modified-Since 做条件请求
+    tx.execute(
+        r#"
+        ALTER TABLE news.feeds
+          ADD COLUMN IF NOT EXISTS last_modified TIMESTAMPTZ;
+        "#,
+    )
+    .await?;
+
+    // 部分 CDN 会错误地返回 304，开启后抓取时不再发送条件请求头
+    tx.execute(
+        r#"
+        ALTER TABLE news.feeds
+          ADD COLUMN IF NOT EXISTS ignore_conditional BOOLEAN NOT NULL DEFAULT FALSE;
+        "#,
+    )
+    .await?;
+
     tx.execute(
         r#"
         CREATE INDEX IF NOT EXISTS idx_feeds_enabled ON news.feeds(enabled);
@@ -154,6 +179,108 @@ pub async fn ensure_schema(pool: &PgPool) -> Result<(), sqlx::Error> {
     )
     .await?;
 
+    // 按簇查询成员，以及删除主文章时外键置空成员的 canonical_id
+    tx.execute(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_articles_canonical_id ON news.articles(canonical_id);
+        "#,
+    )
+    .await?;
+
+    // 文章列表的键集分页：(published_at, id) 行比较走索引
+    tx.execute(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_articles_published_id ON news.articles(published_at DESC, id DESC);
+        "#,
+    )
+    .await?;
+
+    // 文章列表按入库时间排序（sort=fetched_at）
+    tx.execute(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_articles_fetched_id ON news.articles(fetched_at DESC, id DESC);
+        "#,
+    )
+    .await?;
+
+    // 排行分数随时间衰减，由后台任务定期重算（见 service::ranking），列表按 sort=rank 排序
+    tx.execute(
+        r#"
+        ALTER TABLE news.articles
+          ADD COLUMN IF NOT EXISTS rank_score DOUBLE PRECISION NOT NULL DEFAULT 0;
+        CREATE INDEX IF NOT EXISTS idx_articles_rank_id ON news.articles(rank_score DESC, id DESC);
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        ALTER TABLE news.articles
+          ADD COLUMN IF NOT EXISTS original_language TEXT;
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_articles_original_language ON news.articles(original_language);
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.feed_tags (
+          feed_id     BIGINT NOT NULL REFERENCES news.feeds(id) ON DELETE CASCADE,
+          tag         TEXT NOT NULL,
+          created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          PRIMARY KEY (feed_id, tag)
+        );
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_feed_tags_tag ON news.feed_tags(tag);
+        "#,
+    )
+    .await?;
+
+    // 文章写入时从 feed_tags 复制标签，查询时无需 JOIN
+    tx.execute(
+        r#"
+        ALTER TABLE news.articles
+          ADD COLUMN IF NOT EXISTS tags TEXT[] NOT NULL DEFAULT '{}';
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_articles_tags ON news.articles USING GIN (tags);
+        "#,
+    )
+    .await?;
+
+    // 翻译失败的文章先以原文入库，由后台补偿任务重试
+    tx.execute(
+        r#"
+        ALTER TABLE news.articles
+          ADD COLUMN IF NOT EXISTS translation_pending BOOLEAN NOT NULL DEFAULT FALSE,
+          ADD COLUMN IF NOT EXISTS translation_attempts INTEGER NOT NULL DEFAULT 0;
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_articles_translation_pending
+          ON news.articles(id) WHERE translation_pending;
+        "#,
+    )
+    .await?;
+
     tx.execute(
         r#"
         CREATE TABLE IF NOT EXISTS news.article_sources (
@@ -179,6 +306,183 @@ pub async fn ensure_schema(pool: &PgPool) -> Result<(), sqlx::Error> {
     )
     .await?;
 
+    // 去重解释：Jaccard 分数、命中的标题 token 与 LLM 原始输出
+    tx.execute(
+        r#"
+        ALTER TABLE news.article_sources
+          ADD COLUMN IF NOT EXISTS jaccard REAL,
+          ADD COLUMN IF NOT EXISTS matched_tokens TEXT[],
+          ADD COLUMN IF NOT EXISTS llm_raw TEXT;
+        "#,
+    )
+    .await?;
+
+    // 管理员隐藏：隐藏的文章不出现在公开列表，操作记录写入 article_moderation 以便审计
+    tx.execute(
+        r#"
+        ALTER TABLE news.articles
+          ADD COLUMN IF NOT EXISTS hidden_at TIMESTAMPTZ,
+          ADD COLUMN IF NOT EXISTS hidden_reason TEXT;
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.article_moderation (
+          id          BIGSERIAL PRIMARY KEY,
+          article_id  BIGINT NOT NULL REFERENCES news.articles(id) ON DELETE CASCADE,
+          action      TEXT NOT NULL,
+          reason      TEXT,
+          created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        );
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_article_moderation_article
+          ON news.article_moderation(article_id, created_at DESC);
+        "#,
+    )
+    .await?;
+
+    // 审核记录的操作人（后台用户名或 `api-key:<名称>`），入库时自动隐藏等系统操作为空
+    tx.execute(
+        r#"
+        ALTER TABLE news.article_moderation
+          ADD COLUMN IF NOT EXISTS actor TEXT;
+        "#,
+    )
+    .await?;
+
+    // 按需翻译的译文缓存：每篇文章每种目标语言一条，原文变化后按 source_digest 判定过期
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.article_translations (
+          article_id     BIGINT NOT NULL REFERENCES news.articles(id) ON DELETE CASCADE,
+          language       TEXT NOT NULL,
+          title          TEXT NOT NULL,
+          description    TEXT,
+          source_digest  TEXT NOT NULL,
+          provider       TEXT NOT NULL,
+          created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          PRIMARY KEY (article_id, language)
+        );
+        "#,
+    )
+    .await?;
+
+    // 读者的已读/收藏状态：读者以前端生成的匿名 ID 区分，两种状态都清除后删除该行
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.user_article_state (
+          reader_id      TEXT NOT NULL,
+          article_id     BIGINT NOT NULL REFERENCES news.articles(id) ON DELETE CASCADE,
+          read_at        TIMESTAMPTZ,
+          bookmarked_at  TIMESTAMPTZ,
+          updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          PRIMARY KEY (reader_id, article_id)
+        );
+        CREATE INDEX IF NOT EXISTS idx_user_article_state_article
+          ON news.user_article_state(article_id);
+        "#,
+    )
+    .await?;
+
+    // 内容指纹：原始标题 + URL 主机 + 发布日期的哈希，入库前在 SQL 中拦截完全相同的转载
+    tx.execute(
+        r#"
+        ALTER TABLE news.articles
+          ADD COLUMN IF NOT EXISTS content_fingerprint TEXT;
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_articles_content_fingerprint
+          ON news.articles(content_fingerprint)
+          WHERE content_fingerprint IS NOT NULL;
+        "#,
+    )
+    .await?;
+
+    // 标题 SimHash：按 16 位分为 4 段分别建索引，汉明距离不超过 3 的两个哈希至少有一段完全相同，
+    // 近似重复查询先按段命中索引再精确计算距离，可覆盖全部历史文章
+    tx.execute(
+        r#"
+        ALTER TABLE news.articles
+          ADD COLUMN IF NOT EXISTS title_simhash BIGINT;
+        "#,
+    )
+    .await?;
+
+    // 表达式须与 repo::articles::find_by_simhashes 中的写法完全一致才能命中索引
+    for (band, expr) in [
+        (0, "(title_simhash >> 48) & 65535"),
+        (1, "(title_simhash >> 32) & 65535"),
+        (2, "(title_simhash >> 16) & 65535"),
+        (3, "title_simhash & 65535"),
+    ] {
+        tx.execute(
+            format!(
+                r#"
+                CREATE INDEX IF NOT EXISTS idx_articles_title_simhash_b{band}
+                  ON news.articles (({expr}))
+                  WHERE title_simhash IS NOT NULL;
+                "#
+            )
+            .as_str(),
+        )
+        .await?;
+    }
+
+    // 过滤条件删除的文章先整行快照到隔离表，保留一段时间以便恢复
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.filtered_articles (
+          id           BIGSERIAL PRIMARY KEY,
+          article_id   BIGINT NOT NULL,
+          feed_id      BIGINT NOT NULL REFERENCES news.feeds(id) ON DELETE CASCADE,
+          condition    TEXT NOT NULL,
+          payload      JSONB NOT NULL,
+          filtered_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          expires_at   TIMESTAMPTZ NOT NULL
+        );
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_filtered_articles_feed
+          ON news.filtered_articles(feed_id, filtered_at DESC);
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.source_weights (
+          source_domain  TEXT PRIMARY KEY,
+          weight         REAL NOT NULL DEFAULT 1.0,
+          updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        );
+        "#,
+    )
+    .await?;
+
+    // 每日入库上限：超出后的文章仍入库但自动隐藏，NULL 表示不限制
+    tx.execute(
+        r#"
+        ALTER TABLE news.source_weights
+          ADD COLUMN IF NOT EXISTS daily_cap INTEGER;
+        "#,
+    )
+    .await?;
+
     tx.execute(
         r#"
         CREATE TABLE IF NOT EXISTS news.settings (
@@ -190,6 +494,306 @@ pub async fn ensure_schema(pool: &PgPool) -> Result<(), sqlx::Error> {
     )
     .await?;
 
+    // 不落来源记录的去重（批内重复、URL 已存在）按天计数，供去重统计使用
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.dedup_counters (
+          day     DATE NOT NULL,
+          method  TEXT NOT NULL,
+          count   BIGINT NOT NULL DEFAULT 0,
+          PRIMARY KEY (day, method)
+        );
+        "#,
+    )
+    .await?;
+
+    // 管理员会话持久化：只保存 token 的摘要，服务重启后会话仍然有效
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.admin_sessions (
+          id            BIGSERIAL PRIMARY KEY,
+          token_hash    TEXT NOT NULL UNIQUE,
+          username      TEXT NOT NULL,
+          client_ip     TEXT,
+          user_agent    TEXT,
+          created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          last_seen_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          expires_at    TIMESTAMPTZ NOT NULL
+        );
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_admin_sessions_username
+          ON news.admin_sessions(username, created_at);
+        "#,
+    )
+    .await?;
+
+    // 早期版本以 md5 摘要保存 token（32 位十六进制），统一改为 SHA-256 后旧会话无法再匹配，直接清除
+    tx.execute(
+        r#"
+        DELETE FROM news.admin_sessions WHERE length(token_hash) = 32;
+        "#,
+    )
+    .await?;
+
+    // 后台账号：配置文件中的管理员之外的成员，按角色限制可用的后台接口
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.users (
+          id             BIGSERIAL PRIMARY KEY,
+          username       TEXT NOT NULL UNIQUE,
+          password_hash  TEXT NOT NULL,
+          role           TEXT NOT NULL CHECK (role IN ('admin', 'editor', 'viewer')),
+          disabled_at    TIMESTAMPTZ,
+          last_login_at  TIMESTAMPTZ,
+          created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        );
+        "#,
+    )
+    .await?;
+
+    // 账号来源：`password` 为后台创建、密码登录的账号，`oidc` 为单点登录首次登录时自动创建，只能经身份提供方登录
+    tx.execute(
+        r#"
+        ALTER TABLE news.users
+          ADD COLUMN IF NOT EXISTS auth_source TEXT NOT NULL DEFAULT 'password';
+        "#,
+    )
+    .await?;
+
+    // 供脚本与集成使用的长期密钥，只保存 SHA-256 摘要；吊销后保留记录
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.api_keys (
+          id            BIGSERIAL PRIMARY KEY,
+          name          TEXT NOT NULL,
+          key_hash      TEXT NOT NULL UNIQUE,
+          key_prefix    TEXT NOT NULL,
+          role          TEXT NOT NULL CHECK (role IN ('admin', 'editor', 'viewer')),
+          created_by    TEXT NOT NULL,
+          created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          last_used_at  TIMESTAMPTZ,
+          expires_at    TIMESTAMPTZ,
+          revoked_at    TIMESTAMPTZ
+        );
+        "#,
+    )
+    .await?;
+
+    // 静音：保留配置但在截止时间前跳过定时抓取，到期后自动解除
+    tx.execute(
+        r#"
+        ALTER TABLE news.feeds
+          ADD COLUMN IF NOT EXISTS muted_until TIMESTAMPTZ;
+        "#,
+    )
+    .await?;
+
+    // 归并重复文章时的来源偏好：preferred_domain 优先于 over_domain（'*' 表示任意来源）
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.source_preferences (
+          id                BIGSERIAL PRIMARY KEY,
+          preferred_domain  TEXT NOT NULL,
+          over_domain       TEXT NOT NULL,
+          note              TEXT,
+          created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          UNIQUE (preferred_domain, over_domain),
+          CHECK (preferred_domain <> over_domain)
+        );
+        "#,
+    )
+    .await?;
+
+    // 每周精选：按周保存当时的排名快照，文章后续被删除或改动不影响已发布的精选
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.digests (
+          id              BIGSERIAL PRIMARY KEY,
+          week_start      DATE NOT NULL UNIQUE,
+          period_start    TIMESTAMPTZ NOT NULL,
+          period_end      TIMESTAMPTZ NOT NULL,
+          title           TEXT NOT NULL,
+          created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          emailed_at      TIMESTAMPTZ,
+          email_attempts  INTEGER NOT NULL DEFAULT 0
+        );
+        "#,
+    )
+    .await?;
+
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.digest_items (
+          digest_id      BIGINT NOT NULL REFERENCES news.digests(id) ON DELETE CASCADE,
+          rank           INTEGER NOT NULL,
+          article_id     BIGINT REFERENCES news.articles(id) ON DELETE SET NULL,
+          title          TEXT NOT NULL,
+          url            TEXT NOT NULL,
+          source_domain  TEXT NOT NULL,
+          published_at   TIMESTAMPTZ NOT NULL,
+          click_count    BIGINT NOT NULL,
+          cluster_size   INTEGER NOT NULL,
+          score          REAL NOT NULL,
+          PRIMARY KEY (digest_id, rank)
+        );
+        "#,
+    )
+    .await?;
+
+    // 数据库逻辑备份的执行记录；部分唯一索引保证多实例下同时只有一个备份在进行
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.backups (
+          id            BIGSERIAL PRIMARY KEY,
+          trigger       TEXT NOT NULL,
+          mode          TEXT NOT NULL,
+          status        TEXT NOT NULL DEFAULT 'running',
+          location      TEXT NOT NULL,
+          size_bytes    BIGINT,
+          error         TEXT,
+          started_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          finished_at   TIMESTAMPTZ
+        );
+        CREATE UNIQUE INDEX IF NOT EXISTS idx_backups_running ON news.backups((true)) WHERE status = 'running';
+        "#,
+    )
+    .await?;
+
+    // 发布时间解析覆盖：源给出无时区或时区错误的日期时，按指定时区 / 格式解析
+    tx.execute(
+        r#"
+        ALTER TABLE news.feeds
+          ADD COLUMN IF NOT EXISTS date_timezone TEXT,
+          ADD COLUMN IF NOT EXISTS date_format TEXT;
+        "#,
+    )
+    .await?;
+
+    // 按 feed 自定义的抓取请求头（API Token、User-Agent 等），见 util::feed_headers
+    tx.execute(
+        r#"
+        ALTER TABLE news.feeds
+          ADD COLUMN IF NOT EXISTS request_headers JSONB;
+        "#,
+    )
+    .await?;
+
+    // 语种已知且固定的源：直接作为原文语种，跳过按文字检测
+    tx.execute(
+        r#"
+        ALTER TABLE news.feeds
+          ADD COLUMN IF NOT EXISTS language_hint TEXT;
+        "#,
+    )
+    .await?;
+
+    // 需人工审核的源：新文章以 hidden_reason = 'pending_review' 入库，批准后公开
+    tx.execute(
+        r#"
+        ALTER TABLE news.feeds
+          ADD COLUMN IF NOT EXISTS require_review BOOLEAN NOT NULL DEFAULT FALSE;
+        "#,
+    )
+    .await?;
+
+    // 连续失败超过 fetcher.max_fail_count 被自动停用的时间，重新启用时清除
+    tx.execute(
+        r#"
+        ALTER TABLE news.feeds
+          ADD COLUMN IF NOT EXISTS auto_disabled_at TIMESTAMPTZ;
+        "#,
+    )
+    .await?;
+
+    // 最近一次抓取失败的分类与错误信息（见 fetcher::failure），成功后清空
+    tx.execute(
+        r#"
+        ALTER TABLE news.feeds
+          ADD COLUMN IF NOT EXISTS last_error_kind TEXT,
+          ADD COLUMN IF NOT EXISTS last_error TEXT;
+        "#,
+    )
+    .await?;
+
+    // 外部评分服务返回的附加属性（见 service::enrichment）
+    tx.execute(
+        r#"
+        ALTER TABLE news.articles
+          ADD COLUMN IF NOT EXISTS attrs JSONB,
+          ADD COLUMN IF NOT EXISTS enriched_at TIMESTAMPTZ,
+          ADD COLUMN IF NOT EXISTS enrichment_attempts INTEGER NOT NULL DEFAULT 0;
+        "#,
+    )
+    .await?;
+
+    // 正文抽取结果（见 service::extraction）；content 为空时 last_error 记录失败原因
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.article_contents (
+          article_id    BIGINT PRIMARY KEY REFERENCES news.articles(id) ON DELETE CASCADE,
+          content       TEXT,
+          extracted_at  TIMESTAMPTZ,
+          attempts      INTEGER NOT NULL DEFAULT 0,
+          last_error    TEXT,
+          updated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        );
+        "#,
+    )
+    .await?;
+
+    // 正文中的图片地址（已解析为绝对 URL），供前台阅读视图使用
+    tx.execute(
+        r#"
+        ALTER TABLE news.article_contents
+          ADD COLUMN IF NOT EXISTS images TEXT[] NOT NULL DEFAULT '{}';
+        "#,
+    )
+    .await?;
+
+    // 入库时被截断的完整摘要（开启正文存储时保留），与抽取到的正文相互独立
+    tx.execute(
+        r#"
+        ALTER TABLE news.article_contents
+          ADD COLUMN IF NOT EXISTS full_description TEXT;
+        "#,
+    )
+    .await?;
+
+    // 多实例抓取：各实例每轮心跳登记，到期未续的实例不再参与分片（见 repo::fetcher_instances）
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.fetcher_instances (
+          instance_id       TEXT PRIMARY KEY,
+          started_at        TIMESTAMPTZ NOT NULL,
+          last_seen_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          expires_at        TIMESTAMPTZ NOT NULL,
+          last_round_at     TIMESTAMPTZ,
+          last_round_feeds  INTEGER
+        );
+        "#,
+    )
+    .await?;
+
+    // 增强流水线各步骤的输出，每篇文章每个步骤一行（见 service::enrichment::pipeline）
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.article_enrichments (
+          article_id  BIGINT NOT NULL REFERENCES news.articles(id) ON DELETE CASCADE,
+          step        TEXT NOT NULL,
+          output      JSONB NOT NULL,
+          updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+          PRIMARY KEY (article_id, step)
+        );
+        "#,
+    )
+    .await?;
+
     let deleted = sqlx::query_scalar::<_, i64>(
         r#"
         WITH duplicates AS (
@@ -262,6 +866,15 @@ pub async fn ensure_schema(pool: &PgPool) -> Result<(), sqlx::Error> {
     )
     .await?;
 
+    // 结构化属性：键由 ops::event_catalog 中各事件码约定
+    tx.execute(
+        r#"
+        ALTER TABLE news.events
+          ADD COLUMN IF NOT EXISTS attrs JSONB;
+        "#,
+    )
+    .await?;
+
     // Best-effort migration from legacy ops.events
     tx.execute(
         r#"
@@ -303,6 +916,48 @@ pub async fn ensure_schema(pool: &PgPool) -> Result<(), sqlx::Error> {
     )
     .await?;
 
+    // 冷存储：保留期清理时文章移入此表（与 news.articles 同结构，仅主键），供历史分析使用。
+    // 放在最后创建，确保复制到全部列；之后 news.articles 新增的列在每次启动时补齐
+    tx.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS news.articles_archive (LIKE news.articles);
+        ALTER TABLE news.articles_archive
+          ADD COLUMN IF NOT EXISTS archived_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+        DO $$
+        DECLARE
+            col RECORD;
+        BEGIN
+            IF NOT EXISTS (
+                SELECT 1 FROM pg_constraint
+                WHERE conrelid = 'news.articles_archive'::regclass AND contype = 'p'
+            ) THEN
+                ALTER TABLE news.articles_archive ADD PRIMARY KEY (id);
+            END IF;
+            FOR col IN
+                SELECT a.attname, format_type(a.atttypid, a.atttypmod) AS coltype
+                FROM pg_attribute a
+                WHERE a.attrelid = 'news.articles'::regclass
+                  AND a.attnum > 0
+                  AND NOT a.attisdropped
+                  AND NOT EXISTS (
+                      SELECT 1 FROM pg_attribute b
+                      WHERE b.attrelid = 'news.articles_archive'::regclass
+                        AND b.attname = a.attname
+                        AND NOT b.attisdropped
+                  )
+                ORDER BY a.attnum
+            LOOP
+                EXECUTE format(
+                    'ALTER TABLE news.articles_archive ADD COLUMN %I %s',
+                    col.attname,
+                    col.coltype
+                );
+            END LOOP;
+        END$$;
+        "#,
+    )
+    .await?;
+
     tx.commit().await?;
     Ok(())
 }
@@ -1,7 +1,19 @@
+pub mod admin_sessions;
+pub mod api_keys;
+pub mod analytics;
+pub mod article_contents;
+pub mod article_enrichments;
+pub mod article_states;
+pub mod article_translations;
 pub mod article_sources;
 pub mod articles;
+pub mod backups;
+pub mod digests;
 pub mod feeds;
+pub mod fetcher_instances;
 pub mod maintenance;
 pub mod migrations;
 pub mod settings;
+pub mod sources;
+pub mod users;
 pub mod events;
@@ -1,5 +1,44 @@
 use sqlx::PgPool;
 
+#[derive(Debug, sqlx::FromRow)]
+pub struct SettingRow {
+    pub key: String,
+    pub value: String,
+}
+
+pub async fn list_settings(pool: &PgPool) -> Result<Vec<SettingRow>, sqlx::Error> {
+    sqlx::query_as::<_, SettingRow>(
+        r#"
+        SELECT key, value
+        FROM news.settings
+        ORDER BY key
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// 批量写入（同一事务内），用于设置导入
+pub async fn upsert_settings(pool: &PgPool, entries: &[(String, String)]) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    for (key, value) in entries {
+        sqlx::query(
+            r#"
+            INSERT INTO news.settings (key, value)
+            VALUES ($1, $2)
+            ON CONFLICT (key) DO UPDATE
+            SET value = EXCLUDED.value,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(key)
+        .bind(value)
+        .execute(tx.as_mut())
+        .await?;
+    }
+    tx.commit().await
+}
+
 pub async fn get_setting(pool: &PgPool, key: &str) -> Result<Option<String>, sqlx::Error> {
     sqlx::query_scalar::<_, String>(
         r#"
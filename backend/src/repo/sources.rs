@@ -0,0 +1,226 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+/// 未配置权重的来源默认为 1.0
+pub const DEFAULT_SOURCE_WEIGHT: f32 = 1.0;
+/// 来源偏好规则中表示“任意来源”的通配符
+pub const ANY_SOURCE: &str = "*";
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct SourceWeightRow {
+    pub source_domain: String,
+    pub weight: f32,
+    pub daily_cap: Option<i32>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct SourcePreferenceRow {
+    pub id: i64,
+    pub preferred_domain: String,
+    pub over_domain: String,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct PublicSourceRow {
+    pub source_domain: String,
+    pub title: Option<String>,
+    pub site_url: Option<String>,
+    pub article_count: i64,
+    pub last_article_at: Option<DateTime<Utc>>,
+}
+
+/// 公开来源列表：口径与公开文章列表一致（仅主文章、未隐藏），启用中但尚无文章的来源计为 0；
+/// 同一域名有多个订阅源时取最早创建且带站点地址的一个作为展示信息
+pub async fn list_public_sources(pool: &PgPool) -> Result<Vec<PublicSourceRow>, sqlx::Error> {
+    sqlx::query_as::<_, PublicSourceRow>(
+        r#"
+        WITH counts AS (
+            SELECT source_domain,
+                   COUNT(*)::bigint AS article_count,
+                   MAX(published_at) AS last_article_at
+            FROM news.articles
+            WHERE (canonical_id IS NULL OR canonical_id = id)
+              AND hidden_at IS NULL
+            GROUP BY source_domain
+        ),
+        feed_info AS (
+            SELECT DISTINCT ON (source_domain) source_domain, title, site_url, enabled
+            FROM news.feeds
+            ORDER BY source_domain, enabled DESC, (site_url IS NULL), id
+        )
+        SELECT COALESCE(c.source_domain, f.source_domain) AS source_domain,
+               f.title,
+               f.site_url,
+               COALESCE(c.article_count, 0)::bigint AS article_count,
+               c.last_article_at
+        FROM counts c
+        FULL JOIN feed_info f ON f.source_domain = c.source_domain
+        WHERE c.source_domain IS NOT NULL OR f.enabled
+        ORDER BY article_count DESC, source_domain
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn list_weights(pool: &PgPool) -> Result<Vec<SourceWeightRow>, sqlx::Error> {
+    sqlx::query_as::<_, SourceWeightRow>(
+        r#"
+        SELECT source_domain,
+               weight,
+               daily_cap,
+               updated_at
+        FROM news.source_weights
+        ORDER BY weight DESC, source_domain
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn weight_map(pool: &PgPool) -> Result<HashMap<String, f32>, sqlx::Error> {
+    Ok(list_weights(pool)
+        .await?
+        .into_iter()
+        .map(|row| (row.source_domain, row.weight))
+        .collect())
+}
+
+pub async fn daily_cap(pool: &PgPool, source_domain: &str) -> Result<Option<i32>, sqlx::Error> {
+    let cap = sqlx::query_scalar::<_, Option<i32>>(
+        r#"
+        SELECT daily_cap
+        FROM news.source_weights
+        WHERE source_domain = $1
+        "#,
+    )
+    .bind(source_domain)
+    .fetch_optional(pool)
+    .await?;
+    Ok(cap.flatten())
+}
+
+pub async fn upsert_weight(
+    pool: &PgPool,
+    source_domain: &str,
+    weight: f32,
+    daily_cap: Option<i32>,
+) -> Result<SourceWeightRow, sqlx::Error> {
+    sqlx::query_as::<_, SourceWeightRow>(
+        r#"
+        INSERT INTO news.source_weights (source_domain, weight, daily_cap)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (source_domain) DO UPDATE
+        SET weight = EXCLUDED.weight,
+            daily_cap = EXCLUDED.daily_cap,
+            updated_at = NOW()
+        RETURNING source_domain, weight, daily_cap, updated_at
+        "#,
+    )
+    .bind(source_domain)
+    .bind(weight)
+    .bind(daily_cap)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn delete_weight(pool: &PgPool, source_domain: &str) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM news.source_weights
+        WHERE source_domain = $1
+        "#,
+    )
+    .bind(source_domain)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+pub async fn list_preferences(pool: &PgPool) -> Result<Vec<SourcePreferenceRow>, sqlx::Error> {
+    sqlx::query_as::<_, SourcePreferenceRow>(
+        r#"
+        SELECT id,
+               preferred_domain,
+               over_domain,
+               note,
+               created_at
+        FROM news.source_preferences
+        ORDER BY preferred_domain, over_domain
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// 全部偏好规则，以 `(preferred_domain, over_domain)` 为元素
+pub async fn preference_set(pool: &PgPool) -> Result<HashSet<(String, String)>, sqlx::Error> {
+    Ok(list_preferences(pool)
+        .await?
+        .into_iter()
+        .map(|row| (row.preferred_domain, row.over_domain))
+        .collect())
+}
+
+pub async fn upsert_preference(
+    pool: &PgPool,
+    preferred_domain: &str,
+    over_domain: &str,
+    note: Option<&str>,
+) -> Result<SourcePreferenceRow, sqlx::Error> {
+    sqlx::query_as::<_, SourcePreferenceRow>(
+        r#"
+        INSERT INTO news.source_preferences (preferred_domain, over_domain, note)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (preferred_domain, over_domain) DO UPDATE
+        SET note = EXCLUDED.note
+        RETURNING id, preferred_domain, over_domain, note, created_at
+        "#,
+    )
+    .bind(preferred_domain)
+    .bind(over_domain)
+    .bind(note)
+    .fetch_one(pool)
+    .await
+}
+
+/// 删除与给定方向相反的规则，避免两条规则互相矛盾
+pub async fn delete_reverse_preference(
+    pool: &PgPool,
+    preferred_domain: &str,
+    over_domain: &str,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM news.source_preferences
+        WHERE preferred_domain = $2
+          AND over_domain = $1
+        "#,
+    )
+    .bind(preferred_domain)
+    .bind(over_domain)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+pub async fn delete_preference(pool: &PgPool, id: i64) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM news.source_preferences
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
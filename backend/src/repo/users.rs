@@ -0,0 +1,173 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct UserRow {
+    pub id: i64,
+    pub username: String,
+    pub password_hash: String,
+    pub role: String,
+    pub auth_source: String,
+    pub disabled_at: Option<DateTime<Utc>>,
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+const USER_COLUMNS: &str =
+    "id, username, password_hash, role, auth_source, disabled_at, last_login_at, created_at, updated_at";
+
+/// 后台创建、以密码登录的账号
+pub const AUTH_SOURCE_PASSWORD: &str = "password";
+/// 单点登录时自动创建的账号
+pub const AUTH_SOURCE_OIDC: &str = "oidc";
+
+pub async fn list(pool: &PgPool) -> Result<Vec<UserRow>, sqlx::Error> {
+    let sql = format!("SELECT {USER_COLUMNS} FROM news.users ORDER BY id");
+    sqlx::query_as::<_, UserRow>(&sql).fetch_all(pool).await
+}
+
+pub async fn find_by_id(pool: &PgPool, id: i64) -> Result<Option<UserRow>, sqlx::Error> {
+    let sql = format!("SELECT {USER_COLUMNS} FROM news.users WHERE id = $1");
+    sqlx::query_as::<_, UserRow>(&sql)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
+
+pub async fn find_by_username(pool: &PgPool, username: &str) -> Result<Option<UserRow>, sqlx::Error> {
+    let sql = format!("SELECT {USER_COLUMNS} FROM news.users WHERE username = $1");
+    sqlx::query_as::<_, UserRow>(&sql)
+        .bind(username)
+        .fetch_optional(pool)
+        .await
+}
+
+/// 未停用账号的 ID 与角色，账号不存在或已停用时为 None；每次后台请求都会调用
+pub async fn find_active(pool: &PgPool, username: &str) -> Result<Option<(i64, String)>, sqlx::Error> {
+    sqlx::query_as::<_, (i64, String)>(
+        r#"
+        SELECT id, role
+        FROM news.users
+        WHERE username = $1
+          AND disabled_at IS NULL
+        "#,
+    )
+    .bind(username)
+    .fetch_optional(pool)
+    .await
+}
+
+pub struct NewUser<'a> {
+    pub username: &'a str,
+    pub password_hash: &'a str,
+    pub role: &'a str,
+}
+
+/// 新建账号；用户名已存在时返回 None
+pub async fn insert(pool: &PgPool, user: &NewUser<'_>) -> Result<Option<UserRow>, sqlx::Error> {
+    let sql = format!(
+        r#"
+        INSERT INTO news.users (username, password_hash, role)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (username) DO NOTHING
+        RETURNING {USER_COLUMNS}
+        "#
+    );
+    sqlx::query_as::<_, UserRow>(&sql)
+        .bind(user.username)
+        .bind(user.password_hash)
+        .bind(user.role)
+        .fetch_optional(pool)
+        .await
+}
+
+#[derive(Default)]
+pub struct UserChanges<'a> {
+    pub role: Option<&'a str>,
+    pub password_hash: Option<&'a str>,
+    pub disabled: Option<bool>,
+}
+
+/// 只更新给出的字段；停用时间保留首次停用的时刻
+pub async fn update(
+    pool: &PgPool,
+    id: i64,
+    changes: &UserChanges<'_>,
+) -> Result<Option<UserRow>, sqlx::Error> {
+    let sql = format!(
+        r#"
+        UPDATE news.users
+        SET role = COALESCE($2, role),
+            password_hash = COALESCE($3, password_hash),
+            disabled_at = CASE
+                WHEN $4::boolean IS NULL THEN disabled_at
+                WHEN $4 THEN COALESCE(disabled_at, NOW())
+            END,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING {USER_COLUMNS}
+        "#
+    );
+    sqlx::query_as::<_, UserRow>(&sql)
+        .bind(id)
+        .bind(changes.role)
+        .bind(changes.password_hash)
+        .bind(changes.disabled)
+        .fetch_optional(pool)
+        .await
+}
+
+/// 单点登录：按用户名创建或同步 `oidc` 账号的角色，返回账号；同名的密码账号不会被覆盖，此时返回 None
+pub async fn upsert_oidc(
+    pool: &PgPool,
+    username: &str,
+    password_hash: &str,
+    role: &str,
+) -> Result<Option<UserRow>, sqlx::Error> {
+    let sql = format!(
+        r#"
+        INSERT INTO news.users (username, password_hash, role, auth_source)
+        VALUES ($1, $2, $3, '{AUTH_SOURCE_OIDC}')
+        ON CONFLICT (username) DO UPDATE
+        SET role = EXCLUDED.role,
+            updated_at = NOW()
+        WHERE news.users.auth_source = '{AUTH_SOURCE_OIDC}'
+        RETURNING {USER_COLUMNS}
+        "#
+    );
+    sqlx::query_as::<_, UserRow>(&sql)
+        .bind(username)
+        .bind(password_hash)
+        .bind(role)
+        .fetch_optional(pool)
+        .await
+}
+
+/// 删除账号，返回被删除账号的用户名
+pub async fn delete(pool: &PgPool, id: i64) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar::<_, String>(
+        r#"
+        DELETE FROM news.users
+        WHERE id = $1
+        RETURNING username
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn record_login(pool: &PgPool, id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE news.users
+        SET last_login_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
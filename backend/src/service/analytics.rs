@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+use chrono::{Duration, NaiveDate, Utc};
+use sqlx::PgPool;
+
+use crate::{
+    error::{AppError, AppResult},
+    model::{DedupAnalyticsOut, DedupAnalyticsQuery, DedupDayOut, DedupMethodCounts, DedupSourcePairOut},
+    repo,
+};
+
+const DEFAULT_DAYS: i64 = 30;
+const MAX_DAYS: i64 = 365;
+const DEFAULT_TOP: i64 = 10;
+const MAX_TOP: i64 = 50;
+
+pub async fn dedup(pool: &PgPool, query: DedupAnalyticsQuery) -> AppResult<DedupAnalyticsOut> {
+    let days = query.days.unwrap_or(DEFAULT_DAYS);
+    if !(1..=MAX_DAYS).contains(&days) {
+        return Err(AppError::BadRequest(format!("days 必须在 1 到 {MAX_DAYS} 之间")));
+    }
+    let top = query.top.unwrap_or(DEFAULT_TOP);
+    if !(1..=MAX_TOP).contains(&top) {
+        return Err(AppError::BadRequest(format!("top 必须在 1 到 {MAX_TOP} 之间")));
+    }
+
+    let rows = repo::analytics::dedup_daily(pool, days as i32).await?;
+    let pairs = repo::analytics::dedup_top_pairs(pool, days as i32, top).await?;
+
+    // 补齐没有去重记录的日期，便于前端直接画图
+    let today = Utc::now().date_naive();
+    let mut by_day: BTreeMap<NaiveDate, DedupMethodCounts> = (0..days)
+        .map(|offset| (today - Duration::days(offset), DedupMethodCounts::default()))
+        .collect();
+    let mut totals = DedupMethodCounts::default();
+    for row in rows {
+        let Some(counts) = by_day.get_mut(&row.day) else {
+            continue;
+        };
+        add_count(counts, &row.method, row.count);
+        add_count(&mut totals, &row.method, row.count);
+    }
+
+    Ok(DedupAnalyticsOut {
+        days,
+        totals,
+        daily: by_day
+            .into_iter()
+            .map(|(day, counts)| DedupDayOut {
+                day: day.to_string(),
+                counts,
+            })
+            .collect(),
+        top_pairs: pairs
+            .into_iter()
+            .map(|row| DedupSourcePairOut {
+                duplicate_source: row.duplicate_source,
+                canonical_source: row.canonical_source,
+                count: row.count,
+                llm_count: row.llm_count,
+            })
+            .collect(),
+    })
+}
+
+fn add_count(counts: &mut DedupMethodCounts, method: &str, count: i64) {
+    let slot = match method {
+        repo::analytics::DEDUP_METHOD_INTRA_BATCH => &mut counts.intra_batch,
+        repo::analytics::DEDUP_METHOD_URL => &mut counts.url,
+        "fingerprint" => &mut counts.fingerprint,
+        "simhash" => &mut counts.simhash,
+        "jaccard" => &mut counts.jaccard,
+        "embedding" => &mut counts.embedding,
+        "llm" => &mut counts.llm,
+        "superseded" => &mut counts.superseded,
+        "manual" => &mut counts.manual,
+        _ => return,
+    };
+    *slot += count;
+    counts.total += count;
+}
@@ -0,0 +1,129 @@
+//! API 密钥：供无法交互式登录的脚本与集成调用后台接口，请求头 `X-Api-Key` 携带，
+//! 按密钥的角色与会话登录同样受 `auth::require_admin` 约束。密钥只在创建时返回一次，
+//! 数据库中只保存 SHA-256 摘要与用于辨认的前缀。
+
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{self, Role, ROLES},
+    error::{AppError, AppResult},
+    model::{ApiKeyCreatePayload, ApiKeyCreatedOut, ApiKeyOut},
+    ops::event_catalog::{Event, EventCode},
+    repo::{
+        self,
+        api_keys::{ApiKeyRow, NewApiKey},
+    },
+};
+
+const KEY_PREFIX: &str = "na_";
+// 列表中展示的前缀长度（含 `na_`）
+const DISPLAY_PREFIX_LEN: usize = 11;
+const NAME_MAX_CHARS: usize = 64;
+const MAX_EXPIRES_IN_DAYS: i64 = 3650;
+
+pub async fn list(pool: &PgPool) -> AppResult<Vec<ApiKeyOut>> {
+    let rows = repo::api_keys::list(pool).await?;
+    Ok(rows.into_iter().map(api_key_row_to_out).collect())
+}
+
+pub async fn create(
+    pool: &PgPool,
+    created_by: &str,
+    payload: ApiKeyCreatePayload,
+) -> AppResult<ApiKeyCreatedOut> {
+    let name = payload.name.trim();
+    if name.is_empty() || name.chars().count() > NAME_MAX_CHARS || name.chars().any(char::is_control) {
+        return Err(AppError::BadRequest(format!(
+            "name 不能为空且不超过 {NAME_MAX_CHARS} 个字符"
+        )));
+    }
+    let role = match payload.role.as_deref().map(str::trim) {
+        None | Some("") => Role::Viewer,
+        Some(raw) => Role::parse(raw)
+            .ok_or_else(|| AppError::BadRequest(format!("role 可选值：{}", ROLES.join("|"))))?,
+    };
+    let expires_at = payload
+        .expires_in_days
+        .map(|days| {
+            if (1..=MAX_EXPIRES_IN_DAYS).contains(&days) {
+                Ok(Utc::now() + Duration::days(days))
+            } else {
+                Err(AppError::BadRequest(format!(
+                    "expires_in_days 需在 1-{MAX_EXPIRES_IN_DAYS} 之间"
+                )))
+            }
+        })
+        .transpose()?;
+
+    // 两个 UUIDv4 共 244 位随机数，摘要无需加盐或慢哈希
+    let key = format!(
+        "{KEY_PREFIX}{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    );
+    let row = repo::api_keys::insert(
+        pool,
+        &NewApiKey {
+            name,
+            key_hash: &auth::token_hash(&key),
+            key_prefix: &key[..DISPLAY_PREFIX_LEN],
+            role: role.as_str(),
+            created_by,
+            expires_at,
+        },
+    )
+    .await?;
+
+    tracing::info!(api_key_id = row.id, name, role = role.as_str(), "api key created");
+    Event::new(EventCode::ApiKeyCreated)
+        .attr("key_id", row.id)
+        .attr("name", name)
+        .attr("role", role.as_str())
+        .attr("created_by", created_by)
+        .detail(format!(
+            "{created_by} 创建 API 密钥 {name}（{}，{}）",
+            role.as_str(),
+            row.key_prefix
+        ))
+        .emit(pool)
+        .await;
+    Ok(ApiKeyCreatedOut {
+        key,
+        api_key: api_key_row_to_out(row),
+    })
+}
+
+/// 吊销后立即失效，记录保留
+pub async fn revoke(pool: &PgPool, revoked_by: &str, id: i64) -> AppResult<ApiKeyOut> {
+    let row = repo::api_keys::revoke(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("api key {id} not found or already revoked")))?;
+
+    tracing::info!(api_key_id = id, name = %row.name, "api key revoked");
+    Event::new(EventCode::ApiKeyRevoked)
+        .attr("key_id", id)
+        .attr("name", row.name.as_str())
+        .attr("revoked_by", revoked_by)
+        .detail(format!("{revoked_by} 吊销 API 密钥 {}（{}）", row.name, row.key_prefix))
+        .emit(pool)
+        .await;
+    Ok(api_key_row_to_out(row))
+}
+
+fn api_key_row_to_out(row: ApiKeyRow) -> ApiKeyOut {
+    let active = row.revoked_at.is_none() && row.expires_at.is_none_or(|at| at > Utc::now());
+    ApiKeyOut {
+        id: row.id,
+        name: row.name,
+        key_prefix: row.key_prefix,
+        role: row.role,
+        created_by: row.created_by,
+        created_at: row.created_at.to_rfc3339(),
+        last_used_at: row.last_used_at.map(|at| at.to_rfc3339()),
+        expires_at: row.expires_at.map(|at| at.to_rfc3339()),
+        revoked_at: row.revoked_at.map(|at| at.to_rfc3339()),
+        active,
+    }
+}
@@ -1,22 +1,95 @@
-use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveTime, SecondsFormat, Utc};
 use sqlx::PgPool;
 
 use crate::{
+    auth::{AdminContext, Role},
     error::{AppError, AppResult},
-    model::{ArticleListQuery, ArticleOut, PageResp},
-    repo,
+    model::{
+        ArchiveDayOut, ArchiveQuery, ArchiveResp, ArticleChangesOut, ArticleClusterOut, ClusterListQuery, ClusterMemberOut, ClusterOut, ArticleChangesQuery, ArticleApprovePayload, ArticleApproveResult, ArticleBulkPayload, ArticleBulkResult, ArticleFeedQuery, ArticleHidePayload, ArticleListQuery, ArticleOut, ArticleUpdatePayload, ArticleSourceOut, ArticleStateOut, FacetCount,
+        ModerationLogOut, PageCursor, PageResp, PendingArticleOut, ReviewQueueQuery, PublicArticleSourceOut, SearchFacets, SearchQuery, SearchResp, SimilarArticleOut,
+        SimilarArticlesOut, SimilarArticlesQuery,
+    },
+    fetcher,
+    ops::event_catalog::{Event, EventCode},
+    repo::{self, articles::{ArticleSort, FeaturedArgs}},
+    service::{digest, embedding, settings_registry},
+    util::{
+        html::escape_xml,
+        lang::{normalize_language_tag, SUPPORTED_LANGUAGES},
+        title::{jaccard_similarity, prepare_title_signature, title_simhash},
+    },
 };
 
-pub async fn list(pool: &PgPool, query: ArticleListQuery) -> AppResult<PageResp<ArticleOut>> {
+/// `reader_id` 为请求携带的读者 ID，仅在按已读/收藏筛选时需要
+pub async fn list(
+    pool: &PgPool,
+    query: ArticleListQuery,
+    reader_id: Option<&str>,
+) -> AppResult<PageResp<ArticleOut>> {
     let ArticleListQuery {
         from,
         to,
         page,
         page_size,
         keyword,
+        original_language,
+        feed_tag,
+        source_domain,
+        q,
+        count,
+        after_published_at,
+        after_id,
+        sort,
+        order,
+        read,
+        bookmarked,
     } = query;
 
-    let page = if page == 0 { 1 } else { page };
+    let estimate_total = match count.as_deref().map(str::trim) {
+        None | Some("") | Some("exact") => false,
+        Some("estimate") => true,
+        Some(_) => return Err(AppError::BadRequest("count 可选值：exact|estimate".to_string())),
+    };
+    let after = match (after_published_at.as_deref(), after_id) {
+        (None, None) => None,
+        (Some(raw), Some(id)) => {
+            let published_at = DateTime::parse_from_rfc3339(raw.trim())
+                .map_err(|_| AppError::BadRequest("invalid after_published_at timestamp".to_string()))?;
+            Some((published_at.with_timezone(&Utc), id))
+        }
+        _ => {
+            return Err(AppError::BadRequest(
+                "after_published_at 与 after_id 需同时提供".to_string(),
+            ))
+        }
+    };
+    let sort = match sort.as_deref().map(str::trim).filter(|raw| !raw.is_empty()) {
+        None => ArticleSort::default(),
+        Some(raw) => ArticleSort::parse(raw).ok_or_else(|| {
+            AppError::BadRequest("sort 可选值：published_at|click_count|fetched_at|rank".to_string())
+        })?,
+    };
+    let descending = match order.as_deref().map(str::trim) {
+        None | Some("") | Some("desc") => true,
+        Some("asc") => false,
+        Some(_) => return Err(AppError::BadRequest("order 可选值：desc|asc".to_string())),
+    };
+    if (read.is_some() || bookmarked.is_some()) && reader_id.is_none() {
+        return Err(AppError::BadRequest(
+            "按 read / bookmarked 筛选需要 X-Reader-Id 请求头".to_string(),
+        ));
+    }
+    // 游标按 (published_at, id) 降序推进，其他排序只能按页翻
+    let keyset = sort == ArticleSort::PublishedAt && descending;
+    if after.is_some() && !keyset {
+        return Err(AppError::BadRequest(
+            "游标分页仅支持默认排序（sort=published_at&order=desc）".to_string(),
+        ));
+    }
+    // 游标模式下不再按页偏移
+    let page = if after.is_some() || page == 0 { 1 } else { page };
     let page_size = page_size.clamp(1, 50);
     let offset = ((page - 1) * page_size) as i64;
     let limit = page_size as i64;
@@ -28,43 +101,440 @@ pub async fn list(pool: &PgPool, query: ArticleListQuery) -> AppResult<PageResp<
         .map(|value| value.trim())
         .filter(|value| !value.is_empty())
         .map(|value| value.to_string());
+    let original_language = parse_original_language(original_language.as_deref())?;
+    let feed_tag = feed_tag
+        .as_deref()
+        .map(|value| value.trim().to_lowercase())
+        .filter(|value| !value.is_empty());
+    let source_domain = source_domain
+        .as_deref()
+        .map(|value| value.trim().to_lowercase())
+        .filter(|value| !value.is_empty());
+    let q = q
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
 
-    let (rows, total) = repo::articles::list_articles(
+    let (rows, total, total_estimated) = repo::articles::list_articles(
         pool,
         repo::articles::ArticleListArgs {
             from,
             to,
             keyword,
+            original_language,
+            feed_tag,
+            source_domain,
+            query: q,
+            reader_id: reader_id.map(str::to_string),
+            read,
+            bookmarked,
+            after,
+            sort,
+            descending,
             limit,
             offset,
+            estimate_total,
         },
     )
     .await?;
 
-    tracing::info!(page, page_size, total, "articles list queried");
+    tracing::info!(page, page_size, total, total_estimated, cursor = after.is_some(), "articles list queried");
 
-    let items = rows
-        .into_iter()
-        .map(|row| ArticleOut {
-            id: row.id,
-            title: row.title,
-            url: row.url,
-            description: row.description,
-            language: row.language,
-            source_domain: row.source_domain,
-            published_at: row.published_at.to_rfc3339(),
-            click_count: row.click_count,
-        })
-        .collect();
+    // 估算值可能小于已翻过的条数，至少保证当前页可达
+    let reached = offset + rows.len() as i64;
+    let total = if total_estimated { total.max(reached) } else { total };
+    let next_cursor = rows
+        .last()
+        .filter(|_| keyset && rows.len() as i64 == limit)
+        .map(|row| PageCursor {
+            after_published_at: row.published_at.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            after_id: row.id,
+        });
+    let items = rows.into_iter().map(article_row_to_out).collect();
 
     Ok(PageResp {
         page,
         page_size,
         total_hint: total.max(0) as u64,
+        total_estimated,
+        next_cursor,
         items,
     })
 }
 
+const CHANGES_DEFAULT_LIMIT: i64 = 50;
+const CHANGES_MAX_LIMIT: i64 = 200;
+
+/// 增量同步：返回内部 ID 大于 since_id 的新入库文章。按 ID 而非 published_at 推进，
+/// 发布时间被回填到过去的文章同样不会漏掉
+pub async fn changes(pool: &PgPool, query: ArticleChangesQuery) -> AppResult<ArticleChangesOut> {
+    let since_id = query.since_id.max(0);
+    let limit = query
+        .limit
+        .unwrap_or(CHANGES_DEFAULT_LIMIT)
+        .clamp(1, CHANGES_MAX_LIMIT);
+    let (rows, last_id, scanned) = repo::articles::list_changes(pool, since_id, limit).await?;
+    Ok(ArticleChangesOut {
+        items: rows.into_iter().map(article_row_to_out).collect(),
+        next_since_id: last_id.unwrap_or(since_id),
+        has_more: scanned >= limit,
+    })
+}
+
+const FEED_DEFAULT_LIMIT: u32 = 30;
+
+/// 公开 Atom 输出：最新（或按条件筛选）的文章，已去重并使用翻译后的标题。
+/// `self_url` 为本次请求的完整地址（含查询参数），同时作为 feed 的 id。
+/// 条目链接指向点击跳转接口，订阅端的访问同样计入点击数
+pub async fn atom(
+    pool: &PgPool,
+    query: ArticleFeedQuery,
+    site_url: &str,
+    self_url: &str,
+) -> AppResult<String> {
+    let (title, items) = feed_items(pool, query).await?;
+
+    let updated = items
+        .first()
+        .map(|item| item.published_at.clone())
+        .unwrap_or_else(|| Utc::now().to_rfc3339());
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push_str(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#);
+    xml.push_str(&format!("<title>{}</title>", escape_xml(&title)));
+    xml.push_str(&format!("<id>{}</id>", escape_xml(self_url)));
+    xml.push_str(&format!(
+        r#"<link rel="self" type="application/atom+xml" href="{}"/>"#,
+        escape_xml(self_url)
+    ));
+    xml.push_str(&format!(
+        r#"<link rel="alternate" type="text/html" href="{}/"/>"#,
+        escape_xml(site_url)
+    ));
+    xml.push_str(&format!("<updated>{}</updated>", escape_xml(&updated)));
+    xml.push_str("<generator>NewsAggregator</generator>");
+    for item in &items {
+        let link = format!("{site_url}/api/r/{}", item.id);
+        match item.language.as_deref() {
+            Some(lang) => xml.push_str(&format!(r#"<entry xml:lang="{}">"#, escape_xml(lang))),
+            None => xml.push_str("<entry>"),
+        }
+        xml.push_str(&format!("<id>{}</id>", escape_xml(&link)));
+        xml.push_str(&format!(r#"<title type="text">{}</title>"#, feed_text(&item.title)));
+        xml.push_str(&format!(r#"<link rel="alternate" href="{}"/>"#, escape_xml(&link)));
+        xml.push_str(&format!("<published>{}</published>", escape_xml(&item.published_at)));
+        xml.push_str(&format!("<updated>{}</updated>", escape_xml(&item.published_at)));
+        xml.push_str(&format!(
+            "<author><name>{}</name></author>",
+            escape_xml(&item.source_domain)
+        ));
+        for tag in &item.tags {
+            xml.push_str(&format!(r#"<category term="{}"/>"#, escape_xml(tag)));
+        }
+        if let Some(description) = item.description.as_deref().filter(|text| !text.is_empty()) {
+            xml.push_str(&format!(r#"<summary type="text">{}</summary>"#, feed_text(description)));
+        }
+        xml.push_str("</entry>");
+    }
+    xml.push_str("</feed>");
+    Ok(xml)
+}
+
+/// 公开 RSS 2.0 输出：条目与筛选条件同 Atom 输出，供只支持 RSS 的阅读器订阅
+pub async fn rss(
+    pool: &PgPool,
+    query: ArticleFeedQuery,
+    site_url: &str,
+    self_url: &str,
+) -> AppResult<String> {
+    let (title, items) = feed_items(pool, query).await?;
+
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push_str(r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom"><channel>"#);
+    xml.push_str(&format!("<title>{}</title>", escape_xml(&title)));
+    xml.push_str(&format!("<link>{}/</link>", escape_xml(site_url)));
+    xml.push_str(&format!("<description>{}</description>", escape_xml(&title)));
+    xml.push_str(&format!(
+        r#"<atom:link rel="self" type="application/rss+xml" href="{}"/>"#,
+        escape_xml(self_url)
+    ));
+    if let Some(latest) = items.first() {
+        xml.push_str(&format!(
+            "<lastBuildDate>{}</lastBuildDate>",
+            digest::rfc2822(&latest.published_at)
+        ));
+    }
+    xml.push_str("<generator>NewsAggregator</generator>");
+    for item in &items {
+        let link = format!("{site_url}/api/r/{}", item.id);
+        xml.push_str("<item>");
+        xml.push_str(&format!("<title>{}</title>", feed_text(&item.title)));
+        xml.push_str(&format!("<link>{}</link>", escape_xml(&link)));
+        xml.push_str(&format!(r#"<guid isPermaLink="true">{}</guid>"#, escape_xml(&link)));
+        xml.push_str(&format!("<pubDate>{}</pubDate>", digest::rfc2822(&item.published_at)));
+        for tag in &item.tags {
+            xml.push_str(&format!("<category>{}</category>", escape_xml(tag)));
+        }
+        if let Some(description) = item.description.as_deref().filter(|text| !text.is_empty()) {
+            xml.push_str(&format!("<description>{}</description>", feed_text(description)));
+        }
+        xml.push_str("</item>");
+    }
+    xml.push_str("</channel></rss>");
+    Ok(xml)
+}
+
+// 两种输出共用：按筛选条件取最新一页，并给出体现筛选条件的 feed 标题，
+// 订阅多个切片的阅读器中可以区分
+async fn feed_items(pool: &PgPool, query: ArticleFeedQuery) -> AppResult<(String, Vec<ArticleOut>)> {
+    let ArticleFeedQuery {
+        from,
+        to,
+        keyword,
+        original_language,
+        feed_tag,
+        tag,
+        source_domain,
+        q,
+        limit,
+    } = query;
+    let feed_tag = match (feed_tag, tag) {
+        (Some(_), Some(_)) => {
+            return Err(AppError::BadRequest("tag 与 feed_tag 含义相同，只需提供一个".to_string()))
+        }
+        (feed_tag, tag) => feed_tag.or(tag),
+    };
+
+    let mut filters = Vec::new();
+    if let Some(tag) = feed_tag.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        filters.push(format!("标签 {tag}"));
+    }
+    if let Some(domain) = source_domain.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        filters.push(format!("来源 {domain}"));
+    }
+    if let Some(q) = q.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        filters.push(format!("搜索 {q}"));
+    }
+    let title = if filters.is_empty() {
+        "NewsAggregator 最新文章".to_string()
+    } else {
+        format!("NewsAggregator · {}", filters.join(" · "))
+    };
+
+    let page = list(
+        pool,
+        ArticleListQuery {
+            from,
+            to,
+            keyword,
+            original_language,
+            feed_tag,
+            source_domain,
+            q,
+            page_size: limit.unwrap_or(FEED_DEFAULT_LIMIT),
+            // 只取最新一页，不需要精确总数
+            count: Some("estimate".to_string()),
+            ..Default::default()
+        },
+        None,
+    )
+    .await?;
+    Ok((title, page.items))
+}
+
+// XML 1.0 不允许大部分控制字符，来源标题与摘要中偶有出现，直接丢弃以保证输出可解析
+fn feed_text(text: &str) -> String {
+    let cleaned: String = text
+        .chars()
+        .filter(|ch| !ch.is_control() || matches!(ch, '\t' | '\n' | '\r'))
+        .collect();
+    escape_xml(&cleaned)
+}
+
+// 每个分面最多返回的取值数量
+const SEARCH_FACET_LIMIT: usize = 20;
+
+pub async fn search(pool: &PgPool, query: SearchQuery) -> AppResult<SearchResp> {
+    let SearchQuery {
+        q,
+        page,
+        page_size,
+        source_domain,
+        tag,
+        day,
+    } = query;
+
+    let keyword = q
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| AppError::BadRequest("q is required".to_string()))?;
+
+    let page = if page == 0 { 1 } else { page };
+    let page_size = page_size.clamp(1, 50);
+    let day = day
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map_err(|_| AppError::BadRequest("invalid day, expected YYYY-MM-DD".to_string()))
+        })
+        .transpose()?;
+
+    let args = repo::articles::ArticleSearchArgs {
+        pattern: format!("%{keyword}%"),
+        source_domain: source_domain
+            .as_deref()
+            .map(|value| value.trim().to_lowercase())
+            .filter(|value| !value.is_empty()),
+        tag: tag
+            .as_deref()
+            .map(|value| value.trim().to_lowercase())
+            .filter(|value| !value.is_empty()),
+        day,
+        limit: page_size as i64,
+        offset: ((page - 1) * page_size) as i64,
+    };
+
+    let (rows, facet_rows) = repo::articles::search_articles(pool, &args).await?;
+
+    let mut total = 0;
+    let mut facets = SearchFacets::default();
+    for row in facet_rows {
+        let bucket = match row.facet.as_str() {
+            "source_domain" => &mut facets.source_domain,
+            "tag" => &mut facets.tag,
+            "day" => &mut facets.day,
+            _ => {
+                total = row.count;
+                continue;
+            }
+        };
+        // 未打标签的文章在标签分面中表现为 NULL，忽略
+        let Some(value) = row.value else { continue };
+        if bucket.len() < SEARCH_FACET_LIMIT {
+            bucket.push(FacetCount {
+                value,
+                count: row.count,
+            });
+        }
+    }
+
+    tracing::info!(page, page_size, total, "articles searched");
+
+    Ok(SearchResp {
+        page,
+        page_size,
+        total_hint: total.max(0) as u64,
+        items: rows.into_iter().map(article_row_to_out).collect(),
+        facets,
+    })
+}
+
+/// 归档页：一个月（UTC）内每天的文章数，以及其中一天的文章分页
+pub async fn archive(pool: &PgPool, query: ArchiveQuery) -> AppResult<ArchiveResp> {
+    let ArchiveQuery {
+        year,
+        month,
+        day,
+        page,
+        page_size,
+    } = query;
+
+    let (year, month) = match (year, month) {
+        (Some(year), Some(month)) => (year, month),
+        (None, None) => {
+            let today = Utc::now().date_naive();
+            (today.year(), today.month())
+        }
+        _ => return Err(AppError::BadRequest("year 与 month 需同时提供".to_string())),
+    };
+    if !(1..=12).contains(&month) {
+        return Err(AppError::BadRequest("month 需在 1-12 之间".to_string()));
+    }
+    let first_day = NaiveDate::from_ymd_opt(year, month, 1)
+        .ok_or_else(|| AppError::BadRequest("year 超出范围".to_string()))?;
+    let next_month = first_day
+        .checked_add_months(Months::new(1))
+        .ok_or_else(|| AppError::BadRequest("year 超出范围".to_string()))?;
+
+    let selected = day
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map_err(|_| AppError::BadRequest("invalid day, expected YYYY-MM-DD".to_string()))
+        })
+        .transpose()?;
+    if selected.is_some_and(|day| day < first_day || day >= next_month) {
+        return Err(AppError::BadRequest("day 需在所查询的月份内".to_string()));
+    }
+
+    let page = if page == 0 { 1 } else { page };
+    let page_size = page_size.clamp(1, 50);
+
+    let counts: HashMap<NaiveDate, i64> = repo::articles::archive_day_counts(pool, day_start(first_day), day_start(next_month))
+        .await?
+        .into_iter()
+        .map(|row| (row.day, row.count))
+        .collect();
+    let total = counts.values().sum();
+    let selected = selected.or_else(|| counts.keys().max().copied());
+
+    let (day_total, items) = match selected {
+        Some(day) => {
+            let day_total = counts.get(&day).copied().unwrap_or(0);
+            let rows = if day_total > 0 {
+                repo::articles::list_archive_articles(
+                    pool,
+                    day_start(day),
+                    day_start(day + Days::new(1)),
+                    page_size as i64,
+                    ((page - 1) * page_size) as i64,
+                )
+                .await?
+            } else {
+                Vec::new()
+            };
+            (day_total, rows.into_iter().map(article_row_to_out).collect())
+        }
+        None => (0, Vec::new()),
+    };
+
+    let days = first_day
+        .iter_days()
+        .take_while(|day| *day < next_month)
+        .map(|day| ArchiveDayOut {
+            day: day.to_string(),
+            count: counts.get(&day).copied().unwrap_or(0),
+        })
+        .collect();
+
+    tracing::info!(year, month, total, day = ?selected, page, "archive queried");
+
+    Ok(ArchiveResp {
+        year,
+        month,
+        total,
+        days,
+        day: selected.map(|day| day.to_string()),
+        articles: PageResp {
+            page,
+            page_size,
+            total_hint: day_total.max(0) as u64,
+            total_estimated: false,
+            next_cursor: None,
+            items,
+        },
+    })
+}
+
+fn day_start(day: NaiveDate) -> DateTime<Utc> {
+    day.and_time(NaiveTime::MIN).and_utc()
+}
+
 fn parse_optional_datetime(value: Option<&str>, field: &str) -> AppResult<Option<DateTime<Utc>>> {
     match value {
         Some(raw) => {
@@ -76,24 +546,889 @@ fn parse_optional_datetime(value: Option<&str>, field: &str) -> AppResult<Option
     }
 }
 
+fn parse_original_language(value: Option<&str>) -> AppResult<Option<String>> {
+    let Some(raw) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    let lowered = raw.to_ascii_lowercase();
+    if SUPPORTED_LANGUAGES.contains(&lowered.as_str()) {
+        Ok(Some(lowered))
+    } else {
+        Err(AppError::BadRequest(format!(
+            "invalid original_language, expected one of: {}",
+            SUPPORTED_LANGUAGES.join("|")
+        )))
+    }
+}
+
+pub const REFERRER_POLICY_KEY: &str = "redirect.referrer_policy";
+pub const REFERRER_POLICIES: &[&str] = &[
+    "no-referrer",
+    "origin",
+    "strict-origin",
+    "strict-origin-when-cross-origin",
+    "no-referrer-when-downgrade",
+    "unsafe-url",
+];
+pub const DEFAULT_REFERRER_POLICY: &str = "strict-origin-when-cross-origin";
+
+pub struct ClickRedirect {
+    pub url: String,
+    /// 点击计入主文章
+    pub canonical_id: i64,
+    pub referrer_policy: String,
+}
+
+/// 点击跳转的目标。隐藏或不存在的文章返回 400；只跳转 http(s) 链接，避免订阅源中的异常链接被当作跳板
+pub async fn click_redirect(pool: &PgPool, id: i64) -> AppResult<ClickRedirect> {
+    let target = repo::articles::find_redirect_target(pool, id)
+        .await?
+        .filter(|target| !target.hidden)
+        .ok_or_else(|| AppError::BadRequest(format!("article {id} not found")))?;
+    let scheme_ok = url::Url::parse(&target.url).is_ok_and(|url| matches!(url.scheme(), "http" | "https"));
+    if !scheme_ok {
+        return Err(AppError::BadRequest(format!("article {id} has no valid url")));
+    }
+    let referrer_policy = settings_registry::get_value(pool, REFERRER_POLICY_KEY)
+        .await?
+        .unwrap_or_else(|| DEFAULT_REFERRER_POLICY.to_string());
+    Ok(ClickRedirect {
+        url: target.url,
+        canonical_id: target.canonical_id,
+        referrer_policy,
+    })
+}
+
 pub async fn record_click(pool: &PgPool, id: i64) -> AppResult<()> {
     repo::articles::increment_click(pool, id).await?;
     Ok(())
 }
 
-pub async fn list_featured(pool: &PgPool, limit: i64) -> AppResult<Vec<ArticleOut>> {
-    let rows = repo::articles::list_top_articles(pool, limit).await?;
+const FEATURED_DEFAULT_LIMIT: i64 = 10;
+const FEATURED_MAX_LIMIT: i64 = 100;
+const FEATURED_DEFAULT_WINDOW_HOURS: i32 = 24;
+// 窗口越长扫描越多，上限一周
+const FEATURED_MAX_WINDOW_HOURS: i32 = 168;
+
+/// 校验热门文章参数：limit 与 window_hours 超出上限时截断，max_per_source 不超过 limit
+pub fn featured_args(
+    limit: Option<i64>,
+    window_hours: Option<i32>,
+    max_per_source: Option<i64>,
+) -> AppResult<FeaturedArgs> {
+    let limit = limit
+        .unwrap_or(FEATURED_DEFAULT_LIMIT)
+        .clamp(1, FEATURED_MAX_LIMIT);
+    let window_hours = window_hours.unwrap_or(FEATURED_DEFAULT_WINDOW_HOURS);
+    if window_hours < 1 {
+        return Err(AppError::BadRequest("window_hours 必须为正整数".to_string()));
+    }
+    if max_per_source.is_some_and(|max| max < 1) {
+        return Err(AppError::BadRequest("max_per_source 必须为正整数".to_string()));
+    }
+    Ok(FeaturedArgs {
+        limit,
+        window_hours: window_hours.min(FEATURED_MAX_WINDOW_HOURS),
+        // 不小于 limit 的上限等同于不限制，归一后共享同一缓存项
+        max_per_source: max_per_source.filter(|max| *max < limit),
+    })
+}
+
+pub async fn list_featured(pool: &PgPool, args: FeaturedArgs) -> AppResult<Vec<ArticleOut>> {
+    let rows = repo::articles::list_top_articles(pool, args).await?;
+    Ok(rows.into_iter().map(article_row_to_out).collect())
+}
+
+/// 公开来源列表：被归并的文章返回其所在簇（主文章）的来源，主文章已隐藏时视为不存在
+pub async fn list_public_sources(pool: &PgPool, id: i64) -> AppResult<Vec<PublicArticleSourceOut>> {
+    let not_found = || AppError::BadRequest(format!("article {id} not found"));
+    let canonical_id = repo::articles::find_canonical_id(pool, id)
+        .await?
+        .ok_or_else(not_found)?;
+    if !matches!(repo::articles::find_hidden_at(pool, canonical_id).await?, Some(None)) {
+        return Err(not_found());
+    }
+    let rows = repo::article_sources::list_public_by_article(pool, canonical_id).await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| PublicArticleSourceOut {
+            feed_id: row.feed_id,
+            feed_title: row.feed_title,
+            source_name: row.source_name,
+            url: row.source_url,
+            published_at: row.published_at.map(|dt| dt.to_rfc3339()),
+            decision: row.decision,
+            confidence: row.confidence,
+        })
+        .collect())
+}
+
+pub async fn list_sources(pool: &PgPool, id: i64) -> AppResult<Vec<ArticleSourceOut>> {
+    if repo::articles::find_by_id(pool, id).await?.is_none() {
+        return Err(AppError::BadRequest(format!("article {id} not found")));
+    }
+    let rows = repo::article_sources::list_by_article(pool, id).await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| ArticleSourceOut {
+            id: row.id,
+            article_id: row.article_id,
+            feed_id: row.feed_id,
+            source_name: row.source_name,
+            source_url: row.source_url,
+            published_at: row.published_at.map(|dt| dt.to_rfc3339()),
+            inserted_at: row.inserted_at.to_rfc3339(),
+            decision: row.decision,
+            confidence: row.confidence,
+            jaccard: row.jaccard,
+            matched_tokens: row.matched_tokens.unwrap_or_default(),
+            llm_raw: row.llm_raw,
+        })
+        .collect())
+}
+
+// 相似度浏览最多比较的候选数量
+const SIMILAR_CANDIDATE_CAP: i64 = 2000;
+
+/// 相似文章：与去重相同的标题分词与 Jaccard 计算，但不论是否已判为重复都返回得分，
+/// 用于调整阈值与检查去重结果
+pub async fn similar(
+    pool: &PgPool,
+    id: i64,
+    query: SimilarArticlesQuery,
+) -> AppResult<SimilarArticlesOut> {
+    let method = query
+        .method
+        .as_deref()
+        .map(str::trim)
+        .filter(|method| !method.is_empty())
+        .unwrap_or("jaccard")
+        .to_ascii_lowercase();
+    match method.as_str() {
+        "jaccard" => {}
+        "embedding" => return similar_by_embedding(pool, id, query.limit).await,
+        other => {
+            return Err(AppError::BadRequest(format!(
+                "不支持的相似度方式：{other}（可选 jaccard、embedding）"
+            )))
+        }
+    }
+    let limit = query.limit.unwrap_or(20).clamp(1, 100) as usize;
+
+    let article = repo::articles::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {id} not found")))?;
+    let canonical_id = repo::articles::find_canonical_id(pool, id)
+        .await?
+        .unwrap_or(id);
+    let window_hours = settings_registry::get_i64(pool, "dedup.window_hours")
+        .await?
+        .unwrap_or(fetcher::DEFAULT_DEDUP_WINDOW_HOURS);
+    let candidates = repo::articles::list_similarity_candidates(
+        pool,
+        id,
+        article.published_at,
+        window_hours,
+        SIMILAR_CANDIDATE_CAP,
+    )
+    .await?;
+
+    let (normalized, tokens) = prepare_title_signature(&article.title);
+    let scanned = candidates.len();
+    let mut items: Vec<SimilarArticleOut> = candidates
+        .into_iter()
+        .filter_map(|row| {
+            let (other_normalized, other_tokens) = prepare_title_signature(&row.title);
+            let score = jaccard_similarity(&tokens, &other_tokens);
+            let identical = !normalized.is_empty() && other_normalized == normalized;
+            if score <= 0.0 && !identical {
+                return None;
+            }
+            Some(SimilarArticleOut {
+                article_id: row.id,
+                matched_tokens: tokens.intersection(&other_tokens).cloned().collect(),
+                verdict: fetcher::dedup_verdict(score, identical).to_string(),
+                title: row.title,
+                url: row.url,
+                source_domain: row.source_domain,
+                published_at: row.published_at.to_rfc3339(),
+                score,
+                same_cluster: row.canonical_id == canonical_id,
+                canonical_id: row.canonical_id,
+            })
+        })
+        .collect();
+    items.sort_by(|a, b| b.score.total_cmp(&a.score));
+    items.truncate(limit);
+
+    Ok(SimilarArticlesOut {
+        article_id: id,
+        method,
+        window_hours,
+        scanned,
+        strict_threshold: fetcher::STRICT_DUP_THRESHOLD,
+        llm_threshold: fetcher::DEEPSEEK_THRESHOLD,
+        items,
+    })
+}
+
+/// 向量近邻：与抓取去重使用同一 Qdrant 索引，得分为余弦相似度，阈值与判定按 embedding 去重的取值
+async fn similar_by_embedding(
+    pool: &PgPool,
+    id: i64,
+    limit: Option<i64>,
+) -> AppResult<SimilarArticlesOut> {
+    let index = embedding::index().ok_or_else(|| {
+        AppError::BadRequest("未配置 qdrant，无法使用 method=embedding".to_string())
+    })?;
+    let limit = limit.unwrap_or(20).clamp(1, 100) as usize;
+
+    let article = repo::articles::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {id} not found")))?;
+    let canonical_id = repo::articles::find_canonical_id(pool, id)
+        .await?
+        .unwrap_or(id);
+    let window_hours = settings_registry::get_i64(pool, "dedup.window_hours")
+        .await?
+        .unwrap_or(fetcher::DEFAULT_DEDUP_WINDOW_HOURS);
+    let neighbours = index
+        .similar_to(id, article.published_at, window_hours, limit)
+        .await
+        .map_err(|err| AppError::Internal(err.context("embedding neighbour search failed")))?
+        .ok_or_else(|| AppError::BadRequest(format!("文章 {id} 尚未建立 embedding 索引")))?;
+
+    let ids: Vec<i64> = neighbours.iter().map(|point| point.article_id).collect();
+    let mut rows: HashMap<i64, _> = repo::articles::list_similarity_rows(pool, &ids)
+        .await?
+        .into_iter()
+        .map(|row| (row.id, row))
+        .collect();
+    let (_, tokens) = prepare_title_signature(&article.title);
+    let items = neighbours
+        .iter()
+        .filter_map(|point| {
+            let row = rows.remove(&point.article_id)?;
+            let (_, other_tokens) = prepare_title_signature(&row.title);
+            Some(SimilarArticleOut {
+                article_id: row.id,
+                matched_tokens: tokens.intersection(&other_tokens).cloned().collect(),
+                verdict: embedding::verdict(point.score).to_string(),
+                title: row.title,
+                url: row.url,
+                source_domain: row.source_domain,
+                published_at: row.published_at.to_rfc3339(),
+                score: point.score,
+                same_cluster: row.canonical_id == canonical_id,
+                canonical_id: row.canonical_id,
+            })
+        })
+        .collect();
+
+    Ok(SimilarArticlesOut {
+        article_id: id,
+        method: "embedding".to_string(),
+        window_hours,
+        scanned: neighbours.len(),
+        strict_threshold: embedding::DUP_THRESHOLD,
+        llm_threshold: embedding::LLM_THRESHOLD,
+        items,
+    })
+}
+
+/// 人工归并：把文章（及其簇内成员）并入目标文章所在的簇
+pub async fn merge_into(pool: &PgPool, id: i64, target: i64) -> AppResult<()> {
+    if id == target {
+        return Err(AppError::BadRequest("不能将文章归并到自身".to_string()));
+    }
+    let canonical = repo::articles::find_canonical_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {id} not found")))?;
+    let target_canonical = repo::articles::find_canonical_id(pool, target)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {target} not found")))?;
+    if canonical == target_canonical {
+        return Err(AppError::BadRequest("两篇文章已在同一簇中".to_string()));
+    }
+    if target_canonical == id {
+        return Err(AppError::BadRequest("目标文章已归并到该文章，请先拆分".to_string()));
+    }
+
+    if canonical != id {
+        // 文章原属其他簇：先移出原簇，避免原主文章上残留来源记录
+        repo::articles::unmerge_article(pool, id, canonical).await?;
+    }
+    repo::articles::supersede_article(pool, id, target_canonical, "manual_merge").await?;
+    tracing::info!(article_id = id, target_id = target_canonical, "article merged manually");
+    Ok(())
+}
+
+/// 人工拆分：将被归并的文章恢复为独立主文章
+pub async fn unmerge(pool: &PgPool, id: i64) -> AppResult<()> {
+    let canonical = repo::articles::find_canonical_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {id} not found")))?;
+    if canonical == id {
+        return Err(AppError::BadRequest("该文章未被归并".to_string()));
+    }
+
+    repo::articles::unmerge_article(pool, id, canonical).await?;
+    tracing::info!(article_id = id, canonical_id = canonical, "article unmerged manually");
+    Ok(())
+}
+
+const DEFAULT_CLUSTER_HOURS: i64 = 72;
+const MAX_CLUSTER_HOURS: i64 = 720;
+
+/// 按簇列出近期文章：每个主文章一项，附簇大小与来源域名
+pub async fn clusters(pool: &PgPool, query: ClusterListQuery) -> AppResult<PageResp<ClusterOut>> {
+    let hours = query.hours.unwrap_or(DEFAULT_CLUSTER_HOURS);
+    if !(1..=MAX_CLUSTER_HOURS).contains(&hours) {
+        return Err(AppError::BadRequest(format!("hours 需在 1-{MAX_CLUSTER_HOURS} 之间")));
+    }
+    let min_size = query.min_size.unwrap_or(2);
+    if min_size < 1 {
+        return Err(AppError::BadRequest("min_size 需不小于 1".to_string()));
+    }
+    let page = if query.page == 0 { 1 } else { query.page };
+    let page_size = query.page_size.clamp(1, 100);
+
+    let rows = repo::articles::list_clusters(
+        pool,
+        &repo::articles::ClusterListArgs {
+            since: Utc::now() - chrono::Duration::hours(hours),
+            min_size,
+            limit: page_size as i64,
+            offset: ((page - 1) * page_size) as i64,
+        },
+    )
+    .await?;
+    let total = rows.first().map(|row| row.total).unwrap_or(0);
+
+    Ok(PageResp {
+        page,
+        page_size,
+        total_hint: total.max(0) as u64,
+        total_estimated: false,
+        next_cursor: None,
+        items: rows
+            .into_iter()
+            .map(|row| ClusterOut {
+                canonical_id: row.canonical_id,
+                title: row.title,
+                url: row.url,
+                source_domain: row.source_domain,
+                published_at: row.published_at.to_rfc3339(),
+                hidden: row.hidden,
+                size: row.size,
+                source_domains: row.source_domains,
+            })
+            .collect(),
+    })
+}
+
+/// 文章所在簇的全部文章（主文章及被去重并入的转载）
+pub async fn cluster(pool: &PgPool, id: i64) -> AppResult<ArticleClusterOut> {
+    let canonical_id = repo::articles::find_canonical_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {id} not found")))?;
+    let rows = repo::articles::list_cluster_members(pool, canonical_id).await?;
+    Ok(ArticleClusterOut {
+        article_id: id,
+        canonical_id,
+        items: rows
+            .into_iter()
+            .map(|row| ClusterMemberOut {
+                is_canonical: row.id == canonical_id,
+                article_id: row.id,
+                feed_id: row.feed_id,
+                title: row.title,
+                url: row.url,
+                source_domain: row.source_domain,
+                published_at: row.published_at.to_rfc3339(),
+                fetched_at: row.fetched_at.to_rfc3339(),
+                hidden: row.hidden,
+                decision: row.decision,
+                confidence: row.confidence,
+            })
+            .collect(),
+    })
+}
+
+const MAX_HIDE_REASON_LEN: usize = 500;
+
+/// 管理员隐藏文章（软删除）：公开列表、热门与搜索不再展示，需填写原因并记入审计记录
+pub async fn hide(pool: &PgPool, admin: &AdminContext, id: i64, payload: ArticleHidePayload) -> AppResult<()> {
+    let reason = payload.reason.trim();
+    if reason.is_empty() {
+        return Err(AppError::BadRequest("隐藏文章需要填写原因".to_string()));
+    }
+    if reason.chars().count() > MAX_HIDE_REASON_LEN {
+        return Err(AppError::BadRequest(format!(
+            "原因不能超过 {MAX_HIDE_REASON_LEN} 个字符"
+        )));
+    }
+    let hidden_at = repo::articles::find_hidden_at(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {id} not found")))?;
+    if hidden_at.is_some() {
+        return Err(AppError::BadRequest("该文章已被隐藏".to_string()));
+    }
+
+    repo::articles::set_hidden(pool, id, true, Some(reason), &admin.username).await?;
+    tracing::info!(article_id = id, reason, actor = %admin.username, "article hidden by admin");
+    Ok(())
+}
+
+/// 撤销隐藏
+pub async fn unhide(pool: &PgPool, admin: &AdminContext, id: i64) -> AppResult<()> {
+    let hidden_at = repo::articles::find_hidden_at(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {id} not found")))?;
+    if hidden_at.is_none() {
+        return Err(AppError::BadRequest("该文章未被隐藏".to_string()));
+    }
+
+    repo::articles::set_hidden(pool, id, false, None, &admin.username).await?;
+    tracing::info!(article_id = id, actor = %admin.username, "article unhidden by admin");
+    Ok(())
+}
+
+/// 待审核队列：来自开启 `require_review` 的源、尚未批准的文章
+pub async fn list_pending(pool: &PgPool, query: ReviewQueueQuery) -> AppResult<PageResp<PendingArticleOut>> {
+    let page = if query.page == 0 { 1 } else { query.page };
+    let page_size = query.page_size.clamp(1, 100);
+
+    let rows = repo::articles::list_pending_review(
+        pool,
+        fetcher::PENDING_REVIEW_HIDDEN_REASON,
+        query.feed_id,
+        page_size as i64,
+        ((page - 1) * page_size) as i64,
+    )
+    .await?;
+    let total = rows.first().map(|row| row.total).unwrap_or(0);
+
+    Ok(PageResp {
+        page,
+        page_size,
+        total_hint: total.max(0) as u64,
+        total_estimated: false,
+        next_cursor: None,
+        items: rows
+            .into_iter()
+            .map(|row| PendingArticleOut {
+                id: row.id,
+                feed_id: row.feed_id,
+                title: row.title,
+                url: row.url,
+                description: row.description,
+                language: row.language,
+                original_language: row.original_language,
+                source_domain: row.source_domain,
+                published_at: row.published_at.to_rfc3339(),
+                fetched_at: row.fetched_at.to_rfc3339(),
+            })
+            .collect(),
+    })
+}
+
+/// 批准单篇待审核文章，使其公开展示
+pub async fn approve(pool: &PgPool, admin: &AdminContext, id: i64) -> AppResult<()> {
+    let approved = repo::articles::approve_pending(
+        pool,
+        fetcher::PENDING_REVIEW_HIDDEN_REASON,
+        Some(&[id]),
+        None,
+        &admin.username,
+    )
+    .await?;
+    if approved.is_empty() {
+        return match repo::articles::find_hidden_at(pool, id).await? {
+            None => Err(AppError::BadRequest(format!("article {id} not found"))),
+            Some(_) => Err(AppError::BadRequest("该文章不在待审核状态".to_string())),
+        };
+    }
+    tracing::info!(article_id = id, actor = %admin.username, "pending article approved");
+    Ok(())
+}
+
+/// 批量批准：按 ID 列表或整个源；不在待审核状态的 ID 会被忽略
+pub async fn approve_bulk(
+    pool: &PgPool,
+    admin: &AdminContext,
+    payload: ArticleApprovePayload,
+) -> AppResult<ArticleApproveResult> {
+    let approved = match (payload.ids, payload.feed_id) {
+        (Some(ids), None) => {
+            if ids.is_empty() {
+                return Err(AppError::BadRequest("ids 不能为空".to_string()));
+            }
+            if ids.len() > MAX_BULK_ARTICLES {
+                return Err(AppError::BadRequest(format!(
+                    "单次最多批准 {MAX_BULK_ARTICLES} 篇文章"
+                )));
+            }
+            repo::articles::approve_pending(
+                pool,
+                fetcher::PENDING_REVIEW_HIDDEN_REASON,
+                Some(&ids),
+                None,
+                &admin.username,
+            )
+            .await?
+        }
+        (None, Some(feed_id)) => {
+            repo::articles::approve_pending(
+                pool,
+                fetcher::PENDING_REVIEW_HIDDEN_REASON,
+                None,
+                Some(feed_id),
+                &admin.username,
+            )
+            .await?
+        }
+        _ => return Err(AppError::BadRequest("ids 与 feed_id 需且仅需提供一个".to_string())),
+    };
+    tracing::info!(approved = approved.len(), actor = %admin.username, "pending articles approved");
+    Ok(ArticleApproveResult { approved })
+}
+
+const MAX_EDIT_TITLE_LEN: usize = 500;
+
+/// 管理员修正文章标题、摘要或语言（如乱码标题、检测错误的语言），修改的字段记入审计记录
+pub async fn update(
+    pool: &PgPool,
+    admin: &AdminContext,
+    id: i64,
+    payload: ArticleUpdatePayload,
+) -> AppResult<ArticleOut> {
+    let title = payload.title.as_deref().map(str::trim);
+    if let Some(title) = title {
+        if title.is_empty() {
+            return Err(AppError::BadRequest("标题不能为空".to_string()));
+        }
+        if title.chars().count() > MAX_EDIT_TITLE_LEN {
+            return Err(AppError::BadRequest(format!(
+                "标题不能超过 {MAX_EDIT_TITLE_LEN} 个字符"
+            )));
+        }
+    }
+    let description = payload
+        .description
+        .as_deref()
+        .map(|raw| Some(raw.trim()).filter(|v| !v.is_empty()));
+    let language = payload
+        .language
+        .as_deref()
+        .map(parse_display_language)
+        .transpose()?;
+    let original_language = payload
+        .original_language
+        .as_deref()
+        .map(parse_edit_original_language)
+        .transpose()?;
+
+    let changed: Vec<&str> = [
+        ("title", title.is_some()),
+        ("description", description.is_some()),
+        ("language", language.is_some()),
+        ("original_language", original_language.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(field, present)| present.then_some(field))
+    .collect();
+    if changed.is_empty() {
+        return Err(AppError::BadRequest("没有需要修改的字段".to_string()));
+    }
+
+    let edit = repo::articles::ArticleEdit {
+        title,
+        title_simhash: title.and_then(title_simhash),
+        description,
+        language,
+        original_language,
+    };
+    if !repo::articles::update_article(pool, id, &edit, &changed.join(", "), &admin.username).await? {
+        return Err(AppError::BadRequest(format!("article {id} not found")));
+    }
+    tracing::info!(article_id = id, fields = %changed.join(","), actor = %admin.username, "article edited by admin");
+
+    let row = repo::articles::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {id} not found")))?;
+    Ok(article_row_to_out(row))
+}
+
+// language 为展示语言，翻译后为 `zh-CN` 这类完整标签，按原样保存；空字符串表示清空
+fn parse_display_language(raw: &str) -> AppResult<Option<&str>> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    let valid = trimmed.len() <= 35
+        && trimmed
+            .split('-')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric()));
+    if !valid {
+        return Err(AppError::BadRequest(
+            "invalid language, expected a tag like en or zh-CN".to_string(),
+        ));
+    }
+    Ok(Some(trimmed))
+}
+
+// original_language 统一保存为检测器使用的代码（en-US → en）；空字符串表示清空
+fn parse_edit_original_language(raw: &str) -> AppResult<Option<&'static str>> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    normalize_language_tag(trimmed).map(Some).ok_or_else(|| {
+        AppError::BadRequest(format!(
+            "invalid original_language, expected one of: {}",
+            SUPPORTED_LANGUAGES.join("|")
+        ))
+    })
+}
+
+/// 彻底删除文章（用于垃圾条目）；簇主文章被删时由簇内下一篇接任，删除记录写入运维事件。
+/// 订阅源中仍存在的条目会在下次抓取时重新入库，需要长期屏蔽请改用隐藏
+pub async fn delete(pool: &PgPool, admin: &AdminContext, id: i64) -> AppResult<()> {
+    let deleted = repo::articles::delete_article(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {id} not found")))?;
+    tracing::info!(article_id = id, source_domain = %deleted.source_domain, actor = %admin.username, "article deleted by admin");
+    Event::new(EventCode::ArticleDeleted)
+        .attr("article_id", id)
+        .attr("actor", admin.username.as_str())
+        .attr("source_domain", deleted.source_domain.as_str())
+        .attr("url", deleted.url.as_str())
+        .emit(pool)
+        .await;
+    Ok(())
+}
+
+/// 单次批量操作最多涉及的文章数，超出时整体拒绝，需缩小筛选范围分批执行
+const MAX_BULK_ARTICLES: usize = 5000;
+
+#[derive(Debug, Clone, Copy)]
+enum BulkAction {
+    Delete,
+    Retranslate,
+    Resource,
+}
+
+impl BulkAction {
+    fn parse(raw: &str) -> AppResult<Self> {
+        match raw.trim() {
+            "delete" => Ok(Self::Delete),
+            "retranslate" => Ok(Self::Retranslate),
+            "resource" => Ok(Self::Resource),
+            other => Err(AppError::BadRequest(format!(
+                "unsupported action: {other}, expected delete|retranslate|resource"
+            ))),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Delete => "delete",
+            Self::Retranslate => "retranslate",
+            Self::Resource => "resource",
+        }
+    }
+}
+
+/// 批量删除、重新翻译或改挂来源。目标在同一事务内锁定并修改，任一校验失败则整体不生效；
+/// 完成后记录一条 `ARTICLES_BULK_UPDATED` 事件。批量删除无法撤销，仅限 admin 角色
+pub async fn bulk(
+    pool: &PgPool,
+    admin: &AdminContext,
+    payload: ArticleBulkPayload,
+) -> AppResult<ArticleBulkResult> {
+    let action = BulkAction::parse(&payload.action)?;
+    if matches!(action, BulkAction::Delete) {
+        admin.require_role(Role::Admin)?;
+    }
+    let new_source = match action {
+        BulkAction::Resource => Some(crate::service::sources::normalize_domain(
+            payload.source_domain.as_deref().unwrap_or_default(),
+        )?),
+        _ => None,
+    };
+
+    let mut ids = payload.ids.unwrap_or_default();
+    ids.sort_unstable();
+    ids.dedup();
+    let filter = match (ids.is_empty(), payload.filter) {
+        (false, None) => {
+            if ids.len() > MAX_BULK_ARTICLES {
+                return Err(AppError::BadRequest(format!(
+                    "ids 不能超过 {MAX_BULK_ARTICLES} 个"
+                )));
+            }
+            None
+        }
+        (true, Some(filter)) => {
+            let from = parse_optional_datetime(filter.from.as_deref(), "from")?;
+            let to = parse_optional_datetime(filter.to.as_deref(), "to")?;
+            let source_domain = filter
+                .source_domain
+                .map(|raw| raw.trim().to_ascii_lowercase())
+                .filter(|raw| !raw.is_empty());
+            if from.is_none() && to.is_none() && source_domain.is_none() {
+                return Err(AppError::BadRequest(
+                    "filter 需至少包含 from、to 或 source_domain 之一".to_string(),
+                ));
+            }
+            if matches!((from, to), (Some(from), Some(to)) if from > to) {
+                return Err(AppError::BadRequest("from 不能晚于 to".to_string()));
+            }
+            Some((from, to, source_domain))
+        }
+        _ => {
+            return Err(AppError::BadRequest("ids 与 filter 需且只能提供一个".to_string()));
+        }
+    };
+    let (target, scope) = match &filter {
+        None => (
+            repo::articles::BulkTarget::Ids(&ids),
+            format!("指定 {} 个 ID", ids.len()),
+        ),
+        Some((from, to, source_domain)) => (
+            repo::articles::BulkTarget::Filter {
+                from: *from,
+                to: *to,
+                source_domain: source_domain.as_deref(),
+            },
+            format!(
+                "发布于 {} ~ {}，来源 {}",
+                from.map(|ts| ts.to_rfc3339()).as_deref().unwrap_or("不限"),
+                to.map(|ts| ts.to_rfc3339()).as_deref().unwrap_or("不限"),
+                source_domain.as_deref().unwrap_or("不限"),
+            ),
+        ),
+    };
+
+    let mut tx = pool.begin().await?;
+    let matched =
+        repo::articles::lock_bulk_targets(&mut tx, &target, MAX_BULK_ARTICLES as i64 + 1).await?;
+    if matched.len() > MAX_BULK_ARTICLES {
+        return Err(AppError::BadRequest(format!(
+            "命中的文章超过 {MAX_BULK_ARTICLES} 篇，请缩小筛选范围后分批执行"
+        )));
+    }
+    if filter.is_none() && matched.len() < ids.len() {
+        let missing: Vec<String> = ids
+            .iter()
+            .filter(|id| matched.binary_search(id).is_err())
+            .take(10)
+            .map(i64::to_string)
+            .collect();
+        return Err(AppError::BadRequest(format!(
+            "以下文章不存在：{}",
+            missing.join(", ")
+        )));
+    }
+
+    let affected = match action {
+        BulkAction::Delete => repo::articles::delete_ids(&mut tx, &matched).await?,
+        BulkAction::Retranslate => {
+            repo::articles::requeue_translation(&mut tx, &matched, fetcher::TRANSLATION_LANG).await?
+        }
+        BulkAction::Resource => {
+            let new_source = new_source.as_deref().unwrap_or_default();
+            repo::articles::set_source_domain(&mut tx, &matched, new_source).await?
+        }
+    };
+    tx.commit().await?;
+
+    let matched = matched.len() as u64;
+    tracing::info!(action = action.as_str(), matched, affected, scope = %scope, actor = %admin.username, "articles bulk updated by admin");
+    let mut event = Event::new(EventCode::ArticlesBulkUpdated)
+        .attr("action", action.as_str())
+        .attr("actor", admin.username.as_str())
+        .attr("matched", matched)
+        .attr("affected", affected);
+    let mut detail = format!("{}：{scope}，命中 {matched} 篇，变更 {affected} 篇", action.as_str());
+    if let Some(new_source) = &new_source {
+        event = event.attr("source_domain", new_source.as_str());
+        detail.push_str(&format!("，改挂到 {new_source}"));
+    }
+    event.detail(detail).emit(pool).await;
+
+    Ok(ArticleBulkResult {
+        action: action.as_str().to_string(),
+        matched,
+        affected,
+    })
+}
+
+/// 读者标记已读/未读，只接受公开可见的文章
+pub async fn set_read(pool: &PgPool, reader_id: &str, id: i64, read: bool) -> AppResult<ArticleStateOut> {
+    ensure_public(pool, id).await?;
+    let row = repo::article_states::set_read(pool, reader_id, id, read).await?;
+    Ok(article_state_to_out(id, row))
+}
+
+/// 读者加入/移出稍后阅读列表
+pub async fn set_bookmarked(
+    pool: &PgPool,
+    reader_id: &str,
+    id: i64,
+    bookmarked: bool,
+) -> AppResult<ArticleStateOut> {
+    ensure_public(pool, id).await?;
+    let row = repo::article_states::set_bookmarked(pool, reader_id, id, bookmarked).await?;
+    Ok(article_state_to_out(id, row))
+}
+
+// 隐藏的文章对读者等同于不存在
+async fn ensure_public(pool: &PgPool, id: i64) -> AppResult<()> {
+    match repo::articles::find_hidden_at(pool, id).await? {
+        Some(None) => Ok(()),
+        _ => Err(AppError::BadRequest(format!("article {id} not found"))),
+    }
+}
+
+fn article_state_to_out(article_id: i64, row: repo::article_states::ArticleStateRow) -> ArticleStateOut {
+    ArticleStateOut {
+        article_id,
+        read_at: row.read_at.map(|ts| ts.to_rfc3339()),
+        bookmarked_at: row.bookmarked_at.map(|ts| ts.to_rfc3339()),
+    }
+}
+
+pub async fn list_moderation(pool: &PgPool, id: i64) -> AppResult<Vec<ModerationLogOut>> {
+    if repo::articles::find_hidden_at(pool, id).await?.is_none() {
+        return Err(AppError::BadRequest(format!("article {id} not found")));
+    }
+    let rows = repo::articles::list_moderation(pool, id).await?;
     Ok(rows
         .into_iter()
-        .map(|row| ArticleOut {
+        .map(|row| ModerationLogOut {
             id: row.id,
-            title: row.title,
-            url: row.url,
-            description: row.description,
-            language: row.language,
-            source_domain: row.source_domain,
-            published_at: row.published_at.to_rfc3339(),
-            click_count: row.click_count,
+            article_id: row.article_id,
+            action: row.action,
+            reason: row.reason,
+            actor: row.actor,
+            created_at: row.created_at.to_rfc3339(),
         })
         .collect())
 }
+
+fn article_row_to_out(row: repo::articles::ArticleRow) -> ArticleOut {
+    ArticleOut {
+        id: row.id,
+        title: row.title,
+        url: row.url,
+        description: row.description,
+        language: row.language,
+        original_language: row.original_language,
+        source_domain: row.source_domain,
+        published_at: row.published_at.to_rfc3339(),
+        click_count: row.click_count,
+        tags: row.tags,
+    }
+}
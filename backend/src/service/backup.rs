@@ -0,0 +1,329 @@
+//! 数据库逻辑备份：后台手动触发或按 cron 定时执行。配置 `backup.command` 时交给外部命令
+//! （pg_dump、上传 S3 的脚本等），否则在可重复读快照内逐表 COPY 导出 CSV。
+//! 执行记录写入 `news.backups`，最近状态由 `/metrics` 导出。
+
+use std::{
+    fs,
+    path::Path,
+    process::Stdio,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context};
+use chrono::Utc;
+use cron::Schedule;
+use futures::StreamExt;
+use sqlx::PgPool;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt, BufWriter},
+    process::Command,
+};
+
+use crate::{
+    config::BackupConfig,
+    error::{AppError, AppResult},
+    model::BackupOut,
+    ops::event_catalog::{Event, EventCode},
+    repo::{self, backups::BackupRow},
+    util::schedule,
+};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+const TRIGGER_MANUAL: &str = "manual";
+const TRIGGER_SCHEDULE: &str = "schedule";
+// 备份文件与目录的名称前缀，清理旧备份时只处理带该前缀的条目
+const FILE_PREFIX: &str = "newsagg-";
+// 错误信息只保留命令输出的末尾部分
+const MAX_ERROR_CHARS: usize = 1000;
+
+pub struct Backup {
+    config: BackupConfig,
+    db_url: String,
+    schedule: Option<Schedule>,
+}
+
+impl Backup {
+    /// 配置有误（如 cron 表达式无法解析）时启动失败
+    pub fn new(config: &BackupConfig, db_url: &str) -> anyhow::Result<Self> {
+        if config.dir.trim().is_empty() {
+            bail!("backup.dir must not be empty");
+        }
+        let schedule = config
+            .schedule
+            .as_deref()
+            .map(str::trim)
+            .filter(|expr| !expr.is_empty())
+            .map(|expr| {
+                schedule::parse_cron(expr).with_context(|| format!("invalid backup.schedule {expr:?}"))
+            })
+            .transpose()?;
+        Ok(Self {
+            config: config.clone(),
+            db_url: db_url.to_string(),
+            schedule,
+        })
+    }
+
+    fn mode(&self) -> &'static str {
+        if self.config.command.is_some() {
+            "command"
+        } else {
+            "copy"
+        }
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(self.config.timeout_secs.max(1))
+    }
+}
+
+/// 启动定时备份任务，未配置 `backup.schedule` 时不启动
+pub fn spawn(pool: PgPool, backup: Arc<Backup>) {
+    let Some(schedule) = backup.schedule.clone() else {
+        return;
+    };
+    let booted = Utc::now();
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(CHECK_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            let last = match repo::backups::last_scheduled_start(&pool).await {
+                Ok(last) => last,
+                Err(err) => {
+                    tracing::warn!(error = ?err, "failed to load last scheduled backup");
+                    continue;
+                }
+            };
+            // 从未定时备份过时从进程启动起算，避免每次部署都立即备份
+            if !schedule::is_due(&schedule, Some(last.unwrap_or(booted)), Utc::now()) {
+                continue;
+            }
+            match start(&pool, &backup, TRIGGER_SCHEDULE).await {
+                Ok(Some(row)) => run(&pool, &backup, row).await,
+                Ok(None) => tracing::info!("scheduled backup skipped, another backup is running"),
+                Err(err) => tracing::warn!(error = ?err, "failed to start scheduled backup"),
+            }
+        }
+    });
+}
+
+/// 后台手动触发：登记后立即返回 running 状态的记录，备份在后台执行
+pub async fn trigger(pool: &PgPool, backup: Option<&Arc<Backup>>) -> AppResult<BackupOut> {
+    let Some(backup) = backup else {
+        return Err(AppError::BadRequest("未配置 backup，无法执行备份".into()));
+    };
+    let row = start(pool, backup, TRIGGER_MANUAL)
+        .await?
+        .ok_or_else(|| AppError::BadRequest("已有备份在进行中".into()))?;
+    let out = row_to_out(&row);
+
+    let pool = pool.clone();
+    let backup = Arc::clone(backup);
+    tokio::spawn(async move { run(&pool, &backup, row).await });
+    Ok(out)
+}
+
+pub async fn list(pool: &PgPool, limit: i64) -> AppResult<Vec<BackupOut>> {
+    let rows = repo::backups::list_recent(pool, limit.clamp(1, 100)).await?;
+    Ok(rows.iter().map(row_to_out).collect())
+}
+
+async fn start(pool: &PgPool, backup: &Backup, trigger: &str) -> Result<Option<BackupRow>, sqlx::Error> {
+    // 超过时限仍为 running 的记录来自中途退出的进程，先置为失败以免永久占住
+    let stale = repo::backups::fail_stale(pool, backup.timeout().as_secs() as i64 + 60).await?;
+    if stale > 0 {
+        tracing::warn!(stale, "marked interrupted backups as failed");
+    }
+    let name = format!("{FILE_PREFIX}{}", Utc::now().format("%Y%m%dT%H%M%S%.3fZ"));
+    let name = match backup.config.command {
+        Some(_) => format!("{name}.dump"),
+        None => name,
+    };
+    let location = Path::new(&backup.config.dir).join(name);
+    repo::backups::start(pool, trigger, backup.mode(), &location.to_string_lossy()).await
+}
+
+async fn run(pool: &PgPool, backup: &Backup, row: BackupRow) {
+    let started = Instant::now();
+    let result = match backup.config.command.as_deref() {
+        Some(command) => run_command(backup, command, &row.location).await,
+        None => match tokio::time::timeout(backup.timeout(), export_copy(pool, &row.location)).await {
+            Ok(result) => result,
+            Err(_) => Err(anyhow::anyhow!(
+                "backup timed out after {}s",
+                backup.timeout().as_secs()
+            )),
+        },
+    };
+
+    let (status, size_bytes, error) = match result {
+        Ok(()) => {
+            let size_bytes = path_size(Path::new(&row.location));
+            tracing::info!(
+                id = row.id,
+                trigger = %row.trigger,
+                location = %row.location,
+                size_bytes,
+                elapsed_ms = started.elapsed().as_millis() as u64,
+                "database backup succeeded"
+            );
+            if let Err(err) = prune(&backup.config.dir, backup.config.keep) {
+                tracing::warn!(error = ?err, "failed to prune old backups");
+            }
+            ("succeeded", size_bytes, None)
+        }
+        Err(err) => {
+            let message = format!("{err:#}");
+            tracing::warn!(id = row.id, trigger = %row.trigger, error = %message, "database backup failed");
+            Event::new(EventCode::BackupFailed)
+                .attr("backup_id", row.id)
+                .attr("trigger", row.trigger.as_str())
+                .detail(format!("{}｜{}", row.trigger, message))
+                .emit(pool)
+                .await;
+            ("failed", None, Some(message))
+        }
+    };
+    if let Err(err) = repo::backups::finish(pool, row.id, status, size_bytes, error.as_deref()).await {
+        tracing::warn!(id = row.id, error = ?err, "failed to record backup result");
+    }
+}
+
+// 标准库子进程：轮询等待以便超时后结束命令
+async fn run_command(backup: &Backup, command: &str, location: &str) -> anyhow::Result<()> {
+    tokio::fs::create_dir_all(&backup.config.dir)
+        .await
+        .with_context(|| format!("failed to create backup dir {}", backup.config.dir))?;
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .env("BACKUP_FILE", location)
+        .env("BACKUP_DIR", &backup.config.dir)
+        .env("DATABASE_URL", &backup.db_url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        // 任务被取消时同样结束子进程
+        .kill_on_drop(true)
+        .spawn()
+        .context("failed to start backup command")?;
+
+    let stderr = child.stderr.take();
+    let stderr = tokio::spawn(async move {
+        let mut buf = Vec::new();
+        if let Some(mut stderr) = stderr {
+            let _ = stderr.read_to_end(&mut buf).await;
+        }
+        String::from_utf8_lossy(&buf).into_owned()
+    });
+
+    let status = match tokio::time::timeout(backup.timeout(), child.wait()).await {
+        Ok(status) => status.context("failed to wait for backup command")?,
+        Err(_) => {
+            let _ = child.kill().await;
+            bail!(
+                "backup command timed out after {}s",
+                backup.timeout().as_secs()
+            );
+        }
+    };
+    if status.success() {
+        return Ok(());
+    }
+    let stderr = stderr.await.unwrap_or_default();
+    let stderr = stderr.trim();
+    let tail: String = {
+        let skip = stderr.chars().count().saturating_sub(MAX_ERROR_CHARS);
+        stderr.chars().skip(skip).collect()
+    };
+    bail!("backup command exited with {status}: {tail}");
+}
+
+// 同一可重复读快照内导出，表与表之间的数据保持一致；导出可能较久，取消语句超时
+async fn export_copy(pool: &PgPool, location: &str) -> anyhow::Result<()> {
+    let tables = repo::backups::list_tables(pool).await?;
+    tokio::fs::create_dir_all(location)
+        .await
+        .with_context(|| format!("failed to create {location}"))?;
+
+    let mut tx = pool.begin().await?;
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("SET LOCAL statement_timeout = 0")
+        .execute(&mut *tx)
+        .await?;
+    for table in &tables {
+        let path = Path::new(location).join(format!("{table}.csv"));
+        let mut file = BufWriter::new(
+            tokio::fs::File::create(&path)
+                .await
+                .with_context(|| format!("failed to create {}", path.display()))?,
+        );
+        let sql = format!(
+            r#"COPY news."{}" TO STDOUT WITH (FORMAT csv, HEADER)"#,
+            table.replace('"', "\"\"")
+        );
+        let mut stream = tx.copy_out_raw(&sql).await?;
+        while let Some(chunk) = stream.next().await {
+            file.write_all(&chunk?).await?;
+        }
+        file.flush().await?;
+    }
+    tx.rollback().await?;
+    Ok(())
+}
+
+/// 文件大小，目录则累加其下文件（备份目录只有一层）
+fn path_size(path: &Path) -> Option<i64> {
+    let meta = fs::metadata(path).ok()?;
+    if meta.is_file() {
+        return Some(meta.len() as i64);
+    }
+    let total = fs::read_dir(path)
+        .ok()?
+        .filter_map(|entry| entry.ok()?.metadata().ok())
+        .filter(|meta| meta.is_file())
+        .map(|meta| meta.len() as i64)
+        .sum();
+    Some(total)
+}
+
+// 名称带时间戳，按名称排序即按时间排序
+fn prune(dir: &str, keep: u32) -> std::io::Result<()> {
+    if keep == 0 {
+        return Ok(());
+    }
+    let mut entries: Vec<_> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(FILE_PREFIX))
+        .collect();
+    entries.sort_by_key(|entry| entry.file_name());
+    let excess = entries.len().saturating_sub(keep as usize);
+    for entry in entries.into_iter().take(excess) {
+        let path = entry.path();
+        if path.is_dir() {
+            fs::remove_dir_all(&path)?;
+        } else {
+            fs::remove_file(&path)?;
+        }
+        tracing::info!(path = %path.display(), "pruned old backup");
+    }
+    Ok(())
+}
+
+fn row_to_out(row: &BackupRow) -> BackupOut {
+    BackupOut {
+        id: row.id,
+        trigger: row.trigger.clone(),
+        mode: row.mode.clone(),
+        status: row.status.clone(),
+        location: row.location.clone(),
+        size_bytes: row.size_bytes,
+        error: row.error.clone(),
+        started_at: row.started_at.to_rfc3339(),
+        finished_at: row.finished_at.map(|ts| ts.to_rfc3339()),
+    }
+}
@@ -0,0 +1,315 @@
+//! 每周精选：每周一汇总上一自然周（UTC）排名靠前的文章，保存快照并通过 RSS 与可选的邮件发布，
+//! 供不想频繁刷新的读者低频阅读。
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use sqlx::PgPool;
+
+use crate::{
+    error::{AppError, AppResult},
+    model::{DigestGeneratePayload, DigestItemOut, DigestOut},
+    ops::event_catalog::{Event, EventCode},
+    repo::{
+        self,
+        digests::{DigestItemRow, DigestRow},
+    },
+    service::settings_registry,
+    util::{html::escape_xml, mailer::Mailer},
+};
+
+pub const ENABLED_KEY: &str = "digest.enabled";
+pub const TOP_N_KEY: &str = "digest.top_n";
+
+const DEFAULT_TOP_N: i64 = 10;
+// 每多一个来源报道同一事件，得分加上相当于 10 次点击
+const CLUSTER_BONUS: f32 = 10.0;
+const CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+const MAX_EMAIL_ATTEMPTS: i32 = 3;
+const RSS_DIGEST_LIMIT: i64 = 12;
+
+/// 后台任务：每小时检查一次，上一周的精选尚未生成时生成，并补发未成功的邮件
+pub fn spawn(pool: PgPool, mailer: Option<Arc<Mailer>>, site_url: String) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(CHECK_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(err) = run_scheduled(&pool, mailer.as_deref(), &site_url).await {
+                tracing::warn!(error = ?err, "weekly digest job failed");
+            }
+        }
+    });
+}
+
+async fn run_scheduled(pool: &PgPool, mailer: Option<&Mailer>, site_url: &str) -> AppResult<()> {
+    if !settings_registry::get_bool(pool, ENABLED_KEY).await? {
+        return Ok(());
+    }
+    let week_start = previous_week_start(Utc::now());
+    if repo::digests::find_by_week(pool, week_start).await?.is_none() {
+        match build(pool, week_start).await? {
+            Some(digest) => publish_event(pool, &digest).await,
+            None => tracing::info!(%week_start, "no articles for weekly digest"),
+        }
+    }
+
+    let Some(mailer) = mailer else {
+        return Ok(());
+    };
+    let pending = repo::digests::list_pending_email(pool, MAX_EMAIL_ATTEMPTS).await?;
+    for digest in load_items(pool, pending).await? {
+        send_email(pool, mailer, &digest, site_url).await;
+    }
+    Ok(())
+}
+
+pub async fn list(pool: &PgPool, limit: i64) -> AppResult<Vec<DigestOut>> {
+    let rows = repo::digests::list_recent(pool, limit.clamp(1, 52)).await?;
+    load_items(pool, rows).await
+}
+
+/// 手动生成（或重新生成）某一周的精选，只允许已结束的自然周
+pub async fn generate(
+    pool: &PgPool,
+    mailer: Option<&Mailer>,
+    site_url: &str,
+    payload: DigestGeneratePayload,
+) -> AppResult<DigestOut> {
+    let latest = previous_week_start(Utc::now());
+    let week_start = match payload
+        .week_start
+        .as_deref()
+        .map(str::trim)
+        .filter(|raw| !raw.is_empty())
+    {
+        Some(raw) => {
+            let day = NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+                .map_err(|_| AppError::BadRequest("week_start 需为 YYYY-MM-DD 格式".into()))?;
+            monday_of(day)
+        }
+        None => latest,
+    };
+    if week_start > latest {
+        return Err(AppError::BadRequest("只能生成已结束的自然周".into()));
+    }
+    if payload.email && mailer.is_none() {
+        return Err(AppError::BadRequest("未配置 digest.smtp，无法发送邮件".into()));
+    }
+
+    let mut digest = build(pool, week_start)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("{week_start} 所在周没有可入选的文章")))?;
+    publish_event(pool, &digest).await;
+    if let Some(mailer) = mailer.filter(|_| payload.email) {
+        let sent = send_email(pool, mailer, &digest, site_url).await;
+        digest.email_attempts += 1;
+        if sent {
+            digest.emailed_at = Some(Utc::now().to_rfc3339());
+        }
+    }
+    Ok(digest)
+}
+
+/// 公开 RSS：每期精选为一个条目，正文为文章列表
+pub async fn rss(pool: &PgPool, site_url: &str) -> AppResult<String> {
+    let rows = repo::digests::list_recent(pool, RSS_DIGEST_LIMIT).await?;
+    let digests = load_items(pool, rows).await?;
+
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push_str(r#"<rss version="2.0"><channel>"#);
+    xml.push_str("<title>NewsAggregator 每周精选</title>");
+    xml.push_str(&format!("<link>{}/</link>", escape_xml(site_url)));
+    xml.push_str("<description>每周汇总上一周最受关注的新闻</description>");
+    xml.push_str("<language>zh-cn</language>");
+    if let Some(latest) = digests.first() {
+        xml.push_str(&format!(
+            "<lastBuildDate>{}</lastBuildDate>",
+            rfc2822(&latest.created_at)
+        ));
+    }
+    for digest in &digests {
+        xml.push_str("<item>");
+        xml.push_str(&format!("<title>{}</title>", escape_xml(&digest.title)));
+        xml.push_str(&format!("<link>{}/</link>", escape_xml(site_url)));
+        xml.push_str(&format!(
+            r#"<guid isPermaLink="false">digest-{}</guid>"#,
+            digest.week_start
+        ));
+        xml.push_str(&format!("<pubDate>{}</pubDate>", rfc2822(&digest.created_at)));
+        xml.push_str(&format!(
+            "<description>{}</description>",
+            escape_xml(&render_html(digest, site_url))
+        ));
+        xml.push_str("</item>");
+    }
+    xml.push_str("</channel></rss>");
+    Ok(xml)
+}
+
+async fn build(pool: &PgPool, week_start: NaiveDate) -> AppResult<Option<DigestOut>> {
+    let period_start = week_start
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is valid")
+        .and_utc();
+    let period_end = period_start + chrono::Duration::days(7);
+    let top_n = settings_registry::get_i64(pool, TOP_N_KEY)
+        .await?
+        .unwrap_or(DEFAULT_TOP_N);
+
+    let candidates =
+        repo::digests::list_candidates(pool, period_start, period_end, CLUSTER_BONUS, top_n).await?;
+    if candidates.is_empty() {
+        return Ok(None);
+    }
+    let last_day = week_start + chrono::Duration::days(6);
+    let title = format!("每周精选｜{week_start} ~ {last_day}");
+    let row = repo::digests::replace_digest(
+        pool,
+        week_start,
+        period_start,
+        period_end,
+        &title,
+        &candidates,
+    )
+    .await?;
+    tracing::info!(%week_start, items = candidates.len(), "weekly digest generated");
+    Ok(load_items(pool, vec![row]).await?.pop())
+}
+
+async fn load_items(pool: &PgPool, rows: Vec<DigestRow>) -> AppResult<Vec<DigestOut>> {
+    let ids: Vec<i64> = rows.iter().map(|row| row.id).collect();
+    let mut items: HashMap<i64, Vec<DigestItemOut>> = HashMap::new();
+    for item in repo::digests::list_items(pool, &ids).await? {
+        items
+            .entry(item.digest_id)
+            .or_default()
+            .push(item_row_to_out(item));
+    }
+    Ok(rows
+        .into_iter()
+        .map(|row| DigestOut {
+            items: items.remove(&row.id).unwrap_or_default(),
+            id: row.id,
+            week_start: row.week_start.to_string(),
+            period_start: row.period_start.to_rfc3339(),
+            period_end: row.period_end.to_rfc3339(),
+            title: row.title,
+            created_at: row.created_at.to_rfc3339(),
+            emailed_at: row.emailed_at.map(|dt| dt.to_rfc3339()),
+            email_attempts: row.email_attempts,
+        })
+        .collect())
+}
+
+/// 发送一期精选邮件，返回是否成功；失败时计入重试次数并记录事件
+async fn send_email(pool: &PgPool, mailer: &Mailer, digest: &DigestOut, site_url: &str) -> bool {
+    let result = mailer
+        .send(
+            &digest.title,
+            render_text(digest, site_url),
+            render_html(digest, site_url),
+        )
+        .await;
+    match result {
+        Ok(()) => {
+            if let Err(err) = repo::digests::mark_emailed(pool, digest.id).await {
+                tracing::warn!(error = ?err, digest_id = digest.id, "failed to mark digest emailed");
+            }
+            tracing::info!(
+                digest_id = digest.id,
+                recipients = mailer.recipient_count(),
+                "weekly digest emailed"
+            );
+            true
+        }
+        Err(err) => {
+            let attempts = repo::digests::record_email_failure(pool, digest.id)
+                .await
+                .unwrap_or(MAX_EMAIL_ATTEMPTS);
+            tracing::warn!(error = ?err, digest_id = digest.id, attempts, "weekly digest email failed");
+            let detail: String = format!("{err:#}").chars().take(300).collect();
+            Event::new(EventCode::DigestEmailFailed)
+                .attr("digest_id", digest.id)
+                .attr("attempts", attempts)
+                .detail(format!(
+                    "{}（第 {attempts}/{MAX_EMAIL_ATTEMPTS} 次）：{detail}",
+                    digest.title
+                ))
+                .emit(pool)
+                .await;
+            false
+        }
+    }
+}
+
+async fn publish_event(pool: &PgPool, digest: &DigestOut) {
+    Event::new(EventCode::DigestPublished)
+        .attr("digest_id", digest.id)
+        .attr("items", digest.items.len())
+        .detail(format!("{}，共 {} 篇", digest.title, digest.items.len()))
+        .emit(pool)
+        .await;
+}
+
+fn render_html(digest: &DigestOut, site_url: &str) -> String {
+    let mut html = format!("<h2>{}</h2><ol>", escape_xml(&digest.title));
+    for item in &digest.items {
+        html.push_str(&format!(
+            r#"<li><a href="{}">{}</a> <small>{}"#,
+            escape_xml(&item.url),
+            escape_xml(&item.title),
+            escape_xml(&item.source_domain),
+        ));
+        if item.cluster_size > 1 {
+            html.push_str(&format!("，{} 个来源报道", item.cluster_size));
+        }
+        html.push_str("</small></li>");
+    }
+    html.push_str(&format!(
+        r#"</ol><p><a href="{}/">查看更多新闻</a></p>"#,
+        escape_xml(site_url)
+    ));
+    html
+}
+
+fn render_text(digest: &DigestOut, site_url: &str) -> String {
+    let mut text = format!("{}\n\n", digest.title);
+    for item in &digest.items {
+        text.push_str(&format!(
+            "{}. {}（{}）\n   {}\n",
+            item.rank, item.title, item.source_domain, item.url
+        ));
+    }
+    text.push_str(&format!("\n查看更多新闻：{site_url}/\n"));
+    text
+}
+
+fn item_row_to_out(row: DigestItemRow) -> DigestItemOut {
+    DigestItemOut {
+        rank: row.rank,
+        article_id: row.article_id,
+        title: row.title,
+        url: row.url,
+        source_domain: row.source_domain,
+        published_at: row.published_at.to_rfc3339(),
+        click_count: row.click_count,
+        cluster_size: row.cluster_size,
+        score: row.score,
+    }
+}
+
+fn monday_of(day: NaiveDate) -> NaiveDate {
+    day - chrono::Duration::days(day.weekday().num_days_from_monday() as i64)
+}
+
+// 最近一个已结束的自然周（周一至周日，UTC）的周一
+fn previous_week_start(now: DateTime<Utc>) -> NaiveDate {
+    monday_of(now.date_naive()) - chrono::Duration::days(7)
+}
+
+pub(crate) fn rfc2822(rfc3339: &str) -> String {
+    DateTime::parse_from_rfc3339(rfc3339)
+        .map(|dt| dt.to_rfc2822())
+        .unwrap_or_default()
+}
@@ -0,0 +1,177 @@
+//! 向量去重索引：配置 `qdrant` 后，入库文章的标题与摘要经 Ollama 生成 embedding 写入 Qdrant，
+//! 抓取时的历史去重改为检索向量近邻作为候选，不再与时间窗口内的全部近期文章逐条比较。
+//! 未配置、Ollama 不可用或检索失败时，抓取器退回原有的 Jaccard 全量比较。
+
+use std::{sync::Arc, sync::OnceLock, time::Duration};
+
+use anyhow::anyhow;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::{
+    config::QdrantConfig,
+    fetcher::DEFAULT_DEDUP_WINDOW_HOURS,
+    repo,
+    service::settings_registry,
+    util::{
+        qdrant::{QdrantManager, ScoredPoint},
+        translator::TranslationEngine,
+    },
+};
+
+/// 近邻余弦相似度达到该值直接判为重复
+pub const DUP_THRESHOLD: f32 = 0.92;
+/// 达到该值（且开启 AI 去重）时交给大模型判定
+pub const LLM_THRESHOLD: f32 = 0.8;
+/// 每篇新文章检索的近邻数量
+pub const NEIGHBOUR_LIMIT: usize = 10;
+
+// 生成 embedding 的文本只取标题与摘要开头，摘要过长时截断
+const MAX_DESCRIPTION_CHARS: usize = 500;
+// 启动后补建索引前等待翻译客户端完成初始化
+const BACKFILL_DELAY: Duration = Duration::from_secs(30);
+const BACKFILL_CAP: i64 = 2000;
+const BACKFILL_BATCH: usize = 100;
+
+static INDEX: OnceLock<EmbeddingIndex> = OnceLock::new();
+
+pub struct EmbeddingIndex {
+    qdrant: Arc<QdrantManager>,
+    model: String,
+}
+
+/// 使用启动时创建的 Qdrant 客户端初始化进程内共享的索引；未配置 `qdrant` 时不启用
+pub fn init(qdrant: Option<Arc<QdrantManager>>, config: Option<&QdrantConfig>) {
+    let (Some(qdrant), Some(config)) = (qdrant, config) else {
+        return;
+    };
+    let index = EmbeddingIndex {
+        qdrant,
+        model: config.embedding_model.trim().to_string(),
+    };
+    if INDEX.set(index).is_ok() {
+        tracing::info!(
+            url = %config.url,
+            collection = %config.collection,
+            model = %config.embedding_model,
+            "embedding dedup index enabled"
+        );
+    }
+}
+
+pub fn index() -> Option<&'static EmbeddingIndex> {
+    INDEX.get()
+}
+
+/// 按阈值给出向量相似度的判定，取值与 `fetcher::dedup_verdict` 一致
+pub fn verdict(score: f32) -> &'static str {
+    if score >= DUP_THRESHOLD {
+        "strict_duplicate"
+    } else if score >= LLM_THRESHOLD {
+        "llm_check"
+    } else {
+        "distinct"
+    }
+}
+
+impl EmbeddingIndex {
+    pub async fn embed(
+        &self,
+        translation: &TranslationEngine,
+        title: &str,
+        description: Option<&str>,
+    ) -> anyhow::Result<Vec<f32>> {
+        let client = translation
+            .ollama_client()
+            .ok_or_else(|| anyhow!("ollama is not configured"))?;
+        let mut text = title.trim().to_string();
+        if let Some(description) = description.map(str::trim).filter(|d| !d.is_empty()) {
+            text.push('\n');
+            text.extend(description.chars().take(MAX_DESCRIPTION_CHARS));
+        }
+        client.embed(&self.model, &text).await
+    }
+
+    /// 最近 `window_hours` 小时内发布的近邻文章，按相似度从高到低
+    pub async fn neighbours(
+        &self,
+        vector: &[f32],
+        window_hours: i64,
+    ) -> anyhow::Result<Vec<ScoredPoint>> {
+        let since = Utc::now() - chrono::Duration::hours(window_hours);
+        self.qdrant
+            .search(vector, since, None, None, NEIGHBOUR_LIMIT)
+            .await
+    }
+
+    /// 发布时间在 `center` 前后 `window_hours` 小时内、与文章 `article_id` 最相似的文章
+    pub async fn similar_to(
+        &self,
+        article_id: i64,
+        center: DateTime<Utc>,
+        window_hours: i64,
+        limit: usize,
+    ) -> anyhow::Result<Option<Vec<ScoredPoint>>> {
+        let Some(vector) = self.qdrant.vector(article_id).await? else {
+            return Ok(None);
+        };
+        let window = chrono::Duration::hours(window_hours);
+        self.qdrant
+            .search(
+                &vector,
+                center - window,
+                Some(center + window),
+                Some(article_id),
+                limit,
+            )
+            .await
+            .map(Some)
+    }
+
+    pub async fn insert(
+        &self,
+        article_id: i64,
+        vector: &[f32],
+        published_at: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        self.qdrant.upsert(article_id, vector, published_at).await
+    }
+}
+
+/// 启动后为去重窗口内尚未建立索引的主文章补建 embedding，避免启用后一个窗口内的重复漏判
+pub fn spawn_backfill(pool: PgPool, translation: Arc<TranslationEngine>) {
+    let Some(index) = index() else {
+        return;
+    };
+    tokio::spawn(async move {
+        tokio::time::sleep(BACKFILL_DELAY).await;
+        match backfill(index, &pool, &translation).await {
+            Ok(indexed) => tracing::info!(indexed, "embedding index backfill finished"),
+            Err(err) => tracing::warn!(error = ?err, "embedding index backfill failed"),
+        }
+    });
+}
+
+async fn backfill(
+    index: &EmbeddingIndex,
+    pool: &PgPool,
+    translation: &TranslationEngine,
+) -> anyhow::Result<usize> {
+    let window_hours = settings_registry::get_i64(pool, "dedup.window_hours")
+        .await?
+        .unwrap_or(DEFAULT_DEDUP_WINDOW_HOURS);
+    let recent = repo::articles::list_recent_articles(pool, window_hours, BACKFILL_CAP).await?;
+    let mut indexed = 0;
+    for chunk in recent.chunks(BACKFILL_BATCH) {
+        let ids: Vec<i64> = chunk.iter().map(|row| row.id).collect();
+        let existing = index.qdrant.indexed(&ids).await?;
+        for row in chunk.iter().filter(|row| !existing.contains(&row.id)) {
+            let vector = index
+                .embed(translation, &row.title, row.description.as_deref())
+                .await?;
+            index.insert(row.id, &vector, row.published_at).await?;
+            indexed += 1;
+        }
+    }
+    Ok(indexed)
+}
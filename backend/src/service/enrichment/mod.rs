@@ -0,0 +1,205 @@
+//! 文章评分 webhook：配置 `enrichment.webhook_url` 后，后台任务把新入库的文章逐篇 POST 给外部服务，
+//! 响应中的 JSON 对象（主题、向量、评分等）原样保存到 `news.articles.attrs`，无需修改本项目即可接入自有模型。
+//! 内置的摘要、标签等步骤见 `pipeline`。
+
+pub mod pipeline;
+mod steps;
+
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Context};
+use chrono::Utc;
+use reqwest::Client;
+use sqlx::PgPool;
+
+use crate::{
+    config::HttpClientConfig,
+    error::{AppError, AppResult},
+    model::{ArticleAttrsOut, EnrichmentStepOut},
+    ops::event_catalog::{Event, EventCode},
+    repo::{self, articles::ArticleRow},
+    service::settings_registry,
+};
+
+pub const WEBHOOK_URL_KEY: &str = "enrichment.webhook_url";
+pub const TOKEN_KEY: &str = "enrichment.token";
+
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const BATCH_SIZE: i64 = 20;
+const MAX_ATTEMPTS: i32 = 3;
+// 只处理最近入库的文章，启用时不会回填全部历史
+const LOOKBACK_HOURS: i64 = 24;
+const MAX_RESPONSE_BYTES: usize = 256 * 1024;
+// 外部服务持续不可用时，失败事件最多每 30 分钟记录一次
+const FAILURE_REPORT_INTERVAL: Duration = Duration::from_secs(1800);
+
+pub fn spawn(pool: PgPool, http_client: HttpClientConfig) -> anyhow::Result<()> {
+    let client = http_client
+        .apply(Client::builder().user_agent("NewsAggregatorEnrichment/0.1"))?
+        .timeout(REQUEST_TIMEOUT)
+        .build()?;
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(POLL_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut reporter = FailureReporter::default();
+        loop {
+            ticker.tick().await;
+            if let Err(err) = run_once(&pool, &client, &mut reporter).await {
+                tracing::warn!(error = ?err, "article enrichment iteration failed");
+            }
+        }
+    });
+    Ok(())
+}
+
+async fn run_once(pool: &PgPool, client: &Client, reporter: &mut FailureReporter) -> anyhow::Result<()> {
+    let Some(webhook_url) = settings_registry::get_value(pool, WEBHOOK_URL_KEY).await? else {
+        return Ok(());
+    };
+    if settings_registry::quiet_hours_active(pool, Utc::now()).await? {
+        return Ok(());
+    }
+    let token = settings_registry::get_value(pool, TOKEN_KEY)
+        .await?
+        .filter(|value| !value.is_empty());
+
+    loop {
+        let pending =
+            repo::articles::list_enrichment_pending(pool, LOOKBACK_HOURS, MAX_ATTEMPTS, BATCH_SIZE)
+                .await?;
+        let batch_len = pending.len() as i64;
+        let mut failed = 0;
+        for article in pending {
+            let article_id = article.id;
+            match score(client, &webhook_url, token.as_deref(), article).await {
+                Ok(attrs) => {
+                    repo::articles::store_enrichment(pool, article_id, &attrs).await?;
+                    tracing::info!(article_id, "article enriched");
+                }
+                Err(err) => {
+                    failed += 1;
+                    let attempts = repo::articles::record_enrichment_failure(pool, article_id).await?;
+                    tracing::warn!(error = ?err, article_id, attempts, "article enrichment failed");
+                    reporter.report(pool, &err).await;
+                }
+            }
+        }
+        // 整批失败时说明服务不可用，留到下一轮，避免在一轮内耗尽所有文章的重试次数
+        if batch_len < BATCH_SIZE || failed == batch_len {
+            return Ok(());
+        }
+    }
+}
+
+/// 调用外部服务，返回规范化后的 JSON 对象文本
+async fn score(
+    client: &Client,
+    webhook_url: &str,
+    token: Option<&str>,
+    article: ArticleRow,
+) -> anyhow::Result<String> {
+    let body = serde_json::json!({
+        "id": article.id,
+        "title": article.title,
+        "url": article.url,
+        "description": article.description,
+        "language": article.language,
+        "original_language": article.original_language,
+        "source_domain": article.source_domain,
+        "published_at": article.published_at.to_rfc3339(),
+        "tags": article.tags,
+    });
+    let mut request = client.post(webhook_url).json(&body);
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    let mut response = request.send().await.context("request failed")?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(anyhow!("webhook returned status {}", status.as_u16()));
+    }
+    if let Some(len) = response
+        .content_length()
+        .filter(|len| *len > MAX_RESPONSE_BYTES as u64)
+    {
+        return Err(anyhow!(
+            "response of {len} bytes exceeds {MAX_RESPONSE_BYTES}"
+        ));
+    }
+    // 未声明长度时边读边计数，超出上限立即放弃，不把整个响应读入内存
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await.context("failed to read response")? {
+        if bytes.len() + chunk.len() > MAX_RESPONSE_BYTES {
+            return Err(anyhow!("response exceeds {MAX_RESPONSE_BYTES} bytes"));
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    let value: serde_json::Value =
+        serde_json::from_slice(&bytes).context("response is not valid JSON")?;
+    if !value.is_object() {
+        return Err(anyhow!("response must be a JSON object"));
+    }
+    Ok(value.to_string())
+}
+
+#[derive(Default)]
+struct FailureReporter {
+    last_report: Option<Instant>,
+    suppressed: u32,
+}
+
+impl FailureReporter {
+    async fn report(&mut self, pool: &PgPool, err: &anyhow::Error) {
+        if self
+            .last_report
+            .is_some_and(|last| last.elapsed() < FAILURE_REPORT_INTERVAL)
+        {
+            self.suppressed += 1;
+            return;
+        }
+        let mut info: String = format!("{err:#}").chars().take(500).collect();
+        if self.suppressed > 0 {
+            info.push_str(&format!("（上次上报后另有 {} 次失败）", self.suppressed));
+        }
+        let suppressed = std::mem::take(&mut self.suppressed);
+        self.last_report = Some(Instant::now());
+        Event::new(EventCode::EnrichmentFailed)
+            .attr("suppressed", suppressed)
+            .detail(info)
+            .emit(pool)
+            .await;
+    }
+}
+
+pub async fn attrs(pool: &PgPool, article_id: i64) -> AppResult<ArticleAttrsOut> {
+    let row = repo::articles::find_enrichment(pool, article_id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {article_id} not found")))?;
+    let attrs = row
+        .attrs
+        .map(|raw| serde_json::from_str(&raw))
+        .transpose()
+        .map_err(|err| AppError::Internal(err.into()))?;
+    let mut steps = BTreeMap::new();
+    for step in repo::article_enrichments::list_for_article(pool, article_id).await? {
+        let output = serde_json::from_str(&step.output).map_err(|err| AppError::Internal(err.into()))?;
+        steps.insert(
+            step.step,
+            EnrichmentStepOut {
+                output,
+                updated_at: step.updated_at.to_rfc3339(),
+            },
+        );
+    }
+    Ok(ArticleAttrsOut {
+        article_id,
+        attrs,
+        enriched_at: row.enriched_at.map(|dt| dt.to_rfc3339()),
+        enrichment_attempts: row.enrichment_attempts,
+        steps,
+    })
+}
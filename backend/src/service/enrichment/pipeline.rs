@@ -0,0 +1,160 @@
+//! 文章增强流水线：摘要、标签、情感、实体、向量索引等步骤实现 `EnrichmentStep` 并登记在 `STEPS`，
+//! 启用哪些步骤及执行顺序由设置 `enrichment.pipeline`（步骤名数组）决定。抓取器在新文章入库后调用
+//! `spawn_run`，后续的 AI 能力只需新增一个步骤并写入设置，无需再改动抓取流程。
+
+use std::{collections::HashSet, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use futures::{future::BoxFuture, stream, StreamExt};
+use serde_json::{Map, Value};
+use sqlx::PgPool;
+
+use super::steps;
+use crate::{repo, service::settings_registry, util::translator::TranslationEngine};
+
+pub const PIPELINE_KEY: &str = "enrichment.pipeline";
+/// 默认只建立向量索引（未配置 `qdrant` 时为空操作），与引入流水线前的行为一致
+pub const DEFAULT_PIPELINE: &str = r#"["embedding"]"#;
+
+// 同时处理的文章数；同一篇文章的步骤按顺序执行
+const ARTICLE_CONCURRENCY: usize = 4;
+
+pub struct StepContext<'a> {
+    pub pool: &'a PgPool,
+    pub translation: &'a TranslationEngine,
+}
+
+/// 新入库文章在流水线中的输入，标题与摘要为翻译后的内容
+pub struct PipelineArticle {
+    pub id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    pub source_domain: String,
+    pub published_at: DateTime<Utc>,
+    /// 去重时已生成的 embedding，向量索引步骤据此避免重复调用模型
+    pub vector: Option<Vec<f32>>,
+}
+
+pub trait EnrichmentStep: Send + Sync {
+    /// 设置中使用的步骤名，同时作为 `news.article_enrichments.step`
+    fn name(&self) -> &'static str;
+
+    /// 处理一篇文章；`previous` 为本轮中排在前面的步骤的输出（按步骤名）。
+    /// 返回 None 表示没有需要保存的输出（如只写入外部索引，或文章缺少所需字段）
+    fn run<'a>(
+        &'a self,
+        ctx: &'a StepContext<'a>,
+        article: &'a PipelineArticle,
+        previous: &'a Map<String, Value>,
+    ) -> BoxFuture<'a, anyhow::Result<Option<Value>>>;
+}
+
+/// 已注册的步骤；新增步骤在此登记后即可通过设置启用
+pub const STEPS: &[&dyn EnrichmentStep] = &[
+    &steps::Summarize,
+    &steps::Tags,
+    &steps::Sentiment,
+    &steps::Entities,
+    &steps::Embedding,
+];
+
+pub fn lookup(name: &str) -> Option<&'static dyn EnrichmentStep> {
+    STEPS.iter().copied().find(|step| step.name() == name)
+}
+
+/// 设置校验：步骤名数组，必须是已注册的步骤且不能重复；空数组表示停用流水线
+pub fn normalize_pipeline(raw: &str) -> Result<String, String> {
+    let names: Vec<String> =
+        serde_json::from_str(raw).map_err(|_| "需要步骤名数组，如 [\"summarize\", \"embedding\"]".to_string())?;
+    let mut seen = HashSet::new();
+    let mut normalized = Vec::with_capacity(names.len());
+    for name in names {
+        let name = name.trim().to_ascii_lowercase();
+        if lookup(&name).is_none() {
+            let known: Vec<&str> = STEPS.iter().map(|step| step.name()).collect();
+            return Err(format!("未知的步骤 {name}，可选：{}", known.join("|")));
+        }
+        if !seen.insert(name.clone()) {
+            return Err(format!("步骤 {name} 重复"));
+        }
+        normalized.push(name);
+    }
+    serde_json::to_string(&normalized).map_err(|err| err.to_string())
+}
+
+/// 按设置中的顺序返回启用的步骤
+pub async fn enabled_steps(pool: &PgPool) -> Result<Vec<&'static dyn EnrichmentStep>, sqlx::Error> {
+    let raw = settings_registry::get_value(pool, PIPELINE_KEY)
+        .await?
+        .unwrap_or_else(|| DEFAULT_PIPELINE.to_string());
+    let names: Vec<String> = serde_json::from_str(&raw).unwrap_or_default();
+    Ok(names.iter().filter_map(|name| lookup(name)).collect())
+}
+
+/// 在后台对新入库的文章执行流水线，不阻塞抓取
+pub fn spawn_run(pool: PgPool, translation: Arc<TranslationEngine>, articles: Vec<PipelineArticle>) {
+    if articles.is_empty() {
+        return;
+    }
+    tokio::spawn(async move {
+        if let Err(err) = run(&pool, &translation, &articles).await {
+            tracing::warn!(error = ?err, count = articles.len(), "enrichment pipeline failed");
+        }
+    });
+}
+
+async fn run(
+    pool: &PgPool,
+    translation: &TranslationEngine,
+    articles: &[PipelineArticle],
+) -> anyhow::Result<()> {
+    let steps = enabled_steps(pool).await?;
+    if steps.is_empty() {
+        return Ok(());
+    }
+    // 入库后被过滤条件删除或已归并到其他文章的不再处理
+    let ids: Vec<i64> = articles.iter().map(|article| article.id).collect();
+    let live: HashSet<i64> = repo::articles::list_canonical_by_ids(pool, &ids)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect();
+    let ctx = StepContext { pool, translation };
+    let jobs: Vec<_> = articles
+        .iter()
+        .filter(|article| live.contains(&article.id))
+        .map(|article| run_article(&ctx, &steps, article))
+        .collect();
+    stream::iter(jobs)
+        .buffer_unordered(ARTICLE_CONCURRENCY)
+        .collect::<Vec<()>>()
+        .await;
+    Ok(())
+}
+
+// 单个步骤失败只记录日志，不影响后续步骤
+async fn run_article(
+    ctx: &StepContext<'_>,
+    steps: &[&'static dyn EnrichmentStep],
+    article: &PipelineArticle,
+) {
+    let mut outputs = Map::new();
+    for step in steps {
+        let name = step.name();
+        match step.run(ctx, article, &outputs).await {
+            Ok(Some(output)) => {
+                if let Err(err) =
+                    repo::article_enrichments::store(ctx.pool, article.id, name, &output.to_string())
+                        .await
+                {
+                    tracing::warn!(error = ?err, article_id = article.id, step = name, "failed to store enrichment output");
+                }
+                outputs.insert(name.to_string(), output);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                tracing::warn!(error = ?err, article_id = article.id, step = name, "enrichment step failed");
+            }
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! 内置增强步骤。大模型步骤使用当前翻译 provider 的客户端，输入为翻译后的标题与摘要。
+
+use anyhow::{anyhow, Context};
+use futures::future::BoxFuture;
+use serde_json::{json, Map, Value};
+
+use super::pipeline::{EnrichmentStep, PipelineArticle, StepContext};
+use crate::{service::embedding, util::llm::SharedLlmClient};
+
+// 送入模型的摘要上限，避免个别源把全文放进摘要
+const MAX_INPUT_CHARS: usize = 2000;
+const MAX_TAGS: usize = 5;
+const MAX_ENTITIES: usize = 10;
+
+const TAGS_PROMPT: &str = "你是一名新闻编辑。请为输入的新闻给出不超过 5 个简体中文主题标签（如行业、公司、事件类型），每个标签 2-8 个字。输出必须为 JSON：{\"tags\": [\"标签\"]}，不要输出 JSON 之外的任何内容。";
+
+const SENTIMENT_PROMPT: &str = "你是一名财经新闻分析师。请判断输入新闻的整体情感倾向。输出必须为 JSON：{\"label\": \"positive\" | \"neutral\" | \"negative\", \"score\": -1 到 1 之间的小数（负面为负）}，不要输出 JSON 之外的任何内容。";
+
+const ENTITIES_PROMPT: &str = "你是一名新闻信息抽取助手。请从输入新闻中抽取明确提及的人物、机构（公司、政府部门、组织）与地点，保留原文写法，没有则给空数组。输出必须为 JSON：{\"people\": [], \"organizations\": [], \"locations\": []}，不要输出 JSON 之外的任何内容。";
+
+fn llm_client(ctx: &StepContext<'_>) -> anyhow::Result<SharedLlmClient> {
+    let provider = ctx.translation.current_provider();
+    ctx.translation
+        .llm_client(provider)
+        .ok_or_else(|| anyhow!("llm provider {} is not configured", provider.as_str()))
+}
+
+fn article_input(article: &PipelineArticle) -> String {
+    let mut input = format!("Title: {}\nSource: {}", article.title, article.source_domain);
+    if let Some(description) = article.description.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+        input.push_str("\nSummary: ");
+        input.extend(description.chars().take(MAX_INPUT_CHARS));
+    }
+    input
+}
+
+fn parse_object(content: &str) -> anyhow::Result<Map<String, Value>> {
+    let cleaned = content.trim();
+    let json_str = cleaned
+        .trim_start_matches("```json")
+        .trim_start_matches("```")
+        .trim_end_matches("```")
+        .trim();
+    match serde_json::from_str(json_str).or_else(|_| serde_json::from_str(cleaned)) {
+        Ok(Value::Object(map)) => Ok(map),
+        _ => Err(anyhow!("model output is not a JSON object: {cleaned}")),
+    }
+}
+
+/// 去掉空白与重复项，最多保留 `max` 个
+fn string_list(value: Option<&Value>, max: usize) -> Vec<String> {
+    let mut items: Vec<String> = Vec::new();
+    for item in value.and_then(Value::as_array).into_iter().flatten() {
+        let Some(text) = item.as_str().map(str::trim).filter(|text| !text.is_empty()) else {
+            continue;
+        };
+        if !items.iter().any(|existing| existing == text) {
+            items.push(text.to_string());
+        }
+        if items.len() == max {
+            break;
+        }
+    }
+    items
+}
+
+async fn complete(ctx: &StepContext<'_>, prompt: &str, article: &PipelineArticle, purpose: &str) -> anyhow::Result<Map<String, Value>> {
+    let client = llm_client(ctx)?;
+    let content = client.complete(prompt, article_input(article), purpose).await?;
+    parse_object(&content).with_context(|| format!("failed to parse {purpose} output"))
+}
+
+/// `{"summary": "..."}`；没有摘要的条目跳过
+pub struct Summarize;
+
+impl EnrichmentStep for Summarize {
+    fn name(&self) -> &'static str {
+        "summarize"
+    }
+
+    fn run<'a>(
+        &'a self,
+        ctx: &'a StepContext<'a>,
+        article: &'a PipelineArticle,
+        _previous: &'a Map<String, Value>,
+    ) -> BoxFuture<'a, anyhow::Result<Option<Value>>> {
+        Box::pin(async move {
+            let Some(description) = article.description.as_deref().map(str::trim).filter(|d| !d.is_empty()) else {
+                return Ok(None);
+            };
+            let content: String = description.chars().take(MAX_INPUT_CHARS).collect();
+            let summary = llm_client(ctx)?.summarize(&article.title, &content).await?;
+            Ok(Some(json!({ "summary": summary })))
+        })
+    }
+}
+
+/// `{"tags": ["..."]}`，最多 5 个
+pub struct Tags;
+
+impl EnrichmentStep for Tags {
+    fn name(&self) -> &'static str {
+        "tags"
+    }
+
+    fn run<'a>(
+        &'a self,
+        ctx: &'a StepContext<'a>,
+        article: &'a PipelineArticle,
+        _previous: &'a Map<String, Value>,
+    ) -> BoxFuture<'a, anyhow::Result<Option<Value>>> {
+        Box::pin(async move {
+            let output = complete(ctx, TAGS_PROMPT, article, "tagging").await?;
+            Ok(Some(json!({ "tags": string_list(output.get("tags"), MAX_TAGS) })))
+        })
+    }
+}
+
+/// `{"label": "positive|neutral|negative", "score": -1..1}`
+pub struct Sentiment;
+
+impl EnrichmentStep for Sentiment {
+    fn name(&self) -> &'static str {
+        "sentiment"
+    }
+
+    fn run<'a>(
+        &'a self,
+        ctx: &'a StepContext<'a>,
+        article: &'a PipelineArticle,
+        _previous: &'a Map<String, Value>,
+    ) -> BoxFuture<'a, anyhow::Result<Option<Value>>> {
+        Box::pin(async move {
+            let output = complete(ctx, SENTIMENT_PROMPT, article, "sentiment").await?;
+            let label = output
+                .get("label")
+                .and_then(Value::as_str)
+                .map(|label| label.trim().to_ascii_lowercase())
+                .filter(|label| matches!(label.as_str(), "positive" | "neutral" | "negative"))
+                .ok_or_else(|| anyhow!("sentiment output has no valid label"))?;
+            let score = output
+                .get("score")
+                .and_then(Value::as_f64)
+                .map(|score| score.clamp(-1.0, 1.0));
+            Ok(Some(json!({ "label": label, "score": score })))
+        })
+    }
+}
+
+/// `{"people": [], "organizations": [], "locations": []}`，每类最多 10 个
+pub struct Entities;
+
+impl EnrichmentStep for Entities {
+    fn name(&self) -> &'static str {
+        "entities"
+    }
+
+    fn run<'a>(
+        &'a self,
+        ctx: &'a StepContext<'a>,
+        article: &'a PipelineArticle,
+        _previous: &'a Map<String, Value>,
+    ) -> BoxFuture<'a, anyhow::Result<Option<Value>>> {
+        Box::pin(async move {
+            let output = complete(ctx, ENTITIES_PROMPT, article, "entity extraction").await?;
+            Ok(Some(json!({
+                "people": string_list(output.get("people"), MAX_ENTITIES),
+                "organizations": string_list(output.get("organizations"), MAX_ENTITIES),
+                "locations": string_list(output.get("locations"), MAX_ENTITIES),
+            })))
+        })
+    }
+}
+
+/// 写入向量索引（见 `service::embedding`），不保存输出；未配置 `qdrant` 时跳过
+pub struct Embedding;
+
+impl EnrichmentStep for Embedding {
+    fn name(&self) -> &'static str {
+        "embedding"
+    }
+
+    fn run<'a>(
+        &'a self,
+        ctx: &'a StepContext<'a>,
+        article: &'a PipelineArticle,
+        _previous: &'a Map<String, Value>,
+    ) -> BoxFuture<'a, anyhow::Result<Option<Value>>> {
+        Box::pin(async move {
+            let Some(index) = embedding::index() else {
+                return Ok(None);
+            };
+            let computed;
+            let vector = match article.vector.as_deref() {
+                Some(vector) => vector,
+                None => {
+                    computed = index
+                        .embed(ctx.translation, &article.title, article.description.as_deref())
+                        .await?;
+                    &computed
+                }
+            };
+            index.insert(article.id, vector, article.published_at).await?;
+            Ok(None)
+        })
+    }
+}
@@ -0,0 +1,244 @@
+//! 正文抽取：开启 `extraction.enabled` 后，后台任务逐篇抓取新入库文章的原文页面，
+//! 用 readability 风格的抽取器提取正文存入 `news.article_contents`，供去重、摘要与搜索使用。
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use chrono::Utc;
+use reqwest::{header, Client};
+use sqlx::PgPool;
+use url::Url;
+
+use crate::{
+    config::HttpClientConfig,
+    error::{AppError, AppResult},
+    fetcher::transcode_to_utf8,
+    model::{ArticleContentOut, ArticleReaderOut},
+    repo::{self, article_contents::ContentPendingRow},
+    service::settings_registry,
+    util::{
+        readability::{self, Extracted},
+        robots,
+    },
+};
+
+pub const ENABLED_KEY: &str = "extraction.enabled";
+
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+const BATCH_SIZE: i64 = 20;
+const MAX_ATTEMPTS: i32 = 3;
+// 只处理最近入库的文章，启用时不会回填全部历史
+const LOOKBACK_HOURS: i64 = 24;
+// 声明的长度超出时直接放弃；未声明长度时读到上限即停止，正文通常位于页面前部
+const MAX_PAGE_BYTES: usize = 2 * 1024 * 1024;
+
+/// 页面可以访问但不适合抽取（非 HTML、找不到正文），重试也不会改变结果
+struct Unextractable(String);
+
+pub fn spawn(
+    pool: PgPool,
+    http_client: HttpClientConfig,
+    respect_robots_txt: bool,
+) -> anyhow::Result<()> {
+    // 与抓取器使用同一 User-Agent，robots.txt 中针对抓取器的规则同样适用
+    let client = http_client
+        .apply(Client::builder().user_agent("NewsAggregatorFetcher/0.1"))?
+        .timeout(REQUEST_TIMEOUT)
+        .build()?;
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(POLL_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(err) = run_once(&pool, &client, respect_robots_txt).await {
+                tracing::warn!(error = ?err, "article extraction iteration failed");
+            }
+        }
+    });
+    Ok(())
+}
+
+async fn run_once(pool: &PgPool, client: &Client, respect_robots_txt: bool) -> anyhow::Result<()> {
+    if !settings_registry::get_bool(pool, ENABLED_KEY).await? {
+        return Ok(());
+    }
+    if settings_registry::quiet_hours_active(pool, Utc::now()).await? {
+        return Ok(());
+    }
+
+    loop {
+        let pending =
+            repo::article_contents::list_pending(pool, LOOKBACK_HOURS, MAX_ATTEMPTS, BATCH_SIZE)
+                .await?;
+        let batch_len = pending.len() as i64;
+        for article in pending {
+            let article_id = article.id;
+            match extract_article(client, &article, respect_robots_txt).await {
+                Ok(Ok(extracted)) => {
+                    repo::article_contents::store(
+                        pool,
+                        article_id,
+                        &extracted.text,
+                        &extracted.images,
+                    )
+                    .await?;
+                    tracing::info!(
+                        article_id,
+                        chars = extracted.text.chars().count(),
+                        images = extracted.images.len(),
+                        "article content extracted"
+                    );
+                }
+                Ok(Err(Unextractable(reason))) => {
+                    repo::article_contents::record_failure(
+                        pool,
+                        article_id,
+                        &reason,
+                        MAX_ATTEMPTS,
+                        true,
+                    )
+                    .await?;
+                    tracing::info!(article_id, url = %article.url, reason = %reason, "article content not extractable");
+                }
+                Err(err) => {
+                    let error: String = format!("{err:#}").chars().take(500).collect();
+                    let attempts = repo::article_contents::record_failure(
+                        pool,
+                        article_id,
+                        &error,
+                        MAX_ATTEMPTS,
+                        false,
+                    )
+                    .await?;
+                    tracing::warn!(error = ?err, article_id, attempts, "article content extraction failed");
+                }
+            }
+        }
+        if batch_len < BATCH_SIZE {
+            return Ok(());
+        }
+    }
+}
+
+/// 外层错误为可重试的网络/HTTP 失败；图片地址按跳转后的页面地址解析为绝对 URL
+async fn extract_article(
+    client: &Client,
+    article: &ContentPendingRow,
+    respect_robots_txt: bool,
+) -> anyhow::Result<Result<Extracted, Unextractable>> {
+    let url = Url::parse(&article.url).context("invalid article url")?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Ok(Err(Unextractable(format!(
+            "unsupported scheme {}",
+            url.scheme()
+        ))));
+    }
+    if respect_robots_txt && !robots::shared().check(client, &url).await.allowed {
+        // robots.txt 暂时不可用时同样视为禁止，按可重试失败处理
+        return Err(anyhow!("disallowed by robots.txt"));
+    }
+
+    let mut response = client.get(url).send().await.context("request failed")?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(anyhow!("page returned status {}", status.as_u16()));
+    }
+    let page_url = response.url().clone();
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    if let Some(ct) = content_type.as_deref() {
+        if !ct.to_ascii_lowercase().contains("html") {
+            return Ok(Err(Unextractable(format!("not an HTML page ({ct})"))));
+        }
+    }
+    if let Some(len) = response
+        .content_length()
+        .filter(|len| *len > MAX_PAGE_BYTES as u64)
+    {
+        return Ok(Err(Unextractable(format!(
+            "page of {len} bytes exceeds {MAX_PAGE_BYTES}"
+        ))));
+    }
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await.context("failed to read page")? {
+        let room = MAX_PAGE_BYTES - bytes.len();
+        bytes.extend_from_slice(&chunk[..chunk.len().min(room)]);
+        if bytes.len() >= MAX_PAGE_BYTES {
+            break;
+        }
+    }
+    let html =
+        String::from_utf8_lossy(&transcode_to_utf8(&bytes, content_type.as_deref())).into_owned();
+
+    let Some(mut extracted) = readability::extract(&html) else {
+        return Ok(Err(Unextractable("no article content found".to_string())));
+    };
+    let mut images: Vec<String> = Vec::new();
+    for src in &extracted.images {
+        let Ok(url) = page_url.join(src) else {
+            continue;
+        };
+        if !matches!(url.scheme(), "http" | "https") {
+            continue;
+        }
+        let url = String::from(url);
+        if !images.contains(&url) {
+            images.push(url);
+        }
+    }
+    extracted.images = images;
+    Ok(Ok(extracted))
+}
+
+pub async fn content(pool: &PgPool, article_id: i64) -> AppResult<ArticleContentOut> {
+    let row = repo::article_contents::find(pool, article_id).await?;
+    if row.is_none()
+        && repo::articles::find_by_id(pool, article_id)
+            .await?
+            .is_none()
+    {
+        return Err(AppError::BadRequest(format!(
+            "article {article_id} not found"
+        )));
+    }
+    Ok(match row {
+        Some(row) => ArticleContentOut {
+            article_id,
+            content: row.content,
+            images: row.images,
+            extracted_at: row.extracted_at.map(|dt| dt.to_rfc3339()),
+            attempts: row.attempts,
+            last_error: row.last_error,
+            full_description: row.full_description,
+        },
+        None => ArticleContentOut {
+            article_id,
+            content: None,
+            images: Vec::new(),
+            extracted_at: None,
+            attempts: 0,
+            last_error: None,
+            full_description: None,
+        },
+    })
+}
+
+/// 前台阅读视图：隐藏的文章视为不存在；尚未抽取到正文时 `content` 为 null，前台应回退到原文链接
+pub async fn reader(pool: &PgPool, article_id: i64) -> AppResult<ArticleReaderOut> {
+    let row = repo::article_contents::find_public(pool, article_id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {article_id} not found")))?;
+    Ok(ArticleReaderOut {
+        id: row.id,
+        title: row.title,
+        url: row.url,
+        source_domain: row.source_domain,
+        published_at: row.published_at.to_rfc3339(),
+        content: row.content,
+        images: row.images.unwrap_or_default(),
+    })
+}
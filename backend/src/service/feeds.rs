@@ -1,17 +1,35 @@
 use std::{sync::Arc, time::Duration};
 
 use feed_rs::parser;
+use futures::{stream, StreamExt};
 use reqwest::Client;
+use url::Url;
 use tracing::warn;
 
 use crate::{
     config::{FetcherConfig, HttpClientConfig},
     error::{AppError, AppResult},
     fetcher,
-    model::{FeedOut, FeedTestPayload, FeedTestResult, FeedUpsertPayload},
+    model::{
+        BookmarkFeedOut, BookmarkImportOut, BookmarkImportPayload, BookmarkMissOut,
+        SourceFeedOut, SourceImportOut, SourceImportPayload, SourceMissOut,
+        DiscoveredFeedOut, FeedDiscoverOut, FeedDiscoverPayload,
+        FeedClonePayload, FilteredArticleOut, FilteredRestoreOut, FilteredRestorePayload, FeedDatePreviewOut, FeedOut, FeedTestPayload, FeedTestResult, FeedUpsertPayload,
+        FetcherInstanceOut, FetcherQueueOut, FilterSuggestionOut, FilterSuggestionsOut, QueueFeedOut,
+    },
     repo,
-    util::translator::TranslationEngine,
-    ops::events::EventsHub,
+    service::settings_registry,
+    util::{
+        bookmarks, cache::LatestArticlesCache, deepseek::ArticleSnippet, feed_dates, feed_headers,
+        feed_sources::{self, SourceTarget},
+        lang::{normalize_language_tag, SUPPORTED_LANGUAGES},
+        schedule,
+        translator::TranslationEngine,
+    },
+    ops::{
+        event_catalog::{Event, EventCode},
+        events::EventsHub,
+    },
 };
 
 pub async fn list(pool: &sqlx::PgPool) -> AppResult<Vec<FeedOut>> {
@@ -25,6 +43,7 @@ pub async fn upsert(
     fetcher_config: &FetcherConfig,
     translator: &Arc<TranslationEngine>,
     events: &EventsHub,
+    latest_cache: &LatestArticlesCache,
     payload: FeedUpsertPayload,
 ) -> AppResult<FeedOut> {
     let FeedUpsertPayload {
@@ -36,6 +55,14 @@ pub async fn upsert(
         title,
         site_url,
         filter_condition,
+        schedule_cron,
+        ignore_conditional,
+        date_timezone,
+        date_format,
+        language_hint,
+        require_review,
+        request_headers,
+        tags,
     } = payload;
 
     let url = url.trim().to_string();
@@ -69,8 +96,32 @@ pub async fn upsert(
         validate_filter_condition(condition)?;
     }
 
+    let schedule_cron = schedule_cron
+        .map(|raw| raw.trim().to_string())
+        .filter(|value| !value.is_empty());
+
+    if let Some(ref expr) = schedule_cron {
+        schedule::parse_cron(expr)
+            .map_err(|err| AppError::BadRequest(format!("抓取计划 cron 表达式无效: {err}")))?;
+    }
+
+    let date_timezone = date_timezone.map(normalize_date_timezone).transpose()?;
+    let date_format = date_format.map(normalize_date_format).transpose()?;
+    let language_hint = language_hint.map(normalize_language_hint).transpose()?;
+    let tags = tags.map(normalize_tags).transpose()?;
+
     let existing = repo::feeds::find_by_url(pool, &url).await?;
     let is_new_feed = existing.is_none();
+    let request_headers = request_headers
+        .map(|headers| {
+            let stored = feed_headers::from_column(
+                existing.as_ref().and_then(|feed| feed.request_headers.as_deref()),
+            );
+            feed_headers::normalize(feed_headers::unmask(headers, &stored))
+                .map(|headers| feed_headers::to_column(&headers))
+                .map_err(AppError::BadRequest)
+        })
+        .transpose()?;
 
     let record = repo::feeds::FeedUpsertRecord {
         url: url.clone(),
@@ -80,6 +131,13 @@ pub async fn upsert(
         enabled,
         fetch_interval_seconds,
         filter_condition: filter_condition.clone(),
+        schedule_cron,
+        ignore_conditional,
+        date_timezone,
+        date_format,
+        language_hint,
+        require_review,
+        request_headers,
     };
 
     let row = repo::feeds::upsert_feed(pool, record).await?;
@@ -102,7 +160,12 @@ pub async fn upsert(
     }
 
     let feed_id = row.id;
-    let response = feed_row_to_out(row);
+    let mut response = feed_row_to_out(row);
+
+    if let Some(tags) = tags {
+        repo::feeds::set_feed_tags(pool, feed_id, &tags).await?;
+        response.tags = tags;
+    }
 
     if let Some(ref condition) = filter_condition {
         let previous_condition = existing
@@ -137,10 +200,18 @@ pub async fn upsert(
         let fetcher_config = fetcher_config.clone();
         let translator = Arc::clone(translator);
         let events = events.clone();
+        let latest_cache = Arc::clone(latest_cache);
         tokio::spawn(async move {
-            if let Err(err) =
-                fetcher::fetch_feed_once(pool_fetch, fetcher_config, http_client, translator, events.clone(), feed_id)
-                    .await
+            if let Err(err) = fetcher::fetch_feed_once(
+                pool_fetch,
+                fetcher_config,
+                http_client,
+                translator,
+                events.clone(),
+                latest_cache,
+                feed_id,
+            )
+            .await
             {
                 tracing::warn!(
                     error = ?err,
@@ -155,8 +226,191 @@ pub async fn upsert(
     Ok(response)
 }
 
+// 以已有订阅源为模板创建新订阅源：复制过滤条件、抓取间隔与 cron 计划、启用状态、标签与来源域名，仅替换 URL
+#[allow(clippy::too_many_arguments)]
+pub async fn clone_feed(
+    pool: &sqlx::PgPool,
+    http_client: &HttpClientConfig,
+    fetcher_config: &FetcherConfig,
+    translator: &Arc<TranslationEngine>,
+    events: &EventsHub,
+    latest_cache: &LatestArticlesCache,
+    id: i64,
+    payload: FeedClonePayload,
+) -> AppResult<FeedOut> {
+    let source = repo::feeds::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("feed {id} not found")))?;
+
+    let url = payload.url.trim().to_string();
+    if url.is_empty() {
+        return Err(AppError::BadRequest("url is required".into()));
+    }
+    if repo::feeds::find_by_url(pool, &url).await?.is_some() {
+        return Err(AppError::BadRequest("该 URL 的订阅源已存在".into()));
+    }
+
+    let title = payload
+        .title
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+
+    let cloned = upsert(
+        pool,
+        http_client,
+        fetcher_config,
+        translator,
+        events,
+        latest_cache,
+        FeedUpsertPayload {
+            id: None,
+            url,
+            source_domain: source.source_domain,
+            enabled: Some(source.enabled),
+            fetch_interval_seconds: Some(source.fetch_interval_seconds),
+            title,
+            site_url: None,
+            filter_condition: source.filter_condition,
+            schedule_cron: source.schedule_cron,
+            ignore_conditional: Some(source.ignore_conditional),
+            date_timezone: source.date_timezone,
+            date_format: source.date_format,
+            language_hint: source.language_hint,
+            require_review: Some(source.require_review),
+            request_headers: Some(feed_headers::from_column(source.request_headers.as_deref())),
+            tags: Some(source.tags),
+        },
+    )
+    .await?;
+
+    tracing::info!(source_feed_id = id, feed_id = cloned.id, "feed cloned");
+    Ok(cloned)
+}
+
 // no-op: events suppressed; keep minimal imports only where needed
 
+const FILTERED_LIST_LIMIT: i64 = 500;
+
+pub async fn list_filtered(pool: &sqlx::PgPool, feed_id: i64) -> AppResult<Vec<FilteredArticleOut>> {
+    let rows = repo::articles::list_filtered(pool, feed_id, FILTERED_LIST_LIMIT).await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| FilteredArticleOut {
+            id: row.id,
+            article_id: row.article_id,
+            feed_id: row.feed_id,
+            title: row.title,
+            url: row.url,
+            condition: row.condition,
+            filtered_at: row.filtered_at.to_rfc3339(),
+            expires_at: row.expires_at.to_rfc3339(),
+        })
+        .collect())
+}
+
+// 恢复被过滤条件删除的文章。若条件未修正，下次抓取入库后会再次被过滤
+pub async fn restore_filtered(
+    pool: &sqlx::PgPool,
+    feed_id: i64,
+    payload: FilteredRestorePayload,
+) -> AppResult<FilteredRestoreOut> {
+    if repo::feeds::find_by_id(pool, feed_id).await?.is_none() {
+        return Err(AppError::BadRequest(format!("feed {feed_id} not found")));
+    }
+    if payload.ids.as_ref().is_some_and(|ids| ids.is_empty()) {
+        return Err(AppError::BadRequest("ids 不能为空数组".into()));
+    }
+    let (restored, skipped) =
+        repo::articles::restore_filtered(pool, feed_id, payload.ids.as_deref()).await?;
+    tracing::info!(feed_id, restored, skipped, "filtered articles restored");
+    Ok(FilteredRestoreOut { restored, skipped })
+}
+
+// 清除保存的 ETag：服务器返回错误 304 时，下次抓取强制拉取完整内容
+pub async fn clear_etag(pool: &sqlx::PgPool, id: i64) -> AppResult<FeedOut> {
+    let updated = repo::feeds::clear_etag(pool, id).await?;
+    if updated == 0 {
+        return Err(AppError::BadRequest(format!("feed {id} not found")));
+    }
+    tracing::info!(feed_id = id, "feed etag cleared");
+    let row = repo::feeds::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("feed {id} not found")))?;
+    Ok(feed_row_to_out(row))
+}
+
+// 重新启用：同时清零失败计数，避免下一次失败立即再次触发自动停用
+pub async fn reenable(pool: &sqlx::PgPool, id: i64) -> AppResult<FeedOut> {
+    let updated = repo::feeds::reenable(pool, id).await?;
+    if updated == 0 {
+        return Err(AppError::BadRequest(format!("feed {id} not found")));
+    }
+    let row = repo::feeds::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("feed {id} not found")))?;
+    tracing::info!(feed_id = id, "feed re-enabled");
+    Event::new(EventCode::FeedReenabled)
+        .attr("feed_id", id)
+        .attr("source_domain", row.source_domain.as_str())
+        .detail(format!("{} 已重新启用，失败计数已清零", row.source_domain))
+        .emit(pool)
+        .await;
+    Ok(feed_row_to_out(row))
+}
+
+// 静音上限，避免误填年份导致 feed 被长期遗忘
+const MAX_MUTE_DAYS: i64 = 365;
+
+// 静音：保留配置与启用状态，在截止时间前跳过定时抓取
+pub async fn mute(pool: &sqlx::PgPool, id: i64, until: &str) -> AppResult<FeedOut> {
+    let until = chrono::DateTime::parse_from_rfc3339(until.trim())
+        .map_err(|_| AppError::BadRequest("until 需为 RFC3339 格式的时间".into()))?
+        .with_timezone(&chrono::Utc);
+    let now = chrono::Utc::now();
+    if until <= now {
+        return Err(AppError::BadRequest("until 必须晚于当前时间".into()));
+    }
+    if until > now + chrono::Duration::days(MAX_MUTE_DAYS) {
+        return Err(AppError::BadRequest(format!(
+            "静音时长不能超过 {MAX_MUTE_DAYS} 天"
+        )));
+    }
+    set_muted_until(pool, id, Some(until)).await
+}
+
+pub async fn unmute(pool: &sqlx::PgPool, id: i64) -> AppResult<FeedOut> {
+    set_muted_until(pool, id, None).await
+}
+
+async fn set_muted_until(
+    pool: &sqlx::PgPool,
+    id: i64,
+    until: Option<chrono::DateTime<chrono::Utc>>,
+) -> AppResult<FeedOut> {
+    let updated = repo::feeds::set_muted_until(pool, id, until).await?;
+    if updated == 0 {
+        return Err(AppError::BadRequest(format!("feed {id} not found")));
+    }
+    let row = repo::feeds::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("feed {id} not found")))?;
+    let event = match until {
+        Some(until) => Event::new(EventCode::FeedMuted)
+            .attr("until", until.to_rfc3339())
+            .detail(format!("{} 静音至 {}", row.source_domain, until.to_rfc3339())),
+        None => Event::new(EventCode::FeedUnmuted)
+            .attr("reason", "manual")
+            .detail(format!("{} 已手动解除静音", row.source_domain)),
+    };
+    tracing::info!(feed_id = id, muted_until = ?until, "feed mute updated");
+    event
+        .attr("feed_id", id)
+        .attr("source_domain", row.source_domain.as_str())
+        .emit(pool)
+        .await;
+    Ok(feed_row_to_out(row))
+}
+
 pub async fn delete(pool: &sqlx::PgPool, _events: &EventsHub, id: i64) -> AppResult<()> {
     let mut lock_conn = pool.acquire().await?;
     repo::feeds::acquire_processing_lock(&mut lock_conn, id).await?;
@@ -202,6 +456,7 @@ pub async fn delete(pool: &sqlx::PgPool, _events: &EventsHub, id: i64) -> AppRes
 }
 
 pub async fn test(
+    pool: &sqlx::PgPool,
     http_client: &HttpClientConfig,
     payload: FeedTestPayload,
 ) -> AppResult<FeedTestResult> {
@@ -209,26 +464,48 @@ pub async fn test(
     if url.is_empty() {
         return Err(AppError::BadRequest("url is required".into()));
     }
+    let date_timezone = payload.date_timezone.map(normalize_date_timezone).transpose()?;
+    let date_format = payload.date_format.map(normalize_date_format).transpose()?;
+    let overrides =
+        feed_dates::DateOverrides::from_columns(date_timezone.as_deref(), date_format.as_deref());
+    let stored_headers = match payload.feed_id {
+        Some(feed_id) => repo::feeds::find_by_id(pool, feed_id)
+            .await?
+            .ok_or_else(|| AppError::BadRequest(format!("feed {feed_id} not found")))?
+            .request_headers,
+        None => None,
+    };
+    let stored_headers = feed_headers::from_column(stored_headers.as_deref());
+    let request_headers = match payload.request_headers {
+        Some(headers) => feed_headers::normalize(feed_headers::unmask(headers, &stored_headers))
+            .map_err(AppError::BadRequest)?,
+        None => stored_headers,
+    };
 
     let builder = http_client
         .apply(Client::builder().user_agent("NewsAggregatorTester/0.1"))
-        .map_err(|err| AppError::Internal(err.into()))?;
+        .map_err(AppError::Internal)?;
 
     let client = builder
         .timeout(Duration::from_secs(10))
         .build()
         .map_err(|err| AppError::Internal(err.into()))?;
 
-    let response = client.get(url).send().await.map_err(|err| {
-        warn!(
-            error = %err,
-            url = url,
-            chain = %format_error_chain(&err),
-            "feed test request failed"
-        );
-        // event suppressed per new minimal set
-        AppError::BadRequest(format!("请求订阅源失败: {err}"))
-    })?;
+    let response = client
+        .get(url)
+        .headers(feed_headers::header_map(&request_headers))
+        .send()
+        .await
+        .map_err(|err| {
+            warn!(
+                error = %err,
+                url = url,
+                chain = %format_error_chain(&err),
+                "feed test request failed"
+            );
+            // event suppressed per new minimal set
+            AppError::BadRequest(format!("请求订阅源失败: {err}"))
+        })?;
 
     let status = response.status();
     if !status.is_success() {
@@ -254,14 +531,546 @@ pub async fn test(
 
     let site_url = parsed.links.first().map(|link| link.href.to_string());
 
+    // 与抓取时一致：优先 published，其次 updated；条目顺序两次解析相同，按下标对应
+    let adjusted = feed_dates::parse_feed(&bytes[..], &overrides)
+        .map_err(|err| AppError::BadRequest(format!("解析订阅源失败: {err}")))?;
+    let date_preview = parsed
+        .entries
+        .iter()
+        .zip(&adjusted.entries)
+        .take(DATE_PREVIEW_LIMIT)
+        .map(|(default, adjusted)| FeedDatePreviewOut {
+            title: default.title.as_ref().map(|text| text.content.trim().to_string()),
+            published_at_default: default.published.or(default.updated).map(|dt| dt.to_rfc3339()),
+            published_at: adjusted.published.or(adjusted.updated).map(|dt| dt.to_rfc3339()),
+        })
+        .collect();
+
     Ok(FeedTestResult {
         status: status.as_u16(),
         title,
         site_url,
         entry_count: parsed.entries.len(),
+        date_preview,
+    })
+}
+
+const DATE_PREVIEW_LIMIT: usize = 10;
+
+// 时区统一保存为 +08:00 形式；空字符串表示清除
+fn normalize_date_timezone(raw: String) -> AppResult<String> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Ok(String::new());
+    }
+    feed_dates::parse_timezone(trimmed)
+        .map(feed_dates::format_timezone)
+        .map_err(AppError::BadRequest)
+}
+
+fn normalize_date_format(raw: String) -> AppResult<String> {
+    let trimmed = raw.trim();
+    if !trimmed.is_empty() {
+        feed_dates::validate_format(trimmed).map_err(AppError::BadRequest)?;
+    }
+    Ok(trimmed.to_string())
+}
+
+// 语言标签统一保存为检测器使用的代码（en-US → en）；空字符串表示清除
+fn normalize_language_hint(raw: String) -> AppResult<String> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Ok(String::new());
+    }
+    normalize_language_tag(trimmed)
+        .map(str::to_string)
+        .ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "language_hint 可选值：{}",
+                SUPPORTED_LANGUAGES.join("|")
+            ))
+        })
+}
+
+// 单个页面最多验证的候选订阅源数量
+const DISCOVER_CANDIDATE_LIMIT: usize = 10;
+
+/// 从网站地址发现订阅源：地址本身是订阅源时直接返回，否则列出页面 `<link rel="alternate">`
+/// 声明的 RSS/Atom 并逐个验证，同时标出已订阅的地址
+pub async fn discover(
+    pool: &sqlx::PgPool,
+    http_client: &HttpClientConfig,
+    payload: FeedDiscoverPayload,
+) -> AppResult<FeedDiscoverOut> {
+    let raw = payload.url.trim();
+    if raw.is_empty() {
+        return Err(AppError::BadRequest("url is required".into()));
+    }
+    let url = if raw.contains("://") {
+        raw.to_string()
+    } else {
+        format!("https://{raw}")
+    };
+    let parsed_url =
+        Url::parse(&url).map_err(|err| AppError::BadRequest(format!("网站地址无效: {err}")))?;
+    if !matches!(parsed_url.scheme(), "http" | "https") {
+        return Err(AppError::BadRequest("仅支持 http/https 地址".into()));
+    }
+
+    let client = http_client
+        .apply(Client::builder().user_agent("NewsAggregatorTester/0.1"))
+        .map_err(AppError::Internal)?
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|err| AppError::Internal(err.into()))?;
+
+    let response = client
+        .get(parsed_url)
+        .send()
+        .await
+        .map_err(|err| AppError::BadRequest(format!("请求网站失败: {err}")))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(AppError::BadRequest(format!(
+            "网站返回状态码 {}",
+            status.as_u16()
+        )));
+    }
+    let page_url = response.url().clone();
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|err| AppError::BadRequest(format!("读取页面失败: {err}")))?;
+
+    let mut candidates = Vec::new();
+    if let Ok(feed) = parser::parse(&bytes[..]) {
+        candidates.push(DiscoveredFeedOut {
+            url: page_url.to_string(),
+            declared_title: None,
+            title: feed_title(&feed),
+            entry_count: Some(feed.entries.len()),
+            error: None,
+            existing_feed_id: None,
+        });
+    } else {
+        let html = String::from_utf8_lossy(&bytes);
+        let links = bookmarks::discover_feed_links(&html, &page_url);
+        candidates = stream::iter(links.into_iter().take(DISCOVER_CANDIDATE_LIMIT))
+            .map(|link| {
+                let client = client.clone();
+                async move {
+                    let probed = probe_feed(&client, &link.url).await;
+                    DiscoveredFeedOut {
+                        title: probed.as_ref().ok().and_then(feed_title),
+                        entry_count: probed.as_ref().ok().map(|feed| feed.entries.len()),
+                        error: probed.err(),
+                        url: link.url,
+                        declared_title: link.title,
+                        existing_feed_id: None,
+                    }
+                }
+            })
+            .buffered(BOOKMARK_DISCOVERY_CONCURRENCY)
+            .collect()
+            .await;
+    }
+
+    for candidate in &mut candidates {
+        candidate.existing_feed_id = repo::feeds::find_by_url(pool, &candidate.url)
+            .await?
+            .map(|feed| feed.id);
+    }
+
+    tracing::info!(
+        page_url = %page_url,
+        candidates = candidates.len(),
+        "feed discovery finished"
+    );
+    Ok(FeedDiscoverOut {
+        page_url: page_url.to_string(),
+        candidates,
     })
 }
 
+// 单次导入的书签数量上限，避免一次请求发出过多探测
+const BOOKMARK_IMPORT_LIMIT: usize = 300;
+const BOOKMARK_DISCOVERY_CONCURRENCY: usize = 4;
+// 每个站点最多尝试的候选订阅源数量
+const BOOKMARK_CANDIDATE_LIMIT: usize = 3;
+
+// 从浏览器书签导入订阅源：逐个站点做订阅源自动发现，找到的创建为订阅源，其余在结果中列出
+pub async fn import_bookmarks(
+    pool: &sqlx::PgPool,
+    http_client: &HttpClientConfig,
+    fetcher_config: &FetcherConfig,
+    translator: &Arc<TranslationEngine>,
+    events: &EventsHub,
+    latest_cache: &LatestArticlesCache,
+    payload: BookmarkImportPayload,
+) -> AppResult<BookmarkImportOut> {
+    let marks = bookmarks::parse_netscape_bookmarks(&payload.html);
+    if marks.is_empty() {
+        return Err(AppError::BadRequest("未在文件中找到任何书签链接".into()));
+    }
+    if marks.len() > BOOKMARK_IMPORT_LIMIT {
+        return Err(AppError::BadRequest(format!(
+            "书签数量 {} 超过单次导入上限 {BOOKMARK_IMPORT_LIMIT}，请拆分文件后再导入",
+            marks.len()
+        )));
+    }
+
+    let client = http_client
+        .apply(Client::builder().user_agent("NewsAggregatorImporter/0.1"))
+        .map_err(AppError::Internal)?
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|err| AppError::Internal(err.into()))?;
+
+    let discovered: Vec<_> = stream::iter(marks)
+        .map(|mark| {
+            let client = client.clone();
+            async move {
+                let result = discover_feed(&client, &mark.url).await;
+                (mark, result)
+            }
+        })
+        .buffered(BOOKMARK_DISCOVERY_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut out = BookmarkImportOut {
+        total: discovered.len(),
+        created: Vec::new(),
+        existing: Vec::new(),
+        no_feed: Vec::new(),
+    };
+
+    for (mark, result) in discovered {
+        let (feed_url, feed_title) = match result {
+            Ok(found) => found,
+            Err(reason) => {
+                out.no_feed.push(BookmarkMissOut {
+                    bookmark_url: mark.url,
+                    bookmark_title: mark.title,
+                    reason,
+                });
+                continue;
+            }
+        };
+
+        if let Some(existing) = repo::feeds::find_by_url(pool, &feed_url).await? {
+            out.existing.push(BookmarkFeedOut {
+                bookmark_url: mark.url,
+                bookmark_title: mark.title,
+                feed_url,
+                feed_id: existing.id,
+            });
+            continue;
+        }
+
+        let created = upsert(
+            pool,
+            http_client,
+            fetcher_config,
+            translator,
+            events,
+            latest_cache,
+            imported_feed_payload(
+                feed_url.clone(),
+                feed_title.or_else(|| mark.title.clone()),
+                Some(mark.url.clone()),
+                payload.enabled,
+                None,
+            ),
+        )
+        .await;
+
+        match created {
+            Ok(feed) => out.created.push(BookmarkFeedOut {
+                bookmark_url: mark.url,
+                bookmark_title: mark.title,
+                feed_url,
+                feed_id: feed.id,
+            }),
+            Err(AppError::BadRequest(reason)) => out.no_feed.push(BookmarkMissOut {
+                bookmark_url: mark.url,
+                bookmark_title: mark.title,
+                reason,
+            }),
+            Err(err) => return Err(err),
+        }
+    }
+
+    tracing::info!(
+        total = out.total,
+        created = out.created.len(),
+        existing = out.existing.len(),
+        no_feed = out.no_feed.len(),
+        "bookmark import finished"
+    );
+
+    Ok(out)
+}
+
+// 批量导入时新建订阅源的参数，其余设置取默认值
+fn imported_feed_payload(
+    url: String,
+    title: Option<String>,
+    site_url: Option<String>,
+    enabled: Option<bool>,
+    tags: Option<Vec<String>>,
+) -> FeedUpsertPayload {
+    FeedUpsertPayload {
+        id: None,
+        url,
+        source_domain: String::new(),
+        enabled,
+        fetch_interval_seconds: None,
+        title,
+        site_url,
+        filter_condition: None,
+        schedule_cron: None,
+        ignore_conditional: None,
+        date_timezone: None,
+        date_format: None,
+        language_hint: None,
+        require_review: None,
+        request_headers: None,
+        tags,
+    }
+}
+
+/// X 列表经 RSS 桥接服务转换后订阅，模板中的 `{list_id}` 替换为列表 ID；未设置时不支持 X 列表
+pub const X_LIST_FEED_TEMPLATE_KEY: &str = "discovery.x_list_feed_template";
+
+// 映射出的订阅源：地址、标题与站点地址
+struct ResolvedSource {
+    feed_url: String,
+    title: Option<String>,
+    site_url: Option<String>,
+}
+
+/// 按来源清单批量创建订阅源：GitHub 仓库映射为 releases.atom，X 列表经桥接模板映射，
+/// 其余地址按书签导入的方式做订阅源自动发现；每个映射结果都会先验证能否解析
+pub async fn import_sources(
+    pool: &sqlx::PgPool,
+    http_client: &HttpClientConfig,
+    fetcher_config: &FetcherConfig,
+    translator: &Arc<TranslationEngine>,
+    events: &EventsHub,
+    latest_cache: &LatestArticlesCache,
+    payload: SourceImportPayload,
+) -> AppResult<SourceImportOut> {
+    let entries = feed_sources::parse_source_list(&payload.list);
+    if entries.is_empty() {
+        return Err(AppError::BadRequest("清单中没有任何来源".into()));
+    }
+    if entries.len() > BOOKMARK_IMPORT_LIMIT {
+        return Err(AppError::BadRequest(format!(
+            "来源数量 {} 超过单次导入上限 {BOOKMARK_IMPORT_LIMIT}，请拆分清单后再导入",
+            entries.len()
+        )));
+    }
+    let x_list_template = settings_registry::get_value(pool, X_LIST_FEED_TEMPLATE_KEY).await?;
+
+    let client = http_client
+        .apply(Client::builder().user_agent("NewsAggregatorImporter/0.1"))
+        .map_err(AppError::Internal)?
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|err| AppError::Internal(err.into()))?;
+
+    let resolved: Vec<_> = stream::iter(entries)
+        .map(|entry| {
+            let client = client.clone();
+            let x_list_template = x_list_template.clone();
+            async move {
+                let target = feed_sources::classify(&entry.source);
+                let result = match &target {
+                    Ok(target) => resolve_source(&client, target, x_list_template.as_deref()).await,
+                    Err(reason) => Err(reason.clone()),
+                };
+                let kind = target.map(|target| target.kind()).unwrap_or("url");
+                (entry, kind, result)
+            }
+        })
+        .buffered(BOOKMARK_DISCOVERY_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut out = SourceImportOut {
+        total: resolved.len(),
+        created: Vec::new(),
+        existing: Vec::new(),
+        failed: Vec::new(),
+    };
+
+    for (entry, kind, result) in resolved {
+        let resolved = match result {
+            Ok(resolved) => resolved,
+            Err(reason) => {
+                out.failed.push(SourceMissOut {
+                    source: entry.source,
+                    reason,
+                });
+                continue;
+            }
+        };
+
+        if let Some(existing) = repo::feeds::find_by_url(pool, &resolved.feed_url).await? {
+            out.existing.push(SourceFeedOut {
+                source: entry.source,
+                kind: kind.to_string(),
+                feed_url: resolved.feed_url,
+                feed_id: existing.id,
+            });
+            continue;
+        }
+
+        let created = upsert(
+            pool,
+            http_client,
+            fetcher_config,
+            translator,
+            events,
+            latest_cache,
+            imported_feed_payload(
+                resolved.feed_url.clone(),
+                entry.title.or(resolved.title),
+                resolved.site_url,
+                payload.enabled,
+                payload.tags.clone(),
+            ),
+        )
+        .await;
+
+        match created {
+            Ok(feed) => out.created.push(SourceFeedOut {
+                source: entry.source,
+                kind: kind.to_string(),
+                feed_url: resolved.feed_url,
+                feed_id: feed.id,
+            }),
+            Err(AppError::BadRequest(reason)) => out.failed.push(SourceMissOut {
+                source: entry.source,
+                reason,
+            }),
+            Err(err) => return Err(err),
+        }
+    }
+
+    tracing::info!(
+        total = out.total,
+        created = out.created.len(),
+        existing = out.existing.len(),
+        failed = out.failed.len(),
+        "source list import finished"
+    );
+
+    Ok(out)
+}
+
+async fn resolve_source(
+    client: &Client,
+    target: &SourceTarget,
+    x_list_template: Option<&str>,
+) -> Result<ResolvedSource, String> {
+    match target {
+        SourceTarget::GithubReleases { owner, repo } => {
+            let feed_url = feed_sources::github_releases_feed_url(owner, repo);
+            probe_feed(client, &feed_url).await?;
+            Ok(ResolvedSource {
+                feed_url,
+                title: Some(format!("{owner}/{repo} releases")),
+                site_url: Some(feed_sources::github_repo_url(owner, repo)),
+            })
+        }
+        SourceTarget::XList { list_id } => {
+            let template = x_list_template
+                .ok_or_else(|| format!("未设置 {X_LIST_FEED_TEMPLATE_KEY}，无法订阅 X 列表"))?;
+            let feed_url = feed_sources::x_list_feed_url(template, list_id)
+                .ok_or_else(|| format!("{X_LIST_FEED_TEMPLATE_KEY} 需包含 {{list_id}} 占位符"))?;
+            let feed = probe_feed(client, &feed_url).await?;
+            Ok(ResolvedSource {
+                feed_url,
+                title: feed_title(&feed).or_else(|| Some(format!("X 列表 {list_id}"))),
+                site_url: Some(feed_sources::x_list_url(list_id)),
+            })
+        }
+        SourceTarget::Url(url) => {
+            let (feed_url, title) = discover_feed(client, url).await?;
+            Ok(ResolvedSource {
+                feed_url,
+                title,
+                site_url: Some(url.clone()),
+            })
+        }
+    }
+}
+
+// 书签地址本身是订阅源时直接采用，否则解析页面中声明的 RSS/Atom 链接并逐个验证
+async fn discover_feed(
+    client: &Client,
+    bookmark_url: &str,
+) -> Result<(String, Option<String>), String> {
+    let response = client
+        .get(bookmark_url)
+        .send()
+        .await
+        .map_err(|err| format!("请求失败: {err}"))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("站点返回状态码 {}", status.as_u16()));
+    }
+    let page_url = response.url().clone();
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|err| format!("读取页面失败: {err}"))?;
+
+    if let Ok(feed) = parser::parse(&bytes[..]) {
+        return Ok((bookmark_url.to_string(), feed_title(&feed)));
+    }
+
+    let html = String::from_utf8_lossy(&bytes);
+    let candidates = bookmarks::discover_feed_links(&html, &page_url);
+    if candidates.is_empty() {
+        return Err("页面未声明 RSS/Atom 订阅源".into());
+    }
+
+    for candidate in candidates.into_iter().take(BOOKMARK_CANDIDATE_LIMIT) {
+        if let Ok(feed) = probe_feed(client, &candidate.url).await {
+            return Ok((candidate.url, feed_title(&feed)));
+        }
+    }
+    Err("页面声明的订阅源均无法解析".into())
+}
+
+async fn probe_feed(client: &Client, url: &str) -> Result<feed_rs::model::Feed, String> {
+    Url::parse(url).map_err(|err| format!("地址无效: {err}"))?;
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|err| format!("请求失败: {err}"))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("返回状态码 {}", status.as_u16()));
+    }
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|err| format!("读取失败: {err}"))?;
+    parser::parse(&bytes[..]).map_err(|err| format!("解析失败: {err}"))
+}
+
+fn feed_title(feed: &feed_rs::model::Feed) -> Option<String> {
+    feed.title
+        .as_ref()
+        .map(|text| text.content.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
 fn format_error_chain(err: &(dyn std::error::Error + 'static)) -> String {
     let mut parts = vec![err.to_string()];
     let mut current = err.source();
@@ -274,6 +1083,233 @@ fn format_error_chain(err: &(dyn std::error::Error + 'static)) -> String {
     parts.join(" -> ")
 }
 
+// 调度视图向后看的时间窗口
+const QUEUE_HORIZON_SECS: i64 = 3600;
+
+/// 抓取队列：正在抓取、已到期、一小时内到期与静音中的 feed，并给出所处状态的原因
+pub async fn fetcher_queue(
+    pool: &sqlx::PgPool,
+    fetcher_config: &FetcherConfig,
+) -> AppResult<FetcherQueueOut> {
+    let rows = repo::feeds::list_queue_feeds(pool, QUEUE_HORIZON_SECS).await?;
+    let now = chrono::Utc::now();
+
+    let horizon = now + chrono::Duration::seconds(QUEUE_HORIZON_SECS);
+
+    let mut queue = FetcherQueueOut {
+        quiet_hours_active: settings_registry::quiet_hours_active(pool, now).await?,
+        ..Default::default()
+    };
+    for row in rows {
+        let failing = row.fail_count > 0;
+        let scheduled = row.schedule_cron.is_some();
+        // cron feed 的下次抓取时间按表达式计算；表达式失效或不再触发时不入队
+        let next_fetch_at = match row.schedule_cron.as_deref() {
+            Some(expr) => match schedule::parse_cron(expr)
+                .ok()
+                .and_then(|parsed| schedule::next_run(&parsed, row.last_fetch_at, now))
+            {
+                Some(next) => next,
+                None if row.in_flight => now,
+                None => continue,
+            },
+            None => row.next_fetch_at,
+        };
+        if let (Some(muted_until), false) = (row.muted_until, row.in_flight) {
+            queue.muted.push(QueueFeedOut {
+                feed_id: row.id,
+                url: row.url,
+                source_domain: row.source_domain,
+                last_fetch_at: row.last_fetch_at.map(|dt| dt.to_rfc3339()),
+                last_fetch_status: row.last_fetch_status.map(|s| s as i32),
+                fail_count: row.fail_count,
+                last_error_kind: row.last_error_kind,
+                next_fetch_at: next_fetch_at.max(muted_until).to_rfc3339(),
+                muted_until: Some(muted_until.to_rfc3339()),
+                reason: "muted".to_string(),
+            });
+            continue;
+        }
+        if !row.in_flight && next_fetch_at > horizon {
+            continue;
+        }
+
+        let (bucket, reason) = if row.in_flight {
+            (&mut queue.in_flight, "fetching")
+        } else if next_fetch_at <= now {
+            let reason = if row.last_fetch_at.is_none() {
+                "never_fetched"
+            } else if scheduled {
+                "schedule_elapsed"
+            } else if failing {
+                "retry_after_failure"
+            } else {
+                "interval_elapsed"
+            };
+            (&mut queue.due_now, reason)
+        } else if scheduled {
+            (&mut queue.due_next_hour, "waiting_schedule")
+        } else if failing {
+            (&mut queue.due_next_hour, "backoff_after_failure")
+        } else {
+            (&mut queue.due_next_hour, "waiting_interval")
+        };
+
+        bucket.push(QueueFeedOut {
+            feed_id: row.id,
+            url: row.url,
+            source_domain: row.source_domain,
+            last_fetch_at: row.last_fetch_at.map(|dt| dt.to_rfc3339()),
+            last_fetch_status: row.last_fetch_status.map(|s| s as i32),
+            fail_count: row.fail_count,
+            last_error_kind: row.last_error_kind,
+            next_fetch_at: next_fetch_at.to_rfc3339(),
+            muted_until: None,
+            reason: reason.to_string(),
+        });
+    }
+    queue.due_next_hour.sort_by(|a, b| a.next_fetch_at.cmp(&b.next_fetch_at));
+    queue.muted.sort_by(|a, b| a.muted_until.cmp(&b.muted_until));
+
+    let current = fetcher::shard::resolve_instance_id(fetcher_config.instance_id.as_deref());
+    queue.instances = repo::fetcher_instances::list_instances(pool)
+        .await?
+        .into_iter()
+        .map(|row| FetcherInstanceOut {
+            current: row.instance_id == current,
+            instance_id: row.instance_id,
+            alive: row.alive,
+            started_at: row.started_at.to_rfc3339(),
+            last_seen_at: row.last_seen_at.to_rfc3339(),
+            expires_at: row.expires_at.to_rfc3339(),
+            last_round_at: row.last_round_at.map(|dt| dt.to_rfc3339()),
+            last_round_feeds: row.last_round_feeds,
+            owned_feeds: row.owned_feeds,
+        })
+        .collect();
+
+    Ok(queue)
+}
+
+// 过滤建议：采样条目数与等待大模型响应的上限
+const FILTER_SUGGESTION_SAMPLES: i64 = 40;
+const FILTER_SUGGESTION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// 采样 feed 近期条目，请大模型给出排除规则，并转换为可直接应用的过滤条件
+pub async fn suggest_filters(
+    pool: &sqlx::PgPool,
+    translator: &TranslationEngine,
+    id: i64,
+) -> AppResult<FilterSuggestionsOut> {
+    let feed = repo::feeds::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("feed {id} not found")))?;
+
+    let samples = repo::articles::list_feed_samples(pool, id, FILTER_SUGGESTION_SAMPLES).await?;
+    if samples.is_empty() {
+        return Err(AppError::BadRequest("该订阅源还没有可采样的条目".into()));
+    }
+
+    let current = translator.current_provider();
+    let client = translator
+        .llm_client(current)
+        .or_else(|| translator.llm_client(current.other()))
+        .ok_or_else(|| AppError::BadRequest("没有可用的大模型服务".into()))?;
+
+    let snippets: Vec<ArticleSnippet<'_>> = samples
+        .iter()
+        .map(|row| ArticleSnippet {
+            title: &row.title,
+            source: None,
+            url: Some(&row.url),
+            published_at: None,
+            summary: None,
+        })
+        .collect();
+
+    let rules = tokio::time::timeout(FILTER_SUGGESTION_TIMEOUT, client.suggest_filters(&snippets))
+        .await
+        .map_err(|_| AppError::BadRequest("大模型生成过滤建议超时".into()))?
+        .map_err(|err| {
+            warn!(error = ?err, feed_id = id, "filter suggestion failed");
+            AppError::BadRequest(format!("大模型生成过滤建议失败: {err}"))
+        })?;
+
+    let mut suggestions: Vec<FilterSuggestionOut> = Vec::new();
+    for rule in rules {
+        let field = rule.field.trim().to_ascii_lowercase();
+        let pattern = rule.pattern.trim().to_string();
+        if pattern.is_empty() || !matches!(field.as_str(), "url" | "title") {
+            continue;
+        }
+        let condition = format!("{field} NOT ILIKE '%{}%'", escape_like_literal(&pattern));
+        if validate_filter_condition(&condition).is_err()
+            || suggestions.iter().any(|existing| existing.condition == condition)
+        {
+            continue;
+        }
+        let needle = pattern.to_lowercase();
+        let matched = samples
+            .iter()
+            .filter(|row| {
+                let haystack = if field == "url" { &row.url } else { &row.title };
+                haystack.to_lowercase().contains(&needle)
+            })
+            .count();
+        suggestions.push(FilterSuggestionOut {
+            field,
+            pattern,
+            reason: rule.reason.filter(|reason| !reason.trim().is_empty()),
+            condition,
+            matched,
+        });
+    }
+
+    let combined_condition = if suggestions.is_empty() {
+        None
+    } else {
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(existing) = feed.filter_condition.as_deref() {
+            parts.push(format!("({existing})"));
+        }
+        parts.extend(suggestions.iter().map(|s| s.condition.clone()));
+        Some(parts.join(" AND "))
+    };
+
+    tracing::info!(
+        feed_id = id,
+        provider = client.provider().as_str(),
+        samples = samples.len(),
+        suggestions = suggestions.len(),
+        "filter suggestions generated"
+    );
+
+    Ok(FilterSuggestionsOut {
+        feed_id: id,
+        provider: client.provider().as_str().to_string(),
+        sample_size: samples.len(),
+        current_condition: feed.filter_condition,
+        suggestions,
+        combined_condition,
+    })
+}
+
+// 转义为 ILIKE 字面量：单引号按 SQL 规则双写，通配符与反斜杠用默认转义符 `\` 转义
+fn escape_like_literal(raw: &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for ch in raw.chars() {
+        match ch {
+            '\\' | '%' | '_' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            '\'' => escaped.push_str("''"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
 fn feed_row_to_out(row: repo::feeds::FeedRow) -> FeedOut {
     FeedOut {
         id: row.id,
@@ -284,10 +1320,55 @@ fn feed_row_to_out(row: repo::feeds::FeedRow) -> FeedOut {
         enabled: row.enabled,
         fetch_interval_seconds: row.fetch_interval_seconds,
         filter_condition: row.filter_condition,
+        schedule_cron: row.schedule_cron,
+        ignore_conditional: row.ignore_conditional,
+        date_timezone: row.date_timezone,
+        date_format: row.date_format,
+        language_hint: row.language_hint,
+        require_review: row.require_review,
+        request_headers: feed_headers::masked(&feed_headers::from_column(
+            row.request_headers.as_deref(),
+        )),
+        muted_until: row.muted_until.map(|dt| dt.to_rfc3339()),
+        auto_disabled_at: row.auto_disabled_at.map(|dt| dt.to_rfc3339()),
+        last_etag: row.last_etag,
+        last_modified: row.last_modified.map(|dt| dt.to_rfc3339()),
         last_fetch_at: row.last_fetch_at.map(|dt| dt.to_rfc3339()),
         last_fetch_status: row.last_fetch_status.map(|s| s as i32),
         fail_count: row.fail_count,
+        last_error_kind: row.last_error_kind,
+        last_error: row.last_error,
+        tags: row.tags,
+    }
+}
+
+const MAX_TAGS_PER_FEED: usize = 16;
+const MAX_TAG_LEN: usize = 32;
+
+// 标签统一小写、去空白与重复，并按字母序保存，便于文章侧直接复用
+fn normalize_tags(raw: Vec<String>) -> AppResult<Vec<String>> {
+    let mut tags = std::collections::BTreeSet::new();
+    for tag in raw {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() {
+            continue;
+        }
+        if tag.chars().count() > MAX_TAG_LEN {
+            return Err(AppError::BadRequest(format!(
+                "标签长度不能超过 {MAX_TAG_LEN} 个字符"
+            )));
+        }
+        if tag.contains(',') || tag.chars().any(char::is_whitespace) {
+            return Err(AppError::BadRequest("标签不能包含空白或逗号".into()));
+        }
+        tags.insert(tag);
+    }
+    if tags.len() > MAX_TAGS_PER_FEED {
+        return Err(AppError::BadRequest(format!(
+            "每个订阅源最多 {MAX_TAGS_PER_FEED} 个标签"
+        )));
     }
+    Ok(tags.into_iter().collect())
 }
 
 fn validate_filter_condition(condition: &str) -> AppResult<()> {
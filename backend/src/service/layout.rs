@@ -0,0 +1,100 @@
+//! 前台首页布局与功能开关：管理员通过设置项 `frontend.*` 维护分区与开关，前台经 `/config/frontend` 读取，
+//! 调整后无需重新部署前端。
+
+use std::collections::HashSet;
+
+use sqlx::PgPool;
+use tracing::warn;
+
+use crate::{
+    model::{FrontendFeatures, FrontendLayout, LayoutSection},
+    service::{digest, settings_registry, translations},
+};
+
+pub const LAYOUT_KEY: &str = "frontend.layout";
+pub const SEMANTIC_SEARCH_KEY: &str = "frontend.semantic_search_enabled";
+pub const TAGS_KEY: &str = "frontend.tags_enabled";
+pub const PAGE_SIZE_KEY: &str = "frontend.default_page_size";
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+pub const MAX_PAGE_SIZE: i64 = 50;
+pub const DEFAULT_LAYOUT: &str =
+    r#"{"sections":[{"key":"featured","title":"热门","kind":"featured","value":null,"limit":6},{"key":"latest","title":"最新","kind":"latest","value":null,"limit":20}]}"#;
+
+const SECTION_KINDS: &[&str] = &["latest", "featured", "tag", "source"];
+const MAX_SECTIONS: usize = 20;
+const MAX_SECTION_LIMIT: u32 = 50;
+
+/// 校验布局文档并输出规范化 JSON（去除首尾空白、标签与域名小写）
+pub fn normalize_layout(raw: &str) -> Result<String, String> {
+    let layout: FrontendLayout =
+        serde_json::from_str(raw).map_err(|err| format!("布局格式错误: {err}"))?;
+    if layout.sections.is_empty() {
+        return Err("布局至少需要一个分区".into());
+    }
+    if layout.sections.len() > MAX_SECTIONS {
+        return Err(format!("布局最多 {MAX_SECTIONS} 个分区"));
+    }
+
+    let mut keys = HashSet::new();
+    let mut sections = Vec::with_capacity(layout.sections.len());
+    for section in layout.sections {
+        let key = section.key.trim().to_string();
+        let title = section.title.trim().to_string();
+        let kind = section.kind.trim().to_ascii_lowercase();
+        if key.is_empty() || title.is_empty() {
+            return Err("分区的 key 与 title 不能为空".into());
+        }
+        if !keys.insert(key.clone()) {
+            return Err(format!("分区 key 重复：{key}"));
+        }
+        if !SECTION_KINDS.contains(&kind.as_str()) {
+            return Err(format!("分区 {key} 的 kind 可选值：{}", SECTION_KINDS.join("|")));
+        }
+        if !(1..=MAX_SECTION_LIMIT).contains(&section.limit) {
+            return Err(format!("分区 {key} 的 limit 需要在 1~{MAX_SECTION_LIMIT} 之间"));
+        }
+        let value = section
+            .value
+            .map(|value| value.trim().to_lowercase())
+            .filter(|value| !value.is_empty());
+        let value = match kind.as_str() {
+            "tag" | "source" => Some(value.ok_or_else(|| format!("分区 {key} 需要指定 value"))?),
+            _ => None,
+        };
+        sections.push(LayoutSection {
+            key,
+            title,
+            kind,
+            value,
+            limit: section.limit,
+        });
+    }
+
+    serde_json::to_string(&FrontendLayout { sections }).map_err(|err| err.to_string())
+}
+
+/// 读取当前布局；存储值非法时由注册表回退到默认布局
+pub async fn load(pool: &PgPool) -> Result<FrontendLayout, sqlx::Error> {
+    let raw = settings_registry::get_value(pool, LAYOUT_KEY)
+        .await?
+        .unwrap_or_else(|| DEFAULT_LAYOUT.to_string());
+    Ok(serde_json::from_str(&raw).unwrap_or_else(|err| {
+        warn!(error = %err, "failed to decode frontend layout, using default");
+        serde_json::from_str(DEFAULT_LAYOUT).expect("default layout is valid")
+    }))
+}
+
+/// 读取前台功能开关；精选开关与后台任务共用 `digest.enabled`
+pub async fn load_features(pool: &PgPool) -> Result<FrontendFeatures, sqlx::Error> {
+    let page_size = settings_registry::get_i64(pool, PAGE_SIZE_KEY)
+        .await?
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    Ok(FrontendFeatures {
+        semantic_search: settings_registry::get_bool(pool, SEMANTIC_SEARCH_KEY).await?,
+        tags: settings_registry::get_bool(pool, TAGS_KEY).await?,
+        digest: settings_registry::get_bool(pool, digest::ENABLED_KEY).await?,
+        translate: settings_registry::get_bool(pool, translations::ON_DEMAND_ENABLED_KEY).await?,
+        default_page_size: page_size as u32,
+    })
+}
@@ -0,0 +1,90 @@
+use sqlx::PgPool;
+
+use crate::{
+    error::AppResult,
+    model::LanguageBackfillOut,
+    repo,
+    util::{lang::detect_language, title::title_simhash},
+};
+
+const LANGUAGE_BACKFILL_BATCH: i64 = 500;
+const SIMHASH_BACKFILL_BATCH: i64 = 500;
+
+// 为早期缺少语言信息的文章补全 language / original_language：
+// 按标题检测，标题无法判定时再看摘要；分批更新，避免长事务
+pub async fn backfill_languages(pool: &PgPool) -> AppResult<LanguageBackfillOut> {
+    let mut out = LanguageBackfillOut {
+        scanned: 0,
+        updated: 0,
+        undetected: 0,
+    };
+    let mut cursor = 0_i64;
+
+    loop {
+        let rows =
+            repo::articles::list_language_backfill_batch(pool, cursor, LANGUAGE_BACKFILL_BATCH)
+                .await?;
+        let Some(last) = rows.last() else {
+            break;
+        };
+        cursor = last.id;
+        out.scanned += rows.len() as u64;
+
+        let mut ids = Vec::with_capacity(rows.len());
+        let mut languages = Vec::with_capacity(rows.len());
+        for row in &rows {
+            let detected = row.language_hint.as_deref().or_else(|| {
+                detect_language(&row.title)
+                    .or_else(|| row.description.as_deref().and_then(detect_language))
+            });
+            match detected {
+                Some(lang) => {
+                    ids.push(row.id);
+                    languages.push(lang.to_string());
+                }
+                None => out.undetected += 1,
+            }
+        }
+        if !ids.is_empty() {
+            out.updated += repo::articles::fill_languages(pool, &ids, &languages).await?;
+        }
+        tokio::task::yield_now().await;
+    }
+
+    if out.scanned > 0 {
+        tracing::info!(
+            scanned = out.scanned,
+            updated = out.updated,
+            undetected = out.undetected,
+            "article language backfill finished"
+        );
+    }
+    Ok(out)
+}
+
+// 为引入 SimHash 之前入库的文章补算标题哈希。原始标题未保存，已翻译的文章只能按译文计算，
+// 与后续同一事件的外文转载不一定能匹配；标题过短算不出哈希的行保持为空
+pub async fn backfill_simhashes(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let mut updated = 0;
+    let mut cursor = 0_i64;
+    loop {
+        let rows =
+            repo::articles::list_simhash_backfill_batch(pool, cursor, SIMHASH_BACKFILL_BATCH).await?;
+        let Some(last) = rows.last() else {
+            break;
+        };
+        cursor = last.id;
+        let (ids, hashes): (Vec<i64>, Vec<i64>) = rows
+            .iter()
+            .filter_map(|row| title_simhash(&row.title).map(|hash| (row.id, hash)))
+            .unzip();
+        if !ids.is_empty() {
+            updated += repo::articles::fill_simhashes(pool, &ids, &hashes).await?;
+        }
+        tokio::task::yield_now().await;
+    }
+    if updated > 0 {
+        tracing::info!(updated, "article simhash backfill finished");
+    }
+    Ok(updated)
+}
@@ -1,3 +1,23 @@
+pub mod analytics;
+pub mod api_keys;
 pub mod articles;
+pub mod backup;
+pub mod digest;
+pub mod embedding;
+pub mod enrichment;
+pub mod extraction;
 pub mod feeds;
+pub mod layout;
+pub mod maintenance;
+pub mod oidc;
+pub mod ranking;
+pub mod retention;
+pub mod sessions;
 pub mod settings;
+pub mod settings_registry;
+pub mod share;
+pub mod sources;
+pub mod stats;
+pub mod tools;
+pub mod translations;
+pub mod users;
@@ -0,0 +1,452 @@
+//! 后台单点登录：OpenID Connect 授权码流程（PKCE）。`/admin/oidc/login` 生成 state、nonce 与 PKCE verifier，
+//! 写入只发往回调地址的短期 HttpOnly Cookie 后跳转到身份提供方；`/admin/oidc/callback` 要求回调的 state
+//! 与同一浏览器的 Cookie 一致，带上 verifier 换取令牌，核对 ID Token 中的 nonce，再从 userinfo 端点读取声明，
+//! 按配置映射为角色并同步到 `news.users`（`auth_source = 'oidc'`）。服务端不保存登录中的 state。
+//! 令牌只在后端与身份提供方之间经 TLS 直接传递，ID Token 不校验签名，因此无需维护签名密钥。
+
+use std::{sync::Arc, time::Duration};
+
+use anyhow::{bail, Context};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use url::Url;
+use uuid::Uuid;
+
+use crate::{
+    auth::{Account, Role, ROLES},
+    config::{FrontendPublicConfig, HttpClientConfig, OidcConfig},
+    error::{AppError, AppResult},
+    repo,
+    util::cache::TtlCache,
+};
+
+/// 保存登录中 state、nonce 与 PKCE verifier 的 Cookie
+pub const LOGIN_COOKIE: &str = "newsagg_oidc";
+// 从跳转到身份提供方到回调之间允许的最长时间，即 Cookie 的有效期
+const LOGIN_COOKIE_MAX_AGE_SECS: u64 = 600;
+// 发现文档定期重新读取，身份提供方轮换端点后无需重启
+const DISCOVERY_TTL: Duration = Duration::from_secs(3600);
+// 身份提供方的用户名常为邮箱，上限比后台创建的账号宽松
+const USERNAME_MAX_LEN: usize = 64;
+// 单点登录账号不保存密码，写入一个无法解析的占位值，密码校验始终失败
+const NO_PASSWORD_HASH: &str = "!oidc";
+
+#[derive(Debug, Deserialize)]
+struct Discovery {
+    authorization_endpoint: String,
+    token_endpoint: String,
+    userinfo_endpoint: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    id_token: Option<String>,
+}
+
+/// 跳转到身份提供方前需要交给浏览器的内容
+pub struct AuthorizationRequest {
+    pub url: String,
+    /// `Set-Cookie` 的值
+    pub cookie: String,
+}
+
+// Cookie 中的登录上下文，格式为 `<state>.<nonce>.<verifier>`，三段均为十六进制
+struct PendingLogin {
+    state: String,
+    nonce: String,
+    verifier: String,
+}
+
+impl PendingLogin {
+    fn generate() -> Self {
+        let random = || Uuid::new_v4().simple().to_string();
+        Self {
+            state: random(),
+            nonce: random(),
+            // RFC 7636 要求 verifier 为 43-128 个字符
+            verifier: format!("{}{}", random(), random()),
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        let mut parts = raw.split('.');
+        let (state, nonce, verifier) = (parts.next()?, parts.next()?, parts.next()?);
+        if parts.next().is_some() || [state, nonce, verifier].iter().any(|p| p.is_empty()) {
+            return None;
+        }
+        Some(Self {
+            state: state.to_string(),
+            nonce: nonce.to_string(),
+            verifier: verifier.to_string(),
+        })
+    }
+
+    fn encode(&self) -> String {
+        format!("{}.{}.{}", self.state, self.nonce, self.verifier)
+    }
+
+    fn code_challenge(&self) -> String {
+        URL_SAFE_NO_PAD.encode(Sha256::digest(self.verifier.as_bytes()))
+    }
+}
+
+pub struct Oidc {
+    http: Client,
+    issuer: String,
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
+    scope: String,
+    username_claim: String,
+    role_claim: String,
+    // 按角色从高到低排列，匹配多个时取第一个
+    role_mapping: Vec<(Role, Vec<String>)>,
+    default_role: Option<Role>,
+    post_login_redirect: String,
+    discovery: TtlCache<(), Arc<Discovery>>,
+    // Cookie 只发往回调地址；回调地址为 https 时加 Secure
+    cookie_path: String,
+    cookie_secure: bool,
+}
+
+impl Oidc {
+    /// 配置有误时启动失败；身份提供方暂时不可达不影响启动，发现文档在首次登录时读取
+    pub fn new(
+        config: &OidcConfig,
+        http_config: &HttpClientConfig,
+        public: &FrontendPublicConfig,
+    ) -> anyhow::Result<Self> {
+        let issuer = config.issuer.trim().trim_end_matches('/');
+        Url::parse(issuer).with_context(|| format!("invalid admin.oidc.issuer {issuer:?}"))?;
+        if config.client_id.trim().is_empty() {
+            bail!("admin.oidc.client_id must not be empty");
+        }
+        let redirect_uri = config
+            .redirect_uri
+            .as_deref()
+            .map(str::trim)
+            .filter(|uri| !uri.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{}/admin/oidc/callback", public.api_base_url));
+        let parsed_redirect = Url::parse(&redirect_uri)
+            .with_context(|| format!("invalid admin.oidc.redirect_uri {redirect_uri:?}"))?;
+        let cookie_path = parsed_redirect.path().to_string();
+        let cookie_secure = parsed_redirect.scheme() == "https";
+        let mut scopes: Vec<&str> = config
+            .scopes
+            .iter()
+            .map(|scope| scope.trim())
+            .filter(|scope| !scope.is_empty())
+            .collect();
+        if !scopes.contains(&"openid") {
+            scopes.insert(0, "openid");
+        }
+        let default_role = config
+            .default_role
+            .as_deref()
+            .map(|raw| {
+                Role::parse(raw.trim()).with_context(|| {
+                    format!("admin.oidc.default_role must be one of {}", ROLES.join("|"))
+                })
+            })
+            .transpose()?;
+        let mapping = &config.role_mapping;
+        let role_mapping = [
+            (Role::Admin, &mapping.admin),
+            (Role::Editor, &mapping.editor),
+            (Role::Viewer, &mapping.viewer),
+        ]
+        .into_iter()
+        .map(|(role, values)| (role, values.iter().map(|v| v.trim().to_string()).collect()))
+        .collect();
+        let http = http_config
+            .apply(Client::builder())
+            .context("failed to apply proxy settings for oidc client")?
+            .timeout(Duration::from_secs(config.timeout_secs.max(1)))
+            .build()
+            .context("failed to build oidc http client")?;
+
+        Ok(Self {
+            http,
+            issuer: issuer.to_string(),
+            client_id: config.client_id.trim().to_string(),
+            client_secret: config.client_secret.clone(),
+            redirect_uri,
+            scope: scopes.join(" "),
+            username_claim: config.username_claim.trim().to_string(),
+            role_claim: config.role_claim.trim().to_string(),
+            role_mapping,
+            default_role,
+            post_login_redirect: config
+                .post_login_redirect
+                .as_deref()
+                .map(str::trim)
+                .filter(|url| !url.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("{}/admin", public.site_base_url())),
+            discovery: TtlCache::new(DISCOVERY_TTL),
+            cookie_path,
+            cookie_secure,
+        })
+    }
+
+    /// 登录完成（或失败）后跳转回的前端地址，结果放在 URL 片段中，不会出现在服务端日志里
+    pub fn post_login_redirect(&self) -> &str {
+        &self.post_login_redirect
+    }
+
+    /// 生成 state、nonce 与 PKCE verifier，返回身份提供方的授权地址和保存它们的 Cookie
+    pub async fn authorization_url(&self) -> AppResult<AuthorizationRequest> {
+        let discovery = self.discovery().await?;
+        let pending = PendingLogin::generate();
+        let mut url = Url::parse(&discovery.authorization_endpoint)
+            .map_err(|err| AppError::Unavailable(format!("身份提供方的授权地址无效: {err}")))?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.client_id)
+            .append_pair("redirect_uri", &self.redirect_uri)
+            .append_pair("scope", &self.scope)
+            .append_pair("state", &pending.state)
+            .append_pair("nonce", &pending.nonce)
+            .append_pair("code_challenge", &pending.code_challenge())
+            .append_pair("code_challenge_method", "S256");
+        Ok(AuthorizationRequest {
+            url: url.to_string(),
+            cookie: self.cookie(&pending.encode(), LOGIN_COOKIE_MAX_AGE_SECS),
+        })
+    }
+
+    /// 回调结束后清除登录 Cookie 的 `Set-Cookie` 值
+    pub fn clear_cookie(&self) -> String {
+        self.cookie("", 0)
+    }
+
+    fn cookie(&self, value: &str, max_age_secs: u64) -> String {
+        let secure = if self.cookie_secure { "; Secure" } else { "" };
+        format!(
+            "{LOGIN_COOKIE}={value}; Path={}; Max-Age={max_age_secs}; HttpOnly; SameSite=Lax{secure}",
+            self.cookie_path
+        )
+    }
+
+    /// 校验回调的 state 与本浏览器的登录 Cookie 一致，换取令牌并读取声明，返回映射到的后台账号；
+    /// 账号按需创建，角色每次登录时同步
+    pub async fn complete(
+        &self,
+        pool: &PgPool,
+        builtin_username: &str,
+        code: &str,
+        state: &str,
+        cookie: Option<&str>,
+    ) -> AppResult<Account> {
+        let pending = cookie
+            .and_then(PendingLogin::parse)
+            .filter(|pending| constant_time_eq(pending.state.as_bytes(), state.as_bytes()))
+            .ok_or_else(|| {
+                AppError::Unauthorized("登录请求已过期或无效，请重新登录".to_string())
+            })?;
+        let discovery = self.discovery().await?;
+        let token = self
+            .exchange_code(&discovery, code, &pending.verifier)
+            .await?;
+        check_nonce(token.id_token.as_deref(), &pending.nonce)?;
+        let claims = self.userinfo(&discovery, &token.access_token).await?;
+
+        let username = claim_username(&claims, &self.username_claim)?;
+        let role = self
+            .map_role(&claims)
+            .ok_or_else(|| AppError::Forbidden(format!("账号 {username} 未被授予后台角色")))?;
+        if username == builtin_username {
+            return Err(AppError::Forbidden(format!(
+                "用户名 {username} 与配置文件中的管理员重名"
+            )));
+        }
+        let user = repo::users::upsert_oidc(pool, &username, NO_PASSWORD_HASH, role.as_str())
+            .await?
+            .ok_or_else(|| {
+                AppError::Forbidden(format!("用户名 {username} 已被密码登录的账号占用"))
+            })?;
+        if user.disabled_at.is_some() {
+            return Err(AppError::Forbidden(format!("账号 {username} 已停用")));
+        }
+        repo::users::record_login(pool, user.id).await?;
+        Ok(Account {
+            username: user.username,
+            role,
+        })
+    }
+
+    async fn discovery(&self) -> AppResult<Arc<Discovery>> {
+        if let Some(discovery) = self.discovery.get(&()) {
+            return Ok(discovery);
+        }
+        let url = format!("{}/.well-known/openid-configuration", self.issuer);
+        let discovery: Discovery = self
+            .http
+            .get(&url)
+            .send()
+            .await
+            .and_then(|resp| resp.error_for_status())
+            .map_err(|err| unavailable("读取身份提供方配置失败", err))?
+            .json()
+            .await
+            .map_err(|err| unavailable("身份提供方配置格式无效", err))?;
+        let discovery = Arc::new(discovery);
+        self.discovery.insert((), Arc::clone(&discovery));
+        Ok(discovery)
+    }
+
+    async fn exchange_code(
+        &self,
+        discovery: &Discovery,
+        code: &str,
+        verifier: &str,
+    ) -> AppResult<TokenResponse> {
+        let resp = self
+            .http
+            .post(&discovery.token_endpoint)
+            .basic_auth(&self.client_id, Some(&self.client_secret))
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", self.redirect_uri.as_str()),
+                ("client_id", self.client_id.as_str()),
+                ("code_verifier", verifier),
+            ])
+            .send()
+            .await
+            .map_err(|err| unavailable("请求身份提供方令牌失败", err))?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            tracing::warn!(
+                %status,
+                body = %body.chars().take(500).collect::<String>(),
+                "oidc token exchange rejected"
+            );
+            return Err(AppError::Unauthorized(
+                "授权码无效或已过期，请重新登录".to_string(),
+            ));
+        }
+        resp.json()
+            .await
+            .map_err(|err| unavailable("身份提供方令牌响应格式无效", err))
+    }
+
+    async fn userinfo(&self, discovery: &Discovery, access_token: &str) -> AppResult<Value> {
+        self.http
+            .get(&discovery.userinfo_endpoint)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .and_then(|resp| resp.error_for_status())
+            .map_err(|err| unavailable("读取身份提供方用户信息失败", err))?
+            .json()
+            .await
+            .map_err(|err| unavailable("身份提供方用户信息格式无效", err))
+    }
+
+    // 角色声明可以是单个字符串或字符串数组
+    fn map_role(&self, claims: &Value) -> Option<Role> {
+        let values: Vec<&str> = match claims.get(&self.role_claim) {
+            Some(Value::String(value)) => vec![value.as_str()],
+            Some(Value::Array(items)) => items.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        self.role_mapping
+            .iter()
+            .find(|(_, expected)| expected.iter().any(|e| values.contains(&e.as_str())))
+            .map(|(role, _)| *role)
+            .or(self.default_role)
+    }
+}
+
+// 用户名不允许包含冒号，避免与 `api-key:<名称>` 身份混淆
+fn claim_username(claims: &Value, claim: &str) -> AppResult<String> {
+    let username = claims
+        .get(claim)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .unwrap_or_default();
+    let valid = !username.is_empty()
+        && username.chars().count() <= USERNAME_MAX_LEN
+        && !username
+            .chars()
+            .any(|c| c == ':' || c.is_whitespace() || c.is_control());
+    if valid {
+        Ok(username.to_string())
+    } else {
+        Err(AppError::Forbidden(format!(
+            "身份提供方未返回可用的用户名声明 {claim}"
+        )))
+    }
+}
+
+// ID Token 由令牌端点经 TLS 直接返回，按 OIDC Core 3.1.3.7 可不校验签名，这里只核对 nonce
+fn check_nonce(id_token: Option<&str>, expected: &str) -> AppResult<()> {
+    let nonce = id_token
+        .and_then(|token| token.split('.').nth(1))
+        .and_then(|payload| URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok())
+        .and_then(|payload| serde_json::from_slice::<Value>(&payload).ok())
+        .and_then(|claims| claims.get("nonce")?.as_str().map(str::to_string));
+    match nonce {
+        Some(nonce) if constant_time_eq(nonce.as_bytes(), expected.as_bytes()) => Ok(()),
+        _ => Err(AppError::Unauthorized(
+            "身份提供方返回的 ID Token 与本次登录不符，请重新登录".to_string(),
+        )),
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn unavailable(context: &str, err: reqwest::Error) -> AppError {
+    tracing::warn!(error = %err, "{context}");
+    AppError::Unavailable(context.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_login_round_trips_through_cookie() {
+        let pending = PendingLogin::generate();
+        let parsed = PendingLogin::parse(&pending.encode()).unwrap();
+        assert_eq!(parsed.state, pending.state);
+        assert_eq!(parsed.nonce, pending.nonce);
+        assert_eq!(parsed.verifier, pending.verifier);
+        assert!((43..=128).contains(&pending.verifier.len()));
+        assert!(PendingLogin::parse("a.b").is_none());
+        assert!(PendingLogin::parse("a..c").is_none());
+        assert!(PendingLogin::parse("a.b.c.d").is_none());
+    }
+
+    #[test]
+    fn code_challenge_matches_rfc7636_example() {
+        let pending = PendingLogin {
+            state: String::new(),
+            nonce: String::new(),
+            verifier: "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string(),
+        };
+        assert_eq!(
+            pending.code_challenge(),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn id_token_nonce_must_match() {
+        let payload = URL_SAFE_NO_PAD.encode(br#"{"sub":"u1","nonce":"n1"}"#);
+        let token = format!("e30.{payload}.sig");
+        assert!(check_nonce(Some(&token), "n1").is_ok());
+        assert!(check_nonce(Some(&token), "n2").is_err());
+        assert!(check_nonce(None, "n1").is_err());
+    }
+}
@@ -0,0 +1,83 @@
+//! 排行分数：后台任务定期按点击数、簇大小与来源权重重算活跃窗口内文章的 `rank_score`，
+//! 分数随发布时间衰减；窗口之外的文章清零。`GET /articles?sort=rank` 直接按该列排序，请求时不做计算。
+
+use std::time::Duration;
+
+use chrono::{SecondsFormat, Utc};
+use sqlx::PgPool;
+
+use crate::{
+    error::AppResult, model::RankingRecomputeOut, repo, service::settings_registry,
+    util::cache::LatestArticlesCache,
+};
+
+pub const WINDOW_HOURS_KEY: &str = "ranking.window_hours";
+pub const DEFAULT_WINDOW_HOURS: i64 = 168;
+pub const MAX_WINDOW_HOURS: i64 = 720;
+
+/// 衰减指数：越大新文章越占优
+const GRAVITY: f64 = 1.5;
+const RECOMPUTE_INTERVAL: Duration = Duration::from_secs(600);
+const RECOMPUTE_BATCH: i64 = 1000;
+
+/// 后台任务：每 10 分钟重算一次，完成后清空首页缓存，使 `sort=rank` 的首页随之刷新
+pub fn spawn(pool: PgPool, latest_cache: LatestArticlesCache) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(RECOMPUTE_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match recompute(&pool).await {
+                Ok(_) => latest_cache.clear(),
+                Err(err) => tracing::warn!(error = ?err, "rank score recompute failed"),
+            }
+        }
+    });
+}
+
+/// 分批重算活跃窗口内的分数并清零窗口外的残留分数，返回本次统计
+pub async fn recompute(pool: &PgPool) -> AppResult<RankingRecomputeOut> {
+    let window_hours = settings_registry::get_i64(pool, WINDOW_HOURS_KEY)
+        .await?
+        .unwrap_or(DEFAULT_WINDOW_HOURS)
+        .clamp(1, MAX_WINDOW_HOURS);
+    let since = Utc::now() - chrono::Duration::hours(window_hours);
+    let mut out = RankingRecomputeOut {
+        window_hours,
+        since: since.to_rfc3339_opts(SecondsFormat::Secs, true),
+        scored_articles: 0,
+        updated_articles: 0,
+        reset_articles: 0,
+    };
+
+    let mut after_id = 0;
+    loop {
+        let (last_id, scanned, updated) =
+            repo::articles::recompute_rank_scores(pool, since, after_id, RECOMPUTE_BATCH, GRAVITY)
+                .await?;
+        let Some(last_id) = last_id else {
+            break;
+        };
+        after_id = last_id;
+        out.scored_articles += scanned;
+        out.updated_articles += updated;
+        tokio::task::yield_now().await;
+    }
+
+    loop {
+        let reset = repo::articles::reset_rank_scores_before(pool, since, RECOMPUTE_BATCH).await?;
+        out.reset_articles += reset;
+        if reset < RECOMPUTE_BATCH as u64 {
+            break;
+        }
+        tokio::task::yield_now().await;
+    }
+
+    tracing::debug!(
+        window_hours,
+        updated_articles = out.updated_articles,
+        reset_articles = out.reset_articles,
+        "rank scores recomputed"
+    );
+    Ok(out)
+}
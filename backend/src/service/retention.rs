@@ -0,0 +1,96 @@
+//! 文章保留期：设置 `retention.days` 后，后台任务定期把发布时间早于保留窗口的文章移入冷表
+//! `news.articles_archive` 并删除其来源记录，让长期运行的实例热表保持有界，历史分析改查冷表。
+
+use std::time::Duration;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlx::PgPool;
+
+use crate::{
+    error::AppResult,
+    model::RetentionArchiveOut,
+    ops::event_catalog::{Event, EventCode},
+    repo,
+    service::settings_registry,
+};
+
+pub const RETENTION_DAYS_KEY: &str = "retention.days";
+pub const MAX_RETENTION_DAYS: i64 = 3650;
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+const ARCHIVE_BATCH: i64 = 1000;
+
+/// 后台任务：每小时按当前保留期归档一次，保留期为 0（默认）时不做任何处理
+pub fn spawn(pool: PgPool) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(CHECK_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(err) = archive_expired(&pool).await {
+                tracing::warn!(error = ?err, "retention archive failed");
+            }
+        }
+    });
+}
+
+/// 保留窗口的起点；未开启保留期时为 None。抓取器据此跳过窗口之外的旧条目，避免归档后又被重新入库
+pub async fn cutoff(pool: &PgPool) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+    let days = settings_registry::get_i64(pool, RETENTION_DAYS_KEY)
+        .await?
+        .unwrap_or(0);
+    Ok(cutoff_for(days, Utc::now()))
+}
+
+fn cutoff_for(days: i64, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    (days > 0).then(|| now - chrono::Duration::days(days.min(MAX_RETENTION_DAYS)))
+}
+
+/// 分批归档保留窗口之外的文章，返回本次统计
+pub async fn archive_expired(pool: &PgPool) -> AppResult<RetentionArchiveOut> {
+    let days = settings_registry::get_i64(pool, RETENTION_DAYS_KEY)
+        .await?
+        .unwrap_or(0);
+    let mut out = RetentionArchiveOut {
+        retention_days: days.max(0),
+        cutoff: None,
+        archived_articles: 0,
+        deleted_article_sources: 0,
+    };
+    let Some(cutoff) = cutoff_for(days, Utc::now()) else {
+        return Ok(out);
+    };
+    let cutoff_str = cutoff.to_rfc3339_opts(SecondsFormat::Secs, true);
+    out.cutoff = Some(cutoff_str.clone());
+
+    loop {
+        let (articles, sources) =
+            repo::maintenance::archive_articles_before(pool, cutoff, ARCHIVE_BATCH).await?;
+        out.archived_articles += articles;
+        out.deleted_article_sources += sources;
+        if articles < ARCHIVE_BATCH as u64 {
+            break;
+        }
+        tokio::task::yield_now().await;
+    }
+
+    if out.archived_articles > 0 {
+        tracing::info!(
+            archived_articles = out.archived_articles,
+            deleted_article_sources = out.deleted_article_sources,
+            cutoff = %cutoff_str,
+            "archived articles outside retention window"
+        );
+        Event::new(EventCode::ArticlesArchived)
+            .attr("archived", out.archived_articles)
+            .attr("cutoff", cutoff_str.as_str())
+            .attr("retention_days", days)
+            .detail(format!(
+                "归档 {} 篇发布于 {} 之前的文章（删除来源记录 {} 条）",
+                out.archived_articles, cutoff_str, out.deleted_article_sources
+            ))
+            .emit(pool)
+            .await;
+    }
+    Ok(out)
+}
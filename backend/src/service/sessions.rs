@@ -0,0 +1,52 @@
+use sqlx::PgPool;
+
+use crate::{
+    error::{AppError, AppResult},
+    model::{AdminSessionOut, AdminSessionRevokeOut},
+    repo::{self, admin_sessions::AdminSessionRow},
+};
+
+pub async fn list(pool: &PgPool, current_id: Option<i64>) -> AppResult<Vec<AdminSessionOut>> {
+    let rows = repo::admin_sessions::list_active(pool).await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| session_row_to_out(row, current_id))
+        .collect())
+}
+
+pub async fn revoke(pool: &PgPool, id: i64) -> AppResult<AdminSessionRevokeOut> {
+    if !repo::admin_sessions::delete_by_id(pool, id).await? {
+        return Err(AppError::BadRequest(format!("session {id} not found")));
+    }
+    tracing::info!(session_id = id, "admin session revoked");
+    Ok(AdminSessionRevokeOut { revoked: 1 })
+}
+
+/// 保留当前会话，注销同一账号的其余会话；以 API 密钥调用时没有当前会话，直接拒绝
+pub async fn revoke_others(
+    pool: &PgPool,
+    username: &str,
+    current_id: Option<i64>,
+) -> AppResult<AdminSessionRevokeOut> {
+    let Some(current_id) = current_id else {
+        return Err(AppError::BadRequest(
+            "没有当前会话，无法注销其他会话".to_string(),
+        ));
+    };
+    let revoked = repo::admin_sessions::delete_others(pool, username, current_id).await?;
+    tracing::info!(username, revoked, "other admin sessions revoked");
+    Ok(AdminSessionRevokeOut { revoked })
+}
+
+fn session_row_to_out(row: AdminSessionRow, current_id: Option<i64>) -> AdminSessionOut {
+    AdminSessionOut {
+        id: row.id,
+        username: row.username,
+        client_ip: row.client_ip,
+        user_agent: row.user_agent,
+        created_at: row.created_at.to_rfc3339(),
+        last_seen_at: row.last_seen_at.to_rfc3339(),
+        expires_at: row.expires_at.to_rfc3339(),
+        current: current_id == Some(row.id),
+    }
+}
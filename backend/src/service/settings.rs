@@ -6,13 +6,21 @@ use crate::{
     error::{AppError, AppResult},
     model::{
         TranslationSettingsOut, TranslationSettingsUpdate, AiDedupSettingsOut, AiDedupSettingsUpdate,
-        ModelSettingsOut, ModelSettingsUpdate,
+        ModelSettingsOut, ModelSettingsUpdate, SettingEntry, SettingsExport, SettingsImport,
+        SettingsImportResult,
     },
     repo,
-    util::translator::{TranslationEngine, TranslatorCredentialsUpdate, TranslatorProvider},
-    ops::events::EventsHub,
+    util::translation_rules::{self, TranslationRules},
+    util::translator::{mask_secret, TranslationEngine, TranslatorCredentialsUpdate, TranslatorProvider},
+    ops::{
+        event_catalog::{Event, EventCode},
+        events::EventsHub,
+    },
 };
-use crate::repo::events as repo_events;
+use crate::service::settings_registry;
+
+// 已由 `translation.rules` 取代的全局翻译开关
+const LEGACY_ENABLED_KEY: &str = "translation.enabled";
 
 pub async fn get_translation_settings(
     translator: &Arc<TranslationEngine>,
@@ -22,6 +30,7 @@ pub async fn get_translation_settings(
         // 后台仅允许 Ollama 作为默认服务
         provider: "ollama".to_string(),
         translation_enabled: snapshot.translation_enabled,
+        translation_rules: snapshot.translation_rules.rules().to_vec(),
         deepseek_configured: snapshot.deepseek_configured,
         ollama_configured: snapshot.ollama_configured,
         deepseek_api_key_masked: snapshot.deepseek_api_key_masked,
@@ -85,11 +94,26 @@ pub async fn update_translation_settings(
         }
     }
 
-    if let Some(flag) = payload.translation_enabled {
-        let value = if flag { "true" } else { "false" };
-        repo::settings::upsert_setting(pool, "translation.enabled", value).await?;
-        update.translation_enabled = Some(flag);
+    // 显式规则优先；仅有开关时，开启沿用已有规则（没有则为 en→zh），关闭清空规则
+    let rules = match (payload.translation_rules.as_ref(), payload.translation_enabled) {
+        (Some(rules), _) => Some(
+            TranslationRules::parse(&rules.to_string()).map_err(AppError::BadRequest)?,
+        ),
+        (None, Some(true)) if !translator.translation_enabled() => Some(
+            TranslationRules::parse(translation_rules::ENGLISH_TO_CHINESE)
+                .map_err(AppError::BadRequest)?,
+        ),
+        (None, Some(false)) => Some(TranslationRules::default()),
+        _ => None,
+    };
+    if let Some(rules) = rules.as_ref() {
+        repo::settings::upsert_setting(pool, translation_rules::RULES_KEY, &rules.to_json()).await?;
     }
+    let enabled_changed = rules
+        .as_ref()
+        .map(TranslationRules::any_enabled)
+        .filter(|enabled| *enabled != translator.translation_enabled());
+    update.translation_rules = rules;
 
     if let Err(err) = translator.update_credentials(update) {
         let message = err.to_string();
@@ -111,32 +135,44 @@ pub async fn update_translation_settings(
     }
 
     // 强制仅允许 Ollama 作为默认 provider
-    if payload.provider.is_some() || payload.translation_enabled == Some(true) {
+    if payload.provider.is_some() || enabled_changed == Some(true) {
         repo::settings::upsert_setting(pool, "translation.provider", "ollama").await?;
     }
 
     // Emit minimal events for translation toggles / provider changes
-    if let Some(flag) = payload.translation_enabled {
-        let code = if flag { "TRANSLATION_ENABLED" } else { "TRANSLATION_DISABLED" };
-        let _ = repo_events::upsert_event(
-            pool,
-            &repo_events::NewEvent { level: "info".to_string(), code: code.to_string(), addition_info: None },
-            0,
-        ).await;
+    if let Some(flag) = enabled_changed {
+        let code = if flag { EventCode::TranslationEnabled } else { EventCode::TranslationDisabled };
+        Event::new(code).emit(pool).await;
     }
     if let Some(ref provider_raw) = payload.provider {
         let prov = provider_raw.trim().to_ascii_lowercase();
-        let code = format!("TRANSLATION_PROVIDER_SET_{}", prov);
-        let _ = repo_events::upsert_event(
-            pool,
-            &repo_events::NewEvent { level: "info".to_string(), code, addition_info: None },
-            0,
-        ).await;
+        Event::new(EventCode::TranslationProviderSet)
+            .attr("provider", prov)
+            .emit(pool)
+            .await;
     }
 
     get_translation_settings(translator).await
 }
 
+/// 读取翻译规则；旧版只有 `translation.enabled` 开关时按其取值迁移为规则并删除旧键
+pub async fn load_translation_rules(pool: &sqlx::PgPool) -> AppResult<TranslationRules> {
+    if let Some(legacy) = repo::settings::get_setting(pool, LEGACY_ENABLED_KEY).await? {
+        if repo::settings::get_setting(pool, translation_rules::RULES_KEY).await?.is_none() {
+            let rules = if legacy.trim() == "true" {
+                translation_rules::ENGLISH_TO_CHINESE
+            } else {
+                translation_rules::DEFAULT_RULES
+            };
+            repo::settings::upsert_setting(pool, translation_rules::RULES_KEY, rules).await?;
+            tracing::info!(legacy = %legacy, rules, "migrated translation.enabled to translation rules");
+        }
+        repo::settings::delete_setting(pool, LEGACY_ENABLED_KEY).await?;
+    }
+    let stored = settings_registry::get_value(pool, translation_rules::RULES_KEY).await?;
+    Ok(TranslationRules::from_stored(stored.as_deref()))
+}
+
 pub async fn get_model_settings(translator: &Arc<TranslationEngine>) -> AppResult<ModelSettingsOut> {
     let snapshot = translator.snapshot();
     Ok(ModelSettingsOut {
@@ -185,11 +221,7 @@ pub async fn update_model_settings(
     translator
         .update_credentials(update)
         .map_err(|e| AppError::BadRequest(e.to_string()))?;
-    let _ = repo_events::upsert_event(
-        pool,
-        &repo_events::NewEvent { level: "info".to_string(), code: "MODEL_SETTINGS_UPDATED".to_string(), addition_info: None },
-        0,
-    ).await;
+    Event::new(EventCode::ModelSettingsUpdated).emit(pool).await;
     get_model_settings(translator).await
 }
 
@@ -212,9 +244,8 @@ pub async fn get_ai_dedup_settings(
     pool: &sqlx::PgPool,
     translator: &Arc<TranslationEngine>,
 ) -> AppResult<AiDedupSettingsOut> {
-    let enabled_raw = repo::settings::get_setting(pool, "ai_dedup.enabled").await?;
-    let provider_raw = repo::settings::get_setting(pool, "ai_dedup.provider").await?;
-    let enabled = matches!(enabled_raw.as_deref(), Some("true"));
+    let enabled = settings_registry::get_bool(pool, "ai_dedup.enabled").await?;
+    let provider_raw = settings_registry::get_value(pool, "ai_dedup.provider").await?;
     let provider = if enabled { provider_raw } else { None };
     let snapshot = translator.snapshot();
     Ok(AiDedupSettingsOut {
@@ -254,9 +285,9 @@ pub async fn update_ai_dedup_settings(
     }
 
     // 若启用但未指定 provider，则按 Deepseek > Ollama 的优先级自动选择；均未配置则报错并引导前往大模型配置
-    let enabled_raw = repo::settings::get_setting(pool, "ai_dedup.enabled").await?;
-    let provider_raw = repo::settings::get_setting(pool, "ai_dedup.provider").await?;
-    if matches!(enabled_raw.as_deref(), Some("true")) && provider_raw.as_deref().map(str::is_empty).unwrap_or(true) {
+    let enabled = settings_registry::get_bool(pool, "ai_dedup.enabled").await?;
+    let provider_raw = settings_registry::get_value(pool, "ai_dedup.provider").await?;
+    if enabled && provider_raw.is_none() {
         let snapshot = translator.snapshot();
         let auto = if snapshot.deepseek_configured {
             Some("deepseek")
@@ -274,3 +305,93 @@ pub async fn update_ai_dedup_settings(
 
     get_ai_dedup_settings(pool, translator).await
 }
+
+// 含以下片段的设置键视为敏感信息，导出时只给出掩码
+const SECRET_KEY_MARKERS: &[&str] = &["api_key", "secret", "password", "token"];
+const MAX_SETTING_KEY_LEN: usize = 128;
+
+fn is_secret_key(key: &str) -> bool {
+    SECRET_KEY_MARKERS.iter().any(|marker| key.contains(marker))
+}
+
+pub async fn export_settings(pool: &sqlx::PgPool) -> AppResult<SettingsExport> {
+    let rows = repo::settings::list_settings(pool).await?;
+    let settings = rows
+        .into_iter()
+        .map(|row| {
+            if is_secret_key(&row.key) {
+                SettingEntry {
+                    masked: Some(mask_secret(&row.value)),
+                    key: row.key,
+                    value: None,
+                }
+            } else {
+                SettingEntry {
+                    key: row.key,
+                    value: Some(row.value),
+                    masked: None,
+                }
+            }
+        })
+        .collect();
+
+    Ok(SettingsExport {
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        settings,
+    })
+}
+
+/// 导入设置：整体在一个事务内写入，随后把翻译相关设置同步到运行中的翻译引擎。
+/// 没有明文值（导出时被掩码）的项会被跳过，需要在目标实例上单独填写。
+pub async fn import_settings(
+    pool: &sqlx::PgPool,
+    translator: &Arc<TranslationEngine>,
+    payload: SettingsImport,
+) -> AppResult<SettingsImportResult> {
+    let mut entries = Vec::new();
+    let mut skipped = Vec::new();
+    for entry in payload.settings {
+        let key = entry.key.trim().to_string();
+        if key.is_empty() || key.len() > MAX_SETTING_KEY_LEN {
+            return Err(AppError::BadRequest(format!("非法的设置键：{}", entry.key)));
+        }
+        // 未注册的键与无明文值的项均跳过；已注册的键按注册表校验
+        let (Some(def), Some(value)) = (settings_registry::lookup(&key), entry.value) else {
+            skipped.push(key);
+            continue;
+        };
+        let value = settings_registry::normalize_str(def, &value).map_err(AppError::BadRequest)?;
+        entries.push((key, value));
+    }
+
+    repo::settings::upsert_settings(pool, &entries).await?;
+
+    let value_of = |name: &str| {
+        entries
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    };
+    let update = TranslatorCredentialsUpdate {
+        deepseek_api_key: value_of("translation.deepseek_api_key"),
+        ollama_base_url: value_of("translation.ollama_base_url"),
+        ollama_model: value_of("translation.ollama_model"),
+        translation_rules: value_of(translation_rules::RULES_KEY)
+            .map(|value| TranslationRules::from_stored(Some(&value))),
+        ..Default::default()
+    };
+    translator
+        .update_credentials(update)
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+    let imported: Vec<String> = entries.into_iter().map(|(key, _)| key).collect();
+    tracing::info!(imported = imported.len(), skipped = skipped.len(), "settings imported");
+    Event::new(EventCode::SettingsImported)
+        .attr("imported", imported.len())
+        .attr("skipped", skipped.len())
+        .detail(format!("{} imported, {} skipped", imported.len(), skipped.len()))
+        .emit(pool)
+        .await;
+
+    Ok(SettingsImportResult { imported, skipped })
+}
@@ -0,0 +1,457 @@
+//! 类型化设置注册表：每个设置键声明类型、默认值与是否敏感，
+//! 服务层读取与 `GET/PUT /admin/api/settings/:key` 写入都经由这里校验，避免非法字符串写入 `news.settings`。
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::PgPool;
+use tracing::warn;
+
+use crate::{
+    auth,
+    fetcher,
+    error::{AppError, AppResult},
+    model::{SettingUpdate, SettingValueOut},
+    ops::events::ActivityVerbosity,
+    repo,
+    service::{
+        articles, digest, enrichment, extraction, feeds, layout, ranking, retention, share,
+        translations,
+    },
+    util::{
+        schedule::QuietWindow,
+        translation_rules::{self, TranslationRules},
+        translator::{mask_secret, TranslationEngine, TranslatorCredentialsUpdate},
+    },
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum SettingKind {
+    Bool,
+    Text,
+    Url,
+    Choice(&'static [&'static str]),
+    Integer { min: i64, max: i64 },
+    TimeWindow,
+    /// JSON 文档，由给定函数校验并规范化
+    Json(fn(&str) -> Result<String, String>),
+}
+
+impl SettingKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SettingKind::Bool => "bool",
+            SettingKind::Text => "string",
+            SettingKind::Url => "url",
+            SettingKind::Choice(_) => "choice",
+            SettingKind::Integer { .. } => "integer",
+            SettingKind::TimeWindow => "time_window",
+            SettingKind::Json(_) => "json",
+        }
+    }
+}
+
+/// 连续失败多少次后自动停用 feed（0 为不停用），见 fetcher::record_failure
+pub const MAX_FAIL_COUNT_KEY: &str = "fetcher.max_fail_count";
+
+pub struct SettingDef {
+    pub key: &'static str,
+    pub kind: SettingKind,
+    pub default: Option<&'static str>,
+    pub secret: bool,
+}
+
+const PROVIDERS: &[&str] = &["deepseek", "ollama"];
+
+pub const REGISTRY: &[SettingDef] = &[
+    SettingDef {
+        key: translation_rules::RULES_KEY,
+        kind: SettingKind::Json(translation_rules::normalize_rules),
+        default: Some(translation_rules::DEFAULT_RULES),
+        secret: false,
+    },
+    SettingDef {
+        key: "translation.provider",
+        kind: SettingKind::Choice(PROVIDERS),
+        default: Some("ollama"),
+        secret: false,
+    },
+    SettingDef {
+        key: "translation.deepseek_api_key",
+        kind: SettingKind::Text,
+        default: None,
+        secret: true,
+    },
+    SettingDef {
+        key: "translation.ollama_base_url",
+        kind: SettingKind::Url,
+        default: None,
+        secret: false,
+    },
+    SettingDef {
+        key: "translation.ollama_model",
+        kind: SettingKind::Text,
+        default: None,
+        secret: false,
+    },
+    SettingDef {
+        key: "ai_dedup.enabled",
+        kind: SettingKind::Bool,
+        default: Some("false"),
+        secret: false,
+    },
+    SettingDef {
+        key: "ai_dedup.provider",
+        kind: SettingKind::Choice(PROVIDERS),
+        default: None,
+        secret: false,
+    },
+    SettingDef {
+        key: MAX_FAIL_COUNT_KEY,
+        kind: SettingKind::Integer { min: 0, max: 1000 },
+        default: Some("0"),
+        secret: false,
+    },
+    SettingDef {
+        key: fetcher::ACTIVITY_VERBOSITY_KEY,
+        kind: SettingKind::Choice(ActivityVerbosity::CHOICES),
+        default: Some(ActivityVerbosity::DEFAULT),
+        secret: false,
+    },
+    SettingDef {
+        key: "dedup.window_hours",
+        kind: SettingKind::Integer { min: 1, max: 720 },
+        default: Some("72"),
+        secret: false,
+    },
+    SettingDef {
+        key: fetcher::SIMHASH_ENABLED_KEY,
+        kind: SettingKind::Bool,
+        default: Some("false"),
+        secret: false,
+    },
+    SettingDef {
+        key: fetcher::SIMHASH_DISTANCE_KEY,
+        kind: SettingKind::Integer { min: 0, max: 3 },
+        default: Some("3"),
+        secret: false,
+    },
+    SettingDef {
+        key: fetcher::DESCRIPTION_MAX_CHARS_KEY,
+        kind: SettingKind::Integer { min: 0, max: 20000 },
+        default: Some("0"),
+        secret: false,
+    },
+    SettingDef {
+        key: articles::REFERRER_POLICY_KEY,
+        kind: SettingKind::Choice(articles::REFERRER_POLICIES),
+        default: Some(articles::DEFAULT_REFERRER_POLICY),
+        secret: false,
+    },
+    SettingDef {
+        key: layout::LAYOUT_KEY,
+        kind: SettingKind::Json(layout::normalize_layout),
+        default: Some(layout::DEFAULT_LAYOUT),
+        secret: false,
+    },
+    SettingDef {
+        key: layout::SEMANTIC_SEARCH_KEY,
+        kind: SettingKind::Bool,
+        default: Some("false"),
+        secret: false,
+    },
+    SettingDef {
+        key: layout::TAGS_KEY,
+        kind: SettingKind::Bool,
+        default: Some("true"),
+        secret: false,
+    },
+    SettingDef {
+        key: layout::PAGE_SIZE_KEY,
+        kind: SettingKind::Integer {
+            min: 1,
+            max: layout::MAX_PAGE_SIZE,
+        },
+        default: Some("20"),
+        secret: false,
+    },
+    SettingDef {
+        key: "quiet_hours.enabled",
+        kind: SettingKind::Bool,
+        default: Some("false"),
+        secret: false,
+    },
+    SettingDef {
+        key: "quiet_hours.window",
+        kind: SettingKind::TimeWindow,
+        default: Some("02:00-05:00"),
+        secret: false,
+    },
+    SettingDef {
+        key: auth::MAX_SESSIONS_KEY,
+        kind: SettingKind::Integer { min: 0, max: 100 },
+        default: Some("0"),
+        secret: false,
+    },
+    SettingDef {
+        key: auth::SESSION_LIMIT_POLICY_KEY,
+        kind: SettingKind::Choice(auth::SESSION_LIMIT_POLICIES),
+        default: Some("evict_oldest"),
+        secret: false,
+    },
+    SettingDef {
+        key: enrichment::WEBHOOK_URL_KEY,
+        kind: SettingKind::Url,
+        default: None,
+        secret: false,
+    },
+    SettingDef {
+        key: enrichment::TOKEN_KEY,
+        kind: SettingKind::Text,
+        default: None,
+        secret: true,
+    },
+    SettingDef {
+        key: share::SIGNING_SECRET_KEY,
+        kind: SettingKind::Text,
+        default: None,
+        secret: true,
+    },
+    SettingDef {
+        key: enrichment::pipeline::PIPELINE_KEY,
+        kind: SettingKind::Json(enrichment::pipeline::normalize_pipeline),
+        default: Some(enrichment::pipeline::DEFAULT_PIPELINE),
+        secret: false,
+    },
+    SettingDef {
+        key: extraction::ENABLED_KEY,
+        kind: SettingKind::Bool,
+        default: Some("false"),
+        secret: false,
+    },
+    SettingDef {
+        key: translations::ON_DEMAND_ENABLED_KEY,
+        kind: SettingKind::Bool,
+        default: Some("false"),
+        secret: false,
+    },
+    SettingDef {
+        key: retention::RETENTION_DAYS_KEY,
+        kind: SettingKind::Integer {
+            min: 0,
+            max: retention::MAX_RETENTION_DAYS,
+        },
+        default: Some("0"),
+        secret: false,
+    },
+    SettingDef {
+        key: feeds::X_LIST_FEED_TEMPLATE_KEY,
+        kind: SettingKind::Url,
+        default: None,
+        secret: false,
+    },
+    SettingDef {
+        key: ranking::WINDOW_HOURS_KEY,
+        kind: SettingKind::Integer {
+            min: 1,
+            max: ranking::MAX_WINDOW_HOURS,
+        },
+        default: Some("168"),
+        secret: false,
+    },
+    SettingDef {
+        key: digest::ENABLED_KEY,
+        kind: SettingKind::Bool,
+        default: Some("false"),
+        secret: false,
+    },
+    SettingDef {
+        key: digest::TOP_N_KEY,
+        kind: SettingKind::Integer { min: 3, max: 50 },
+        default: Some("10"),
+        secret: false,
+    },
+];
+
+pub fn lookup(key: &str) -> Option<&'static SettingDef> {
+    REGISTRY.iter().find(|def| def.key == key)
+}
+
+fn parse_bool(raw: &str) -> Option<bool> {
+    match raw.trim().to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => Some(true),
+        "false" | "0" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// 校验原始字符串并转换为规范的存储形式（布尔统一为 true/false，选项统一小写）
+pub fn normalize_str(def: &SettingDef, raw: &str) -> Result<String, String> {
+    let trimmed = raw.trim();
+    match def.kind {
+        SettingKind::Bool => parse_bool(trimmed)
+            .map(|flag| flag.to_string())
+            .ok_or_else(|| format!("{} 需要布尔值", def.key)),
+        SettingKind::Text => {
+            if trimmed.is_empty() {
+                Err(format!("{} 不能为空", def.key))
+            } else {
+                Ok(trimmed.to_string())
+            }
+        }
+        SettingKind::Url => match url::Url::parse(trimmed) {
+            Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => {
+                Ok(trimmed.trim_end_matches('/').to_string())
+            }
+            _ => Err(format!("{} 需要 http(s) 地址", def.key)),
+        },
+        SettingKind::Choice(options) => {
+            let lowered = trimmed.to_ascii_lowercase();
+            if options.contains(&lowered.as_str()) {
+                Ok(lowered)
+            } else {
+                Err(format!("{} 可选值：{}", def.key, options.join("|")))
+            }
+        }
+        SettingKind::Integer { min, max } => trimmed
+            .parse::<i64>()
+            .ok()
+            .filter(|value| (min..=max).contains(value))
+            .map(|value| value.to_string())
+            .ok_or_else(|| format!("{} 需要 {min}~{max} 之间的整数", def.key)),
+        SettingKind::TimeWindow => QuietWindow::parse(trimmed)
+            .map(|window| window.to_string())
+            .ok_or_else(|| format!("{} 需要 HH:MM-HH:MM 格式（UTC，起止不能相同）", def.key)),
+        SettingKind::Json(validate) => validate(trimmed).map_err(|err| format!("{}: {err}", def.key)),
+    }
+}
+
+fn normalize_json(def: &SettingDef, value: &Value) -> Result<String, String> {
+    match (def.kind, value) {
+        (SettingKind::Bool, Value::Bool(flag)) => Ok(flag.to_string()),
+        (SettingKind::Integer { .. }, Value::Number(number)) => normalize_str(def, &number.to_string()),
+        (SettingKind::Json(_), Value::Object(_) | Value::Array(_)) => normalize_str(def, &value.to_string()),
+        (_, Value::String(raw)) => normalize_str(def, raw),
+        _ => Err(format!("{} 需要 {} 类型的值", def.key, def.kind.as_str())),
+    }
+}
+
+fn to_json(def: &SettingDef, stored: &str) -> Value {
+    match def.kind {
+        SettingKind::Bool => parse_bool(stored).map(Value::Bool).unwrap_or(Value::Null),
+        SettingKind::Integer { .. } => stored.parse::<i64>().map(Value::from).unwrap_or(Value::Null),
+        SettingKind::Json(_) => serde_json::from_str(stored).unwrap_or(Value::Null),
+        _ => Value::String(stored.to_string()),
+    }
+}
+
+/// 读取已注册设置：存储值非法时记录告警并回退到默认值
+pub async fn get_value(pool: &PgPool, key: &str) -> Result<Option<String>, sqlx::Error> {
+    let Some(def) = lookup(key) else {
+        return repo::settings::get_setting(pool, key).await;
+    };
+    let stored = repo::settings::get_setting(pool, key).await?;
+    let value = match stored {
+        Some(raw) => match normalize_str(def, &raw) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                warn!(key, error = %err, "invalid stored setting, falling back to default");
+                def.default.map(str::to_string)
+            }
+        },
+        None => def.default.map(str::to_string),
+    };
+    Ok(value)
+}
+
+pub async fn get_bool(pool: &PgPool, key: &str) -> Result<bool, sqlx::Error> {
+    Ok(get_value(pool, key)
+        .await?
+        .as_deref()
+        .and_then(parse_bool)
+        .unwrap_or(false))
+}
+
+/// 当前是否处于静默时段：抓取、补偿翻译与 LLM 去重在此期间暂停，管理接口不受影响
+pub async fn quiet_hours_active(pool: &PgPool, now: DateTime<Utc>) -> Result<bool, sqlx::Error> {
+    if !get_bool(pool, "quiet_hours.enabled").await? {
+        return Ok(false);
+    }
+    Ok(get_value(pool, "quiet_hours.window")
+        .await?
+        .as_deref()
+        .and_then(QuietWindow::parse)
+        .is_some_and(|window| window.contains(now)))
+}
+
+pub async fn get_i64(pool: &PgPool, key: &str) -> Result<Option<i64>, sqlx::Error> {
+    Ok(get_value(pool, key)
+        .await?
+        .and_then(|raw| raw.parse::<i64>().ok()))
+}
+
+pub async fn get(pool: &PgPool, key: &str) -> AppResult<SettingValueOut> {
+    let def = lookup(key).ok_or_else(|| AppError::BadRequest(format!("未知的设置项：{key}")))?;
+    let stored = repo::settings::get_setting(pool, key).await?;
+    let effective = get_value(pool, key).await?;
+
+    let value = match effective.as_deref() {
+        Some(raw) if def.secret => Value::String(mask_secret(raw)),
+        Some(raw) => to_json(def, raw),
+        None => Value::Null,
+    };
+
+    Ok(SettingValueOut {
+        key: def.key.to_string(),
+        kind: def.kind.as_str().to_string(),
+        value,
+        default: def.default.map(|raw| to_json(def, raw)).unwrap_or(Value::Null),
+        is_default: stored.is_none(),
+        secret: def.secret,
+        options: match def.kind {
+            SettingKind::Choice(options) => options.iter().map(|o| o.to_string()).collect(),
+            _ => Vec::new(),
+        },
+    })
+}
+
+/// 写入单个设置；`value` 为 null 时删除存储值（恢复默认）。翻译相关键会同步到运行中的翻译引擎。
+pub async fn put(
+    pool: &PgPool,
+    translator: &Arc<TranslationEngine>,
+    key: &str,
+    payload: SettingUpdate,
+) -> AppResult<SettingValueOut> {
+    let def = lookup(key).ok_or_else(|| AppError::BadRequest(format!("未知的设置项：{key}")))?;
+
+    let normalized = match &payload.value {
+        Value::Null => None,
+        value => Some(normalize_json(def, value).map_err(AppError::BadRequest)?),
+    };
+
+    match normalized.as_deref() {
+        Some(value) => repo::settings::upsert_setting(pool, key, value).await?,
+        None => repo::settings::delete_setting(pool, key).await?,
+    }
+
+    let mut update = TranslatorCredentialsUpdate::default();
+    let cleared = || Some(normalized.clone().unwrap_or_default());
+    match key {
+        "translation.deepseek_api_key" => update.deepseek_api_key = cleared(),
+        "translation.ollama_base_url" => update.ollama_base_url = cleared(),
+        "translation.ollama_model" => update.ollama_model = cleared(),
+        translation_rules::RULES_KEY => {
+            update.translation_rules = Some(TranslationRules::from_stored(
+                normalized.as_deref().or(def.default),
+            ))
+        }
+        _ => {}
+    }
+    translator
+        .update_credentials(update)
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+    tracing::info!(key, reset = normalized.is_none(), "setting updated");
+    get(pool, key).await
+}
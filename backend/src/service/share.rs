@@ -0,0 +1,92 @@
+//! 签名分享链接：在公共 API 不对外开放的部署中，把 Atom 输出、每周精选 RSS 与事件导出
+//! 以带过期时间的 HMAC 签名链接分享给外部订阅方，只需对外开放 `/api/shared/` 前缀。
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+
+use crate::{
+    error::{AppError, AppResult},
+    model::{SignedUrlOut, SignedUrlPayload},
+    service::settings_registry,
+    util::signed_url::{self, VerifyError},
+};
+
+/// 签名密钥；修改后此前签发的链接全部失效
+pub const SIGNING_SECRET_KEY: &str = "share.signing_secret";
+/// 签名链接挂载的路径前缀（经 nginx 为 `/api/shared`）
+pub const SHARED_PREFIX: &str = "/shared";
+/// 可签名分享的接口（不含前缀）
+pub const SIGNABLE_PATHS: &[&str] = &[
+    "/articles/feed.xml",
+    "/articles/feed.rss",
+    "/digest.rss",
+    "/alerts/export",
+];
+
+const MIN_SECRET_LEN: usize = 32;
+const DEFAULT_TTL_SECS: i64 = 7 * 24 * 3600;
+const MAX_TTL_SECS: i64 = 365 * 24 * 3600;
+
+/// 为指定接口签发链接，返回完整地址与过期时间
+pub async fn sign(pool: &PgPool, site_url: &str, payload: SignedUrlPayload) -> AppResult<SignedUrlOut> {
+    let path = payload.path.trim();
+    if !SIGNABLE_PATHS.contains(&path) {
+        return Err(AppError::BadRequest(format!(
+            "path 可选值：{}",
+            SIGNABLE_PATHS.join("|")
+        )));
+    }
+    let ttl = payload.ttl_secs.unwrap_or(DEFAULT_TTL_SECS);
+    if !(60..=MAX_TTL_SECS).contains(&ttl) {
+        return Err(AppError::BadRequest(format!(
+            "ttl_secs 需在 60-{MAX_TTL_SECS} 之间"
+        )));
+    }
+    let secret = signing_secret(pool).await?.ok_or_else(|| {
+        AppError::BadRequest(format!("请先设置 {SIGNING_SECRET_KEY}"))
+    })?;
+    if secret.chars().count() < MIN_SECRET_LEN {
+        return Err(AppError::BadRequest(format!(
+            "{SIGNING_SECRET_KEY} 至少需要 {MIN_SECRET_LEN} 个字符"
+        )));
+    }
+
+    let query: Vec<(String, String)> = payload
+        .query
+        .as_deref()
+        .map(|raw| raw.trim().trim_start_matches('?'))
+        .map(|raw| form_urlencoded::parse(raw.as_bytes()).into_owned().collect())
+        .unwrap_or_default();
+    let expires_at = Utc::now() + Duration::seconds(ttl);
+    let signed_path = format!("{SHARED_PREFIX}{path}");
+    let signed_query = signed_url::sign(&secret, &signed_path, &query, expires_at.timestamp());
+
+    tracing::info!(path, ttl_secs = ttl, "signed share url issued");
+    Ok(SignedUrlOut {
+        url: format!("{site_url}/api{signed_path}?{signed_query}"),
+        expires_at: expires_at.to_rfc3339(),
+    })
+}
+
+/// 校验 `/shared` 下请求的签名；未配置密钥时一律拒绝
+pub async fn verify(pool: &PgPool, path: &str, raw_query: Option<&str>, now: DateTime<Utc>) -> AppResult<()> {
+    let Some(secret) = signing_secret(pool).await? else {
+        return Err(AppError::Unauthorized("签名链接未启用".to_string()));
+    };
+    signed_url::verify(&secret, path, raw_query, now.timestamp()).map_err(|err| {
+        AppError::Unauthorized(
+            match err {
+                VerifyError::Missing => "缺少签名参数",
+                VerifyError::Expired => "链接已过期",
+                VerifyError::Invalid => "签名无效",
+            }
+            .to_string(),
+        )
+    })
+}
+
+async fn signing_secret(pool: &PgPool) -> AppResult<Option<String>> {
+    Ok(settings_registry::get_value(pool, SIGNING_SECRET_KEY)
+        .await?
+        .filter(|value| !value.is_empty()))
+}
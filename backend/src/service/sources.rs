@@ -0,0 +1,166 @@
+use sqlx::PgPool;
+use url::Url;
+
+use crate::{
+    error::{AppError, AppResult},
+    model::{PublicSourceOut, SourcePreferenceOut, SourcePreferenceUpdate, SourceWeightOut, SourceWeightUpdate},
+    repo,
+};
+
+const MAX_SOURCE_WEIGHT: f32 = 10.0;
+const MAX_DAILY_CAP: i32 = 10_000;
+const MAX_PREFERENCE_NOTE_LEN: usize = 200;
+
+pub async fn list_public(pool: &PgPool) -> AppResult<Vec<PublicSourceOut>> {
+    let rows = repo::sources::list_public_sources(pool).await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| PublicSourceOut {
+            favicon_url: favicon_url(row.site_url.as_deref(), &row.source_domain),
+            source_domain: row.source_domain,
+            title: row.title,
+            site_url: row.site_url,
+            article_count: row.article_count,
+            last_article_at: row.last_article_at.map(|ts| ts.to_rfc3339()),
+        })
+        .collect())
+}
+
+/// 优先使用订阅源站点地址的主机名，没有时退回来源域名（须形如主机名）
+fn favicon_url(site_url: Option<&str>, source_domain: &str) -> Option<String> {
+    let from_site = site_url
+        .and_then(|raw| Url::parse(raw).ok())
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+        .and_then(|url| {
+            let host = url.host_str()?.to_string();
+            Some(format!("{}://{host}/favicon.ico", url.scheme()))
+        });
+    from_site.or_else(|| {
+        let looks_like_host = source_domain.contains('.')
+            && source_domain
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+        looks_like_host.then(|| format!("https://{source_domain}/favicon.ico"))
+    })
+}
+
+pub async fn list_weights(pool: &PgPool) -> AppResult<Vec<SourceWeightOut>> {
+    let rows = repo::sources::list_weights(pool).await?;
+    Ok(rows.into_iter().map(weight_row_to_out).collect())
+}
+
+pub async fn upsert_weight(pool: &PgPool, payload: SourceWeightUpdate) -> AppResult<SourceWeightOut> {
+    let source_domain = normalize_domain(&payload.source_domain)?;
+    if !payload.weight.is_finite() || !(0.0..=MAX_SOURCE_WEIGHT).contains(&payload.weight) {
+        return Err(AppError::BadRequest(format!(
+            "来源权重必须在 0 到 {MAX_SOURCE_WEIGHT} 之间"
+        )));
+    }
+
+    if let Some(cap) = payload.daily_cap {
+        if !(1..=MAX_DAILY_CAP).contains(&cap) {
+            return Err(AppError::BadRequest(format!(
+                "每日入库上限必须在 1 到 {MAX_DAILY_CAP} 之间"
+            )));
+        }
+    }
+
+    let row =
+        repo::sources::upsert_weight(pool, &source_domain, payload.weight, payload.daily_cap).await?;
+    tracing::info!(
+        source_domain = %row.source_domain,
+        weight = row.weight,
+        daily_cap = ?row.daily_cap,
+        "source weight saved"
+    );
+    Ok(weight_row_to_out(row))
+}
+
+pub async fn delete_weight(pool: &PgPool, source_domain: &str) -> AppResult<()> {
+    let source_domain = normalize_domain(source_domain)?;
+    let deleted = repo::sources::delete_weight(pool, &source_domain).await?;
+    if deleted == 0 {
+        return Err(AppError::BadRequest(format!(
+            "source {source_domain} has no weight configured"
+        )));
+    }
+    Ok(())
+}
+
+pub async fn list_preferences(pool: &PgPool) -> AppResult<Vec<SourcePreferenceOut>> {
+    let rows = repo::sources::list_preferences(pool).await?;
+    Ok(rows.into_iter().map(preference_row_to_out).collect())
+}
+
+/// 保存一条来源偏好；同一对来源只保留一个方向，新规则会替换相反方向的旧规则
+pub async fn upsert_preference(
+    pool: &PgPool,
+    payload: SourcePreferenceUpdate,
+) -> AppResult<SourcePreferenceOut> {
+    let preferred_domain = normalize_domain(&payload.preferred_domain)?;
+    let over_domain = normalize_domain(&payload.over_domain)?;
+    if preferred_domain == repo::sources::ANY_SOURCE {
+        return Err(AppError::BadRequest("preferred_domain 不能为通配符 *".into()));
+    }
+    if preferred_domain == over_domain {
+        return Err(AppError::BadRequest("preferred_domain 与 over_domain 不能相同".into()));
+    }
+    let note = payload
+        .note
+        .as_deref()
+        .map(str::trim)
+        .filter(|note| !note.is_empty());
+    if note.is_some_and(|note| note.chars().count() > MAX_PREFERENCE_NOTE_LEN) {
+        return Err(AppError::BadRequest(format!(
+            "备注不能超过 {MAX_PREFERENCE_NOTE_LEN} 个字符"
+        )));
+    }
+
+    let replaced =
+        repo::sources::delete_reverse_preference(pool, &preferred_domain, &over_domain).await?;
+    let row = repo::sources::upsert_preference(pool, &preferred_domain, &over_domain, note).await?;
+    tracing::info!(
+        preferred_domain = %row.preferred_domain,
+        over_domain = %row.over_domain,
+        replaced,
+        "source preference saved"
+    );
+    Ok(preference_row_to_out(row))
+}
+
+pub async fn delete_preference(pool: &PgPool, id: i64) -> AppResult<()> {
+    let deleted = repo::sources::delete_preference(pool, id).await?;
+    if deleted == 0 {
+        return Err(AppError::BadRequest(format!(
+            "source preference {id} not found"
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn normalize_domain(raw: &str) -> AppResult<String> {
+    let trimmed = raw.trim().to_ascii_lowercase();
+    if trimmed.is_empty() {
+        return Err(AppError::BadRequest("source_domain is required".into()));
+    }
+    Ok(trimmed)
+}
+
+fn weight_row_to_out(row: repo::sources::SourceWeightRow) -> SourceWeightOut {
+    SourceWeightOut {
+        source_domain: row.source_domain,
+        weight: row.weight,
+        daily_cap: row.daily_cap,
+        updated_at: row.updated_at.to_rfc3339(),
+    }
+}
+
+fn preference_row_to_out(row: repo::sources::SourcePreferenceRow) -> SourcePreferenceOut {
+    SourcePreferenceOut {
+        id: row.id,
+        preferred_domain: row.preferred_domain,
+        over_domain: row.over_domain,
+        note: row.note,
+        created_at: row.created_at.to_rfc3339(),
+    }
+}
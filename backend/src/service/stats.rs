@@ -0,0 +1,13 @@
+use sqlx::PgPool;
+
+use crate::{error::AppResult, model::StatsSummaryOut, repo};
+
+pub async fn summary(pool: &PgPool) -> AppResult<StatsSummaryOut> {
+    let row = repo::articles::stats_summary(pool).await?;
+    Ok(StatsSummaryOut {
+        total_articles: row.total_articles,
+        articles_today: row.articles_today,
+        active_sources: row.active_sources,
+        last_updated_at: row.last_updated_at.map(|ts| ts.to_rfc3339()),
+    })
+}
@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+
+use crate::{
+    error::{AppError, AppResult},
+    fetcher::{dedup_verdict, html_unescape_minimal},
+    model::{
+        NormalizePreviewOut, NormalizePreviewPayload, SummaryStepsOut, TitleComparisonOut,
+        TitleStepsOut, UrlStepsOut,
+    },
+    util::{
+        html::strip_html_basic,
+        lang::detect_language,
+        translator::TranslationEngine,
+        title::{content_fingerprint, jaccard_similarity, prepare_title_signature},
+        url_norm::{infer_source_domain, normalize_article_url},
+    },
+};
+
+// 按抓取器入库时的顺序逐步处理标题/摘要/URL，并返回每一步的结果，便于排查去重为何命中或未命中
+pub fn normalize_preview(
+    translator: &TranslationEngine,
+    payload: NormalizePreviewPayload,
+) -> AppResult<NormalizePreviewOut> {
+    let raw_title = payload.title.trim().to_string();
+    if raw_title.is_empty() {
+        return Err(AppError::BadRequest("title is required".into()));
+    }
+
+    let published_at = match payload.published_at.as_deref().map(str::trim) {
+        Some(value) if !value.is_empty() => DateTime::parse_from_rfc3339(value)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|err| AppError::BadRequest(format!("published_at 格式无效: {err}")))?,
+        _ => Utc::now(),
+    };
+
+    // 标题只做实体解码，不去标签（与 convert_entry 一致）
+    let entity_decoded = html_unescape_minimal(&raw_title);
+    let (normalized, tokens) = prepare_title_signature(&entity_decoded);
+    let detected_language = detect_language(&entity_decoded);
+    let title = TitleStepsOut {
+        raw: raw_title,
+        detected_language: detected_language.map(str::to_string),
+        needs_translation: translator.should_translate(detected_language),
+        entity_decoded,
+        normalized,
+        tokens: tokens.iter().cloned().collect(),
+    };
+
+    let summary = payload
+        .summary
+        .filter(|raw| !raw.trim().is_empty())
+        .map(|raw| {
+            let html_stripped = strip_html_basic(raw.trim());
+            let entity_decoded = html_unescape_minimal(&html_stripped);
+            SummaryStepsOut {
+                raw,
+                html_stripped,
+                entity_decoded,
+            }
+        });
+
+    let url = payload
+        .url
+        .map(|raw| raw.trim().to_string())
+        .filter(|raw| !raw.is_empty())
+        .map(|raw| {
+            let (normalized, error) = match normalize_article_url(&raw) {
+                Ok(value) => (Some(value), None),
+                Err(err) => (None, Some(err.to_string())),
+            };
+            UrlStepsOut {
+                source_domain: infer_source_domain(normalized.as_deref().unwrap_or(&raw)),
+                raw,
+                normalized,
+                error,
+            }
+        });
+
+    // 抓取器在 URL 归一化失败时沿用原始地址
+    let content_fingerprint = url.as_ref().and_then(|steps| {
+        let effective = steps.normalized.as_deref().unwrap_or(&steps.raw);
+        content_fingerprint(&title.entity_decoded, effective, published_at)
+    });
+
+    let comparison = payload
+        .compare_title
+        .filter(|other| !other.trim().is_empty())
+        .map(|other| {
+            let (other_normalized, other_tokens) =
+                prepare_title_signature(&html_unescape_minimal(other.trim()));
+            let jaccard = jaccard_similarity(&tokens, &other_tokens);
+            let identical_normalized = other_normalized == title.normalized;
+            let verdict = dedup_verdict(jaccard, identical_normalized);
+            TitleComparisonOut {
+                normalized: other_normalized,
+                matched_tokens: tokens.intersection(&other_tokens).cloned().collect(),
+                tokens: other_tokens.into_iter().collect(),
+                jaccard,
+                identical_normalized,
+                verdict: verdict.to_string(),
+            }
+        });
+
+    Ok(NormalizePreviewOut {
+        title,
+        summary,
+        url,
+        content_fingerprint,
+        comparison,
+    })
+}
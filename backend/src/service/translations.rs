@@ -0,0 +1,152 @@
+//! 按需翻译：读者在前台点击“翻译”时把单篇文章的标题与摘要翻译为指定语言，
+//! 结果按 (文章, 语言) 缓存在 `news.article_translations`，同一篇文章每种语言只调用一次模型。
+
+use std::sync::LazyLock;
+
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use tokio::sync::Semaphore;
+
+use crate::{
+    error::{AppError, AppResult},
+    fetcher,
+    model::ArticleTranslationOut,
+    repo::{self, article_translations::NewArticleTranslation},
+    service::settings_registry,
+    util::{lang::normalize_language_tag, translator::TranslationEngine},
+};
+
+pub const ON_DEMAND_ENABLED_KEY: &str = "translation.on_demand_enabled";
+
+// 同时进行的按需翻译上限，其余请求排队，避免突发点击占满模型配额
+const MAX_CONCURRENT: usize = 4;
+static PERMITS: LazyLock<Semaphore> = LazyLock::new(|| Semaphore::new(MAX_CONCURRENT));
+
+/// 写入提示词的目标语言名称
+fn language_name(code: &str) -> &'static str {
+    match code {
+        "zh" => "简体中文",
+        "ja" => "日语",
+        "ko" => "韩语",
+        _ => "英语",
+    }
+}
+
+pub async fn translate(
+    pool: &PgPool,
+    engine: &TranslationEngine,
+    id: i64,
+    lang: Option<&str>,
+) -> AppResult<ArticleTranslationOut> {
+    if !settings_registry::get_bool(pool, ON_DEMAND_ENABLED_KEY).await? {
+        return Err(AppError::BadRequest("未开启按需翻译".to_string()));
+    }
+    let lang = lang
+        .map(str::trim)
+        .filter(|raw| !raw.is_empty())
+        .ok_or_else(|| AppError::BadRequest("缺少 lang 参数".to_string()))?;
+    let lang = normalize_language_tag(lang)
+        .ok_or_else(|| AppError::BadRequest("lang 可选值：en|zh|ja|ko".to_string()))?;
+
+    if !matches!(repo::articles::find_hidden_at(pool, id).await?, Some(None)) {
+        return Err(AppError::BadRequest(format!("article {id} not found")));
+    }
+    let article = repo::articles::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("article {id} not found")))?;
+
+    // 当前文本已是目标语言（原文或入库时已翻译）时原样返回
+    let current = article
+        .language
+        .as_deref()
+        .and_then(normalize_language_tag)
+        .or_else(|| article.original_language.as_deref().and_then(normalize_language_tag));
+    if current == Some(lang) {
+        return Ok(ArticleTranslationOut {
+            article_id: id,
+            lang: lang.to_string(),
+            title: article.title,
+            description: article.description,
+            translated: false,
+            cached: false,
+        });
+    }
+
+    let digest = source_digest(&article.title, article.description.as_deref());
+    if let Some(out) = cached(pool, id, lang, &digest).await? {
+        return Ok(out);
+    }
+    let _permit = PERMITS
+        .acquire()
+        .await
+        .map_err(|err| AppError::Internal(err.into()))?;
+    // 排队期间同一译文可能已由其他请求写入
+    if let Some(out) = cached(pool, id, lang, &digest).await? {
+        return Ok(out);
+    }
+
+    let result = match engine
+        .translate_into(&article.title, article.description.as_deref(), language_name(lang))
+        .await
+    {
+        Ok(Some(result)) => result,
+        Ok(None) => return Err(AppError::Unavailable("翻译服务未配置".to_string())),
+        Err(err) => {
+            fetcher::report_translation_failure(pool, &err).await;
+            return Err(AppError::Unavailable("翻译失败，请稍后重试".to_string()));
+        }
+    };
+    let description = result
+        .description
+        .filter(|desc| !desc.trim().is_empty() && article.description.is_some());
+    repo::article_translations::upsert(
+        pool,
+        &NewArticleTranslation {
+            article_id: id,
+            language: lang,
+            title: &result.title,
+            description: description.as_deref(),
+            source_digest: &digest,
+            provider: engine.current_provider().as_str(),
+        },
+    )
+    .await?;
+    tracing::info!(article_id = id, lang, "article translated on demand");
+
+    Ok(ArticleTranslationOut {
+        article_id: id,
+        lang: lang.to_string(),
+        title: result.title,
+        description,
+        translated: true,
+        cached: false,
+    })
+}
+
+async fn cached(
+    pool: &PgPool,
+    id: i64,
+    lang: &str,
+    digest: &str,
+) -> AppResult<Option<ArticleTranslationOut>> {
+    let row = repo::article_translations::find(pool, id, lang).await?;
+    Ok(row
+        .filter(|row| row.source_digest == digest)
+        .map(|row| ArticleTranslationOut {
+            article_id: id,
+            lang: lang.to_string(),
+            title: row.title,
+            description: row.description,
+            translated: true,
+            cached: true,
+        }))
+}
+
+// 标题或摘要被管理员修改、补偿翻译改写后摘要值变化，缓存的译文随之失效
+fn source_digest(title: &str, description: Option<&str>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(title.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(description.unwrap_or_default().as_bytes());
+    format!("{:x}", hasher.finalize())
+}
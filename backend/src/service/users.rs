@@ -0,0 +1,195 @@
+//! 后台账号管理：配置文件中的管理员之外，团队成员以 `news.users` 中的账号登录，
+//! 角色（admin / editor / viewer）由 `auth::require_admin` 在每次请求时校验。
+
+use sqlx::PgPool;
+
+use crate::{
+    auth::{Role, ROLES},
+    error::{AppError, AppResult},
+    model::{UserCreatePayload, UserOut, UserUpdatePayload},
+    ops::event_catalog::{Event, EventCode},
+    repo::{
+        self,
+        users::{NewUser, UserChanges, UserRow},
+    },
+    util::password::hash_password,
+};
+
+const USERNAME_MIN_LEN: usize = 3;
+const USERNAME_MAX_LEN: usize = 32;
+const PASSWORD_MIN_LEN: usize = 8;
+// argon2 对超长输入同样要做完整计算，限制长度避免被用来消耗 CPU
+const PASSWORD_MAX_LEN: usize = 128;
+
+pub async fn list(pool: &PgPool) -> AppResult<Vec<UserOut>> {
+    let rows = repo::users::list(pool).await?;
+    Ok(rows.into_iter().map(user_row_to_out).collect())
+}
+
+pub async fn create(pool: &PgPool, builtin_username: &str, payload: UserCreatePayload) -> AppResult<UserOut> {
+    let username = payload.username.trim();
+    validate_username(username)?;
+    if username == builtin_username {
+        return Err(AppError::BadRequest(format!("用户名 {username} 已被占用")));
+    }
+    let role = parse_role(&payload.role)?;
+    let password_hash = password_hash_for(&payload.password).await?;
+
+    let row = repo::users::insert(
+        pool,
+        &NewUser {
+            username,
+            password_hash: &password_hash,
+            role: role.as_str(),
+        },
+    )
+    .await?
+    .ok_or_else(|| AppError::BadRequest(format!("用户名 {username} 已被占用")))?;
+
+    tracing::info!(user_id = row.id, username, role = role.as_str(), "admin user created");
+    Event::new(EventCode::UserChanged)
+        .attr("action", "created")
+        .attr("username", username)
+        .attr("role", role.as_str())
+        .detail(format!("新建账号 {username}（{}）", role.as_str()))
+        .emit(pool)
+        .await;
+    Ok(user_row_to_out(row))
+}
+
+/// 修改角色、重置密码或停用/启用账号；重置密码与停用会让该账号的现有会话立即失效
+pub async fn update(
+    pool: &PgPool,
+    acting_username: &str,
+    id: i64,
+    payload: UserUpdatePayload,
+) -> AppResult<UserOut> {
+    let current = repo::users::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("user {id} not found")))?;
+    let role = payload.role.as_deref().map(parse_role).transpose()?;
+    // 避免管理员误操作把自己锁在后台之外
+    if current.username == acting_username
+        && (role.is_some_and(|role| role.as_str() != current.role) || payload.disabled == Some(true))
+    {
+        return Err(AppError::BadRequest("不能修改自己的角色或停用自己".to_string()));
+    }
+    if payload.password.is_some() && current.auth_source != repo::users::AUTH_SOURCE_PASSWORD {
+        return Err(AppError::BadRequest("单点登录账号不能设置密码".to_string()));
+    }
+    let password_hash = match payload.password.as_deref() {
+        Some(password) => Some(password_hash_for(password).await?),
+        None => None,
+    };
+    if role.is_none() && password_hash.is_none() && payload.disabled.is_none() {
+        return Err(AppError::BadRequest("role、password、disabled 至少提供一项".to_string()));
+    }
+
+    let row = repo::users::update(
+        pool,
+        id,
+        &UserChanges {
+            role: role.map(Role::as_str),
+            password_hash: password_hash.as_deref(),
+            disabled: payload.disabled,
+        },
+    )
+    .await?
+    .ok_or_else(|| AppError::BadRequest(format!("user {id} not found")))?;
+
+    let revoked = if password_hash.is_some() || payload.disabled == Some(true) {
+        repo::admin_sessions::delete_by_username(pool, &row.username).await?
+    } else {
+        0
+    };
+
+    let mut changes = Vec::new();
+    if let Some(role) = role {
+        changes.push(format!("角色改为 {}", role.as_str()));
+    }
+    if password_hash.is_some() {
+        changes.push("重置密码".to_string());
+    }
+    match payload.disabled {
+        Some(true) => changes.push("停用".to_string()),
+        Some(false) => changes.push("启用".to_string()),
+        None => {}
+    }
+    tracing::info!(user_id = id, username = %row.username, revoked, "admin user updated");
+    Event::new(EventCode::UserChanged)
+        .attr("action", "updated")
+        .attr("username", row.username.as_str())
+        .attr("role", row.role.as_str())
+        .attr("revoked_sessions", revoked)
+        .detail(format!("账号 {}：{}", row.username, changes.join("，")))
+        .emit(pool)
+        .await;
+    Ok(user_row_to_out(row))
+}
+
+/// 删除账号并注销其全部会话
+pub async fn delete(pool: &PgPool, acting_username: &str, id: i64) -> AppResult<()> {
+    let current = repo::users::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("user {id} not found")))?;
+    if current.username == acting_username {
+        return Err(AppError::BadRequest("不能删除自己".to_string()));
+    }
+    let username = repo::users::delete(pool, id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("user {id} not found")))?;
+    let revoked = repo::admin_sessions::delete_by_username(pool, &username).await?;
+
+    tracing::info!(user_id = id, username = %username, revoked, "admin user deleted");
+    Event::new(EventCode::UserChanged)
+        .attr("action", "deleted")
+        .attr("username", username.as_str())
+        .attr("revoked_sessions", revoked)
+        .detail(format!("删除账号 {username}"))
+        .emit(pool)
+        .await;
+    Ok(())
+}
+
+fn validate_username(username: &str) -> AppResult<()> {
+    let valid = (USERNAME_MIN_LEN..=USERNAME_MAX_LEN).contains(&username.len())
+        && username
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(AppError::BadRequest(format!(
+            "用户名需为 {USERNAME_MIN_LEN}~{USERNAME_MAX_LEN} 位字母、数字、-、_ 或 ."
+        )))
+    }
+}
+
+fn parse_role(raw: &str) -> AppResult<Role> {
+    Role::parse(raw.trim())
+        .ok_or_else(|| AppError::BadRequest(format!("role 可选值：{}", ROLES.join("|"))))
+}
+
+async fn password_hash_for(password: &str) -> AppResult<String> {
+    let len = password.chars().count();
+    if !(PASSWORD_MIN_LEN..=PASSWORD_MAX_LEN).contains(&len) {
+        return Err(AppError::BadRequest(format!(
+            "密码长度需在 {PASSWORD_MIN_LEN}~{PASSWORD_MAX_LEN} 个字符之间"
+        )));
+    }
+    hash_password(password).await.map_err(AppError::Internal)
+}
+
+fn user_row_to_out(row: UserRow) -> UserOut {
+    UserOut {
+        id: row.id,
+        username: row.username,
+        role: row.role,
+        auth_source: row.auth_source,
+        disabled: row.disabled_at.is_some(),
+        disabled_at: row.disabled_at.map(|at| at.to_rfc3339()),
+        last_login_at: row.last_login_at.map(|at| at.to_rfc3339()),
+        created_at: row.created_at.to_rfc3339(),
+        updated_at: row.updated_at.to_rfc3339(),
+    }
+}
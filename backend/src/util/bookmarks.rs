@@ -0,0 +1,196 @@
+//! 浏览器书签（Netscape Bookmark File 格式）解析与站点订阅源自动发现。
+
+use std::collections::HashSet;
+
+use url::Url;
+
+use super::html::strip_html_basic;
+
+#[derive(Debug, Clone)]
+pub struct Bookmark {
+    pub title: Option<String>,
+    pub url: String,
+}
+
+/// 页面中声明的订阅源链接，`title` 取自 `<link>` 的 title 属性。
+#[derive(Debug, Clone)]
+pub struct FeedLink {
+    pub url: String,
+    pub title: Option<String>,
+}
+
+/// 提取书签文件中所有 http(s) 链接，按出现顺序去重。
+/// Chrome/Firefox/Safari 导出的书签均为 `<DT><A HREF="...">标题</A>` 结构。
+pub fn parse_netscape_bookmarks(html: &str) -> Vec<Bookmark> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    let mut rest = html;
+    while let Some((tag, after)) = next_tag(rest) {
+        rest = after;
+        if !tag.name.eq_ignore_ascii_case("a") {
+            continue;
+        }
+        let Some(href) = tag.attr("href") else {
+            continue;
+        };
+        let Ok(url) = Url::parse(href.trim()) else {
+            continue;
+        };
+        if !matches!(url.scheme(), "http" | "https") {
+            continue;
+        }
+        let text_end = find_ascii_ci(rest, "</a").unwrap_or(rest.len());
+        let title = decode_entities(strip_html_basic(&rest[..text_end]).trim());
+        let url = url.to_string();
+        if seen.insert(url.clone()) {
+            out.push(Bookmark {
+                title: Some(title).filter(|t| !t.is_empty()),
+                url,
+            });
+        }
+    }
+    out
+}
+
+/// 从网页 HTML 中找出 `<link rel="alternate">` 声明的 RSS/Atom/JSON Feed 地址，相对地址按页面地址补全。
+pub fn discover_feed_links(html: &str, page_url: &Url) -> Vec<FeedLink> {
+    let mut out: Vec<FeedLink> = Vec::new();
+    let mut rest = html;
+    while let Some((tag, after)) = next_tag(rest) {
+        rest = after;
+        if tag.name.eq_ignore_ascii_case("body") {
+            break;
+        }
+        if !tag.name.eq_ignore_ascii_case("link") {
+            continue;
+        }
+        let is_alternate = tag
+            .attr("rel")
+            .map(|rel| rel.split_ascii_whitespace().any(|r| r.eq_ignore_ascii_case("alternate")))
+            .unwrap_or(false);
+        let is_feed_type = tag
+            .attr("type")
+            .map(|ty| {
+                let ty = ty.trim().to_ascii_lowercase();
+                ty == "application/rss+xml"
+                    || ty == "application/atom+xml"
+                    || ty == "application/feed+json"
+                    || ty == "application/json"
+            })
+            .unwrap_or(false);
+        if !is_alternate || !is_feed_type {
+            continue;
+        }
+        let Some(href) = tag.attr("href") else {
+            continue;
+        };
+        if let Ok(resolved) = page_url.join(href.trim()) {
+            let resolved = resolved.to_string();
+            if !out.iter().any(|link| link.url == resolved) {
+                out.push(FeedLink {
+                    url: resolved,
+                    title: tag
+                        .attr("title")
+                        .map(|title| title.trim().to_string())
+                        .filter(|title| !title.is_empty()),
+                });
+            }
+        }
+    }
+    out
+}
+
+struct Tag {
+    name: String,
+    attrs: Vec<(String, String)>,
+}
+
+impl Tag {
+    fn attr(&self, name: &str) -> Option<String> {
+        self.attrs
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| decode_entities(value))
+    }
+}
+
+// 返回下一个开始标签及其之后的剩余文本；注释、结束标签与 doctype 被跳过
+fn next_tag(input: &str) -> Option<(Tag, &str)> {
+    let mut rest = input;
+    loop {
+        let start = rest.find('<')?;
+        rest = &rest[start + 1..];
+        if let Some(comment) = rest.strip_prefix("!--") {
+            rest = comment.find("-->").map(|end| &comment[end + 3..]).unwrap_or("");
+            continue;
+        }
+        let name_len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        if name_len == 0 {
+            continue;
+        }
+        let name = rest[..name_len].to_string();
+        let (attrs, after) = parse_attrs(&rest[name_len..]);
+        return Some((Tag { name, attrs }, after));
+    }
+}
+
+fn parse_attrs(input: &str) -> (Vec<(String, String)>, &str) {
+    let mut attrs = Vec::new();
+    let mut rest = input;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+        if rest.is_empty() {
+            return (attrs, rest);
+        }
+        if let Some(after) = rest.strip_prefix('>') {
+            return (attrs, after);
+        }
+        let key_len = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/')
+            .unwrap_or(rest.len())
+            .max(1);
+        let key = rest[..key_len].to_string();
+        rest = rest[key_len..].trim_start();
+        let Some(after_eq) = rest.strip_prefix('=') else {
+            attrs.push((key, String::new()));
+            continue;
+        };
+        rest = after_eq.trim_start();
+        let value = match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let body = &rest[1..];
+                let end = body.find(quote).unwrap_or(body.len());
+                rest = body.get(end + 1..).unwrap_or("");
+                &body[..end]
+            }
+            _ => {
+                let end = rest
+                    .find(|c: char| c.is_whitespace() || c == '>')
+                    .unwrap_or(rest.len());
+                let value = &rest[..end];
+                rest = &rest[end..];
+                value
+            }
+        };
+        attrs.push((key, value.to_string()));
+    }
+}
+
+fn find_ascii_ci(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+fn decode_entities(input: &str) -> String {
+    input
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,114 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    collections::HashMap,
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::body::Bytes;
+
+use crate::repo::articles::ArticleSort;
+
+/// Small in-process cache whose entries expire `ttl` after insertion.
+///
+/// Expired entries are never returned and are swept out at most once per `ttl`, so
+/// inserts stay O(1) amortized. An optional capacity bounds memory: once full, an
+/// arbitrary entry makes room for the new one.
+pub struct TtlCache<K, V> {
+    ttl: Duration,
+    capacity: Option<usize>,
+    inner: Mutex<Inner<K, V>>,
+}
+
+struct Inner<K, V> {
+    entries: HashMap<K, (Instant, V)>,
+    last_sweep: Instant,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            capacity: None,
+            inner: Mutex::new(Inner {
+                entries: HashMap::new(),
+                last_sweep: Instant::now(),
+            }),
+        }
+    }
+
+    /// Like [`TtlCache::new`] but holding at most `capacity` entries.
+    pub fn with_capacity(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity.max(1)),
+            ..Self::new(ttl)
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner
+            .entries
+            .get(key)
+            .filter(|(stored_at, _)| stored_at.elapsed() < self.ttl)
+            .map(|(_, value)| value.clone())
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        if inner.last_sweep.elapsed() >= self.ttl {
+            let ttl = self.ttl;
+            inner
+                .entries
+                .retain(|_, (stored_at, _)| stored_at.elapsed() < ttl);
+            inner.last_sweep = Instant::now();
+        }
+        if let Some(capacity) = self.capacity {
+            if inner.entries.len() >= capacity && !inner.entries.contains_key(&key) {
+                if let Some(evicted) = inner.entries.keys().next().cloned() {
+                    inner.entries.remove(&evicted);
+                }
+            }
+        }
+        inner.entries.insert(key, (Instant::now(), value));
+    }
+
+    pub fn clear(&self) {
+        self.inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entries
+            .clear();
+    }
+}
+
+/// A pre-serialized JSON response body together with its validator.
+#[derive(Debug, Clone)]
+pub struct CachedJson {
+    pub body: Bytes,
+    pub etag: String,
+}
+
+impl CachedJson {
+    pub fn new(body: Vec<u8>) -> Self {
+        let mut hasher = DefaultHasher::new();
+        body.hash(&mut hasher);
+        Self {
+            etag: format!("W/\"{:016x}\"", hasher.finish()),
+            body: Bytes::from(body),
+        }
+    }
+
+    /// Whether an `If-None-Match` header value matches this body.
+    pub fn matches(&self, if_none_match: &str) -> bool {
+        if_none_match
+            .split(',')
+            .map(str::trim)
+            .any(|tag| tag == "*" || tag == self.etag)
+    }
+}
+
+/// First-page responses of the unfiltered public article listing, keyed by sort and page size.
+/// Cleared whenever articles are inserted, their visible fields change or rank scores are recomputed.
+pub type LatestArticlesCache = Arc<TtlCache<(ArticleSort, u32), CachedJson>>;
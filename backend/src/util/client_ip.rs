@@ -0,0 +1,167 @@
+//! 反向代理场景下的真实客户端地址识别。
+//!
+//! 仅当直连对端属于受信代理时才采信 `X-Forwarded-For`，从右向左跳过受信代理，
+//! 第一个非受信地址即为客户端；否则直接使用对端地址，防止客户端伪造请求头。
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts},
+    http::{request::Parts, HeaderMap},
+};
+
+/// 中间件写入请求扩展的客户端地址；缺失时（如未启用 ConnectInfo）为 `None`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub Option<IpAddr>);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ClientIp {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<ClientIp>()
+            .copied()
+            .unwrap_or(ClientIp(None)))
+    }
+}
+
+impl std::fmt::Display for ClientIp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(ip) => write!(f, "{ip}"),
+            None => f.write_str("unknown"),
+        }
+    }
+}
+
+/// 受信代理列表，支持单个地址与 CIDR 网段。
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies {
+    nets: Vec<(IpAddr, u8)>,
+}
+
+impl TrustedProxies {
+    pub fn parse(entries: &[String]) -> anyhow::Result<Self> {
+        let mut nets = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let entry = entry.trim();
+            if entry.is_empty() {
+                continue;
+            }
+            let (addr, prefix) = match entry.split_once('/') {
+                Some((addr, prefix)) => (addr, Some(prefix)),
+                None => (entry, None),
+            };
+            let ip: IpAddr = addr
+                .parse()
+                .map_err(|_| anyhow::anyhow!("invalid trusted proxy address: {entry}"))?;
+            let max = if ip.is_ipv4() { 32 } else { 128 };
+            let prefix = match prefix {
+                Some(p) => p
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|p| *p <= max)
+                    .ok_or_else(|| anyhow::anyhow!("invalid trusted proxy prefix: {entry}"))?,
+                None => max,
+            };
+            // IPv4 映射的网段（::ffff:a.b.c.d/96 及更长）换算为 IPv4 网段，与 canonical 后的地址比较
+            let net = match ip {
+                IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+                    Some(v4) if prefix >= 96 => (IpAddr::V4(v4), prefix - 96),
+                    Some(_) => anyhow::bail!(
+                        "invalid trusted proxy prefix: {entry} (IPv4-mapped networks need a prefix of at least 96)"
+                    ),
+                    None => (ip, prefix),
+                },
+                IpAddr::V4(_) => (ip, prefix),
+            };
+            nets.push(net);
+        }
+        Ok(Self { nets })
+    }
+
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = canonical(ip);
+        self.nets
+            .iter()
+            .any(|(net, prefix)| in_network(ip, *net, *prefix))
+    }
+
+    /// 根据对端地址与转发头推断客户端地址。
+    pub fn resolve(&self, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+        let peer = canonical(peer);
+        if !self.contains(peer) {
+            return peer;
+        }
+        // 多个 X-Forwarded-For 头按出现顺序拼接，最右侧为最近一跳
+        let hops: Vec<IpAddr> = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .filter_map(parse_hop)
+            .collect();
+        if hops.is_empty() {
+            return headers
+                .get("x-real-ip")
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_hop)
+                .unwrap_or(peer);
+        }
+        // 整条链都是受信代理时取最左侧地址
+        let mut client = peer;
+        for hop in hops.into_iter().rev() {
+            client = hop;
+            if !self.contains(hop) {
+                break;
+            }
+        }
+        client
+    }
+}
+
+/// 从请求扩展中取出连接对端并推断客户端地址。
+pub fn from_parts(
+    proxies: &TrustedProxies,
+    connect_info: Option<&ConnectInfo<SocketAddr>>,
+    headers: &HeaderMap,
+) -> ClientIp {
+    ClientIp(connect_info.map(|ConnectInfo(addr)| proxies.resolve(addr.ip(), headers)))
+}
+
+fn parse_hop(raw: &str) -> Option<IpAddr> {
+    let raw = raw.trim();
+    if let Ok(ip) = raw.parse::<IpAddr>() {
+        return Some(canonical(ip));
+    }
+    // 兼容带端口的写法，如 `1.2.3.4:5678` 或 `[::1]:5678`
+    raw.parse::<SocketAddr>().ok().map(|addr| canonical(addr.ip()))
+}
+
+// IPv4 映射的 IPv6 地址（::ffff:a.b.c.d）统一按 IPv4 比较
+fn canonical(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => v6
+            .to_ipv4_mapped()
+            .map(IpAddr::V4)
+            .unwrap_or(IpAddr::V6(v6)),
+        v4 => v4,
+    }
+}
+
+fn in_network(ip: IpAddr, net: IpAddr, prefix: u8) -> bool {
+    match (ip, net) {
+        (IpAddr::V4(ip), IpAddr::V4(net)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0);
+            u32::from(ip) & mask == u32::from(net) & mask
+        }
+        (IpAddr::V6(ip), IpAddr::V6(net)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+            u128::from(ip) & mask == u128::from(net) & mask
+        }
+        _ => false,
+    }
+}
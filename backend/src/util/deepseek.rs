@@ -22,7 +22,17 @@ pub struct DeepseekDecision {
     pub is_duplicate: bool,
     pub reason: Option<String>,
     pub confidence: Option<f32>,
-    pub _raw: String,
+    /// 模型原始输出，用于去重解释与排查
+    pub raw: String,
+}
+
+/// 大模型建议的过滤规则：排除 `field`（url/title）中包含 `pattern` 的条目
+#[derive(Debug, Clone, Deserialize)]
+pub struct FilterRule {
+    pub field: String,
+    pub pattern: String,
+    #[serde(default)]
+    pub reason: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -131,7 +141,7 @@ impl DeepseekClient {
             format!("failed to parse deepseek decision from content: {content}")
         })?;
 
-        decision._raw = content;
+        decision.raw = content;
         Ok(decision)
     }
 
@@ -198,6 +208,80 @@ impl DeepseekClient {
 
         parse_translation(&content)
     }
+
+    pub async fn summarize(&self, title: &str, content: &str) -> Result<String> {
+        let content = self
+            .chat(SUMMARY_PROMPT, build_summary_input(title, content), "summary")
+            .await?;
+        parse_summary(&content)
+    }
+
+    pub async fn suggest_filters(&self, samples: &[ArticleSnippet<'_>]) -> Result<Vec<FilterRule>> {
+        let content = self
+            .chat(FILTER_SUGGESTION_PROMPT, build_filter_suggestion_input(samples), "filter suggestion")
+            .await?;
+        parse_filter_rules(&content)
+    }
+
+    /// 单轮对话：返回模型输出的原始文本，由各能力自行解析
+    pub(crate) async fn chat(&self, system_prompt: &str, input: String, purpose: &str) -> Result<String> {
+        let api_key = self
+            .config
+            .api_key
+            .as_deref()
+            .ok_or_else(|| anyhow!("deepseek api key missing"))?;
+
+        let base = self.config.base_url.trim_end_matches('/');
+        let url = format!("{base}/v1/chat/completions");
+
+        let body = ChatCompletionRequest {
+            model: &self.config.model,
+            messages: vec![
+                ChatMessage {
+                    role: "system",
+                    content: system_prompt.to_string(),
+                },
+                ChatMessage {
+                    role: "user",
+                    content: input,
+                },
+            ],
+            temperature: 0.2,
+        };
+
+        let response = self
+            .http
+            .post(&url)
+            .header(header::AUTHORIZATION, format!("Bearer {api_key}"))
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&body)
+            .send()
+            .await
+            .with_context(|| format!("deepseek {purpose} request failed"))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(anyhow!(
+                "deepseek {} returned non-success status {}: {}",
+                purpose,
+                status,
+                text
+            ));
+        }
+
+        let payload: ChatCompletionResponse = response
+            .json()
+            .await
+            .with_context(|| format!("failed to parse deepseek {purpose} response"))?;
+
+        payload
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|choice| choice.message.content)
+            .ok_or_else(|| anyhow!("deepseek {purpose} response missing message content"))
+    }
 }
 
 fn base_url_from_config(config: &DeepseekConfig) -> &str {
@@ -257,7 +341,7 @@ pub fn parse_decision(content: &str) -> Result<DeepseekDecision> {
         is_duplicate: payload.is_duplicate,
         reason: payload.reason,
         confidence: payload.confidence,
-        _raw: String::new(),
+        raw: String::new(),
     })
 }
 
@@ -291,7 +375,14 @@ struct ChatCompletionMessage {
 
 const SYSTEM_PROMPT: &str = "你是一名资深的新闻比对助手，需要判断两条新闻是否描述同一事件。输出必须是 JSON，字段 is_duplicate、reason、confidence。";
 
-pub(crate) const TRANSLATION_PROMPT: &str = "你是一名专业的财经翻译。\n\n严格要求：\n- 将输入的英文新闻标题与摘要翻译为自然、准确的简体中文。\n- 输出必须为 JSON，且仅包含两个字段：{\"title\": string, \"description\": string|null}。\n- 当提供了非空摘要时，\"description\" 必须返回非空的中文摘要（1-3 句，简洁、忠实，不添加观点）。严禁返回空字符串或省略该字段。\n- 若未提供摘要或原摘要为空，则将 \"description\" 设置为 null。\n- 不得输出除上述 JSON 之外的任何多余字符（包括解释、前后缀、Markdown 代码块标记等）。";
+pub(crate) const TRANSLATION_PROMPT: &str = "你是一名专业的财经翻译。\n\n严格要求：\n- 将输入的外文（英文、日文、韩文等）新闻标题与摘要翻译为自然、准确的简体中文。\n- 输出必须为 JSON，且仅包含两个字段：{\"title\": string, \"description\": string|null}。\n- 当提供了非空摘要时，\"description\" 必须返回非空的中文摘要（1-3 句，简洁、忠实，不添加观点）。严禁返回空字符串或省略该字段。\n- 若未提供摘要或原摘要为空，则将 \"description\" 设置为 null。\n- 不得输出除上述 JSON 之外的任何多余字符（包括解释、前后缀、Markdown 代码块标记等）。";
+
+/// 按需翻译的提示词：与 `TRANSLATION_PROMPT` 输出格式相同，目标语言由调用方指定
+pub(crate) fn translation_prompt_for(target: &str) -> String {
+    format!(
+        "你是一名专业的新闻翻译。\n\n严格要求：\n- 将输入的新闻标题与摘要翻译为自然、准确的{target}。\n- 输出必须为 JSON，且仅包含两个字段：{{\"title\": string, \"description\": string|null}}。\n- 当提供了非空摘要时，\"description\" 必须返回非空的译文（简洁、忠实，不添加观点）。\n- 若未提供摘要或原摘要为空，则将 \"description\" 设置为 null。\n- 不得输出除上述 JSON 之外的任何多余字符（包括解释、前后缀、Markdown 代码块标记等）。"
+    )
+}
 
 pub(crate) fn build_translation_input(title: &str, description: Option<&str>) -> String {
     let mut lines = vec![format!("Title: {title}")];
@@ -335,3 +426,58 @@ pub(crate) fn parse_translation(content: &str) -> Result<TranslationResult> {
         description,
     })
 }
+
+pub(crate) const SUMMARY_PROMPT: &str = "你是一名新闻编辑。请将输入的新闻标题与正文概括为 1-3 句简体中文摘要，忠实原文、不添加观点。仅输出摘要文本，不要包含任何解释或 Markdown 标记。";
+
+pub(crate) fn build_summary_input(title: &str, content: &str) -> String {
+    format!("Title: {title}\nContent: {content}")
+}
+
+pub(crate) fn parse_summary(content: &str) -> Result<String> {
+    let summary = content
+        .trim()
+        .trim_start_matches("```")
+        .trim_end_matches("```")
+        .trim();
+    if summary.is_empty() {
+        return Err(anyhow!("summary response is empty"));
+    }
+    Ok(summary.to_string())
+}
+
+pub(crate) const FILTER_SUGGESTION_PROMPT: &str = "你是一名新闻订阅源的内容编辑。输入是某个订阅源近期的条目（标题与链接）。请找出明显不属于新闻正文、值得排除的条目模式（如体育/娱乐频道路径、直播回顾、广告、专题汇总等），给出最多 5 条排除规则。输出必须为 JSON：{\"rules\": [{\"field\": \"url\" 或 \"title\", \"pattern\": \"要匹配的子串\", \"reason\": \"简要原因\"}]}；pattern 必须是样本中实际出现的子串；没有合适规则时输出 {\"rules\": []}。不要输出 JSON 之外的任何内容。";
+
+pub(crate) fn build_filter_suggestion_input(samples: &[ArticleSnippet<'_>]) -> String {
+    samples
+        .iter()
+        .enumerate()
+        .map(|(idx, sample)| {
+            format!(
+                "{}. 标题: {} | 链接: {}",
+                idx + 1,
+                sample.title,
+                sample.url.unwrap_or("")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub(crate) fn parse_filter_rules(content: &str) -> Result<Vec<FilterRule>> {
+    #[derive(Deserialize)]
+    struct RulesPayload {
+        #[serde(default)]
+        rules: Vec<FilterRule>,
+    }
+
+    let cleaned = content.trim();
+    let json_str = cleaned
+        .trim_start_matches("```json")
+        .trim_start_matches("```")
+        .trim_end_matches("```")
+        .trim();
+
+    let payload: RulesPayload =
+        serde_json::from_str(json_str).or_else(|_| serde_json::from_str(cleaned))?;
+    Ok(payload.rules)
+}
@@ -0,0 +1,150 @@
+//! Per-feed date parsing overrides for feeds that emit naive or wrongly-zoned timestamps.
+
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, FixedOffset, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc,
+};
+use feed_rs::{model::Feed, parser};
+
+// Formats tried when no custom format is set (or it does not match); all carry no zone.
+const NAIVE_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+    "%Y/%m/%d %H:%M:%S",
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct DateOverrides {
+    /// Zone the wall-clock time is interpreted in, replacing whatever the feed declares.
+    pub timezone: Option<FixedOffset>,
+    /// chrono strftime format tried before the standard formats.
+    pub format: Option<String>,
+}
+
+impl DateOverrides {
+    /// Build from stored feed columns; invalid values were rejected on save and are ignored here.
+    pub fn from_columns(timezone: Option<&str>, format: Option<&str>) -> Self {
+        Self {
+            timezone: timezone.and_then(|raw| parse_timezone(raw).ok()),
+            format: format
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timezone.is_none() && self.format.is_none()
+    }
+
+    /// Parse a raw feed timestamp. A timezone override re-reads the wall-clock time in that zone
+    /// even when the feed declares an offset; naive timestamps default to UTC.
+    pub fn parse(&self, raw: &str) -> Option<DateTime<Utc>> {
+        let raw = raw.trim();
+        let (local, declared) = self
+            .format
+            .as_deref()
+            .and_then(|format| parse_with_format(raw, format))
+            .or_else(|| parse_standard(raw))?;
+        let offset = self.timezone.or(declared).unwrap_or_else(|| Utc.fix());
+        offset
+            .from_local_datetime(&local)
+            .single()
+            .map(|dt| dt.with_timezone(&Utc))
+    }
+}
+
+/// Parse feed bytes, plugging in the overrides when any are set.
+pub fn parse_feed(bytes: &[u8], overrides: &DateOverrides) -> Result<Feed, parser::ParseFeedError> {
+    if overrides.is_empty() {
+        return parser::parse(bytes);
+    }
+    let overrides = overrides.clone();
+    parser::Builder::new()
+        .timestamp_parser(move |raw| overrides.parse(raw))
+        .build()
+        .parse(bytes)
+}
+
+/// Accepts `UTC`/`Z` or a fixed offset such as `+08:00`, `-0500`, `+8`; returns it normalized.
+pub fn parse_timezone(raw: &str) -> Result<FixedOffset, String> {
+    let raw = raw.trim();
+    if raw.eq_ignore_ascii_case("utc") || raw.eq_ignore_ascii_case("z") || raw.eq_ignore_ascii_case("gmt") {
+        return Ok(Utc.fix());
+    }
+    let invalid = || format!("时区需为 UTC 或 +08:00 形式的固定偏移：{raw}");
+    let (sign, rest) = match raw.as_bytes().first() {
+        Some(b'+') => (1, &raw[1..]),
+        Some(b'-') => (-1, &raw[1..]),
+        _ => return Err(invalid()),
+    };
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if rest.len() == 4 => rest.split_at(2),
+        None => (rest, "0"),
+    };
+    let hours: i32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+    if !(0..=14).contains(&hours) || !(0..60).contains(&minutes) {
+        return Err(invalid());
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(invalid)
+}
+
+pub fn format_timezone(offset: FixedOffset) -> String {
+    let seconds = offset.local_minus_utc();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.abs() / 60;
+    format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// Reject formats chrono cannot interpret or that carry no date at all.
+pub fn validate_format(format: &str) -> Result<(), String> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(format!("日期格式无效：{format}"));
+    }
+    if !format.contains("%Y") && !format.contains("%F") && !format.contains("%s") {
+        return Err("日期格式需包含年份（%Y / %F）或时间戳（%s）".into());
+    }
+    Ok(())
+}
+
+fn parse_with_format(raw: &str, format: &str) -> Option<(NaiveDateTime, Option<FixedOffset>)> {
+    if let Ok(dt) = DateTime::parse_from_str(raw, format) {
+        return Some((dt.naive_local(), Some(*dt.offset())));
+    }
+    if let Ok(local) = NaiveDateTime::parse_from_str(raw, format) {
+        return Some((local, None));
+    }
+    NaiveDate::parse_from_str(raw, format)
+        .ok()
+        .and_then(|day| day.and_hms_opt(0, 0, 0))
+        .map(|local| (local, None))
+}
+
+fn parse_standard(raw: &str) -> Option<(NaiveDateTime, Option<FixedOffset>)> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(raw) {
+        return Some((dt.naive_local(), Some(*dt.offset())));
+    }
+    // RFC 2822 with the common deviations: long weekday names and a `UTC` suffix
+    let rfc2822 = raw
+        .split_once(", ")
+        .filter(|(weekday, _)| weekday.chars().all(|c| c.is_ascii_alphabetic()))
+        .map_or(raw, |(_, rest)| rest)
+        .replace("UTC", "+0000");
+    if let Ok(dt) = DateTime::parse_from_rfc2822(&rfc2822) {
+        return Some((dt.naive_local(), Some(*dt.offset())));
+    }
+    if let Some(local) = NAIVE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(raw, format).ok())
+    {
+        return Some((local, None));
+    }
+    NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .ok()
+        .and_then(|day| day.and_hms_opt(0, 0, 0))
+        .map(|local| (local, None))
+}
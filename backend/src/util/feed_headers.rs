@@ -0,0 +1,111 @@
+//! Per-feed HTTP request headers, e.g. an API token or a custom `User-Agent`.
+
+use std::collections::BTreeMap;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::util::translator::mask_secret;
+
+const MAX_HEADERS: usize = 20;
+const MAX_VALUE_LEN: usize = 2048;
+// Managed by the client or the fetcher's conditional requests; overriding them breaks fetching.
+const RESERVED: &[&str] = &[
+    "host",
+    "content-length",
+    "transfer-encoding",
+    "connection",
+    "if-none-match",
+    "if-modified-since",
+];
+// Header names containing these fragments carry credentials and are masked in API responses.
+const SECRET_MARKERS: &[&str] = &["authorization", "token", "key", "secret", "cookie", "password"];
+
+pub type RequestHeaders = BTreeMap<String, String>;
+
+/// Validate submitted headers; names are lowercased, values trimmed.
+pub fn normalize(headers: RequestHeaders) -> Result<RequestHeaders, String> {
+    if headers.len() > MAX_HEADERS {
+        return Err(format!("请求头最多 {MAX_HEADERS} 个"));
+    }
+    let mut normalized = RequestHeaders::new();
+    for (name, value) in headers {
+        let name = name.trim().to_ascii_lowercase();
+        let value = value.trim().to_string();
+        if HeaderName::from_bytes(name.as_bytes()).is_err() {
+            return Err(format!("请求头名称无效：{name}"));
+        }
+        if RESERVED.contains(&name.as_str()) {
+            return Err(format!("请求头 {name} 由抓取器管理，不能自定义"));
+        }
+        if value.len() > MAX_VALUE_LEN || HeaderValue::from_str(&value).is_err() {
+            return Err(format!("请求头 {name} 的值无效"));
+        }
+        if normalized.insert(name.clone(), value).is_some() {
+            return Err(format!("请求头 {name} 重复"));
+        }
+    }
+    Ok(normalized)
+}
+
+/// Parse the stored JSONB text; invalid values were rejected on save and are ignored here.
+pub fn from_column(raw: Option<&str>) -> RequestHeaders {
+    raw.and_then(|raw| serde_json::from_str(raw).ok())
+        .unwrap_or_default()
+}
+
+/// Serialize for storage; an empty map clears the column.
+pub fn to_column(headers: &RequestHeaders) -> String {
+    if headers.is_empty() {
+        return String::new();
+    }
+    serde_json::to_string(headers).unwrap_or_default()
+}
+
+pub fn header_map(headers: &RequestHeaders) -> HeaderMap {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            Some((
+                HeaderName::from_bytes(name.as_bytes()).ok()?,
+                HeaderValue::from_str(value).ok()?,
+            ))
+        })
+        .collect()
+}
+
+pub fn is_secret(name: &str) -> bool {
+    SECRET_MARKERS.iter().any(|marker| name.contains(marker))
+}
+
+/// Copy for API responses with credential values masked.
+pub fn masked(headers: &RequestHeaders) -> RequestHeaders {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let shown = if is_secret(name) {
+                mask_secret(value)
+            } else {
+                value.clone()
+            };
+            (name.clone(), shown)
+        })
+        .collect()
+}
+
+/// Editing forms send back the masked values they were given; restore the stored secret for those.
+/// Runs before `normalize`, which would reject the mask characters.
+pub fn unmask(submitted: RequestHeaders, stored: &RequestHeaders) -> RequestHeaders {
+    submitted
+        .into_iter()
+        .map(|(name, value)| {
+            let key = name.trim().to_ascii_lowercase();
+            let value = match stored.get(&key) {
+                Some(original) if is_secret(&key) && value.trim() == mask_secret(original) => {
+                    original.clone()
+                }
+                _ => value,
+            };
+            (name, value)
+        })
+        .collect()
+}
@@ -0,0 +1,140 @@
+//! 订阅源清单解析与平台适配：把 GitHub 仓库、X（Twitter）列表等本身不是订阅源的地址映射为可抓取的订阅源。
+
+use std::collections::HashSet;
+
+use url::Url;
+
+/// 清单中的一行：来源与可选的标题
+#[derive(Debug, Clone)]
+pub struct SourceEntry {
+    pub source: String,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceTarget {
+    /// GitHub 仓库，订阅其 `releases.atom`
+    GithubReleases { owner: String, repo: String },
+    /// X 列表，官方不提供订阅源，需经配置的 RSS 桥接服务转换
+    XList { list_id: String },
+    /// 其他 http(s) 地址：订阅源本身，或需要自动发现订阅源的网站
+    Url(String),
+}
+
+impl SourceTarget {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SourceTarget::GithubReleases { .. } => "github_releases",
+            SourceTarget::XList { .. } => "x_list",
+            SourceTarget::Url(_) => "url",
+        }
+    }
+}
+
+/// 每行一项 `<来源> [标题]`，来源与标题以空白分隔；空行与 `#` 开头的注释行忽略，按来源去重。
+pub fn parse_source_list(text: &str) -> Vec<SourceEntry> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (source, title) = match line.split_once(char::is_whitespace) {
+            Some((source, title)) => (source, Some(title.trim())),
+            None => (line, None),
+        };
+        if seen.insert(source.to_string()) {
+            out.push(SourceEntry {
+                source: source.to_string(),
+                title: title.filter(|t| !t.is_empty()).map(str::to_string),
+            });
+        }
+    }
+    out
+}
+
+// github.com 下不是用户或组织名的一级路径
+const GITHUB_RESERVED_OWNERS: &[&str] = &[
+    "orgs", "topics", "collections", "explore", "settings", "marketplace", "sponsors", "features",
+    "about", "login", "search", "trending",
+];
+
+/// 识别来源：`github:<owner>/<repo>`、`https://github.com/<owner>/<repo>[/...]`、
+/// `https://x.com/i/lists/<id>`（含 twitter.com），其余 http(s) 地址原样返回；地址可省略协议（默认 https）。
+pub fn classify(source: &str) -> Result<SourceTarget, String> {
+    let source = source.trim();
+    if let Some(rest) = source.strip_prefix("github:") {
+        let (owner, repo) = rest
+            .split_once('/')
+            .ok_or_else(|| "GitHub 仓库应写作 github:<owner>/<repo>".to_string())?;
+        return github_repo(owner, repo);
+    }
+
+    let raw = if source.contains("://") {
+        source.to_string()
+    } else {
+        format!("https://{source}")
+    };
+    let url = Url::parse(&raw).map_err(|err| format!("地址无效: {err}"))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err("仅支持 http/https 地址".to_string());
+    }
+    let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    let segments: Vec<&str> = url
+        .path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+
+    match host {
+        "github.com" => match segments.as_slice() {
+            [owner, repo, ..] if !GITHUB_RESERVED_OWNERS.contains(owner) => github_repo(owner, repo),
+            _ => Ok(SourceTarget::Url(url.to_string())),
+        },
+        "x.com" | "twitter.com" | "mobile.twitter.com" | "mobile.x.com" => match segments.as_slice() {
+            ["i", "lists", id, ..] if id.chars().all(|c| c.is_ascii_digit()) => Ok(SourceTarget::XList {
+                list_id: id.to_string(),
+            }),
+            _ => Err("X 上仅支持列表地址（https://x.com/i/lists/<id>）".to_string()),
+        },
+        _ => Ok(SourceTarget::Url(url.to_string())),
+    }
+}
+
+fn github_repo(owner: &str, repo: &str) -> Result<SourceTarget, String> {
+    let repo = repo.trim_end_matches('/');
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+    let valid = |name: &str| {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+    if !valid(owner) || !valid(repo) {
+        return Err(format!("GitHub 仓库名无效: {owner}/{repo}"));
+    }
+    Ok(SourceTarget::GithubReleases {
+        owner: owner.to_string(),
+        repo: repo.to_string(),
+    })
+}
+
+pub fn github_releases_feed_url(owner: &str, repo: &str) -> String {
+    format!("https://github.com/{owner}/{repo}/releases.atom")
+}
+
+pub fn github_repo_url(owner: &str, repo: &str) -> String {
+    format!("https://github.com/{owner}/{repo}")
+}
+
+pub fn x_list_url(list_id: &str) -> String {
+    format!("https://x.com/i/lists/{list_id}")
+}
+
+/// 按桥接模板生成 X 列表的订阅源地址，模板须包含 `{list_id}` 占位符，例如 `https://rsshub.app/twitter/list/{list_id}`
+pub fn x_list_feed_url(template: &str, list_id: &str) -> Option<String> {
+    template
+        .contains("{list_id}")
+        .then(|| template.replace("{list_id}", list_id))
+}
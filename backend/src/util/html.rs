@@ -59,3 +59,59 @@ pub fn strip_html_basic(input: &str) -> String {
     collapsed.trim().to_string()
 }
 
+
+/// Escape text for embedding in HTML/XML element content or attribute values.
+pub fn escape_xml(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for ch in input.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Marker appended to text cut by [`truncate_summary`].
+const ELLIPSIS: char = '…';
+
+/// Shorten a feed summary to at most `max_chars` characters (ellipsis included).
+///
+/// The cut prefers the last sentence end (`。！？；` or `.!?` followed by whitespace) in the second
+/// half of the allowed length, then the last whitespace for space-separated scripts, and falls
+/// back to a plain character cut for unbroken CJK text. Returns `None` when the text already fits.
+pub fn truncate_summary(text: &str, max_chars: usize) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() <= max_chars {
+        return None;
+    }
+    let budget = max_chars.saturating_sub(1);
+    let window = &chars[..budget];
+    let min_keep = budget / 2;
+
+    let sentence_end = (min_keep..budget).rev().find(|&i| match window[i] {
+        '。' | '！' | '？' | '；' | '…' => true,
+        '.' | '!' | '?' => chars[i + 1].is_whitespace(),
+        _ => false,
+    });
+    let cut = match sentence_end {
+        Some(i) => i + 1,
+        None => (min_keep..budget)
+            .rev()
+            .find(|&i| chars[i].is_whitespace())
+            .unwrap_or(budget),
+    };
+
+    let mut out: String = chars[..cut].iter().collect();
+    // Dangling separators read badly before the marker
+    let trimmed_len = out
+        .trim_end_matches(|ch: char| ch.is_whitespace() || matches!(ch, ',' | '，' | '、' | ':' | '：' | ';' | '-' | '—'))
+        .len();
+    out.truncate(trimmed_len);
+    out.push(ELLIPSIS);
+    Some(out)
+}
@@ -0,0 +1,87 @@
+/// Languages the detector can report; also the accepted values of `?original_language=`.
+pub const SUPPORTED_LANGUAGES: &[&str] = &["en", "zh", "ja", "ko"];
+
+/// Lightweight script-based language detection for titles/summaries.
+/// - Any Hiragana/Katakana → `ja`
+/// - Otherwise any Hangul → `ko`
+/// - Otherwise any CJK ideograph → `zh`
+/// - Otherwise ASCII letters dominate (>= 60%) → `en`
+///
+/// Returns `None` when the text has no letters or is dominated by other scripts.
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    let mut ascii_letters = 0usize;
+    let mut other_letters = 0usize;
+    let mut has_cjk = false;
+    let mut has_hangul = false;
+
+    for ch in text.chars() {
+        if is_kana(ch) {
+            return Some("ja");
+        }
+        if is_hangul(ch) {
+            has_hangul = true;
+        } else if is_cjk(ch) {
+            has_cjk = true;
+        } else if ch.is_ascii_alphabetic() {
+            ascii_letters += 1;
+        } else if ch.is_alphabetic() {
+            other_letters += 1;
+        }
+    }
+
+    if has_hangul {
+        return Some("ko");
+    }
+    if has_cjk {
+        return Some("zh");
+    }
+
+    let total = ascii_letters + other_letters;
+    if total == 0 || ascii_letters == 0 {
+        return None;
+    }
+    if ascii_letters as f32 / total as f32 >= 0.6 {
+        Some("en")
+    } else {
+        None
+    }
+}
+
+/// Map a feed-declared language tag (e.g. `en-US`, `zh_CN`) to a supported code.
+pub fn normalize_language_tag(tag: &str) -> Option<&'static str> {
+    let primary = tag
+        .trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    SUPPORTED_LANGUAGES
+        .iter()
+        .copied()
+        .find(|code| *code == primary)
+}
+
+fn is_cjk(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{4E00}'..='\u{9FFF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{20000}'..='\u{2A6DF}'
+            | '\u{2A700}'..='\u{2B73F}'
+            | '\u{2B740}'..='\u{2B81F}'
+            | '\u{2B820}'..='\u{2CEAF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{2F800}'..='\u{2FA1F}'
+    )
+}
+
+fn is_kana(ch: char) -> bool {
+    matches!(ch, '\u{3040}'..='\u{309F}' | '\u{30A0}'..='\u{30FF}')
+}
+
+fn is_hangul(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{AC00}'..='\u{D7AF}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}'
+    )
+}
@@ -0,0 +1,130 @@
+//! 大模型客户端抽象：去重判定、翻译、摘要、过滤规则建议等能力统一走 `LlmClient`，
+//! 新增 provider 只需实现该 trait，调用方无需按 provider 名称分支。
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::future::BoxFuture;
+
+use super::{
+    deepseek::{ArticleSnippet, DeepseekClient, DeepseekDecision, FilterRule, TranslationResult},
+    ollama::OllamaClient,
+    translator::TranslatorProvider,
+};
+
+pub trait LlmClient: Send + Sync {
+    fn provider(&self) -> TranslatorProvider;
+
+    fn judge_similarity<'a>(
+        &'a self,
+        a: &'a ArticleSnippet<'_>,
+        b: &'a ArticleSnippet<'_>,
+    ) -> BoxFuture<'a, Result<DeepseekDecision>>;
+
+    fn translate_news<'a>(
+        &'a self,
+        title: &'a str,
+        description: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<TranslationResult>>;
+
+    fn summarize<'a>(&'a self, title: &'a str, content: &'a str) -> BoxFuture<'a, Result<String>>;
+
+    fn suggest_filters<'a>(
+        &'a self,
+        samples: &'a [ArticleSnippet<'_>],
+    ) -> BoxFuture<'a, Result<Vec<FilterRule>>>;
+
+    /// 自定义提示词的单轮对话，返回模型输出的原始文本；供增强流水线等没有专用方法的能力使用
+    fn complete<'a>(
+        &'a self,
+        system_prompt: &'a str,
+        input: String,
+        purpose: &'a str,
+    ) -> BoxFuture<'a, Result<String>>;
+}
+
+pub type SharedLlmClient = Arc<dyn LlmClient>;
+
+impl LlmClient for DeepseekClient {
+    fn provider(&self) -> TranslatorProvider {
+        TranslatorProvider::Deepseek
+    }
+
+    fn judge_similarity<'a>(
+        &'a self,
+        a: &'a ArticleSnippet<'_>,
+        b: &'a ArticleSnippet<'_>,
+    ) -> BoxFuture<'a, Result<DeepseekDecision>> {
+        Box::pin(DeepseekClient::judge_similarity(self, a, b))
+    }
+
+    fn translate_news<'a>(
+        &'a self,
+        title: &'a str,
+        description: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<TranslationResult>> {
+        Box::pin(DeepseekClient::translate_news(self, title, description))
+    }
+
+    fn summarize<'a>(&'a self, title: &'a str, content: &'a str) -> BoxFuture<'a, Result<String>> {
+        Box::pin(DeepseekClient::summarize(self, title, content))
+    }
+
+    fn suggest_filters<'a>(
+        &'a self,
+        samples: &'a [ArticleSnippet<'_>],
+    ) -> BoxFuture<'a, Result<Vec<FilterRule>>> {
+        Box::pin(DeepseekClient::suggest_filters(self, samples))
+    }
+
+    fn complete<'a>(
+        &'a self,
+        system_prompt: &'a str,
+        input: String,
+        purpose: &'a str,
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(DeepseekClient::chat(self, system_prompt, input, purpose))
+    }
+}
+
+impl LlmClient for OllamaClient {
+    fn provider(&self) -> TranslatorProvider {
+        TranslatorProvider::Ollama
+    }
+
+    fn judge_similarity<'a>(
+        &'a self,
+        a: &'a ArticleSnippet<'_>,
+        b: &'a ArticleSnippet<'_>,
+    ) -> BoxFuture<'a, Result<DeepseekDecision>> {
+        Box::pin(OllamaClient::judge_similarity(self, a, b))
+    }
+
+    fn translate_news<'a>(
+        &'a self,
+        title: &'a str,
+        description: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<TranslationResult>> {
+        Box::pin(OllamaClient::translate_news(self, title, description))
+    }
+
+    fn summarize<'a>(&'a self, title: &'a str, content: &'a str) -> BoxFuture<'a, Result<String>> {
+        Box::pin(OllamaClient::summarize(self, title, content))
+    }
+
+    fn suggest_filters<'a>(
+        &'a self,
+        samples: &'a [ArticleSnippet<'_>],
+    ) -> BoxFuture<'a, Result<Vec<FilterRule>>> {
+        Box::pin(OllamaClient::suggest_filters(self, samples))
+    }
+
+    fn complete<'a>(
+        &'a self,
+        system_prompt: &'a str,
+        input: String,
+        purpose: &'a str,
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(OllamaClient::chat(self, system_prompt, input, purpose))
+    }
+}
@@ -0,0 +1,83 @@
+//! SMTP 邮件发送（每周精选等通知），配置见 `digest.smtp`。
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use lettre::{
+    message::{Mailbox, MultiPart},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+
+use crate::config::SmtpConfig;
+
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct Mailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+}
+
+impl Mailer {
+    /// 校验配置并构建连接；配置有误时启动即失败，而不是等到第一次发信
+    pub fn new(config: &SmtpConfig) -> anyhow::Result<Self> {
+        let host = config.host.trim();
+        if host.is_empty() {
+            return Err(anyhow!("digest.smtp.host is required"));
+        }
+        let builder = match config.security.trim().to_ascii_lowercase().as_str() {
+            "starttls" | "" => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+                .with_context(|| format!("invalid smtp host: {host}"))?,
+            "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(host)
+                .with_context(|| format!("invalid smtp host: {host}"))?,
+            "none" => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host),
+            other => return Err(anyhow!("unsupported digest.smtp.security: {other}")),
+        };
+        let mut builder = builder.port(config.port).timeout(Some(SMTP_TIMEOUT));
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        let from = config
+            .from
+            .parse::<Mailbox>()
+            .with_context(|| format!("invalid digest.smtp.from: {}", config.from))?;
+        let to = config
+            .to
+            .iter()
+            .map(|addr| {
+                addr.parse::<Mailbox>()
+                    .with_context(|| format!("invalid digest.smtp.to entry: {addr}"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        if to.is_empty() {
+            return Err(anyhow!("digest.smtp.to must contain at least one recipient"));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+            to,
+        })
+    }
+
+    pub fn recipient_count(&self) -> usize {
+        self.to.len()
+    }
+
+    pub async fn send(&self, subject: &str, text: String, html: String) -> anyhow::Result<()> {
+        let mut builder = Message::builder().from(self.from.clone()).subject(subject);
+        for to in &self.to {
+            builder = builder.to(to.clone());
+        }
+        let message = builder
+            .multipart(MultiPart::alternative_plain_html(text, html))
+            .context("failed to build email")?;
+        self.transport
+            .send(message)
+            .await
+            .context("smtp send failed")?;
+        Ok(())
+    }
+}
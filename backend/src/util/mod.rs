@@ -5,4 +5,22 @@ pub mod ollama;
 pub mod title;
 pub mod translator;
 pub mod url_norm;
+pub mod bookmarks;
+pub mod cache;
+pub mod client_ip;
+pub mod feed_dates;
+pub mod feed_headers;
+pub mod feed_sources;
 pub mod html;
+pub mod lang;
+pub mod llm;
+pub mod mailer;
+pub mod password;
+pub mod qdrant;
+pub mod rate_limit;
+pub mod readability;
+pub mod reader_id;
+pub mod robots;
+pub mod schedule;
+pub mod signed_url;
+pub mod translation_rules;
@@ -8,8 +8,10 @@ use url::Url;
 use crate::config::HttpClientConfig;
 
 use super::deepseek::{
-    build_prompt, build_translation_input, parse_decision, parse_translation, DeepseekDecision,
-    TranslationResult, TRANSLATION_PROMPT,
+    build_filter_suggestion_input, build_prompt, build_summary_input, build_translation_input,
+    parse_decision, parse_filter_rules, parse_summary, parse_translation, ArticleSnippet,
+    DeepseekDecision, FilterRule, TranslationResult, FILTER_SUGGESTION_PROMPT, SUMMARY_PROMPT,
+    TRANSLATION_PROMPT,
 };
 
 pub struct OllamaClient {
@@ -159,8 +161,126 @@ impl OllamaClient {
             .context("failed to read ollama similarity response")?;
 
         let content = extract_content(&text).unwrap_or_else(|| text.clone());
-        parse_decision(&content).context("failed to parse ollama similarity payload: ensure输出 JSON")
+        let mut decision = parse_decision(&content)
+            .context("failed to parse ollama similarity payload: ensure输出 JSON")?;
+        decision.raw = content;
+        Ok(decision)
     }
+
+    pub async fn summarize(&self, title: &str, content: &str) -> Result<String> {
+        let content = self
+            .chat(SUMMARY_PROMPT, build_summary_input(title, content), "summary")
+            .await?;
+        parse_summary(&content)
+    }
+
+    pub async fn suggest_filters(&self, samples: &[ArticleSnippet<'_>]) -> Result<Vec<FilterRule>> {
+        let content = self
+            .chat(FILTER_SUGGESTION_PROMPT, build_filter_suggestion_input(samples), "filter suggestion")
+            .await?;
+        parse_filter_rules(&content)
+    }
+
+    /// 单轮对话：返回模型输出的原始文本，由各能力自行解析
+    pub(crate) async fn chat(&self, system_prompt: &str, input: String, purpose: &str) -> Result<String> {
+        if self.base_url.is_empty() {
+            return Err(anyhow!("ollama base url not configured"));
+        }
+
+        let url = format!("{}/api/chat", self.base_url);
+        let payload = ChatRequest {
+            model: self.model.clone(),
+            messages: vec![
+                ChatMessage {
+                    role: "system",
+                    content: system_prompt.to_string(),
+                },
+                ChatMessage {
+                    role: "user",
+                    content: input,
+                },
+            ],
+            stream: false,
+        };
+
+        let response = self
+            .http
+            .post(&url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&payload)
+            .send()
+            .await
+            .with_context(|| format!("ollama {purpose} request failed"))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!(
+                "ollama {} returned non-success status {}: {}",
+                purpose,
+                status,
+                body
+            ));
+        }
+
+        let text = response
+            .text()
+            .await
+            .with_context(|| format!("failed to read ollama {purpose} response"))?;
+
+        Ok(extract_content(&text).unwrap_or(text))
+    }
+
+    /// 文本向量：embedding 模型通常与对话模型不同，由调用方指定
+    pub async fn embed(&self, model: &str, input: &str) -> Result<Vec<f32>> {
+        if self.base_url.is_empty() {
+            return Err(anyhow!("ollama base url not configured"));
+        }
+
+        let url = format!("{}/api/embed", self.base_url);
+        let payload = EmbedRequest { model, input };
+        let response = self
+            .http
+            .post(&url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&payload)
+            .send()
+            .await
+            .context("ollama embedding request failed")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!(
+                "ollama embedding returned non-success status {}: {}",
+                status,
+                body
+            ));
+        }
+
+        let parsed: EmbedResponse = response
+            .json()
+            .await
+            .context("failed to parse ollama embedding response")?;
+        parsed
+            .embeddings
+            .into_iter()
+            .next()
+            .filter(|vector| !vector.is_empty())
+            .ok_or_else(|| anyhow!("ollama embedding response contains no vector"))
+    }
+}
+
+#[derive(Serialize)]
+struct EmbedRequest<'a> {
+    model: &'a str,
+    input: &'a str,
+}
+
+#[derive(Deserialize)]
+struct EmbedResponse {
+    #[serde(default)]
+    embeddings: Vec<Vec<f32>>,
 }
 
 #[derive(Serialize)]
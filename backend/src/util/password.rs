@@ -0,0 +1,49 @@
+//! Password hashing for admin accounts stored in `news.users`.
+//!
+//! Hashes are argon2id PHC strings (`$argon2id$v=19$...`), so parameters and
+//! salt travel with the hash and can be tightened later without a migration.
+//! Hashing is deliberately slow, so both helpers run on the blocking pool.
+
+use anyhow::Context;
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use uuid::Uuid;
+
+/// Hash a password with a fresh random salt.
+pub async fn hash_password(password: &str) -> anyhow::Result<String> {
+    let password = password.to_string();
+    tokio::task::spawn_blocking(move || {
+        let salt = SaltString::encode_b64(Uuid::new_v4().as_bytes())
+            .map_err(|err| anyhow::anyhow!("failed to encode password salt: {err}"))?;
+        let hash = Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .map_err(|err| anyhow::anyhow!("failed to hash password: {err}"))?;
+        Ok(hash.to_string())
+    })
+    .await
+    .context("password hashing task failed")?
+}
+
+/// Whether a string parses as a PHC hash, used to reject malformed configured hashes at startup.
+pub fn is_phc_hash(hash: &str) -> bool {
+    PasswordHash::new(hash).is_ok()
+}
+
+/// Check a password against a stored PHC string; malformed hashes never match.
+pub async fn verify_password(password: &str, hash: &str) -> bool {
+    let password = password.to_string();
+    let hash = hash.to_string();
+    tokio::task::spawn_blocking(move || {
+        PasswordHash::new(&hash)
+            .map(|parsed| {
+                Argon2::default()
+                    .verify_password(password.as_bytes(), &parsed)
+                    .is_ok()
+            })
+            .unwrap_or(false)
+    })
+    .await
+    .unwrap_or(false)
+}
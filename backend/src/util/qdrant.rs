@@ -0,0 +1,327 @@
+//! Minimal Qdrant REST client for the article embedding index.
+//!
+//! Points are keyed by article ID and carry the publish time (unix seconds) as payload so searches
+//! can be limited to the dedup window. The collection is created on first upsert, sized to the
+//! first vector seen.
+
+use std::{
+    collections::HashSet,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::config::{HttpClientConfig, QdrantConfig};
+
+const PUBLISHED_AT_FIELD: &str = "published_at";
+
+#[derive(Debug, Clone, Copy)]
+pub struct ScoredPoint {
+    pub article_id: i64,
+    /// Cosine similarity in [-1, 1]
+    pub score: f32,
+}
+
+pub struct QdrantManager {
+    http: Client,
+    base_url: String,
+    api_key: Option<String>,
+    collection: String,
+    collection_ready: AtomicBool,
+}
+
+/// Collection state reported by the health check.
+#[derive(Debug, Clone)]
+pub struct CollectionHealth {
+    pub collection: String,
+    /// False until the first article has been indexed
+    pub exists: bool,
+    /// Qdrant's own collection status (green / yellow / grey / red)
+    pub status: Option<String>,
+    pub points_count: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct CollectionInfo {
+    status: Option<String>,
+    points_count: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct QdrantResponse<T> {
+    result: T,
+}
+
+#[derive(Deserialize)]
+struct SearchHit {
+    id: Value,
+    score: f32,
+}
+
+#[derive(Deserialize)]
+struct StoredPoint {
+    id: Value,
+    vector: Option<Vec<f32>>,
+}
+
+impl QdrantManager {
+    pub fn new(config: &QdrantConfig, http_config: &HttpClientConfig) -> Result<Self> {
+        let base_url = config.url.trim().trim_end_matches('/');
+        if base_url.is_empty() {
+            bail!("qdrant.url must not be empty");
+        }
+        let collection = config.collection.trim();
+        if collection.is_empty() {
+            bail!("qdrant.collection must not be empty");
+        }
+        let http = http_config
+            .apply(Client::builder())
+            .context("failed to apply proxy settings for qdrant client")?
+            .timeout(Duration::from_secs(config.timeout_secs.max(1)))
+            .build()
+            .context("failed to build qdrant http client")?;
+        Ok(Self {
+            http,
+            base_url: base_url.to_string(),
+            api_key: config
+                .api_key
+                .as_deref()
+                .map(str::trim)
+                .filter(|key| !key.is_empty())
+                .map(str::to_string),
+            collection: collection.to_string(),
+            collection_ready: AtomicBool::new(false),
+        })
+    }
+
+    /// Insert or replace the vector of one article.
+    pub async fn upsert(
+        &self,
+        article_id: i64,
+        vector: &[f32],
+        published_at: DateTime<Utc>,
+    ) -> Result<()> {
+        self.ensure_collection(vector.len()).await?;
+        let body = json!({
+            "points": [{
+                "id": article_id,
+                "vector": vector,
+                "payload": { PUBLISHED_AT_FIELD: published_at.timestamp() },
+            }]
+        });
+        let result = self
+            .send(
+                self.http.put(self.url("/points?wait=true")).json(&body),
+                "upsert",
+            )
+            .await;
+        if let Err(err) = &result {
+            // Collection removed behind our back: recreate it on the next upsert
+            if is_missing_collection(err) {
+                self.collection_ready.store(false, Ordering::Relaxed);
+            }
+        }
+        result.map(|_| ())
+    }
+
+    /// Nearest articles published within `[from, to]`, best first, excluding `exclude`.
+    pub async fn search(
+        &self,
+        vector: &[f32],
+        from: DateTime<Utc>,
+        to: Option<DateTime<Utc>>,
+        exclude: Option<i64>,
+        limit: usize,
+    ) -> Result<Vec<ScoredPoint>> {
+        let mut range = json!({ "gte": from.timestamp() });
+        if let Some(to) = to {
+            range["lte"] = json!(to.timestamp());
+        }
+        let mut filter = json!({ "must": [{ "key": PUBLISHED_AT_FIELD, "range": range }] });
+        if let Some(id) = exclude {
+            filter["must_not"] = json!([{ "has_id": [id] }]);
+        }
+        let body = json!({ "vector": vector, "limit": limit, "filter": filter });
+        let response = self
+            .send(
+                self.http.post(self.url("/points/search")).json(&body),
+                "search",
+            )
+            .await;
+        let response = match response {
+            // Nothing has been indexed yet
+            Err(err) if is_missing_collection(&err) => return Ok(Vec::new()),
+            other => other?,
+        };
+        let parsed: QdrantResponse<Vec<SearchHit>> = response
+            .json()
+            .await
+            .context("failed to parse qdrant search response")?;
+        Ok(parsed
+            .result
+            .into_iter()
+            .filter_map(|hit| {
+                Some(ScoredPoint {
+                    article_id: hit.id.as_i64()?,
+                    score: hit.score,
+                })
+            })
+            .collect())
+    }
+
+    /// Stored vector of an article; None when it has not been indexed.
+    pub async fn vector(&self, article_id: i64) -> Result<Option<Vec<f32>>> {
+        let body = json!({ "ids": [article_id], "with_vector": true, "with_payload": false });
+        let response = self
+            .send(self.http.post(self.url("/points")).json(&body), "retrieve")
+            .await;
+        let response = match response {
+            Err(err) if is_missing_collection(&err) => return Ok(None),
+            other => other?,
+        };
+        let parsed: QdrantResponse<Vec<StoredPoint>> = response
+            .json()
+            .await
+            .context("failed to parse qdrant retrieve response")?;
+        Ok(parsed
+            .result
+            .into_iter()
+            .next()
+            .and_then(|point| point.vector))
+    }
+
+    /// The subset of `article_ids` that already have a vector.
+    pub async fn indexed(&self, article_ids: &[i64]) -> Result<HashSet<i64>> {
+        let body = json!({ "ids": article_ids, "with_vector": false, "with_payload": false });
+        let response = self
+            .send(self.http.post(self.url("/points")).json(&body), "retrieve")
+            .await;
+        let response = match response {
+            Err(err) if is_missing_collection(&err) => return Ok(HashSet::new()),
+            other => other?,
+        };
+        let parsed: QdrantResponse<Vec<StoredPoint>> = response
+            .json()
+            .await
+            .context("failed to parse qdrant retrieve response")?;
+        Ok(parsed
+            .result
+            .into_iter()
+            .filter_map(|point| point.id.as_i64())
+            .collect())
+    }
+
+    /// Check that Qdrant is reachable and report the collection state. A missing collection is
+    /// not an error: it is created on the first upsert.
+    pub async fn health(&self) -> Result<CollectionHealth> {
+        let response = self
+            .send(self.http.get(self.url("")), "collection info")
+            .await;
+        let response = match response {
+            Err(err) if is_missing_collection(&err) => {
+                return Ok(CollectionHealth {
+                    collection: self.collection.clone(),
+                    exists: false,
+                    status: None,
+                    points_count: None,
+                })
+            }
+            other => other?,
+        };
+        let parsed: QdrantResponse<CollectionInfo> = response
+            .json()
+            .await
+            .context("failed to parse qdrant collection info")?;
+        Ok(CollectionHealth {
+            collection: self.collection.clone(),
+            exists: true,
+            status: parsed.result.status,
+            points_count: parsed.result.points_count,
+        })
+    }
+
+    async fn ensure_collection(&self, dimension: usize) -> Result<()> {
+        if self.collection_ready.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let existing = self
+            .authorize(self.http.get(self.url("")))
+            .send()
+            .await
+            .context("qdrant collection lookup failed")?;
+        if existing.status() == StatusCode::NOT_FOUND {
+            let body = json!({ "vectors": { "size": dimension, "distance": "Cosine" } });
+            self.send(self.http.put(self.url("")).json(&body), "create collection")
+                .await?;
+            let index = json!({ "field_name": PUBLISHED_AT_FIELD, "field_schema": "integer" });
+            self.send(
+                self.http.put(self.url("/index?wait=true")).json(&index),
+                "create payload index",
+            )
+            .await?;
+            tracing::info!(collection = %self.collection, dimension, "created qdrant collection");
+        } else if !existing.status().is_success() {
+            let status = existing.status();
+            let body = existing.text().await.unwrap_or_default();
+            return Err(anyhow!(
+                "qdrant collection lookup returned status {}: {}",
+                status,
+                body
+            ));
+        }
+        self.collection_ready.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/collections/{}{}", self.base_url, self.collection, path)
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.api_key {
+            Some(key) => request.header("api-key", key),
+            None => request,
+        }
+    }
+
+    async fn send(&self, request: RequestBuilder, purpose: &str) -> Result<reqwest::Response> {
+        let response = self
+            .authorize(request)
+            .send()
+            .await
+            .with_context(|| format!("qdrant {purpose} request failed"))?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        let body = response.text().await.unwrap_or_default();
+        Err(anyhow!(QdrantStatusError {
+            status,
+            message: format!("qdrant {purpose} returned status {status}: {body}"),
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct QdrantStatusError {
+    status: StatusCode,
+    message: String,
+}
+
+impl std::fmt::Display for QdrantStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for QdrantStatusError {}
+
+fn is_missing_collection(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<QdrantStatusError>()
+        .is_some_and(|err| err.status == StatusCode::NOT_FOUND)
+}
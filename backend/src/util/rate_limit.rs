@@ -0,0 +1,67 @@
+//! Per-domain token bucket keeping concurrent fetches from hammering a single host.
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+struct Bucket {
+    /// Negative while callers are queued behind reservations already handed out.
+    tokens: f64,
+    updated: Instant,
+}
+
+pub struct DomainRateLimiter {
+    rate_per_sec: f64,
+    burst: f64,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl DomainRateLimiter {
+    /// Returns None when `requests_per_minute` is 0 (unlimited).
+    pub fn new(requests_per_minute: u32, burst: u32) -> Option<Self> {
+        if requests_per_minute == 0 {
+            return None;
+        }
+        Some(Self {
+            rate_per_sec: requests_per_minute as f64 / 60.0,
+            burst: burst.max(1) as f64,
+            buckets: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Wait until a request to `domain` is allowed; returns how long the caller was delayed.
+    pub async fn acquire(&self, domain: &str) -> Duration {
+        let wait = self.reserve(domain);
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+        wait
+    }
+
+    /// Take a token right away, possibly going into debt; the debt is the delay before sending.
+    /// Reserving up front keeps waiting callers in arrival order.
+    fn reserve(&self, domain: &str) -> Duration {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        // Buckets that have refilled completely carry no state worth keeping
+        buckets.retain(|_, bucket| self.refilled(bucket, now) < self.burst);
+        let bucket = buckets.entry(domain.to_string()).or_insert(Bucket {
+            tokens: self.burst,
+            updated: now,
+        });
+        bucket.tokens = self.refilled(bucket, now) - 1.0;
+        bucket.updated = now;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / self.rate_per_sec)
+        }
+    }
+
+    fn refilled(&self, bucket: &Bucket, now: Instant) -> f64 {
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        (bucket.tokens + elapsed * self.rate_per_sec).min(self.burst)
+    }
+}
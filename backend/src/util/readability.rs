@@ -0,0 +1,372 @@
+//! Readability-style main text extraction for article pages.
+//!
+//! Paragraphs score their enclosing container (and half of it to the grandparent) by length and comma
+//! count; class/id hints adjust container scores, and the best container's paragraphs form the body.
+
+const SKIPPED_TAGS: &[&str] = &[
+    "script", "style", "noscript", "nav", "header", "footer", "aside", "form", "svg", "iframe",
+    "button", "template", "select", "textarea",
+];
+const CONTAINER_TAGS: &[&str] = &["article", "main", "section", "div", "td", "body"];
+const PARAGRAPH_TAGS: &[&str] = &["p", "pre"];
+const POSITIVE_HINTS: &[&str] = &[
+    "article", "content", "post", "entry", "body", "main", "story", "text", "blog",
+];
+const NEGATIVE_HINTS: &[&str] = &[
+    "comment",
+    "sidebar",
+    "footer",
+    "related",
+    "share",
+    "social",
+    "promo",
+    "sponsor",
+    "nav",
+    "menu",
+    "banner",
+    "advert",
+    "widget",
+    "breadcrumb",
+    "subscribe",
+];
+const MIN_PARAGRAPH_CHARS: usize = 25;
+// Lazy-loading pages keep the real image in one of these while `src` holds a placeholder
+const LAZY_SRC_ATTRS: &[&str] = &["data-src", "data-original", "data-lazy-src"];
+const MAX_IMAGES: usize = 20;
+/// Pages whose best candidate is shorter than this are not treated as articles.
+pub const MIN_CONTENT_CHARS: usize = 140;
+pub const MAX_CONTENT_CHARS: usize = 100_000;
+
+struct Container {
+    tag: String,
+    weight: f64,
+    score: f64,
+}
+
+struct Paragraph {
+    text: String,
+    /// Container ids from the outermost to the innermost enclosing container
+    path: Vec<usize>,
+}
+
+struct Image {
+    src: String,
+    path: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Extracted {
+    /// Paragraphs separated by blank lines
+    pub text: String,
+    /// Image sources inside the main content in document order, as written in the page (may be relative)
+    pub images: Vec<String>,
+}
+
+#[derive(Default)]
+struct OpenParagraph {
+    text: String,
+    link_chars: usize,
+}
+
+/// Extract the main body text and its images; None when nothing article-like is found.
+pub fn extract(html: &str) -> Option<Extracted> {
+    let mut containers: Vec<Container> = Vec::new();
+    let mut open: Vec<usize> = Vec::new();
+    let mut paragraphs: Vec<Paragraph> = Vec::new();
+    let mut images: Vec<Image> = Vec::new();
+    let mut current: Option<OpenParagraph> = None;
+    let mut in_link = false;
+
+    let lower = html.to_ascii_lowercase();
+    let mut pos = 0;
+    while pos < html.len() {
+        let Some(rel) = html[pos..].find('<') else {
+            push_text(&mut current, &html[pos..], in_link);
+            break;
+        };
+        push_text(&mut current, &html[pos..pos + rel], in_link);
+        let start = pos + rel;
+
+        if lower[start..].starts_with("<!--") {
+            pos = lower[start..]
+                .find("-->")
+                .map(|end| start + end + 3)
+                .unwrap_or(html.len());
+            continue;
+        }
+        let Some(end) = tag_end(html, start) else {
+            break;
+        };
+        let raw = &html[start + 1..end];
+        pos = end + 1;
+
+        let closing = raw.starts_with('/');
+        let body = raw.trim_start_matches('/');
+        let name_len = body
+            .find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+            .unwrap_or(body.len());
+        let name = body[..name_len].to_ascii_lowercase();
+        if name.is_empty() || name.starts_with('!') || name.starts_with('?') {
+            continue;
+        }
+
+        if !closing && !raw.ends_with('/') && SKIPPED_TAGS.contains(&name.as_str()) {
+            // Jump past the matching close tag; nested tags of the same name are rare in practice
+            let close = format!("</{name}");
+            pos = lower[pos..]
+                .find(&close)
+                .and_then(|rel| tag_end(html, pos + rel).map(|end| end + 1))
+                .unwrap_or(html.len());
+            continue;
+        }
+
+        match name.as_str() {
+            "a" => in_link = !closing,
+            "br" => push_text(&mut current, " ", false),
+            "img" if !closing => {
+                if let Some(src) = image_src(&body[name_len..]) {
+                    images.push(Image {
+                        src,
+                        path: open.clone(),
+                    });
+                }
+            }
+            tag if PARAGRAPH_TAGS.contains(&tag) => {
+                finish_paragraph(&mut current, &open, &mut containers, &mut paragraphs);
+                if !closing {
+                    current = Some(OpenParagraph::default());
+                }
+            }
+            tag if CONTAINER_TAGS.contains(&tag) => {
+                finish_paragraph(&mut current, &open, &mut containers, &mut paragraphs);
+                if closing {
+                    // Unbalanced markup: close up to the nearest matching container, ignore strays
+                    if let Some(idx) = open.iter().rposition(|id| containers[*id].tag == tag) {
+                        open.truncate(idx);
+                    }
+                } else {
+                    containers.push(Container {
+                        tag: tag.to_string(),
+                        weight: container_weight(tag, &body[name_len..]),
+                        score: 0.0,
+                    });
+                    open.push(containers.len() - 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    finish_paragraph(&mut current, &open, &mut containers, &mut paragraphs);
+
+    let best = containers
+        .iter()
+        .enumerate()
+        .filter(|(_, container)| container.score > 0.0)
+        .max_by(|(_, a), (_, b)| (a.score + a.weight).total_cmp(&(b.score + b.weight)))
+        .map(|(id, _)| id)?;
+
+    let mut content = String::new();
+    for paragraph in paragraphs.iter().filter(|p| p.path.contains(&best)) {
+        if !content.is_empty() {
+            content.push_str("\n\n");
+        }
+        content.push_str(&paragraph.text);
+    }
+    if content.chars().count() < MIN_CONTENT_CHARS {
+        return None;
+    }
+    if content.len() > MAX_CONTENT_CHARS {
+        content = content.chars().take(MAX_CONTENT_CHARS).collect();
+    }
+
+    let mut sources: Vec<String> = Vec::new();
+    for image in images
+        .into_iter()
+        .filter(|image| image.path.contains(&best))
+    {
+        if sources.len() >= MAX_IMAGES {
+            break;
+        }
+        if !sources.contains(&image.src) {
+            sources.push(image.src);
+        }
+    }
+    Some(Extracted {
+        text: content,
+        images: sources,
+    })
+}
+
+/// The real source of an `<img>`, skipping inline data and 1x1 tracking pixels.
+fn image_src(attrs: &str) -> Option<String> {
+    let is_pixel = |name| attr_value(attrs, name).is_some_and(|value| value.trim() == "1");
+    if is_pixel("width") || is_pixel("height") {
+        return None;
+    }
+    LAZY_SRC_ATTRS
+        .iter()
+        .chain(std::iter::once(&"src"))
+        .filter_map(|name| attr_value(attrs, name))
+        .map(|value| decode_entities(value.trim()))
+        .find(|value| !value.is_empty() && !value.starts_with("data:"))
+}
+
+/// Index of the `>` closing the tag opened at `start`, skipping quoted attribute values.
+fn tag_end(html: &str, start: usize) -> Option<usize> {
+    let mut quote: Option<u8> = None;
+    for (idx, &byte) in html.as_bytes()[start..].iter().enumerate() {
+        match (quote, byte) {
+            (Some(q), b) if b == q => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') => quote = Some(byte),
+            (None, b'>') => return Some(start + idx),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn push_text(current: &mut Option<OpenParagraph>, text: &str, in_link: bool) {
+    if let Some(paragraph) = current {
+        paragraph.text.push_str(text);
+        if in_link {
+            paragraph.link_chars += text.chars().filter(|c| !c.is_whitespace()).count();
+        }
+    }
+}
+
+fn finish_paragraph(
+    current: &mut Option<OpenParagraph>,
+    open: &[usize],
+    containers: &mut [Container],
+    paragraphs: &mut Vec<Paragraph>,
+) {
+    let Some(paragraph) = current.take() else {
+        return;
+    };
+    let text = collapse_whitespace(&decode_entities(&paragraph.text));
+    let chars = text.chars().count();
+    if chars < MIN_PARAGRAPH_CHARS {
+        return;
+    }
+    // Mostly-link paragraphs are navigation or "read more" lists
+    let visible = text.chars().filter(|c| !c.is_whitespace()).count();
+    if paragraph.link_chars * 2 > visible {
+        return;
+    }
+    let commas = text
+        .chars()
+        .filter(|c| matches!(c, ',' | '，' | '、'))
+        .count();
+    let score = 1.0 + commas as f64 + (chars as f64 / 100.0).min(3.0);
+    if let Some(&parent) = open.last() {
+        containers[parent].score += score;
+    }
+    if open.len() >= 2 {
+        containers[open[open.len() - 2]].score += score / 2.0;
+    }
+    paragraphs.push(Paragraph {
+        text,
+        path: open.to_vec(),
+    });
+}
+
+fn container_weight(tag: &str, attrs: &str) -> f64 {
+    let mut weight = match tag {
+        "article" => 10.0,
+        "main" => 5.0,
+        _ => 0.0,
+    };
+    let hints = [attr_value(attrs, "class"), attr_value(attrs, "id")]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_lowercase();
+    if NEGATIVE_HINTS.iter().any(|hint| hints.contains(hint)) {
+        weight -= 25.0;
+    } else if POSITIVE_HINTS.iter().any(|hint| hints.contains(hint)) {
+        weight += 25.0;
+    }
+    weight
+}
+
+fn attr_value<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let lower = attrs.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(rel) = lower[from..].find(name) {
+        let idx = from + rel;
+        from = idx + name.len();
+        // Must be a whole attribute name, e.g. not `data-class`
+        let boundary = idx == 0 || lower.as_bytes()[idx - 1].is_ascii_whitespace();
+        let rest = lower[from..].trim_start();
+        if !boundary || !rest.starts_with('=') {
+            continue;
+        }
+        let value_start = attrs.len() - rest.len() + 1;
+        let value = attrs[value_start..].trim_start();
+        return Some(match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let inner = &value[1..];
+                &inner[..inner.find(quote).unwrap_or(inner.len())]
+            }
+            _ => &value[..value.find(char::is_whitespace).unwrap_or(value.len())],
+        });
+    }
+    None
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(idx) = rest.find('&') {
+        out.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| decode_entity(&rest[1..=end]).map(|ch| (ch, end + 2)));
+        match decoded {
+            Some((ch, len)) => {
+                out.push(ch);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    if let Some(num) = name.strip_prefix('#') {
+        let code = match num.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => num.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    Some(match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "mdash" => '—',
+        "ndash" => '–',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        _ => return None,
+    })
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
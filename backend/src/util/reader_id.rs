@@ -0,0 +1,58 @@
+//! Anonymous reader identity for per-reader article state (read / bookmarked).
+//!
+//! There are no reader accounts: the frontend generates a random id once,
+//! keeps it in local storage and sends it in the `X-Reader-Id` header.
+
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+
+use crate::error::AppError;
+
+pub const READER_ID_HEADER: &str = "x-reader-id";
+
+const MIN_LEN: usize = 16;
+const MAX_LEN: usize = 64;
+
+/// The request's reader id, `None` when the header is absent or blank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReaderId(pub Option<String>);
+
+impl ReaderId {
+    /// The id, or a 400 explaining that the endpoint needs one.
+    pub fn require(&self) -> Result<&str, AppError> {
+        self.0
+            .as_deref()
+            .ok_or_else(|| AppError::BadRequest("需要 X-Reader-Id 请求头".to_string()))
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ReaderId {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(raw) = parts.headers.get(READER_ID_HEADER) else {
+            return Ok(ReaderId(None));
+        };
+        let raw = raw.to_str().map_err(|_| invalid())?.trim();
+        if raw.is_empty() {
+            return Ok(ReaderId(None));
+        }
+        if !is_valid(raw) {
+            return Err(invalid());
+        }
+        Ok(ReaderId(Some(raw.to_string())))
+    }
+}
+
+fn is_valid(raw: &str) -> bool {
+    (MIN_LEN..=MAX_LEN).contains(&raw.len())
+        && raw
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+}
+
+fn invalid() -> AppError {
+    AppError::BadRequest(format!(
+        "X-Reader-Id 需为 {MIN_LEN}~{MAX_LEN} 位字母、数字、- 或 _"
+    ))
+}
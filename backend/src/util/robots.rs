@@ -0,0 +1,258 @@
+//! robots.txt support (RFC 9309) so fetches behave like a polite crawler.
+//!
+//! Rules are cached per origin. Use [`shared`] for feed fetches and any other outbound page fetches
+//! so they all hit the same cache.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use reqwest::{Client, StatusCode};
+use tracing::{info, warn};
+use url::Url;
+
+/// Product token matched against `User-agent` lines; the fetcher's User-Agent starts with it.
+pub const USER_AGENT_TOKEN: &str = "newsaggregatorfetcher";
+
+const CACHE_TTL: Duration = Duration::from_secs(24 * 3600);
+// Server errors and unreachable hosts disallow everything (RFC 9309 §2.3.1.4); retry sooner.
+const UNAVAILABLE_TTL: Duration = Duration::from_secs(600);
+// Parsers must handle at least 500 KiB; anything beyond is ignored.
+const MAX_BODY_BYTES: usize = 512 * 1024;
+
+#[derive(Debug, Clone)]
+struct Rule {
+    allow: bool,
+    pattern: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RobotsRules {
+    rules: Vec<Rule>,
+    disallow_all: bool,
+}
+
+impl RobotsRules {
+    fn allow_all() -> Self {
+        Self::default()
+    }
+
+    fn disallow_all() -> Self {
+        Self {
+            rules: Vec::new(),
+            disallow_all: true,
+        }
+    }
+
+    /// Parse a robots.txt body, keeping the groups that apply to `agent` (or `*` when none do).
+    pub fn parse(body: &str, agent: &str) -> Self {
+        let agent = agent.to_ascii_lowercase();
+        let mut specific = Vec::new();
+        let mut wildcard = Vec::new();
+        let mut has_specific = false;
+
+        // Agents of the group being read and whether its rules have started
+        let mut group_agents: Vec<String> = Vec::new();
+        let mut in_rules = false;
+
+        for line in body.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let Some((field, value)) = line.split_once(':') else {
+                continue;
+            };
+            let field = field.trim().to_ascii_lowercase();
+            let value = value.trim();
+            match field.as_str() {
+                "user-agent" => {
+                    if in_rules {
+                        group_agents.clear();
+                        in_rules = false;
+                    }
+                    group_agents.push(value.to_ascii_lowercase());
+                }
+                "allow" | "disallow" => {
+                    in_rules = true;
+                    // An empty Disallow matches nothing
+                    if value.is_empty() {
+                        continue;
+                    }
+                    let rule = Rule {
+                        allow: field == "allow",
+                        pattern: value.to_string(),
+                    };
+                    if group_agents.contains(&agent) {
+                        has_specific = true;
+                        specific.push(rule);
+                    } else if group_agents.iter().any(|ua| ua == "*") {
+                        wildcard.push(rule);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            rules: if has_specific { specific } else { wildcard },
+            disallow_all: false,
+        }
+    }
+
+    /// The longest matching pattern decides; Allow wins ties. `path` includes the query string.
+    pub fn is_allowed(&self, path: &str) -> bool {
+        if path == "/robots.txt" {
+            return true;
+        }
+        if self.disallow_all {
+            return false;
+        }
+        let mut best: Option<(usize, bool)> = None;
+        for rule in &self.rules {
+            if !pattern_matches(&rule.pattern, path) {
+                continue;
+            }
+            let len = rule.pattern.len();
+            best = match best {
+                Some((best_len, best_allow))
+                    if best_len > len || (best_len == len && best_allow) =>
+                {
+                    Some((best_len, best_allow))
+                }
+                _ => Some((len, rule.allow)),
+            };
+        }
+        best.map(|(_, allow)| allow).unwrap_or(true)
+    }
+}
+
+/// `*` matches any sequence, a trailing `$` anchors the end; otherwise a prefix match.
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(stripped) => (stripped, true),
+        None => (pattern, false),
+    };
+    let pattern = pattern.as_bytes();
+    let path = path.as_bytes();
+    // Positions in `path` reachable after consuming each prefix of `pattern`
+    let mut reachable = vec![false; path.len() + 1];
+    reachable[0] = true;
+    for &byte in pattern {
+        if byte == b'*' {
+            for idx in 1..=path.len() {
+                reachable[idx] |= reachable[idx - 1];
+            }
+        } else {
+            for idx in (1..=path.len()).rev() {
+                reachable[idx] = reachable[idx - 1] && path[idx - 1] == byte;
+            }
+            reachable[0] = false;
+        }
+    }
+    if anchored {
+        reachable[path.len()]
+    } else {
+        reachable.iter().any(|ok| *ok)
+    }
+}
+
+pub struct RobotsCheck {
+    pub allowed: bool,
+    /// robots.txt was fetched for this check rather than served from the cache
+    pub fresh: bool,
+}
+
+struct CachedRules {
+    fetched_at: Instant,
+    ttl: Duration,
+    rules: Arc<RobotsRules>,
+}
+
+#[derive(Default)]
+pub struct RobotsCache {
+    entries: Mutex<HashMap<String, CachedRules>>,
+}
+
+/// Process-wide cache shared by every fetch path.
+pub fn shared() -> &'static RobotsCache {
+    static CACHE: OnceLock<RobotsCache> = OnceLock::new();
+    CACHE.get_or_init(RobotsCache::default)
+}
+
+impl RobotsCache {
+    /// Whether `url` may be fetched; non-HTTP URLs are always allowed.
+    pub async fn check(&self, client: &Client, url: &Url) -> RobotsCheck {
+        if !matches!(url.scheme(), "http" | "https") {
+            return RobotsCheck {
+                allowed: true,
+                fresh: false,
+            };
+        }
+        let origin = url.origin().ascii_serialization();
+        let mut path = url.path().to_string();
+        if let Some(query) = url.query() {
+            path.push('?');
+            path.push_str(query);
+        }
+
+        if let Some(rules) = self.get(&origin) {
+            return RobotsCheck {
+                allowed: rules.is_allowed(&path),
+                fresh: false,
+            };
+        }
+        let (rules, ttl) = fetch_rules(client, &origin).await;
+        let rules = Arc::new(rules);
+        let allowed = rules.is_allowed(&path);
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).insert(
+            origin,
+            CachedRules {
+                fetched_at: Instant::now(),
+                ttl,
+                rules,
+            },
+        );
+        RobotsCheck {
+            allowed,
+            fresh: true,
+        }
+    }
+
+    fn get(&self, origin: &str) -> Option<Arc<RobotsRules>> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|_, cached| cached.fetched_at.elapsed() < cached.ttl);
+        entries.get(origin).map(|cached| Arc::clone(&cached.rules))
+    }
+}
+
+async fn fetch_rules(client: &Client, origin: &str) -> (RobotsRules, Duration) {
+    let robots_url = format!("{origin}/robots.txt");
+    let response = match client.get(&robots_url).send().await {
+        Ok(response) => response,
+        Err(err) => {
+            warn!(url = %robots_url, error = %err, "robots.txt unreachable, disallowing");
+            return (RobotsRules::disallow_all(), UNAVAILABLE_TTL);
+        }
+    };
+    let status = response.status();
+    if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+        warn!(url = %robots_url, status = status.as_u16(), "robots.txt unavailable, disallowing");
+        return (RobotsRules::disallow_all(), UNAVAILABLE_TTL);
+    }
+    if !status.is_success() {
+        // 4xx: no robots.txt, everything is allowed
+        return (RobotsRules::allow_all(), CACHE_TTL);
+    }
+    match response.bytes().await {
+        Ok(body) => {
+            let body = &body[..body.len().min(MAX_BODY_BYTES)];
+            let rules = RobotsRules::parse(&String::from_utf8_lossy(body), USER_AGENT_TOKEN);
+            info!(url = %robots_url, rules = rules.rules.len(), "robots.txt loaded");
+            (rules, CACHE_TTL)
+        }
+        Err(err) => {
+            warn!(url = %robots_url, error = %err, "failed to read robots.txt, disallowing");
+            (RobotsRules::disallow_all(), UNAVAILABLE_TTL)
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::{collections::BTreeSet, str::FromStr};
+
+use chrono::{DateTime, NaiveTime, Utc};
+use cron::{error::ErrorKind, Schedule};
+
+/// Parse a feed fetch schedule; all times are evaluated in UTC.
+///
+/// The standard 5-field form (`min hour day month weekday`) numbers weekdays 0-7
+/// from Sunday (0 and 7 are both Sunday), so `1-5` is Monday to Friday. The
+/// 6/7-field form with seconds is handed to the `cron` crate unchanged, where
+/// weekdays are numbered 1-7 from Sunday. Weekday names work in both forms.
+pub fn parse_cron(expr: &str) -> Result<Schedule, cron::error::Error> {
+    let expr = expr.trim();
+    let fields: Vec<&str> = expr.split_whitespace().collect();
+    if let [minute, hour, day, month, weekday] = fields.as_slice() {
+        let weekday = standard_weekdays(weekday)?;
+        Schedule::from_str(&format!("0 {minute} {hour} {day} {month} {weekday}"))
+    } else {
+        Schedule::from_str(expr)
+    }
+}
+
+// Rewrite numeric items of a standard weekday field (ranges and steps included)
+// into the crate's numbering; `*`, `?` and weekday names pass through.
+fn standard_weekdays(field: &str) -> Result<String, cron::error::Error> {
+    let invalid = || ErrorKind::Expression(format!("invalid weekday field {field:?}")).into();
+    let mut days = BTreeSet::new();
+    let mut passthrough = Vec::new();
+    for item in field.split(',') {
+        if item == "*" || item == "?" {
+            passthrough.push(item.to_string());
+            continue;
+        }
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, Some(step.parse::<usize>().map_err(|_| invalid())?)),
+            None => (item, None),
+        };
+        let bounds = match range {
+            "*" | "?" => Some((0, 6)),
+            _ => match range.split_once('-') {
+                Some((start, end)) => start.parse::<u32>().ok().zip(end.parse::<u32>().ok()),
+                // `a/step` runs from `a` to the end of the week
+                None => range
+                    .parse::<u32>()
+                    .ok()
+                    .map(|start| (start, if step.is_some() { 7 } else { start })),
+            },
+        };
+        let Some((start, end)) = bounds else {
+            passthrough.push(item.to_string());
+            continue;
+        };
+        if start > end || end > 7 || step == Some(0) {
+            return Err(invalid());
+        }
+        days.extend(
+            (start..=end)
+                .step_by(step.unwrap_or(1))
+                .map(|day| day % 7 + 1),
+        );
+    }
+    Ok(days
+        .into_iter()
+        .map(|day| day.to_string())
+        .chain(passthrough)
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// First scheduled run after the last fetch; `None` when the schedule never fires again.
+/// A feed that has never been fetched is due immediately.
+pub fn next_run(
+    schedule: &Schedule,
+    last_fetch_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    match last_fetch_at {
+        Some(last) => schedule.after(&last).next(),
+        None => Some(now),
+    }
+}
+
+pub fn is_due(schedule: &Schedule, last_fetch_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+    next_run(schedule, last_fetch_at, now).is_some_and(|next| next <= now)
+}
+
+/// Daily UTC window (`HH:MM-HH:MM`, end exclusive) during which background work is paused.
+/// A window whose end is earlier than its start wraps past midnight, e.g. `22:00-06:00`.
+#[derive(Debug, Clone, Copy)]
+pub struct QuietWindow {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl QuietWindow {
+    pub fn parse(raw: &str) -> Option<Self> {
+        let (start, end) = raw.trim().split_once('-')?;
+        let start = NaiveTime::parse_from_str(start.trim(), "%H:%M").ok()?;
+        let end = NaiveTime::parse_from_str(end.trim(), "%H:%M").ok()?;
+        (start != end).then_some(Self { start, end })
+    }
+
+    pub fn contains(&self, now: DateTime<Utc>) -> bool {
+        let time = now.time();
+        if self.start < self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+impl std::fmt::Display for QuietWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start.format("%H:%M"), self.end.format("%H:%M"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, TimeZone, Weekday};
+
+    use super::*;
+
+    fn weekdays(expr: &str) -> Vec<Weekday> {
+        let schedule = parse_cron(expr).expect("valid cron expression");
+        // 2024-01-07 is a Sunday
+        let start = Utc.with_ymd_and_hms(2024, 1, 6, 12, 0, 0).unwrap();
+        schedule
+            .after(&start)
+            .take_while(|at| *at < start + chrono::Duration::days(7))
+            .map(|at| at.weekday())
+            .collect()
+    }
+
+    #[test]
+    fn weekday_range_is_monday_to_friday() {
+        assert_eq!(
+            weekdays("0 9 * * 1-5"),
+            [
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri
+            ]
+        );
+    }
+
+    #[test]
+    fn zero_and_seven_are_sunday() {
+        assert_eq!(weekdays("0 9 * * 0"), [Weekday::Sun]);
+        assert_eq!(weekdays("0 9 * * 7"), [Weekday::Sun]);
+        assert_eq!(
+            weekdays("0 9 * * 5-7"),
+            [Weekday::Sun, Weekday::Fri, Weekday::Sat]
+        );
+    }
+
+    #[test]
+    fn names_and_wildcards_pass_through() {
+        assert_eq!(weekdays("0 9 * * MON").len(), 1);
+        assert_eq!(weekdays("0 9 * * *").len(), 7);
+        assert!(parse_cron("0 9 * * 8").is_err());
+    }
+}
@@ -1,5 +1,8 @@
 use std::collections::BTreeSet;
 
+use chrono::{DateTime, Utc};
+use url::Url;
+
 /// Normalize a title for duplicate comparison: lowercase, replace punctuation with spaces,
 /// and collapse multiple spaces.
 pub fn normalize_title_for_comparison(title: &str) -> String {
@@ -12,16 +15,10 @@ pub fn normalize_title_for_comparison(title: &str) -> String {
                 normalized.push(lower);
             }
             space_pending = false;
-        } else if ch.is_whitespace() {
-            if !space_pending {
-                normalized.push(' ');
-                space_pending = true;
-            }
-        } else {
-            if !space_pending {
-                normalized.push(' ');
-                space_pending = true;
-            }
+        } else if !space_pending {
+            // 空白与标点统一折叠为单个空格
+            normalized.push(' ');
+            space_pending = true;
         }
     }
 
@@ -54,3 +51,62 @@ pub fn jaccard_similarity(a: &BTreeSet<String>, b: &BTreeSet<String>) -> f32 {
         intersection / union
     }
 }
+
+/// Exact-duplicate fingerprint: md5 of the normalized original title, the URL host
+/// (without `www.`) and the UTC publish date. Returns `None` when the title normalizes
+/// to nothing or the URL has no host.
+pub fn content_fingerprint(title: &str, url: &str, published_at: DateTime<Utc>) -> Option<String> {
+    let normalized = normalize_title_for_comparison(title);
+    if normalized.is_empty() {
+        return None;
+    }
+    let parsed = Url::parse(url).ok()?;
+    let host = parsed.host_str()?.to_ascii_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    let bucket = published_at.format("%Y-%m-%d");
+    let digest = md5::compute(format!("{normalized}\n{host}\n{bucket}"));
+    Some(format!("{digest:x}"))
+}
+
+// Titles shorter than this (in normalized characters) are too short for a meaningful simhash
+const SIMHASH_MIN_CHARS: usize = 8;
+const SHINGLE_CHARS: usize = 3;
+
+/// Near-duplicate fingerprint: 64-bit SimHash over character 3-gram shingles of the normalized
+/// title. Character shingles work for both space-delimited and CJK titles; titles differing by a
+/// word or punctuation end up a few bits apart in Hamming distance. Returns `None` for titles
+/// shorter than 8 normalized characters, which collide too easily.
+pub fn title_simhash(title: &str) -> Option<i64> {
+    let chars: Vec<char> = normalize_title_for_comparison(title).chars().collect();
+    if chars.len() < SIMHASH_MIN_CHARS {
+        return None;
+    }
+    let mut weights = [0i32; 64];
+    let mut shingle = String::new();
+    for window in chars.windows(SHINGLE_CHARS) {
+        shingle.clear();
+        shingle.extend(window);
+        let hash = fnv1a64(shingle.as_bytes());
+        for (bit, weight) in weights.iter_mut().enumerate() {
+            if hash & (1 << bit) != 0 {
+                *weight += 1;
+            } else {
+                *weight -= 1;
+            }
+        }
+    }
+    let simhash = weights
+        .iter()
+        .enumerate()
+        .filter(|(_, weight)| **weight > 0)
+        .fold(0u64, |acc, (bit, _)| acc | (1 << bit));
+    // Stored as BIGINT; the bit pattern is what matters
+    Some(simhash as i64)
+}
+
+// Stable across builds and platforms, unlike std's DefaultHasher
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
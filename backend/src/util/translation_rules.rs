@@ -0,0 +1,119 @@
+//! Per-language translation rules, e.g. "translate en→zh and ja→zh, never ko".
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::lang::SUPPORTED_LANGUAGES;
+
+pub const RULES_KEY: &str = "translation.rules";
+/// Empty rule list: translation stays off until rules are configured.
+pub const DEFAULT_RULES: &str = "[]";
+/// Rule set applied when translation is switched on without custom rules (the former behaviour).
+pub const ENGLISH_TO_CHINESE: &str = r#"[{"from":"en","to":"zh"}]"#;
+/// Matches any detected language not covered by an earlier rule.
+pub const ANY_LANGUAGE: &str = "*";
+
+// Translation prompts only produce Simplified Chinese
+const TARGET_LANGUAGES: &[&str] = &["zh"];
+const MAX_RULES: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranslationRule {
+    pub from: String,
+    /// Target language; absent together with `translate: false` for "never translate".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    #[serde(default = "default_translate", skip_serializing_if = "is_true")]
+    pub translate: bool,
+}
+
+fn default_translate() -> bool {
+    true
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
+}
+
+/// Ordered rules; the first rule whose `from` matches the detected language decides.
+/// Languages matching no rule (or undetected) are kept as-is.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TranslationRules(Vec<TranslationRule>);
+
+impl TranslationRules {
+    /// Parse a stored value; invalid values were rejected on save and yield no rules here.
+    pub fn from_stored(raw: Option<&str>) -> Self {
+        raw.and_then(|raw| Self::parse(raw).ok()).unwrap_or_default()
+    }
+
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let rules: Vec<TranslationRule> =
+            serde_json::from_str(raw).map_err(|err| format!("翻译规则格式错误: {err}"))?;
+        if rules.len() > MAX_RULES {
+            return Err(format!("翻译规则最多 {MAX_RULES} 条"));
+        }
+        let mut normalized: Vec<TranslationRule> = Vec::with_capacity(rules.len());
+        for rule in rules {
+            let from = rule.from.trim().to_ascii_lowercase();
+            if from != ANY_LANGUAGE && !SUPPORTED_LANGUAGES.contains(&from.as_str()) {
+                return Err(format!(
+                    "from 可选值：{}|{ANY_LANGUAGE}",
+                    SUPPORTED_LANGUAGES.join("|")
+                ));
+            }
+            if normalized.iter().any(|existing| existing.from == from) {
+                return Err(format!("语言 {from} 的规则重复"));
+            }
+            let to = rule
+                .to
+                .map(|to| to.trim().to_ascii_lowercase())
+                .filter(|to| !to.is_empty());
+            let to = match (rule.translate, to) {
+                (false, _) => None,
+                (true, Some(to)) if TARGET_LANGUAGES.contains(&to.as_str()) => {
+                    if to == from {
+                        return Err(format!("语言 {from} 不能翻译为自身"));
+                    }
+                    Some(to)
+                }
+                (true, Some(to)) => {
+                    return Err(format!("暂不支持翻译为 {to}，可选值：{}", TARGET_LANGUAGES.join("|")))
+                }
+                (true, None) => return Err(format!("语言 {from} 的规则需要指定 to，或设置 translate: false")),
+            };
+            normalized.push(TranslationRule {
+                from,
+                translate: to.is_some(),
+                to,
+            });
+        }
+        Ok(Self(normalized))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.0).unwrap_or_else(|_| DEFAULT_RULES.to_string())
+    }
+
+    pub fn rules(&self) -> &[TranslationRule] {
+        &self.0
+    }
+
+    /// Whether any rule translates at all; replaces the former global on/off flag.
+    pub fn any_enabled(&self) -> bool {
+        self.0.iter().any(|rule| rule.translate)
+    }
+
+    /// Target language for an article in `language`, or None to keep the original text.
+    pub fn target_for(&self, language: Option<&str>) -> Option<&str> {
+        let language = language?;
+        self.0
+            .iter()
+            .find(|rule| rule.from == language || rule.from == ANY_LANGUAGE)
+            .and_then(|rule| rule.to.as_deref())
+            .filter(|to| *to != language)
+    }
+}
+
+/// Settings registry validator: outputs the normalized rule list.
+pub fn normalize_rules(raw: &str) -> Result<String, String> {
+    TranslationRules::parse(raw).map(|rules| rules.to_json())
+}
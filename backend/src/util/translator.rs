@@ -2,17 +2,28 @@ use std::sync::{Arc, RwLock};
 
 use anyhow::{anyhow, Result};
 use tokio::runtime::Handle;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::{info, warn};
 
 use crate::config::HttpClientConfig;
 
 use super::{
-    deepseek::{DeepseekClient, TranslationResult},
+    deepseek::{
+        build_translation_input, parse_translation, translation_prompt_for, DeepseekClient,
+        TranslationResult,
+    },
+    llm::SharedLlmClient,
     ollama::OllamaClient,
+    translation_rules::TranslationRules,
 };
 
 const VERIFICATION_SAMPLE_TEXT: &str = "NewsAggregator ping"; // 验证连接用的短文本
+// AI 去重故障切换：首选 provider 连续失败次数达到阈值后切到备用 provider
+const DEDUP_FAILOVER_THRESHOLD: u32 = 3;
+// 切换后至少停留在备用 provider 的时长，之后再试探首选 provider（避免来回抖动）
+const DEDUP_FAILBACK_COOLDOWN: Duration = Duration::from_secs(300);
+// 翻译失败事件的上报间隔（按 provider），期间的失败只计数
+const TRANSLATION_FAILURE_REPORT_INTERVAL: Duration = Duration::from_secs(1800);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TranslatorProvider {
@@ -33,6 +44,24 @@ fn clear_verification(state: &mut TranslationState, provider: TranslatorProvider
     }
 }
 
+impl TranslatorProvider {
+    pub fn other(self) -> TranslatorProvider {
+        match self {
+            TranslatorProvider::Deepseek => TranslatorProvider::Ollama,
+            TranslatorProvider::Ollama => TranslatorProvider::Deepseek,
+        }
+    }
+}
+
+// 可作为故障切换目标：客户端存在且最近一次凭据验证未报错
+fn provider_healthy(state: &TranslationState, provider: TranslatorProvider) -> bool {
+    let error = match provider {
+        TranslatorProvider::Deepseek => &state.deepseek_error,
+        TranslatorProvider::Ollama => &state.ollama_error,
+    };
+    provider_available(state, provider) && error.is_none()
+}
+
 fn provider_available(state: &TranslationState, provider: TranslatorProvider) -> bool {
     // 不再依赖自动验证标记，仅以是否存在可用客户端判定“可用”
     match provider {
@@ -215,7 +244,51 @@ struct TranslationState {
     ollama_client: Option<Arc<OllamaClient>>,
     ollama_verified: bool,
     ollama_error: Option<String>,
-    translation_enabled: bool,
+    translation_rules: TranslationRules,
+    dedup_failover: DedupFailoverState,
+    deepseek_failures: FailureReportState,
+    ollama_failures: FailureReportState,
+}
+
+#[derive(Debug, Default)]
+struct FailureReportState {
+    last_reported_at: Option<Instant>,
+    // 上次上报之后被限频吞掉的失败次数
+    suppressed: u32,
+}
+
+/// 翻译失败时附加在错误链上的上下文；`report` 为 Some 时调用方应上报运维事件，
+/// 其值为上次上报以来被限频省略的失败次数
+#[derive(Debug, Clone, Copy)]
+pub struct TranslationFailed {
+    pub provider: TranslatorProvider,
+    pub report: Option<u32>,
+}
+
+impl std::fmt::Display for TranslationFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} translation failed", self.provider.as_str())
+    }
+}
+
+#[derive(Debug, Default)]
+struct DedupFailoverState {
+    preferred: Option<TranslatorProvider>,
+    consecutive_failures: u32,
+    // Some 表示当前已切换到备用 provider，记录切换（或最近一次试探失败）的时间
+    failed_over_at: Option<Instant>,
+}
+
+/// AI 去重 provider 切换结果，由调用方上报事件
+#[derive(Debug, Clone, Copy)]
+pub enum DedupSwitch {
+    FailedOver {
+        from: TranslatorProvider,
+        to: TranslatorProvider,
+    },
+    Recovered {
+        to: TranslatorProvider,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -238,7 +311,7 @@ pub struct TranslatorCredentialsUpdate {
     pub deepseek_api_key: Option<String>,
     pub ollama_base_url: Option<String>,
     pub ollama_model: Option<String>,
-    pub translation_enabled: Option<bool>,
+    pub translation_rules: Option<TranslationRules>,
 }
 
 #[derive(Debug, Clone)]
@@ -251,6 +324,7 @@ pub struct TranslatorSnapshot {
     pub ollama_base_url: Option<String>,
     pub ollama_model: Option<String>,
     pub translation_enabled: bool,
+    pub translation_rules: TranslationRules,
 }
 
 impl TranslationEngine {
@@ -266,7 +340,10 @@ impl TranslationEngine {
             ollama_client: None,
             ollama_verified: false,
             ollama_error: None,
-            translation_enabled: false,
+            translation_rules: TranslationRules::default(),
+            dedup_failover: DedupFailoverState::default(),
+            deepseek_failures: FailureReportState::default(),
+            ollama_failures: FailureReportState::default(),
         };
 
         let base_deepseek = DeepseekBaseConfig {
@@ -359,18 +436,89 @@ impl TranslationEngine {
             .unwrap_or(false)
     }
 
-    pub fn deepseek_client(&self) -> Option<Arc<DeepseekClient>> {
-        self.state
-            .read()
-            .ok()
-            .and_then(|state| state.deepseek_client.as_ref().map(Arc::clone))
+    /// 按 provider 取出对应的大模型客户端；未配置时返回 None
+    pub fn llm_client(&self, provider: TranslatorProvider) -> Option<SharedLlmClient> {
+        let state = self.state.read().ok()?;
+        match provider {
+            TranslatorProvider::Deepseek => state
+                .deepseek_client
+                .as_ref()
+                .map(|client| Arc::clone(client) as SharedLlmClient),
+            TranslatorProvider::Ollama => state
+                .ollama_client
+                .as_ref()
+                .map(|client| Arc::clone(client) as SharedLlmClient),
+        }
     }
 
+    /// Ollama 客户端（embedding 等 `LlmClient` 之外的能力使用）；未配置时返回 None
     pub fn ollama_client(&self) -> Option<Arc<OllamaClient>> {
-        self.state
-            .read()
-            .ok()
-            .and_then(|state| state.ollama_client.as_ref().map(Arc::clone))
+        self.state.read().ok()?.ollama_client.clone()
+    }
+
+    /// AI 去重使用的客户端：默认为配置的首选 provider；首选连续失败后切换到健康的备用 provider，
+    /// 冷却期结束后重新试探首选 provider。
+    pub fn dedup_client(&self, preferred: TranslatorProvider) -> Option<SharedLlmClient> {
+        let failed_over = {
+            let state = self.state.read().ok()?;
+            let failover = &state.dedup_failover;
+            failover.preferred == Some(preferred)
+                && failover
+                    .failed_over_at
+                    .is_some_and(|at| at.elapsed() < DEDUP_FAILBACK_COOLDOWN)
+                && provider_healthy(&state, preferred.other())
+        };
+
+        if failed_over {
+            self.llm_client(preferred.other())
+        } else {
+            self.llm_client(preferred)
+        }
+    }
+
+    /// 记录一次 AI 去重调用结果；发生切换时返回切换信息。
+    pub fn record_dedup_result(
+        &self,
+        preferred: TranslatorProvider,
+        used: TranslatorProvider,
+        success: bool,
+    ) -> Option<DedupSwitch> {
+        let mut state = self.state.write().ok()?;
+        let fallback_healthy = provider_healthy(&state, preferred.other());
+        let failover = &mut state.dedup_failover;
+        if failover.preferred != Some(preferred) {
+            *failover = DedupFailoverState {
+                preferred: Some(preferred),
+                ..Default::default()
+            };
+        }
+        if used != preferred {
+            // 备用 provider 的结果不影响首选 provider 的健康判定
+            return None;
+        }
+
+        if success {
+            failover.consecutive_failures = 0;
+            return failover
+                .failed_over_at
+                .take()
+                .map(|_| DedupSwitch::Recovered { to: preferred });
+        }
+
+        failover.consecutive_failures = failover.consecutive_failures.saturating_add(1);
+        if failover.failed_over_at.is_some() {
+            // 冷却后的试探仍失败：继续停留在备用 provider
+            failover.failed_over_at = Some(Instant::now());
+            return None;
+        }
+        if failover.consecutive_failures >= DEDUP_FAILOVER_THRESHOLD && fallback_healthy {
+            failover.failed_over_at = Some(Instant::now());
+            return Some(DedupSwitch::FailedOver {
+                from: preferred,
+                to: preferred.other(),
+            });
+        }
+        None
     }
 
     fn spawn_verification_tasks(
@@ -437,14 +585,24 @@ impl TranslationEngine {
             ollama_error: state.ollama_error.clone(),
             ollama_base_url,
             ollama_model,
-            translation_enabled: state.translation_enabled,
+            translation_enabled: state.translation_rules.any_enabled(),
+            translation_rules: state.translation_rules.clone(),
         }
     }
 
+    /// 是否有任一语言启用了翻译
     pub fn translation_enabled(&self) -> bool {
         self.state
             .read()
-            .map(|state| state.translation_enabled)
+            .map(|state| state.translation_rules.any_enabled())
+            .unwrap_or(false)
+    }
+
+    /// 按翻译规则判断检测到的原文语种是否需要翻译
+    pub fn should_translate(&self, language: Option<&str>) -> bool {
+        self.state
+            .read()
+            .map(|state| state.translation_rules.target_for(language).is_some())
             .unwrap_or(false)
     }
 
@@ -518,8 +676,8 @@ impl TranslationEngine {
             state.ollama_client = build_ollama_client(&self.http_config, &base_guard)?;
         }
 
-        if let Some(flag) = update.translation_enabled {
-            state.translation_enabled = flag;
+        if let Some(rules) = update.translation_rules {
+            state.translation_rules = rules;
         }
 
         if let Some(provider) = update.provider {
@@ -589,73 +747,93 @@ impl TranslationEngine {
             Err(TranslationError::NotConfigured) => Ok(None),
             Err(err) => {
                 warn!(provider = provider.as_str(), error = %err, "translator failed");
-                Err(err.into_anyhow())
+                let report = self.note_failure(provider);
+                Err(err.into_anyhow().context(TranslationFailed { provider, report }))
             }
         }
     }
 
-    async fn try_provider(
+    /// 按需翻译为指定语言（`target` 为写入提示词的语言名称，如“简体中文”、“English”）。
+    /// 与 `translate` 共用当前 provider 与失败限频；provider 不可用时返回 `Ok(None)`
+    pub async fn translate_into(
         &self,
-        provider: TranslatorProvider,
         title: &str,
         description: Option<&str>,
-    ) -> Result<TranslationResult, TranslationError> {
-        match provider {
-            TranslatorProvider::Deepseek => {
-                let (client, _verified) = {
-                    let state = self.state.read().map_err(|_| {
-                        TranslationError::Other(anyhow!("translator lock poisoned"))
-                    })?;
-                    (state.deepseek_client.clone(), state.deepseek_verified)
-                };
-
-                let client = client.ok_or(TranslationError::NotConfigured)?;
-                client
-                    .translate_news(title, description)
-                    .await
-                    .map(|result| {
-                        let desc_in_len = description.map(|s| s.len()).unwrap_or(0);
-                        let desc_out_len = result.description.as_ref().map(|s| s.len()).unwrap_or(0);
-                        info!(
-                            provider = %TranslatorProvider::Deepseek.as_str(),
-                            title_len = result.title.len(),
-                            desc_in_len,
-                            desc_out_len,
-                            "translation success"
-                        );
-                        result
-                    })
-                    .map_err(TranslationError::Other)
+        target: &str,
+    ) -> Result<Option<TranslationResult>> {
+        let provider = {
+            let state = self.state.read().map_err(|_| anyhow!("translator lock poisoned"))?;
+            if !provider_available(&state, state.provider) {
+                return Ok(None);
             }
-            TranslatorProvider::Ollama => {
-                let (client, _verified) = {
-                    let state = self.state.read().map_err(|_| {
-                        TranslationError::Other(anyhow!("translator lock poisoned"))
-                    })?;
-                    (state.ollama_client.clone(), state.ollama_verified)
-                };
+            state.provider
+        };
+        let Some(client) = self.llm_client(provider) else {
+            return Ok(None);
+        };
 
-                let client = client.ok_or(TranslationError::NotConfigured)?;
-
-                client
-                    .translate_news(title, description)
-                    .await
-                    .map(|result| {
-                        let desc_in_len = description.map(|s| s.len()).unwrap_or(0);
-                        let desc_out_len = result.description.as_ref().map(|s| s.len()).unwrap_or(0);
-                        info!(
-                            provider = %TranslatorProvider::Ollama.as_str(),
-                            title_len = result.title.len(),
-                            desc_in_len,
-                            desc_out_len,
-                            "translation success"
-                        );
-                        result
-                    })
-                    .map_err(TranslationError::Other)
+        let prompt = translation_prompt_for(target);
+        let result = client
+            .complete(&prompt, build_translation_input(title, description), "on-demand translation")
+            .await
+            .and_then(|content| parse_translation(&content));
+        match result {
+            Ok(result) => {
+                info!(provider = %provider.as_str(), target, "on-demand translation success");
+                Ok(Some(result))
+            }
+            Err(err) => {
+                warn!(provider = provider.as_str(), target, error = %err, "on-demand translation failed");
+                let report = self.note_failure(provider);
+                Err(err.context(TranslationFailed { provider, report }))
             }
         }
     }
+
+    // 按 provider 限频：距上次上报超过间隔才返回 Some（附带期间省略的失败次数）
+    fn note_failure(&self, provider: TranslatorProvider) -> Option<u32> {
+        let mut state = self.state.write().ok()?;
+        let failures = match provider {
+            TranslatorProvider::Deepseek => &mut state.deepseek_failures,
+            TranslatorProvider::Ollama => &mut state.ollama_failures,
+        };
+        let due = failures
+            .last_reported_at
+            .is_none_or(|at| at.elapsed() >= TRANSLATION_FAILURE_REPORT_INTERVAL);
+        if !due {
+            failures.suppressed = failures.suppressed.saturating_add(1);
+            return None;
+        }
+        failures.last_reported_at = Some(Instant::now());
+        Some(std::mem::take(&mut failures.suppressed))
+    }
+
+    async fn try_provider(
+        &self,
+        provider: TranslatorProvider,
+        title: &str,
+        description: Option<&str>,
+    ) -> Result<TranslationResult, TranslationError> {
+        let client = self
+            .llm_client(provider)
+            .ok_or(TranslationError::NotConfigured)?;
+
+        client
+            .translate_news(title, description)
+            .await
+            .inspect(|result| {
+                let desc_in_len = description.map(|s| s.len()).unwrap_or(0);
+                let desc_out_len = result.description.as_ref().map(|s| s.len()).unwrap_or(0);
+                info!(
+                    provider = %provider.as_str(),
+                    title_len = result.title.len(),
+                    desc_in_len,
+                    desc_out_len,
+                    "translation success"
+                );
+            })
+            .map_err(TranslationError::Other)
+    }
 }
 
 
@@ -669,11 +847,12 @@ fn build_deepseek_client(
         _ => return Ok(None),
     };
 
-    let mut config = crate::config::DeepseekConfig::default();
-    config.api_key = Some(api_key);
-    config.base_url = base_config.base_url.clone();
-    config.model = base_config.model.clone();
-    config.timeout_secs = base_config.timeout_secs;
+    let config = crate::config::DeepseekConfig {
+        api_key: Some(api_key),
+        base_url: base_config.base_url.clone(),
+        model: base_config.model.clone(),
+        timeout_secs: base_config.timeout_secs,
+    };
 
     Ok(Some(Arc::new(DeepseekClient::new(config, http_config)?)))
 }
@@ -696,7 +875,7 @@ fn build_ollama_client(
 
  
 
-fn mask_secret(value: &str) -> String {
+pub fn mask_secret(value: &str) -> String {
     if value.is_empty() {
         return "".to_string();
     }
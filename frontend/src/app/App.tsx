@@ -1,4 +1,7 @@
+import { useQuery } from "@tanstack/react-query";
 import { NavLink, Outlet, useNavigate } from "react-router-dom";
+import { getStatsSummary } from "../lib/api";
+import { formatRelative } from "../lib/time";
 
 const navLinkClass = ({ isActive }: { isActive: boolean }) =>
   `px-3 py-2 rounded-md text-sm font-medium transition-colors duration-150 ${
@@ -9,6 +12,12 @@ const navLinkClass = ({ isActive }: { isActive: boolean }) =>
 
 export function AppLayout() {
   const navigate = useNavigate();
+  const statsQuery = useQuery({
+    queryKey: ["stats", "summary"],
+    queryFn: getStatsSummary,
+    staleTime: 60_000,
+  });
+  const stats = statsQuery.data;
 
   return (
     <div className="min-h-screen flex flex-col">
@@ -42,8 +51,14 @@ export function AppLayout() {
       </main>
 
       <footer className="bg-white border-t border-slate-200">
-        <div className="mx-auto max-w-6xl px-4 py-3 text-sm text-slate-500">
-          © {new Date().getFullYear()} 新闻聚合服务 · Rust + React
+        <div className="mx-auto max-w-6xl px-4 py-3 text-sm text-slate-500 flex flex-wrap items-center justify-between gap-2">
+          <span>© {new Date().getFullYear()} 新闻聚合服务 · Rust + React</span>
+          {stats && (
+            <span>
+              共 {stats.total_articles} 篇 · 今日 {stats.articles_today} 篇 · {stats.active_sources} 个来源
+              {stats.last_updated_at && ` · 更新于${formatRelative(stats.last_updated_at)}`}
+            </span>
+          )}
         </div>
       </footer>
     </div>
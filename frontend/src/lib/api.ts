@@ -9,6 +9,21 @@ import {
   TranslationSettingsUpdate,
   AiDedupSettings,
   AiDedupSettingsUpdate,
+  PublicSource,
+  ArticleReader,
+  StatsSummary,
+  ArchiveResp,
+  PublicArticleSource,
+  ArticleStateOut,
+  ArticleTranslationOut,
+  AdminMe,
+  AdminUser,
+  AdminUserCreate,
+  AdminUserUpdate,
+  ApiKey,
+  ApiKeyCreate,
+  ApiKeyCreated,
+  FrontendConfig,
 } from "../types/api";
 
 type QueryParams = Record<string, string | number | undefined | null>;
@@ -130,6 +145,9 @@ const adminRequest = async (path: string, token: string, init?: RequestInit) =>
   if (res.status === 401) {
     throw new UnauthorizedError();
   }
+  if (res.status === 403) {
+    throw new Error("当前账号的角色无权执行该操作");
+  }
   // Sliding session: on any successful admin request, bump local expiry
   refreshAdminSessionExpiry();
   return res;
@@ -141,11 +159,76 @@ export async function getArticles(params: {
   page?: number;
   page_size?: number;
   keyword?: string;
+  source_domain?: string;
+  q?: string; // 标题或摘要关键词
+  after_published_at?: string;
+  after_id?: number;
+  sort?: "published_at" | "click_count" | "fetched_at" | "rank";
+  order?: "desc" | "asc";
+  read?: boolean; // 按本机读者的已读状态筛选
+  bookmarked?: boolean; // true 即稍后阅读列表
 }): Promise<PageResp<ArticleOut>> {
-  const res = await request(`/articles${toQueryString(params)}`, {
+  const { read, bookmarked, ...rest } = params;
+  const query: QueryParams = { ...rest };
+  const headers: Record<string, string> = { Accept: "application/json" };
+  if (read !== undefined || bookmarked !== undefined) {
+    query.read = read === undefined ? undefined : String(read);
+    query.bookmarked = bookmarked === undefined ? undefined : String(bookmarked);
+    headers["X-Reader-Id"] = getReaderId();
+  }
+  const res = await request(`/articles${toQueryString(query)}`, { headers });
+  return parseJSON<PageResp<ArticleOut>>(res);
+}
+
+const READER_ID_KEY = "news_reader_id";
+
+// 匿名读者 ID：首次使用时生成并保存在本机，用于已读与稍后阅读状态
+export function getReaderId(): string {
+  let id = localStorage.getItem(READER_ID_KEY);
+  if (!id) {
+    id = crypto.randomUUID();
+    localStorage.setItem(READER_ID_KEY, id);
+  }
+  return id;
+}
+
+async function setArticleState(
+  id: number,
+  kind: "read" | "bookmark",
+  body: Record<string, boolean>
+): Promise<ArticleStateOut> {
+  const res = await request(`/articles/${id}/${kind}`, {
+    method: "POST",
+    headers: {
+      "Content-Type": "application/json",
+      Accept: "application/json",
+      "X-Reader-Id": getReaderId(),
+    },
+    body: JSON.stringify(body),
+  });
+  return parseJSON<ArticleStateOut>(res);
+}
+
+export function markArticleRead(id: number, read = true): Promise<ArticleStateOut> {
+  return setArticleState(id, "read", { read });
+}
+
+export function setArticleBookmarked(
+  id: number,
+  bookmarked = true
+): Promise<ArticleStateOut> {
+  return setArticleState(id, "bookmark", { bookmarked });
+}
+
+export async function translateArticle(
+  id: number,
+  lang: string
+): Promise<ArticleTranslationOut> {
+  const res = await request(`/articles/${id}/translate${toQueryString({ lang })}`, {
+    method: "POST",
     headers: { Accept: "application/json" },
   });
-  return parseJSON<PageResp<ArticleOut>>(res);
+  return parseJSON<ArticleTranslationOut>(res);
 }
 
 export async function adminLogin(
@@ -179,6 +262,19 @@ export async function adminLogin(
   return parseJSON<AdminLoginResponse>(res);
 }
 
+export async function getFrontendConfig(): Promise<FrontendConfig> {
+  const res = await fetch("/config/frontend", {
+    headers: { Accept: "application/json" },
+  });
+  return parseJSON<FrontendConfig>(res);
+}
+
+// 单点登录由浏览器整页跳转完成，返回入口地址而不是发起请求
+export async function adminOidcLoginUrl(): Promise<string> {
+  const base = await getApiBase();
+  return `${base}/admin/oidc/login`;
+}
+
 export async function adminLogout(token: string): Promise<void> {
   const res = await adminRequest("/admin/logout", token, {
     method: "POST",
@@ -201,9 +297,17 @@ export async function listFeeds(token: string): Promise<FeedOut[]> {
   return parseJSON<FeedOut[]>(res);
 }
 
-export async function getFeaturedArticles(limit = 6): Promise<ArticleOut[]> {
+export type FeaturedOptions = {
+  window_hours?: number; // 统计窗口（小时），默认 24，上限 168
+  max_per_source?: number; // 每个来源最多入选的篇数
+};
+
+export async function getFeaturedArticles(
+  limit = 6,
+  options: FeaturedOptions = {}
+): Promise<ArticleOut[]> {
   const res = await request(
-    `/articles/featured${toQueryString({ limit })}`,
+    `/articles/featured${toQueryString({ limit, ...options })}`,
     {
       headers: { Accept: "application/json" },
     }
@@ -211,6 +315,53 @@ export async function getFeaturedArticles(limit = 6): Promise<ArticleOut[]> {
   return parseJSON<ArticleOut[]>(res);
 }
 
+export async function getStatsSummary(): Promise<StatsSummary> {
+  const res = await request("/stats/summary", {
+    headers: { Accept: "application/json" },
+  });
+  return parseJSON<StatsSummary>(res);
+}
+
+export async function getArchive(options: {
+  year?: number;
+  month?: number;
+  day?: string;
+  page?: number;
+  page_size?: number;
+} = {}): Promise<ArchiveResp> {
+  const res = await request(`/archive${toQueryString(options)}`, {
+    headers: { Accept: "application/json" },
+  });
+  return parseJSON<ArchiveResp>(res);
+}
+
+export async function getPublicSources(): Promise<PublicSource[]> {
+  const res = await request("/sources", {
+    headers: { Accept: "application/json" },
+  });
+  return parseJSON<PublicSource[]>(res);
+}
+
+export async function getArticleReader(id: number): Promise<ArticleReader> {
+  const res = await request(`/articles/${id}/content`, {
+    headers: { Accept: "application/json" },
+  });
+  return parseJSON<ArticleReader>(res);
+}
+
+export async function getArticleSources(id: number): Promise<PublicArticleSource[]> {
+  const res = await request(`/articles/${id}/sources`, {
+    headers: { Accept: "application/json" },
+  });
+  return parseJSON<PublicArticleSource[]>(res);
+}
+
+// 服务端计数后跳转原文的链接，适合直接作为 <a href> 使用
+export async function getArticleRedirectUrl(id: number): Promise<string> {
+  const base = await getApiBase();
+  return `${base}/r/${id}`;
+}
+
 export async function recordArticleClick(id: number): Promise<void> {
   await request(`/articles/${id}/click`, {
     method: "POST",
@@ -242,6 +393,84 @@ export async function deleteFeed(token: string, id: number): Promise<void> {
   }
 }
 
+export async function getAdminMe(token: string): Promise<AdminMe> {
+  const res = await adminRequest("/admin/api/me", token, { method: "GET" });
+  return parseJSON<AdminMe>(res);
+}
+
+export async function listAdminUsers(token: string): Promise<AdminUser[]> {
+  const res = await adminRequest("/admin/api/users", token, { method: "GET" });
+  return parseJSON<AdminUser[]>(res);
+}
+
+export async function createAdminUser(
+  token: string,
+  payload: AdminUserCreate
+): Promise<AdminUser> {
+  const res = await adminRequest("/admin/api/users", token, {
+    method: "POST",
+    headers: {
+      "Content-Type": "application/json",
+      Accept: "application/json",
+    },
+    body: JSON.stringify(payload),
+  });
+  return parseJSON<AdminUser>(res);
+}
+
+export async function updateAdminUser(
+  token: string,
+  id: number,
+  payload: AdminUserUpdate
+): Promise<AdminUser> {
+  const res = await adminRequest(`/admin/api/users/${id}`, token, {
+    method: "PATCH",
+    headers: {
+      "Content-Type": "application/json",
+      Accept: "application/json",
+    },
+    body: JSON.stringify(payload),
+  });
+  return parseJSON<AdminUser>(res);
+}
+
+export async function deleteAdminUser(token: string, id: number): Promise<void> {
+  const res = await adminRequest(`/admin/api/users/${id}`, token, {
+    method: "DELETE",
+  });
+  if (!res.ok) {
+    const message = await res.text();
+    throw new Error(message || `Failed to delete user ${id}`);
+  }
+}
+
+export async function listApiKeys(token: string): Promise<ApiKey[]> {
+  const res = await adminRequest("/admin/api/api_keys", token, { method: "GET" });
+  return parseJSON<ApiKey[]>(res);
+}
+
+export async function createApiKey(
+  token: string,
+  payload: ApiKeyCreate
+): Promise<ApiKeyCreated> {
+  const res = await adminRequest("/admin/api/api_keys", token, {
+    method: "POST",
+    headers: {
+      "Content-Type": "application/json",
+      Accept: "application/json",
+    },
+    body: JSON.stringify(payload),
+  });
+  return parseJSON<ApiKeyCreated>(res);
+}
+
+export async function revokeApiKey(token: string, id: number): Promise<ApiKey> {
+  const res = await adminRequest(`/admin/api/api_keys/${id}`, token, {
+    method: "DELETE",
+  });
+  return parseJSON<ApiKey>(res);
+}
+
 export async function getTranslationSettings(
   token: string
 ): Promise<TranslationSettings> {
@@ -378,8 +607,19 @@ export async function updateAiDedupSettings(
 // Alerts
 export async function listAlerts(
   token: string,
-  params?: { level?: string; code?: string; source?: string; from?: string; to?: string; since_id?: number; limit?: number }
-): Promise<import("../types/api").AlertRecord[]> {
+  params?: {
+    level?: string;
+    code?: string;
+    source?: string;
+    from?: string;
+    to?: string;
+    since_id?: number;
+    before_id?: number;
+    q?: string;
+    group_by?: "code";
+    limit?: number;
+  }
+): Promise<import("../types/api").AlertListResp> {
   const res = await adminRequest(`/admin/api/alerts${toQueryString(params)}`, token, {
     headers: { Accept: "application/json" },
   });
@@ -3,6 +3,8 @@ import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 import {
   adminLogin,
   adminLogout,
+  adminOidcLoginUrl,
+  getFrontendConfig,
   UnauthorizedError,
   getTranslationSettings,
   updateTranslationSettings,
@@ -15,7 +17,7 @@ import {
 import { TranslationSettings, TranslationSettingsUpdate, AiDedupSettings, AiDedupSettingsUpdate, AdminLoginResponse } from "../../types/api";
 import { FeedsPage } from "../Feeds";
 import { listAlerts, openAlertsStream } from "../../lib/api";
-import type { AlertRecord } from "../../types/api";
+import type { AlertCodeCount, AlertRecord, FetcherActivity } from "../../types/api";
 
 type AdminSession = {
   token: string;
@@ -67,11 +69,47 @@ const persistSession = (session: AdminSession | null) => {
   storage.setItem(SESSION_STORAGE_KEY, JSON.stringify(session));
 };
 
+// 单点登录回调把结果放在 URL 片段中：成功为 `#token=...&expires_in=...`，失败为 `#oidc_error=...`
+const consumeOidcFragment = (): { session: AdminSession | null; error: string | null } => {
+  if (typeof window === "undefined" || !window.location.hash) {
+    return { session: null, error: null };
+  }
+  const params = new URLSearchParams(window.location.hash.slice(1));
+  const token = params.get("token");
+  const error = params.get("oidc_error");
+  if (!token && !error) {
+    return { session: null, error: null };
+  }
+  window.history.replaceState(null, "", window.location.pathname + window.location.search);
+  if (!token) {
+    return { session: null, error };
+  }
+  const ttlSecs = Number(params.get("expires_in")) || 0;
+  const session: AdminSession = {
+    token,
+    expiresAt: Date.now() + ttlSecs * 1000,
+    ttlSecs,
+  };
+  persistSession(session);
+  return { session, error: null };
+};
+
 export function AdminPage() {
-  const [session, setSession] = useState<AdminSession | null>(() => loadSession());
+  const [oidcResult] = useState(() => consumeOidcFragment());
+  const [session, setSession] = useState<AdminSession | null>(
+    () => oidcResult.session ?? loadSession()
+  );
   const [username, setUsername] = useState("");
   const [password, setPassword] = useState("");
-  const [notice, setNotice] = useState<string | null>(null);
+  const [notice, setNotice] = useState<string | null>(() =>
+    oidcResult.error ? `单点登录失败：${oidcResult.error}` : null
+  );
+  const frontendConfigQuery = useQuery({
+    queryKey: ["frontend-config"],
+    queryFn: getFrontendConfig,
+    staleTime: 5 * 60_000,
+  });
+  const oidcEnabled = frontendConfigQuery.data?.admin_oidc ?? false;
   const [info, setInfo] = useState<string | null>(null);
 
   const clearSession = useCallback(
@@ -331,6 +369,18 @@ export function AdminPage() {
                 "登录"
               )}
             </button>
+
+            {oidcEnabled && (
+              <button
+                type="button"
+                onClick={async () => {
+                  window.location.assign(await adminOidcLoginUrl());
+                }}
+                className="w-full inline-flex items-center justify-center rounded-lg border border-slate-300 px-4 py-2 text-sm font-medium text-slate-700 transition hover:bg-slate-50"
+              >
+                使用单点登录
+              </button>
+            )}
           </form>
         </div>
       </div>
@@ -533,25 +583,67 @@ export function AdminPage() {
 
 function AlertsPanel({ token, onUnauthorized }: { token: string; onUnauthorized: () => void }) {
   const [items, setItems] = useState<AlertRecord[]>([]);
+  // 实时抓取进度，只保留最近 50 条，刷新页面即清空
+  const [activity, setActivity] = useState<FetcherActivity[]>([]);
   // UI 级别过滤：ALL/INFO/WRN/ERR -> 实际 level: ''/'info'/'warn'/'error'
   const [uiLevel, setUiLevel] = useState<string>("ALL");
-  // Source domain no longer a primary filter; keep minimal UI
+  // 搜索框输入与已提交的关键字（在事件码、说明与属性中匹配）
+  const [searchInput, setSearchInput] = useState("");
+  const [search, setSearch] = useState("");
+  const [codeFilter, setCodeFilter] = useState("");
+  const [groups, setGroups] = useState<AlertCodeCount[]>([]);
+  const [nextBeforeId, setNextBeforeId] = useState<number | undefined>(undefined);
+  const [loadingMore, setLoadingMore] = useState(false);
   const [error, setError] = useState<string | null>(null);
   // Removed local hide/dismiss operations: list is read-only
 
+  const levelMap: Record<string, string> = { ALL: "", INFO: "info", WRN: "warn", ERR: "error" };
+  const actualLevel = levelMap[uiLevel] ?? "";
+
   useEffect(() => {
     let canceled = false;
     (async () => {
       try {
-        const list = await listAlerts(token, { limit: 30 });
-        if (!canceled) setItems(list);
+        const resp = await listAlerts(token, {
+          limit: 50,
+          level: actualLevel,
+          q: search,
+          code: codeFilter,
+          group_by: "code",
+        });
+        if (canceled) return;
+        setItems(resp.items);
+        setNextBeforeId(resp.next_before_id);
+        setGroups(resp.groups ?? []);
+        setError(null);
       } catch (e) {
         if ((e as Error) instanceof UnauthorizedError) onUnauthorized();
-        else setError((e as Error).message || "加载事件失败");
+        else if (!canceled) setError((e as Error).message || "加载事件失败");
       }
     })();
     return () => { canceled = true; };
-  }, [token, onUnauthorized]);
+  }, [token, onUnauthorized, actualLevel, search, codeFilter]);
+
+  const loadMore = async () => {
+    if (nextBeforeId === undefined || loadingMore) return;
+    setLoadingMore(true);
+    try {
+      const resp = await listAlerts(token, {
+        limit: 50,
+        level: actualLevel,
+        q: search,
+        code: codeFilter,
+        before_id: nextBeforeId,
+      });
+      setItems((prev) => [...prev, ...resp.items]);
+      setNextBeforeId(resp.next_before_id);
+    } catch (e) {
+      if ((e as Error) instanceof UnauthorizedError) onUnauthorized();
+      else setError((e as Error).message || "加载事件失败");
+    } finally {
+      setLoadingMore(false);
+    }
+  };
 
   useEffect(() => {
     let es: EventSource | null = null;
@@ -561,7 +653,13 @@ function AlertsPanel({ token, onUnauthorized }: { token: string; onUnauthorized:
         es.addEventListener("alert", (ev: MessageEvent) => {
           try {
             const rec = JSON.parse(ev.data) as AlertRecord;
-            setItems((prev) => [rec, ...prev].slice(0, 200));
+            setItems((prev) => [rec, ...prev]);
+          } catch {}
+        });
+        es.addEventListener("fetcher", (ev: MessageEvent) => {
+          try {
+            const rec = JSON.parse(ev.data) as FetcherActivity;
+            setActivity((prev) => [rec, ...prev].slice(0, 50));
           } catch {}
         });
         es.onerror = () => {
@@ -573,9 +671,10 @@ function AlertsPanel({ token, onUnauthorized }: { token: string; onUnauthorized:
     return () => { es?.close(); };
   }, [token]);
 
-  const levelMap: Record<string, string> = { ALL: "", INFO: "info", WRN: "warn", ERR: "error" };
-  const actualLevel = levelMap[uiLevel] ?? "";
-  const filtered = items.filter((it) => (!actualLevel || it.level === actualLevel));
+  // 实时推送的新事件未经服务端筛选，这里按当前级别与事件码再过滤一遍；关键字只对已加载的列表生效
+  const filtered = items.filter(
+    (it) => (!actualLevel || it.level === actualLevel) && (!codeFilter || it.code === codeFilter)
+  );
 
   const levelPill = (lvl: string) => {
     const map: Record<string, { text: string; color: string; bg: string }> = {
@@ -593,6 +692,18 @@ function AlertsPanel({ token, onUnauthorized }: { token: string; onUnauthorized:
     );
   };
 
+  // 旧版本把 provider 拼进事件码（TRANSLATION_PROVIDER_SET_<provider>），新事件放在 attrs.provider
+  const providerSetOf = (it: AlertRecord) => {
+    const code = (it.code || '').toUpperCase();
+    if (code === 'TRANSLATION_PROVIDER_SET') {
+      return String(it.attrs?.provider ?? '');
+    }
+    if (code.startsWith('TRANSLATION_PROVIDER_SET_')) {
+      return code.replace('TRANSLATION_PROVIDER_SET_', '');
+    }
+    return null;
+  };
+
   const titleFor = (it: AlertRecord) => {
     // Simple mapping from code to human-friendly title
     const code = (it.code || '').toUpperCase();
@@ -600,21 +711,45 @@ function AlertsPanel({ token, onUnauthorized }: { token: string; onUnauthorized:
       SYSTEM_STARTED: "系统启动",
       ADMIN_LOGIN: "管理员登录",
       ADMIN_LOGOUT: "管理员登出",
+      ADMIN_SESSION_EVICTED: "会话数超限，旧会话已下线",
+      ADMIN_SESSION_REVOKED: "管理员会话已强制下线",
+      USER_CHANGED: "后台账号变更",
+      API_KEY_CREATED: "API 密钥创建",
+      API_KEY_REVOKED: "API 密钥吊销",
       TRANSLATION_ENABLED: "已开启翻译",
       TRANSLATION_DISABLED: "已关闭翻译",
       MODEL_SETTINGS_UPDATED: "大模型配置已更新",
+      TRANSLATION_PROVIDER_FAILED: "翻译服务调用失败",
+      FEED_MUTED: "订阅源已静音",
+      FEED_UNMUTED: "订阅源已解除静音",
+      DIGEST_PUBLISHED: "每周精选已生成",
+      DIGEST_EMAIL_FAILED: "每周精选邮件发送失败",
+      ENRICHMENT_FAILED: "外部评分服务调用失败",
+      ARTICLE_DELETED: "文章已被管理员删除",
+      ARTICLES_BULK_UPDATED: "文章批量操作",
+      ARTICLES_ARCHIVED: "过期文章归档",
+      SETTINGS_IMPORTED: "设置已导入",
+      FEED_AUTO_DISABLED: "订阅源连续失败，已自动停用",
+      FEED_REENABLED: "订阅源已重新启用",
+      FEED_ROBOTS_DISALLOWED: "robots.txt 禁止抓取订阅源",
+      TRANSLATION_FAILED: "文章翻译失败",
+      AI_DEDUP_FAILOVER: "AI 去重已切换备用服务",
+      AI_DEDUP_RECOVERED: "AI 去重已恢复首选服务",
+      DB_SLOW_QUERY: "数据库慢查询",
+      DB_POOL_PRESSURE: "数据库连接池吃紧",
+      BACKUP_FAILED: "数据库备份失败",
     };
-    if (code.startsWith("TRANSLATION_PROVIDER_SET_")) {
-      const p = code.replace("TRANSLATION_PROVIDER_SET_", "");
-      return `已切换翻译服务：${p}`;
+    const provider = providerSetOf(it);
+    if (provider !== null) {
+      return `已切换翻译服务：${provider}`;
     }
     return map[code] || code;
   };
 
   const detailFor = (it: AlertRecord) => {
     const code = (it.code || '').toUpperCase();
-    // 优先：对 ADMIN_LOGOUT 展示 addition_info，用于区分“主动登出/自动登出”
-    if (code === 'ADMIN_LOGOUT' && it.addition_info) {
+    // 优先：对登录/登出展示 addition_info，用于区分“主动登出/自动登出”及来源 IP
+    if ((code === 'ADMIN_LOGIN' || code === 'ADMIN_LOGOUT') && it.addition_info) {
       return it.addition_info;
     }
     // 对于纯系统/管理操作类事件，默认不显示详情
@@ -628,9 +763,9 @@ function AlertsPanel({ token, onUnauthorized }: { token: string; onUnauthorized:
     if (noDetailCodes.has(code)) return '';
 
     // 翻译服务切换：展示 provider 名称
-    if (code.startsWith('TRANSLATION_PROVIDER_SET_')) {
-      const p = code.replace('TRANSLATION_PROVIDER_SET_', '');
-      return `服务：${p}`;
+    const provider = providerSetOf(it);
+    if (provider !== null) {
+      return `服务：${provider}`;
     }
 
     // 其他未知事件：展示 addition_info（若有）
@@ -640,11 +775,36 @@ function AlertsPanel({ token, onUnauthorized }: { token: string; onUnauthorized:
     return '';
   };
 
+  const activityText = (it: FetcherActivity) => {
+    switch (it.kind) {
+      case "started":
+        return "开始抓取";
+      case "finished":
+        return `完成：${it.entries} 条，新增 ${it.inserted}，重复 ${it.duplicates}`;
+      case "failed":
+        return `失败：${it.error}`;
+    }
+  };
+
   return (
     <div className="space-y-4">
       {error && (
         <div className="rounded-md border border-red-200 bg-red-50 px-4 py-3 text-sm text-red-600">{error}</div>
       )}
+      {activity.length > 0 && (
+        <div className="rounded-lg border border-slate-200 bg-white px-3 py-2 text-xs">
+          <div className="mb-1 font-medium text-slate-700">实时抓取</div>
+          <ul className="max-h-40 space-y-0.5 overflow-y-auto">
+            {activity.map((it, idx) => (
+              <li key={`${it.ts}-${it.feed_id}-${idx}`} className="flex gap-2 text-slate-500">
+                <span className="whitespace-nowrap">{new Date(it.ts).toLocaleTimeString()}</span>
+                <span className="whitespace-nowrap text-slate-700">{it.source_domain}</span>
+                <span className={`truncate ${it.kind === "failed" ? "text-red-600" : ""}`}>{activityText(it)}</span>
+              </li>
+            ))}
+          </ul>
+        </div>
+      )}
       <div className="flex flex-wrap gap-3 text-sm items-center">
         <div className="inline-flex overflow-hidden rounded-full border border-slate-300 bg-white">
           {(["ALL","INFO","WRN","ERR"] as const).map(opt => (
@@ -655,9 +815,44 @@ function AlertsPanel({ token, onUnauthorized }: { token: string; onUnauthorized:
             >{opt}</button>
           ))}
         </div>
-        {/* 暂不提供事件码过滤，保持简洁 */}
+        <form
+          className="flex items-center gap-2"
+          onSubmit={(e) => {
+            e.preventDefault();
+            setSearch(searchInput.trim());
+          }}
+        >
+          <input
+            value={searchInput}
+            onChange={(e) => setSearchInput(e.target.value)}
+            placeholder="搜索事件码、说明或属性"
+            className="w-56 rounded-md border border-slate-300 px-2 py-1 text-xs focus:border-slate-500 focus:outline-none"
+          />
+          <button type="submit" className="rounded-md border border-slate-300 px-2 py-1 text-xs text-slate-700 hover:bg-slate-100">搜索</button>
+        </form>
         {/* 来源域名作为详情附加信息，不提供隐藏/清空操作 */}
       </div>
+      {groups.length > 0 && (
+        <div className="flex flex-wrap gap-2 text-xs">
+          {codeFilter && (
+            <button
+              onClick={() => setCodeFilter("")}
+              className="rounded-full border border-slate-300 px-2 py-0.5 text-slate-600 hover:bg-slate-100"
+            >全部事件</button>
+          )}
+          {groups.map((g) => (
+            <button
+              key={g.code}
+              onClick={() => setCodeFilter(codeFilter === g.code ? "" : g.code)}
+              title={`最近一次：${new Date(g.last_ts).toLocaleString()}`}
+              className={`rounded-full border px-2 py-0.5 ${codeFilter === g.code ? 'border-slate-900 bg-slate-900 text-white' : 'border-slate-300 text-slate-700 hover:bg-slate-100'}`}
+            >
+              {titleFor({ id: 0, ts: g.last_ts, level: g.level, code: g.code })}
+              <span className={`ml-1 ${g.level === 'error' ? 'text-red-500' : g.level === 'warn' ? 'text-amber-500' : 'text-slate-400'}`}>{g.count}</span>
+            </button>
+          ))}
+        </div>
+      )}
       <div className="overflow-x-auto rounded-lg border border-slate-200 bg-white">
         <table className="min-w-full table-fixed text-sm">
           <thead className="bg-slate-50 text-slate-600">
@@ -691,6 +886,15 @@ function AlertsPanel({ token, onUnauthorized }: { token: string; onUnauthorized:
           </tbody>
         </table>
       </div>
+      {nextBeforeId !== undefined && (
+        <div className="text-center">
+          <button
+            onClick={loadMore}
+            disabled={loadingMore}
+            className="rounded-md border border-slate-300 bg-white px-3 py-1 text-xs text-slate-700 hover:bg-slate-100 disabled:opacity-50"
+          >{loadingMore ? "加载中…" : "加载更早的事件"}</button>
+        </div>
+      )}
     </div>
   );
 }
@@ -1,4 +1,4 @@
-import { FeedOut } from "../../types/api";
+import { FeedOut, FetchErrorKind } from "../../types/api";
 import { formatDateTime } from "../../lib/time";
 
 export type FeedTableProps = {
@@ -120,7 +120,30 @@ export function FeedTable({ feeds, onEdit, onDelete, busyIds, emptyMessage }: Fe
   );
 }
 
+const ERROR_KIND_HINTS: Record<FetchErrorKind, { label: string; hint: string }> = {
+  dns: { label: "域名解析失败", hint: "检查订阅地址的域名是否正确、是否已过期" },
+  tls: { label: "证书错误", hint: "站点证书无效或 TLS 握手失败，可尝试改用 http 地址" },
+  timeout: { label: "请求超时", hint: "源站响应过慢，可稍后重试或调大超时时间" },
+  connection: { label: "连接失败", hint: "源站拒绝连接或网络不通，检查代理设置" },
+  http_4xx: { label: "地址失效", hint: "源站返回 4xx，订阅地址可能已变更或需要鉴权" },
+  http_5xx: { label: "源站故障", hint: "源站返回 5xx，通常会自行恢复" },
+  parse: { label: "解析失败", hint: "返回内容不是有效的 RSS / Atom / JSON Feed" },
+  encoding: { label: "编码错误", hint: "内容编码无法识别，检查源站的 charset 声明" },
+  robots: { label: "robots 禁止", hint: "站点的 robots.txt 禁止抓取该地址" },
+  other: { label: "抓取失败", hint: "查看错误详情了解原因" },
+};
+
 function getStatusBadge(feed: FeedOut) {
+  if (feed.fail_count > 0 && feed.last_error_kind) {
+    const { label, hint } = ERROR_KIND_HINTS[feed.last_error_kind] ?? ERROR_KIND_HINTS.other;
+    const status = feed.last_fetch_status ? ` ${feed.last_fetch_status}` : "";
+    return {
+      label: `${label}${status}`,
+      className: "bg-rose-50 text-rose-700",
+      tooltip: feed.last_error ? `${hint}\n${feed.last_error}` : hint,
+    };
+  }
+
   const status = feed.last_fetch_status ?? undefined;
   if (typeof status === "number") {
     if (status >= 200 && status < 300) {
@@ -4,9 +4,11 @@ export type ArticleOut = {
   url: string;
   description?: string | null;
   language?: string | null;
+  original_language?: "en" | "zh" | "ja" | null;
   source_domain: string;
   published_at: string; // ISO8601 UTC
   click_count: number;
+  tags: string[];
 };
 
 export type FeedOut = {
@@ -19,18 +21,127 @@ export type FeedOut = {
   enabled: boolean;
   fetch_interval_seconds: number;
   filter_condition?: string | null;
+  schedule_cron?: string | null; // cron 表达式（UTC），设置后取代固定间隔
+  ignore_conditional: boolean; // 抓取时不发送 If-None-Match / If-Modified-Since
+  date_timezone?: string | null; // 发布时间按此时区解释，如 +08:00
+  date_format?: string | null; // chrono 日期格式，如 %Y-%m-%d %H:%M:%S
+  language_hint?: string | null; // 源的固定语种，设置后跳过语种检测
+  request_headers: Record<string, string>; // 抓取请求头，凭据类的值已掩码
+  muted_until?: string | null; // 静音截止时间，到期自动解除
+  require_review: boolean; // 新文章需管理员批准后才公开
+  auto_disabled_at?: string | null; // 连续失败被自动停用的时间
+  last_etag?: string | null;
+  last_modified?: string | null;
   last_fetch_at?: string | null;
   last_fetch_status?: number | null;
   fail_count: number;
+  last_error_kind?: FetchErrorKind | null; // 最近一次失败的分类，成功后为 null
+  last_error?: string | null;
+  tags: string[];
 };
 
+export type FetchErrorKind =
+  | "dns"
+  | "tls"
+  | "timeout"
+  | "connection"
+  | "http_4xx"
+  | "http_5xx"
+  | "parse"
+  | "encoding"
+  | "robots"
+  | "other";
+
 export type PageResp<T> = {
   page: number;
   page_size: number;
   total_hint: number;
+  total_estimated?: boolean; // count=estimate 时为估算值
+  next_cursor?: PageCursor; // 本页取满时返回，用于键集分页
   items: T[];
 };
 
+export type PageCursor = {
+  after_published_at: string;
+  after_id: number;
+};
+
+export type ArticleAttrs = {
+  article_id: number;
+  attrs?: Record<string, unknown> | null; // 外部评分服务返回的 JSON 对象
+  enriched_at?: string | null;
+  enrichment_attempts: number;
+  steps: Record<string, EnrichmentStepOutput>; // 增强流水线各步骤的输出，按步骤名
+};
+
+export type EnrichmentStepOutput = {
+  output: Record<string, unknown>;
+  updated_at: string;
+};
+
+export type ArticleContent = {
+  article_id: number;
+  content?: string | null; // 抽取出的正文，段落以空行分隔
+  images: string[];
+  extracted_at?: string | null;
+  attempts: number;
+  last_error?: string | null;
+  full_description?: string | null; // 入库时被截断的完整摘要
+};
+
+export type ArticleReader = {
+  id: number;
+  title: string;
+  url: string;
+  source_domain: string;
+  published_at: string;
+  content?: string | null; // 尚未抽取到正文时为 null，回退到原文链接
+  images: string[];
+};
+
+export type PublicArticleSource = {
+  feed_id?: number | null;
+  feed_title?: string | null;
+  source_name?: string | null;
+  url: string;
+  published_at?: string | null;
+  decision?: string | null; // primary 为主文章本身，其余为归并依据
+  confidence?: number | null;
+};
+
+export type ArticleChangesResp = {
+  items: ArticleOut[]; // 按 id 升序
+  next_since_id: number;
+  has_more: boolean;
+};
+
+export type FacetCount = {
+  value: string;
+  count: number;
+};
+
+export type SearchResp = PageResp<ArticleOut> & {
+  facets: {
+    source_domain: FacetCount[];
+    tag: FacetCount[];
+    day: FacetCount[]; // YYYY-MM-DD (UTC)
+  };
+};
+
+export type ArchiveDay = {
+  day: string; // YYYY-MM-DD (UTC)
+  count: number;
+};
+
+export type ArchiveResp = {
+  year: number;
+  month: number;
+  total: number;
+  days: ArchiveDay[]; // 当月每一天，无文章的日期为 0
+  day: string | null; // articles 对应的日期
+  articles: PageResp<ArticleOut>;
+};
+
 export type FeedUpsertPayload = {
   id?: number;
   url: string;
@@ -41,6 +152,91 @@ export type FeedUpsertPayload = {
   title?: string | null;
   site_url?: string | null;
   filter_condition?: string | null;
+  schedule_cron?: string | null;
+  ignore_conditional?: boolean;
+  require_review?: boolean; // 未提交保持原值
+  date_timezone?: string; // 空字符串清除
+  date_format?: string; // 空字符串清除
+  language_hint?: string; // en|zh|ja|ko，空字符串清除
+  request_headers?: Record<string, string>; // 整体替换，空对象清除；回传掩码值保留原值
+  tags?: string[];
+};
+
+export type ArticleStateOut = {
+  article_id: number;
+  read_at?: string | null;
+  bookmarked_at?: string | null;
+};
+
+export type ArticleTranslationOut = {
+  article_id: number;
+  lang: string;
+  title: string;
+  description?: string | null;
+  translated: boolean;
+  cached: boolean;
+};
+
+export type ModerationLogOut = {
+  id: number;
+  article_id: number;
+  action: "hide" | "unhide" | "edit" | "approve";
+  reason?: string | null;
+  actor?: string | null;
+  created_at: string;
+};
+
+export type FilterSuggestion = {
+  field: "url" | "title";
+  pattern: string;
+  reason?: string | null;
+  condition: string;
+  matched: number;
+};
+
+export type FilterSuggestionsOut = {
+  feed_id: number;
+  provider: string;
+  sample_size: number;
+  current_condition?: string | null;
+  suggestions: FilterSuggestion[];
+  combined_condition?: string | null;
+};
+
+export type BookmarkFeed = {
+  bookmark_url: string;
+  bookmark_title?: string | null;
+  feed_url: string;
+  feed_id: number;
+};
+
+export type BookmarkMiss = {
+  bookmark_url: string;
+  bookmark_title?: string | null;
+  reason: string;
+};
+
+export type BookmarkImportOut = {
+  total: number;
+  created: BookmarkFeed[];
+  existing: BookmarkFeed[];
+  no_feed: BookmarkMiss[];
+};
+
+export type FilteredArticleOut = {
+  id: number; // 隔离记录 ID
+  article_id: number;
+  feed_id: number;
+  title: string;
+  url: string;
+  condition: string;
+  filtered_at: string;
+  expires_at: string;
+};
+
+export type FilteredRestoreOut = {
+  restored: number;
+  skipped: number;
 };
 
 export type FeedTestResult = {
@@ -48,11 +244,39 @@ export type FeedTestResult = {
   title?: string | null;
   site_url?: string | null;
   entry_count: number;
+  date_preview: FeedDatePreview[];
+};
+
+export type DiscoveredFeed = {
+  url: string;
+  declared_title?: string | null; // 页面 <link> 的 title
+  title?: string | null;
+  entry_count?: number | null;
+  error?: string | null; // 验证失败原因
+  existing_feed_id?: number | null; // 已订阅时的订阅源 ID
+};
+
+export type FeedDiscoverResult = {
+  page_url: string;
+  candidates: DiscoveredFeed[];
+};
+
+export type FeedDatePreview = {
+  title?: string | null;
+  published_at_default?: string | null;
+  published_at?: string | null;
+};
+
+export type TranslationRule = {
+  from: string;
+  to?: string;
+  translate?: boolean;
 };
 
 export type TranslationSettings = {
   provider: string;
   translation_enabled: boolean;
+  translation_rules: TranslationRule[];
   deepseek_configured: boolean;
   ollama_configured: boolean;
   deepseek_api_key_masked?: string | null;
@@ -65,6 +289,7 @@ export type TranslationSettings = {
 export type TranslationSettingsUpdate = {
   provider?: string;
   translation_enabled?: boolean;
+  translation_rules?: TranslationRule[];
   deepseek_api_key?: string;
   ollama_base_url?: string;
   ollama_model?: string;
@@ -84,9 +309,82 @@ export type AiDedupSettingsUpdate = {
   provider?: string;
 };
 
+export type AdminRole = "admin" | "editor" | "viewer";
+
 export type AdminLoginResponse = {
   token: string;
   expires_in: number;
+  username: string;
+  role: AdminRole;
+};
+
+export type AdminMe = {
+  user_id?: number | null;
+  username: string;
+  role: AdminRole;
+  scopes: string[];
+  builtin: boolean;
+};
+
+export type AdminUser = {
+  id: number;
+  username: string;
+  role: AdminRole;
+  auth_source: "password" | "oidc";
+  disabled: boolean;
+  disabled_at: string | null;
+  last_login_at: string | null;
+  created_at: string;
+  updated_at: string;
+};
+
+export type ApiKey = {
+  id: number;
+  name: string;
+  key_prefix: string;
+  role: AdminRole;
+  created_by: string;
+  created_at: string;
+  last_used_at: string | null;
+  expires_at: string | null;
+  revoked_at: string | null;
+  active: boolean;
+};
+
+// `key` 只在创建时返回一次
+export type ApiKeyCreated = ApiKey & { key: string };
+
+export type ApiKeyCreate = {
+  name: string;
+  role?: AdminRole;
+  expires_in_days?: number;
+};
+
+export type AdminUserCreate = {
+  username: string;
+  password: string;
+  role: AdminRole;
+};
+
+export type AdminUserUpdate = {
+  role?: AdminRole;
+  password?: string;
+  disabled?: boolean;
+};
+
+export type AdminSession = {
+  id: number;
+  username: string;
+  client_ip: string | null;
+  user_agent: string | null;
+  created_at: string;
+  last_seen_at: string;
+  expires_at: string;
+  current: boolean;
+};
+
+export type AdminSessionRevokeOut = {
+  revoked: number;
 };
 
 // Alerts / Notification Center
@@ -96,4 +394,181 @@ export type AlertRecord = {
   level: "info" | "warn" | "error" | string;
   code: string;
   addition_info?: string | null;
+  // 结构化属性，键随事件码而定（如 feed_id、source_domain、provider）
+  attrs?: Record<string, unknown> | null;
+};
+
+export type AlertCodeCount = {
+  code: string;
+  count: number;
+  // 该事件码下最严重的级别
+  level: "info" | "warn" | "error" | string;
+  last_ts: string;
+};
+
+export type AlertListResp = {
+  items: AlertRecord[];
+  // 本页取满时返回，作为 before_id 传回取更早的事件
+  next_before_id?: number;
+  // 请求带 group_by=code 时返回
+  groups?: AlertCodeCount[];
+};
+
+// SSE `fetcher` 事件：抓取进度，仅实时推送、不落库；详细程度由设置 fetcher.activity_verbosity 控制
+export type FetcherActivity = {
+  ts: string;
+  feed_id: number;
+  source_domain: string;
+} & (
+  | { kind: "started" }
+  | { kind: "finished"; entries: number; inserted: number; duplicates: number }
+  | { kind: "failed"; error: string }
+);
+
+export type LayoutSection = {
+  key: string;
+  title: string;
+  kind: "latest" | "featured" | "tag" | "source";
+  value?: string | null; // tag 或 source_domain
+  limit: number;
+};
+
+export type FrontendFeatures = {
+  semantic_search: boolean;
+  tags: boolean;
+  digest: boolean;
+  translate: boolean;
+  default_page_size: number;
+};
+
+export type FrontendConfig = {
+  api_base_url: string;
+  layout: { sections: LayoutSection[] };
+  features: FrontendFeatures;
+  admin_oidc: boolean;
+};
+
+export type StatsSummary = {
+  total_articles: number;
+  articles_today: number;
+  active_sources: number;
+  last_updated_at?: string | null;
+};
+
+export type PublicSource = {
+  source_domain: string;
+  title?: string | null;
+  site_url?: string | null;
+  favicon_url?: string | null;
+  article_count: number;
+  last_article_at?: string | null;
+};
+
+export type DedupMethodCounts = {
+  intra_batch: number;
+  fingerprint: number;
+  simhash: number;
+  jaccard: number;
+  embedding: number;
+  llm: number;
+  url: number;
+  superseded: number;
+  manual: number;
+  total: number;
+};
+
+export type DedupDay = DedupMethodCounts & {
+  day: string;
+};
+
+export type DedupSourcePair = {
+  duplicate_source: string;
+  canonical_source: string;
+  count: number;
+  llm_count: number;
+};
+
+export type DedupAnalytics = {
+  days: number;
+  totals: DedupMethodCounts;
+  daily: DedupDay[];
+  top_pairs: DedupSourcePair[];
+};
+
+export type SimilarArticle = {
+  article_id: number;
+  title: string;
+  url: string;
+  source_domain: string;
+  published_at: string;
+  score: number;
+  matched_tokens: string[];
+  verdict: 'strict_duplicate' | 'llm_check' | 'distinct';
+  canonical_id: number;
+  same_cluster: boolean;
+};
+
+export type SimilarArticles = {
+  article_id: number;
+  method: string;
+  window_hours: number;
+  scanned: number;
+  strict_threshold: number;
+  llm_threshold: number;
+  items: SimilarArticle[];
+};
+
+export type ArticleCluster = {
+  canonical_id: number;
+  title: string;
+  url: string;
+  source_domain: string;
+  published_at: string;
+  hidden: boolean;
+  size: number; // 含主文章
+  source_domains: string[];
+};
+
+export type ArticleClusterMember = {
+  article_id: number;
+  feed_id?: number | null;
+  title: string;
+  url: string;
+  source_domain: string;
+  published_at: string;
+  fetched_at: string;
+  hidden: boolean;
+  is_canonical: boolean;
+  decision?: string | null; // 并入时的判重依据，主文章为 primary
+  confidence?: number | null;
+};
+
+export type ArticleClusterDetail = {
+  article_id: number;
+  canonical_id: number;
+  items: ArticleClusterMember[]; // 主文章在前
+};
+
+export type DigestItem = {
+  rank: number;
+  article_id?: number | null;
+  title: string;
+  url: string;
+  source_domain: string;
+  published_at: string;
+  click_count: number;
+  cluster_size: number;
+  score: number;
+};
+
+export type Digest = {
+  id: number;
+  week_start: string; // YYYY-MM-DD（周一，UTC）
+  period_start: string;
+  period_end: string;
+  title: string;
+  created_at: string;
+  emailed_at?: string | null;
+  email_attempts: number;
+  items: DigestItem[];
 };